@@ -0,0 +1,93 @@
+//! Vergleich der Allokationen von `Argumente::wert` und `Argumente::wert_mit_geliehenen_werten`
+//! für ein Enum mit vielen Varianten.
+//!
+//! Ausführen mit `cargo bench --bench geliehene_werte`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    ffi::OsString,
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use kommandozeilen_argumente::{Argumente, Beschreibung, NonEmpty, ParseFehler, Sprache};
+use void::Void;
+
+const ANZAHL_VARIANTEN: usize = 1000;
+
+/// Zählt alle Allokationen und die dabei angeforderten Bytes.
+struct ZählenderAllokator;
+
+static ALLOKATIONEN: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for ZählenderAllokator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOKATIONEN.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        // SAFETY: Weiterleitung an den System-Allokator mit unverändertem Layout.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: Weiterleitung an den System-Allokator mit unverändertem Layout.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOKATOR: ZählenderAllokator = ZählenderAllokator;
+
+/// Ein Enum mit vielen Varianten, dargestellt über ihren Index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Variante(usize);
+
+fn beschreibung() -> Beschreibung<'static, Variante> {
+    Beschreibung::neu_mit_sprache("variante", None::<&str>, None, None, Sprache::DEUTSCH)
+}
+
+fn parse(os_string: OsString) -> Result<Variante, ParseFehler<Void>> {
+    os_string
+        .into_string()
+        .map_err(ParseFehler::InvaliderString)?
+        .parse()
+        .map(Variante)
+        .map_err(|_fehler| ParseFehler::InvaliderString(OsString::new()))
+}
+
+fn anzeige(variante: &Variante) -> String {
+    variante.0.to_string()
+}
+
+/// Anzahl der Allokationen und angeforderten Bytes während `f`.
+fn messe<R>(f: impl FnOnce() -> R) -> (usize, usize) {
+    let allokationen = ALLOKATIONEN.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    drop(black_box(f()));
+    (ALLOKATIONEN.load(Ordering::Relaxed) - allokationen, BYTES.load(Ordering::Relaxed) - bytes)
+}
+
+fn main() {
+    let varianten: Vec<Variante> = (0..ANZAHL_VARIANTEN).map(Variante).collect();
+    let (allokationen, bytes) = messe(|| {
+        let mögliche_werte = NonEmpty::from_slice(&varianten);
+        Argumente::<'_, Variante, Void>::wert_mit_sprache(
+            beschreibung(),
+            mögliche_werte,
+            parse,
+            anzeige,
+            Sprache::DEUTSCH,
+        )
+    });
+    println!("wert: {allokationen} Allokationen, {bytes} Bytes");
+    let (allokationen, bytes) = messe(|| {
+        Argumente::<'_, Variante, Void>::wert_mit_geliehenen_werten_und_sprache(
+            beschreibung(),
+            &varianten,
+            parse,
+            anzeige,
+            Sprache::DEUTSCH,
+        )
+    });
+    println!("wert_mit_geliehenen_werten: {allokationen} Allokationen, {bytes} Bytes");
+}
@@ -0,0 +1,95 @@
+//! Laufzeit von `Argumente::parse` für kombinierte Argumente mit vielen Flags und Werten.
+//!
+//! Jedes Argument wird genannt. Verglichen wird die Laufzeit mit dem beim Kombinieren erzeugten
+//! Index der Namen und ohne Index, bei dem jeder Token mit den Namen aller Argumente verglichen
+//! wird.
+//!
+//! Ausführen mit `cargo bench --bench viele_argumente`.
+
+use std::{
+    ffi::OsString,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use kommandozeilen_argumente::{Argumente, Beschreibung, Ergebnis, Sprache};
+use void::Void;
+
+const ANZAHLEN: [usize; 4] = [50, 100, 200, 400];
+const WIEDERHOLUNGEN: u32 = 20;
+
+fn beschreibung(name: String, kurz: Option<String>) -> Beschreibung<'static, usize> {
+    let name: &'static str = Box::leak(name.into_boxed_str());
+    let kurz: Option<&'static str> = kurz.map(|kurz| &*Box::leak(kurz.into_boxed_str()));
+    Beschreibung::neu_mit_sprache(name, kurz, None, None, Sprache::DEUTSCH)
+}
+
+/// Abwechselnd ein Flag- und ein Wert-Argument, jeweils mit Lang- und Kurzname.
+fn viele_argumente(anzahl: usize) -> Argumente<'static, Vec<usize>, Void> {
+    (0..anzahl).fold(Argumente::konstant(Vec::new), |argumente, i| {
+        let kurz = Some(format!("k{i}"));
+        let argument = if i % 2 == 0 {
+            Argumente::flag_mit_sprache(
+                beschreibung(format!("flag{i}"), kurz),
+                usize::from,
+                usize::to_string,
+                Sprache::DEUTSCH,
+            )
+        } else {
+            Argumente::wert_string_mit_sprache(
+                beschreibung(format!("wert{i}"), kurz),
+                None,
+                |s: &str| Ok(s.len()),
+                usize::to_string,
+                Sprache::DEUTSCH,
+            )
+        };
+        Argumente::kombiniere2(
+            |mut werte: Vec<usize>, wert| {
+                werte.push(wert);
+                werte
+            },
+            argumente,
+            argument,
+        )
+    })
+}
+
+/// Jedes zweite Argument über den Langnamen, die anderen über den Kurznamen.
+fn args(anzahl: usize) -> Vec<OsString> {
+    let mut args = Vec::new();
+    for i in 0..anzahl {
+        let kurz = format!("k{i}");
+        match (i % 2 == 0, i % 4 < 2) {
+            (true, true) => args.push(format!("--flag{i}")),
+            (true, false) => args.push(format!("-{kurz}")),
+            (false, true) => args.push(format!("--wert{i}=wert{i}")),
+            (false, false) => args.extend([format!("-{kurz}"), format!("wert{i}")]),
+        }
+    }
+    args.into_iter().map(OsString::from).collect()
+}
+
+fn messe(argumente: &Argumente<'static, Vec<usize>, Void>, args: &[OsString]) -> Duration {
+    let start = Instant::now();
+    for _ in 0..WIEDERHOLUNGEN {
+        match argumente.parse(black_box(args.to_vec()).into_iter()) {
+            (Ergebnis::Wert(_werte), nicht_verwendet) => assert!(nicht_verwendet.is_empty()),
+            res => panic!("Unerwartetes Ergebnis: {:?}", res),
+        }
+    }
+    start.elapsed() / WIEDERHOLUNGEN
+}
+
+fn main() {
+    for anzahl in ANZAHLEN {
+        let args = args(anzahl);
+        let mit_index = messe(&viele_argumente(anzahl), &args);
+        let ohne_index = messe(&viele_argumente(anzahl).ohne_namen_index(), &args);
+        println!(
+            "{anzahl} Argumente: {mit_index:?} mit Index, {ohne_index:?} ohne Index \
+            (Faktor {:.1}) pro parse",
+            ohne_index.as_secs_f64() / mit_index.as_secs_f64()
+        );
+    }
+}
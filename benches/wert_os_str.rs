@@ -0,0 +1,82 @@
+//! Laufzeit-Vergleich von `Argumente::wert` und `Argumente::wert_os_str` für viele Pfade.
+//!
+//! Ausführen mit `cargo bench --bench wert_os_str`.
+
+use std::{
+    borrow::Cow,
+    ffi::{OsStr, OsString},
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use kommandozeilen_argumente::{Argumente, Beschreibung, Ergebnis, ParseFehler, Sprache};
+use void::Void;
+
+const ANZAHL_ARGUMENTE: usize = 200;
+const WIEDERHOLUNGEN: u32 = 20;
+
+fn pfad_beschreibung(i: usize) -> Beschreibung<'static, usize> {
+    let name: &'static str = Box::leak(format!("pfad{i}").into_boxed_str());
+    Beschreibung::neu_mit_sprache(name, None::<&str>, None, None, Sprache::DEUTSCH)
+}
+
+fn anzahl_komponenten(pfad: &OsStr) -> Result<usize, ParseFehler<Void>> {
+    Ok(Path::new(pfad).components().count())
+}
+
+fn viele_pfade(
+    anzahl: usize,
+    erzeuge: impl Fn(usize) -> Argumente<'static, usize, Void>,
+) -> Argumente<'static, Vec<usize>, Void> {
+    (0..anzahl).fold(Argumente::konstant(Vec::new), |argumente, i| {
+        Argumente::kombiniere2(
+            |mut werte: Vec<usize>, wert| {
+                werte.push(wert);
+                werte
+            },
+            argumente,
+            erzeuge(i),
+        )
+    })
+}
+
+fn messe(argumente: &Argumente<'static, Vec<usize>, Void>, args: &[OsString]) -> Duration {
+    let start = Instant::now();
+    for _ in 0..WIEDERHOLUNGEN {
+        match argumente.parse(black_box(args.to_vec()).into_iter()) {
+            (Ergebnis::Wert(werte), nicht_verwendet) => {
+                assert_eq!(werte.len(), args.len());
+                assert!(nicht_verwendet.is_empty());
+            },
+            res => panic!("Unerwartetes Ergebnis: {:?}", res),
+        }
+    }
+    start.elapsed() / WIEDERHOLUNGEN
+}
+
+fn main() {
+    let wert = viele_pfade(ANZAHL_ARGUMENTE, |i| {
+        Argumente::wert_mit_sprache(
+            pfad_beschreibung(i),
+            None,
+            |os_string: OsString| anzahl_komponenten(&os_string),
+            usize::to_string,
+            Sprache::DEUTSCH,
+        )
+    });
+    let wert_os_str = viele_pfade(ANZAHL_ARGUMENTE, |i| {
+        Argumente::wert_os_str_mit_sprache(
+            pfad_beschreibung(i),
+            None,
+            |os_str: Cow<'_, OsStr>| anzahl_komponenten(&os_str),
+            usize::to_string,
+            Sprache::DEUTSCH,
+        )
+    });
+    let args: Vec<OsString> = (0..ANZAHL_ARGUMENTE)
+        .map(|i| OsString::from(format!("--pfad{i}=/ein/etwas/längerer/pfad/zur/datei{i}.txt")))
+        .collect();
+    println!("wert: {:?} pro parse", messe(&wert, &args));
+    println!("wert_os_str: {:?} pro parse", messe(&wert_os_str, &args));
+}
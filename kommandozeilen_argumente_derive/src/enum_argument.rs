@@ -4,10 +4,13 @@ use std::fmt::{self, Display, Formatter};
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{parse2, Attribute, Data, DataEnum, DeriveInput, Fields, Ident, Variant};
+use syn::{parse2, Attribute, Data, DataEnum, DeriveInput, Fields, Ident, LitInt, Variant};
+
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::utility::{
-    crate_name, split_klammer_argumente, Argument, ArgumentWert, Case, SplitArgumenteFehler,
+    crate_name, literal_oder_to_string, split_klammer_argumente, Argument, ArgumentWert, Case,
+    SplitArgumenteFehler, Umbenennung,
 };
 
 #[derive(Debug)]
@@ -33,6 +36,7 @@ pub(crate) enum Fehler {
     DatenVariante { variante: Ident },
     SplitArgumente(SplitArgumenteFehler),
     NichtUnterstützt(Argument),
+    KurzNameMehrdeutig { kurz: String, variante: Ident, andere: Ident },
 }
 
 impl Display for Fehler {
@@ -55,6 +59,9 @@ impl Display for Fehler {
                 write!(f, "Nur Enums mit Unit-Varianten unterstützt, aber {variante} hält Daten.")
             },
             SplitArgumente(fehler) => write!(f, "{fehler}"),
+            KurzNameMehrdeutig { kurz, variante, andere } => {
+                write!(f, "Kurzname \"{kurz}\" von {variante} ist nicht eindeutig, Konflikt mit {andere}.")
+            },
             NichtUnterstützt(Argument { name, wert: KeinWert }) => {
                 write!(f, "Argument nicht unterstützt: {name}")
             },
@@ -83,7 +90,17 @@ impl From<SplitArgumenteFehler> for Fehler {
     }
 }
 
-fn parse_attributes(feld: Option<&Ident>, attrs: Vec<Attribute>) -> Result<Option<Case>, Fehler> {
+/// Über Attribute konfigurierte Einstellungen eines Enums oder einer Variante.
+#[derive(Debug, Default)]
+struct Einstellungen {
+    case: Option<Case>,
+    umbenennung: Option<Umbenennung>,
+    reihenfolge: Option<i64>,
+    kurz: Option<String>,
+    anzeige: bool,
+}
+
+fn parse_attributes(feld: Option<&Ident>, attrs: Vec<Attribute>) -> Result<Einstellungen, Fehler> {
     let mut args = Vec::new();
     for attr in attrs {
         if attr.path.is_ident("kommandozeilen_argumente") {
@@ -95,6 +112,10 @@ fn parse_attributes(feld: Option<&Ident>, attrs: Vec<Attribute>) -> Result<Optio
         }
     }
     let mut case = None;
+    let mut umbenennung = None;
+    let mut reihenfolge = None;
+    let mut kurz = None;
+    let mut anzeige = false;
     for arg in args {
         match arg {
             Argument { name, wert: ArgumentWert::Stream(ts) } if name == "case" => {
@@ -102,10 +123,42 @@ fn parse_attributes(feld: Option<&Ident>, attrs: Vec<Attribute>) -> Result<Optio
                     Fehler::NichtUnterstützt(Argument { name, wert: ArgumentWert::Stream(ts) })
                 })?)
             },
+            Argument { name, wert: ArgumentWert::Stream(ts) }
+                if feld.is_none() && (name == "umbenennen_alle" || name == "rename_all") =>
+            {
+                umbenennung = Some(Umbenennung::parse(&ts).ok_or({
+                    Fehler::NichtUnterstützt(Argument { name, wert: ArgumentWert::Stream(ts) })
+                })?)
+            },
+            Argument { name, wert: ArgumentWert::Stream(ts) }
+                if feld.is_some() && (name == "reihenfolge" || name == "order") =>
+            {
+                let wert = parse2::<LitInt>(ts.clone()).and_then(|lit| lit.base10_parse());
+                reihenfolge = Some(wert.map_err(|_fehler| {
+                    Fehler::NichtUnterstützt(Argument { name, wert: ArgumentWert::Stream(ts) })
+                })?)
+            },
+            Argument { name, wert: ArgumentWert::Stream(ts) }
+                if feld.is_some() && (name == "kurz" || name == "short") =>
+            {
+                let string = literal_oder_to_string(&ts);
+                if string.graphemes(true).count() != 1 {
+                    return Err(Fehler::NichtUnterstützt(Argument {
+                        name,
+                        wert: ArgumentWert::Stream(ts),
+                    }));
+                }
+                kurz = Some(string)
+            },
+            Argument { name, wert: ArgumentWert::KeinWert }
+                if feld.is_none() && (name == "anzeige" || name == "display") =>
+            {
+                anzeige = true
+            },
             _ => return Err(Fehler::NichtUnterstützt(arg)),
         }
     }
-    Ok(case)
+    Ok(Einstellungen { case, umbenennung, reihenfolge, kurz, anzeige })
 }
 
 pub(crate) fn derive_enum_argument(input: TokenStream) -> Result<TokenStream, Fehler> {
@@ -122,20 +175,90 @@ pub(crate) fn derive_enum_argument(input: TokenStream) -> Result<TokenStream, Fe
     if !generics.params.is_empty() || has_where_clause {
         return Err(Generics { anzahl: generics.params.len(), where_clause: has_where_clause });
     }
-    let standard_case = parse_attributes(None, attrs)?;
-    let mut varianten = Vec::new();
-    let mut cases = Vec::new();
-    for Variant { ident, fields, attrs, .. } in variants {
+    let Einstellungen { case: standard_case, umbenennung, reihenfolge: _, kurz: _, anzeige } =
+        parse_attributes(None, attrs)?;
+    let mut sortierte_varianten = Vec::new();
+    for (index, Variant { ident, fields, attrs, .. }) in variants.into_iter().enumerate() {
         if let Fields::Unit = fields {
-            let case = parse_attributes(Some(&ident), attrs)?;
-            cases.push(case.or(standard_case).unwrap_or_default());
-            varianten.push(ident);
+            let Einstellungen { case, umbenennung: _, reihenfolge, kurz, anzeige: _ } =
+                parse_attributes(Some(&ident), attrs)?;
+            let case = case.or(standard_case).unwrap_or_default();
+            // Ohne explizite Reihenfolge wird der Index in der Deklaration verwendet.
+            let reihenfolge = reihenfolge.unwrap_or(index as i64);
+            sortierte_varianten.push((reihenfolge, ident, case, kurz));
         } else {
             return Err(DatenVariante { variante: ident });
         }
     }
-    let varianten_str: Vec<_> = varianten.iter().map(ToString::to_string).collect();
+    sortierte_varianten.sort_by_key(|(reihenfolge, _ident, _case, _kurz)| *reihenfolge);
+    let mut varianten = Vec::new();
+    let mut cases = Vec::new();
+    let mut kurznamen = Vec::new();
+    for (_reihenfolge, ident, case, kurz) in sortierte_varianten {
+        varianten.push(ident);
+        cases.push(case);
+        kurznamen.push(kurz);
+    }
+    let varianten_str: Vec<_> = varianten
+        .iter()
+        .map(|variante| {
+            let string = variante.to_string();
+            match umbenennung {
+                Some(umbenennung) => umbenennung.anwenden(&string),
+                None => string,
+            }
+        })
+        .collect();
+    // Ein Kurzname darf weder mit dem Namen, noch dem Kurznamen einer anderen Variante übereinstimmen.
+    let gleich = |a: &str, b: &str, case_a: Case, case_b: Case| {
+        if matches!(case_a, Case::Sensitive) && matches!(case_b, Case::Sensitive) {
+            a == b
+        } else {
+            a.to_lowercase() == b.to_lowercase()
+        }
+    };
+    for (index, (variante, kurz)) in varianten.iter().zip(&kurznamen).enumerate() {
+        let Some(kurz) = kurz else { continue };
+        for (anderer_index, andere) in varianten.iter().enumerate() {
+            if index == anderer_index {
+                continue;
+            }
+            let case = cases[index];
+            let anderer_case = cases[anderer_index];
+            let konflikt = gleich(kurz, &varianten_str[anderer_index], case, anderer_case)
+                || kurznamen[anderer_index]
+                    .as_ref()
+                    .is_some_and(|anderer_kurz| gleich(kurz, anderer_kurz, case, anderer_case));
+            if konflikt {
+                return Err(KurzNameMehrdeutig {
+                    kurz: kurz.clone(),
+                    variante: variante.clone(),
+                    andere: andere.clone(),
+                });
+            }
+        }
+    }
+    let kurz_bedingungen = kurznamen.iter().zip(&cases).map(|(kurz, case)| {
+        kurz.as_ref()
+            .map(|kurz| quote!(|| #crate_name::unicode::Normalisiert::neu(#kurz).eq(string, #case)))
+    });
+    let erlaubte_varianten = varianten_str.join(", ");
+    // Nur auf Wunsch, eine eigene Display-Implementierung würde sonst kollidieren.
+    let display_instance = anzeige.then(|| {
+        quote!(
+            impl std::fmt::Display for #ident {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(match self {
+                        #(Self::#varianten => #varianten_str),*
+                    })
+                }
+            }
+        )
+    });
     let instance = quote!(
+        #display_instance
+
+
         impl #crate_name::EnumArgument for #ident {
             fn varianten() -> Vec<Self> {
                 vec![#(Self::#varianten),*]
@@ -145,14 +268,17 @@ pub(crate) fn derive_enum_argument(input: TokenStream) -> Result<TokenStream, Fe
                 if let Some(string) = arg.to_str() {
                     #(
                         if #crate_name::unicode::Normalisiert::neu(#varianten_str).eq(string, #cases)
+                            #kurz_bedingungen
                         {
                             Ok(Self::#varianten)
                         } else
                     )*
                     {
-                        Err(#crate_name::ParseFehler::ParseFehler(
-                            format!("Unbekannte Variante: {}", string))
-                        )
+                        Err(#crate_name::ParseFehler::ParseFehler(format!(
+                            "Unbekannte Variante \"{}\", erlaubt sind: {}",
+                            string,
+                            #erlaubte_varianten
+                        )))
                     }
                 } else {
                     Err(#crate_name::ParseFehler::InvaliderString(arg))
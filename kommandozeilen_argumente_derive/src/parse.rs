@@ -2,14 +2,17 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use proc_macro2::{TokenStream, TokenTree};
-use quote::{quote, ToTokens};
-use syn::{parse2, Data, DataStruct, DeriveInput, Field, Ident, LitStr};
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse2, Data, DataStruct, DeriveInput, Field, GenericArgument, Ident, Index, PathArguments,
+    Type, TypePath,
+};
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::utility::{
-    crate_name, genau_eines, split_klammer_argumente, Argument, ArgumentWert, Case,
-    SplitArgumenteFehler,
+    crate_name, genau_eines, literal_oder_to_string, split_klammer_argumente, Argument,
+    ArgumentWert, Case, SplitArgumenteFehler,
 };
 
 #[derive(Debug, Clone)]
@@ -48,21 +51,52 @@ enum FeldArgument {
     EnumArgument,
     FromStr,
     Parse,
+    Mehrfach,
+}
+
+/// Hat `ty` einen bekannten Standard-Wert für `standard_werte`?
+/// Das ist der Fall für `bool`, `String` und `Option<T>`, jeweils mit ihrem [Default]-Wert.
+fn hat_bekannten_standard(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => path
+            .segments
+            .last()
+            .is_some_and(|segment| ["bool", "String", "Option"].iter().any(|name| segment.ident == name)),
+        _ => false,
+    }
+}
+
+/// Element-Typ `T`, falls `ty` die Form `Vec<T>` hat.
+fn vec_element_typ(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(TypePath { qself: None, path }) => path.segments.last()?,
+        _ => return None,
+    };
+    match &segment.arguments {
+        PathArguments::AngleBracketed(argumente) if segment.ident == "Vec" => {
+            match genau_eines(argumente.args.iter()) {
+                Ok(GenericArgument::Type(element_typ)) => Some(element_typ),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
 }
 
 fn erstelle_version_methode(
     feste_sprache: Option<Sprache>,
     namen: Option<(LangPräfix, TokenStream, KurzPräfix, TokenStream)>,
-) -> impl FnOnce(TokenStream, Sprache) -> TokenStream {
+) -> impl Fn(TokenStream, Sprache) -> TokenStream {
     let crate_name = crate_name();
     move |item, standard_sprache| {
-        let sprache = feste_sprache.unwrap_or(standard_sprache);
+        let sprache = feste_sprache.clone().unwrap_or(standard_sprache);
         let sprache_ts = sprache.token_stream();
         let lang_standard = quote!(#sprache_ts.version_lang);
         let kurz_standard = quote!(#sprache_ts.version_kurz);
-        let (lang_präfix, lang_namen, kurz_präfix, kurz_namen) = namen.unwrap_or_else(|| {
-            (LangPräfix::default(), lang_standard, KurzPräfix::default(), kurz_standard)
-        });
+        let (lang_präfix, lang_namen, kurz_präfix, kurz_namen) =
+            namen.clone().unwrap_or_else(|| {
+                (LangPräfix::default(), lang_standard, KurzPräfix::default(), kurz_standard)
+            });
         let lang_präfix = lang_präfix.token_stream(&sprache);
         let kurz_präfix = kurz_präfix.token_stream(&sprache);
         let beschreibung = quote!(
@@ -190,8 +224,8 @@ impl ToTokens for ProgrammBeschreibung {
     }
 }
 
-struct ErstelleHilfe(Option<Box<dyn FnOnce(TokenStream) -> TokenStream>>);
-struct ErstelleVersion(Option<Box<dyn FnOnce(TokenStream, Sprache) -> TokenStream>>);
+struct ErstelleHilfe(Option<Box<dyn Fn(TokenStream) -> TokenStream>>);
+struct ErstelleVersion(Option<Box<dyn Fn(TokenStream, Sprache) -> TokenStream>>);
 
 macro_rules! create_newtype {
     ($($name: ident : $type: ty),* $(,)?) => {
@@ -213,6 +247,21 @@ create_newtype! {
     Standard: TokenStream,
 }
 
+impl Standard {
+    /// Der über `standard: <wert>` explizit angegebene Wert, ohne das umgebende `Some`.
+    fn explizit(&self) -> Option<TokenStream> {
+        let mut token_trees = self.0.clone().into_iter();
+        match (token_trees.next(), token_trees.next(), token_trees.next()) {
+            (Some(TokenTree::Ident(some)), Some(TokenTree::Group(gruppe)), None)
+                if some == "Some" && gruppe.delimiter() == Delimiter::Parenthesis =>
+            {
+                Some(gruppe.stream())
+            },
+            _ => None,
+        }
+    }
+}
+
 macro_rules! vergleich_typen {
     ($($name: ident ($sprache_ident: ident)),* $(,)?) => {
         $(
@@ -235,10 +284,10 @@ macro_rules! vergleich_typen {
                         quote!(#sprache_ts.$sprache_ident)
                     };
                     let case = self.case.unwrap_or_default();
-                    quote!(#crate_name::unicode::Vergleich {
-                        string: #crate_name::unicode::Normalisiert::neu(#string),
-                        case: #case,
-                    })
+                    quote!(#crate_name::unicode::Vergleich::neu(
+                        #crate_name::unicode::Normalisiert::neu(#string),
+                        #case,
+                    ))
                 }
             }
         )*
@@ -265,6 +314,51 @@ impl Default for LangNamen {
     }
 }
 
+impl LangNamen {
+    /// Gebe den ersten Langnamen zurück, der mit Leerzeichen oder `lang_präfix` beginnt.
+    /// Solche Namen können beim Parsen nie gefunden werden.
+    /// Ein leerer `lang_präfix` ist erlaubt, z.B. für Unterbefehle im Stil `start`.
+    /// Ohne explizite Namen wird `standard` geprüft.
+    fn ungültiger_name(&self, standard: &str, lang_präfix: Option<&str>) -> Option<String> {
+        let (head, tail) = match &self.namen {
+            Some((head, tail)) => (head.as_str(), tail.as_slice()),
+            None => (standard, &[][..]),
+        };
+        std::iter::once(head).chain(tail.iter().map(String::as_str)).find_map(|name| {
+            let ungültig = name.starts_with(char::is_whitespace)
+                || lang_präfix
+                    .is_some_and(|präfix| !präfix.is_empty() && name.starts_with(präfix));
+            ungültig.then(|| name.to_owned())
+        })
+    }
+
+    /// Erzeuge die Langnamen und gebe den ersten davon zurück.
+    /// Ohne explizite Namen wird `standard` verwendet.
+    fn to_ts<'s>(&'s self, standard: &'s str) -> (TokenStream, &'s str) {
+        let crate_name = crate_name();
+        let (head, tail) = match &self.namen {
+            Some((head, tail)) => (head.as_str(), tail.as_slice()),
+            None => (standard, &[][..]),
+        };
+        let ts = match self.case {
+            Some(case) => quote!(
+                #crate_name::NonEmpty {
+                    head: (#head, #case),
+                    tail: vec![#((#tail, #case)),*]
+                }
+            ),
+            None if self.namen.is_some() => quote!(
+                #crate_name::NonEmpty {
+                    head: #head,
+                    tail: vec![#(#tail),*]
+                }
+            ),
+            None => quote!(#head),
+        };
+        (ts, head)
+    }
+}
+
 #[derive(Debug)]
 enum KurzNamenEnum {
     Keiner,
@@ -278,6 +372,26 @@ struct KurzNamen {
     case: Option<Case>,
 }
 
+#[derive(Debug, Default)]
+struct Aus {
+    name: Option<String>,
+    trenner: Option<String>,
+}
+
+struct Gruppe {
+    name: String,
+    ident: Ident,
+    index: usize,
+    felder: Vec<Type>,
+    feld_idents: Vec<Ident>,
+    trenner: Option<String>,
+    hilfe: Vec<String>,
+    lang_präfix: TokenStream,
+    kurz_präfix: TokenStream,
+    wert_infix: TokenStream,
+    meta_var: TokenStream,
+}
+
 impl Default for KurzNamen {
     fn default() -> Self {
         KurzNamen { namen: KurzNamenEnum::Keiner, case: None }
@@ -304,34 +418,21 @@ impl KurzNamen {
         let (vec, case) = self.to_vec(lang_name, lang_namen_case);
         if vec.is_empty() {
             quote!(None::<&str>)
+        } else if let Some(case) = case {
+            quote!(vec![#((#vec, #case)),*])
         } else {
-            let crate_name = crate_name();
-            if let Some(case) = case {
-                quote!(vec![#(#crate_name::unicode::Vergleich {
-                    string: #vec,
-                    case: #case,
-                }),*])
-            } else {
-                quote!(vec![#(#vec),*])
-            }
+            quote!(vec![#(#vec),*])
         }
     }
 }
 
-fn literal_oder_to_string(token_stream: &TokenStream) -> String {
-    if let Ok(lit_str) = parse2::<LitStr>(token_stream.clone()) {
-        lit_str.value()
-    } else {
-        token_stream.to_string()
-    }
-}
-
 fn parse_wert_arg(
     args: Vec<Argument>,
     mut sprache: Option<&mut Option<Sprache>>,
     mut erstelle_hilfe: Option<&mut ErstelleHilfe>,
     mut programm_beschreibung: Option<&mut ProgrammBeschreibung>,
     mut erstelle_version: Option<&mut ErstelleVersion>,
+    mut standard_werte: Option<&mut bool>,
     mut lang_präfix: Option<&mut LangPräfix>,
     mut lang_namen: Option<&mut LangNamen>,
     mut kurz_präfix: Option<&mut KurzPräfix>,
@@ -342,9 +443,9 @@ fn parse_wert_arg(
     mut meta_var: Option<&mut Option<MetaVar>>,
     mut standard: Option<&mut Standard>,
     mut feld_argument: Option<&mut FeldArgument>,
+    mut aus: Option<&mut Aus>,
 ) -> Result<(), ErstelleFehler> {
     use ParseWertFehler::*;
-    let crate_name = crate_name();
     macro_rules! setze_argument {
         ($mut_var: expr, $wert: expr, $sub_arg: expr) => {
             if let Some(var) = $mut_var.as_mut() {
@@ -410,6 +511,9 @@ fn parse_wert_arg(
                     )))),
                     Argument { name, wert }
                 ),
+                "standard_werte" | "default_values" => {
+                    setze_argument!(standard_werte, true, Argument { name, wert })
+                },
                 "kurz" | "short" => {
                     setze_argument_namen!(kurz_namen, KurzNamenEnum::Auto, Argument { name, wert })
                 },
@@ -419,6 +523,9 @@ fn parse_wert_arg(
                 "FromStr" => {
                     setze_argument!(feld_argument, FeldArgument::FromStr, Argument { name, wert })
                 },
+                "mehrfach" | "multiple" => {
+                    setze_argument!(feld_argument, FeldArgument::Mehrfach, Argument { name, wert })
+                },
                 "benötigt" | "required" => {
                     setze_argument!(standard, Standard(quote!(None)), Argument { name, wert })
                 },
@@ -514,6 +621,18 @@ fn parse_wert_arg(
                     KurzNamenEnum::Namen(vec![literal_oder_to_string(&ts)]),
                     Argument { name, wert: ArgumentWert::Stream(ts) }
                 ),
+                "aus" | "from" => setze_argument_feld!(
+                    aus,
+                    name,
+                    Some(literal_oder_to_string(&ts)),
+                    Argument { name, wert: ArgumentWert::Stream(ts) }
+                ),
+                "trenner" | "separator" => setze_argument_feld!(
+                    aus,
+                    trenner,
+                    Some(literal_oder_to_string(&ts)),
+                    Argument { name, wert: ArgumentWert::Stream(ts) }
+                ),
                 "case" => {
                     let case = if let Some(case) = Case::parse(&ts) {
                         case
@@ -553,6 +672,7 @@ fn parse_wert_arg(
                             None,
                             $programm_beschreibung,
                             None,
+                            None,
                             Some(&mut sub_lang_präfix),
                             Some(&mut sub_lang),
                             Some(&mut sub_kurz_präfix),
@@ -563,6 +683,7 @@ fn parse_wert_arg(
                             None,
                             None,
                             None,
+                            None,
                         );
                         if let Err(erstelle_fehler) = result {
                             return Err(Box::new(|arg_name| match erstelle_fehler(arg_name) {
@@ -570,28 +691,17 @@ fn parse_wert_arg(
                                     arg_name,
                                     argument: Argument {
                                         name,
-                                        wert: ArgumentWert::Unterargument(vec![argument])
-                                    }
+                                        wert: ArgumentWert::Unterargument(vec![argument]),
+                                    },
                                 },
                                 fehler => fehler,
-                            }))
-                        };
-                        let (sub_lang_ts, erster) = match sub_lang.namen.as_ref() {
-                            Some((head, tail)) => (
-                                quote!(
-                                    #crate_name::NonEmpty {
-                                        head: #head,
-                                        tail: vec![#(#tail),*]
-                                    }
-                                ),
-                                head,
-                            ),
-                            None => (quote!(#name), &name),
+                            }));
                         };
+                        let (sub_lang_ts, erster) = sub_lang.to_ts(&name);
                         let sub_kurz_ts = sub_kurz.to_vec_ts(erster, sub_lang.case);
                         let $präfix_und_namen =
                             (sub_lang_präfix, sub_lang_ts, sub_kurz_präfix, sub_kurz_ts);
-                    }
+                    };
                 }
                 match (name.as_str(), erstelle_hilfe.as_mut(), erstelle_version.as_mut()) {
                     ("hilfe" | "help", Some(erstelle_hilfe), _) => {
@@ -727,6 +837,9 @@ pub(crate) enum Fehler {
     Generics { anzahl: usize, where_clause: bool },
     FeldOhneName,
     LeererFeldName(Ident),
+    UngültigerLangName { feld: Ident, name: String, lang_präfix: Option<String> },
+    MehrfachOhneVec { feld: Ident, typ: TokenStream },
+    StandardWertFehlt(Ident),
 }
 
 impl Display for Fehler {
@@ -748,6 +861,23 @@ impl Display for Fehler {
             },
             FeldOhneName => f.write_str("Nur benannte Felder unterstützt."),
             LeererFeldName(ident) => write!(f, "Benanntes Feld mit leerem Namen: {ident}"),
+            UngültigerLangName { feld, name, lang_präfix } => {
+                write!(f, "Feld {feld} mit ungültigem Langnamen \"{name}\": ")?;
+                f.write_str("Langnamen dürfen nicht mit Leerzeichen")?;
+                if let Some(lang_präfix) = lang_präfix {
+                    write!(f, " oder dem Präfix \"{lang_präfix}\"")?;
+                }
+                f.write_str(" beginnen.")
+            },
+            MehrfachOhneVec { feld, typ } => {
+                write!(
+                    f,
+                    "Feld {feld} mit Attribut mehrfach benötigt Typ Vec<T>, aber {typ} bekommen."
+                )
+            },
+            StandardWertFehlt(feld) => {
+                write!(f, "Feld {feld} ohne Standard-Wert, benötigt für standard_werte.")
+            },
         }
     }
 }
@@ -808,9 +938,13 @@ pub(crate) fn derive_parse(input: TokenStream) -> Result<TokenStream, Fehler> {
     // CARGO_BIN_NAME — The name of the binary that is currently being compiled (if it is a binary). This name does not include any file extension, such as .exe
     let mut erstelle_version = ErstelleVersion(None);
     let mut erstelle_hilfe = ErstelleHilfe(None);
+    let mut standard_werte = false;
     let mut sprache = None;
     let mut lang_präfix = LangPräfix::default();
     let mut kurz_präfix = KurzPräfix::default();
+    // Nur die Groß-/Kleinschreibung kann für alle Felder festgelegt werden.
+    let mut lang_namen = LangNamen::default();
+    let mut kurz_namen = KurzNamen::default();
     let mut invertiere_präfix = InvertierePräfix::default();
     let mut invertiere_infix = InvertiereInfix::default();
     let mut wert_infix = WertInfix::default();
@@ -823,45 +957,59 @@ pub(crate) fn derive_parse(input: TokenStream) -> Result<TokenStream, Fehler> {
             Some(&mut erstelle_hilfe),
             None,
             Some(&mut erstelle_version),
+            Some(&mut standard_werte),
             Some(&mut lang_präfix),
-            None,
+            Some(&mut lang_namen),
             Some(&mut kurz_präfix),
-            None,
+            Some(&mut kurz_namen),
             Some(&mut invertiere_präfix),
             Some(&mut invertiere_infix),
             Some(&mut wert_infix),
             Some(&mut meta_var),
             None,
             None,
+            None,
         ),
         None
     );
+    for (name, namen_gesetzt) in [
+        ("lang", lang_namen.namen.is_some()),
+        ("kurz", !matches!(kurz_namen.namen, KurzNamenEnum::Keiner)),
+    ] {
+        if namen_gesetzt {
+            let argument = Argument { name: name.to_owned(), wert: ArgumentWert::KeinWert };
+            return Err(ParseWert(ParseWertFehler::NichtUnterstützt { arg_name: None, argument }));
+        }
+    }
     let sprache = sprache.unwrap_or(English);
-    let meta_var = if let Some(meta_var) = meta_var {
-        quote!(#meta_var)
-    } else {
-        let sprache_ts = sprache.token_stream();
-        quote!(#sprache_ts.meta_var)
-    };
+    let sprache_ts = sprache.token_stream();
+    let sprache_meta_var = quote!(#sprache_ts.meta_var);
+    let meta_var = meta_var.map(|meta_var| quote!(#meta_var));
     let mut tuples = Vec::new();
+    let mut feld_inits = Vec::new();
+    let mut standard_inits = Vec::new();
+    let mut alle_felder = Vec::new();
+    let mut gruppen: Vec<Gruppe> = Vec::new();
     for field in fields {
-        let Field { attrs, ident, .. } = field;
+        let Field { attrs, ident, ty, .. } = field;
         let mut hilfe_lits = Vec::new();
         let ident = ident.ok_or(FeldOhneName)?;
         let ident_str = ident.to_string();
         if ident_str.is_empty() {
             return Err(LeererFeldName(ident));
         }
-        let mut lang = quote!(#ident_str);
-        let mut kurz = quote!(None::<&str>);
+        let mut feld_lang_namen = LangNamen { namen: None, case: lang_namen.case };
+        let mut feld_kurz_namen = KurzNamen { namen: KurzNamenEnum::Keiner, case: kurz_namen.case };
         let mut feld_lang_präfix = lang_präfix.clone();
         let mut feld_kurz_präfix = kurz_präfix.clone();
         let mut feld_invertiere_präfix = invertiere_präfix.clone();
         let mut feld_invertiere_infix = invertiere_infix.clone();
         let mut feld_wert_infix = wert_infix.clone();
         let mut feld_meta_var = None;
-        let mut standard = Standard(quote!(#crate_name::parse::ParseArgument::standard()));
+        // Ein leerer Standard-Wert wird abhängig vom FeldArgument ersetzt.
+        let mut standard = Standard(TokenStream::new());
         let mut feld_argument = FeldArgument::EnumArgument;
+        let mut aus = Aus::default();
         for attr in attrs {
             if attr.path.is_ident("doc") {
                 let args_str = attr.tokens.to_string();
@@ -876,8 +1024,6 @@ pub(crate) fn derive_parse(input: TokenStream) -> Result<TokenStream, Fehler> {
             } else if attr.path.is_ident("kommandozeilen_argumente") {
                 let mut feld_args = Vec::new();
                 split_klammer_argumente(vec![ident.to_string()], &mut feld_args, attr.tokens)?;
-                let mut lang_namen = LangNamen::default();
-                let mut kurz_namen = KurzNamen::default();
                 unwrap_or_call_return!(
                     parse_wert_arg(
                         feld_args,
@@ -885,47 +1031,76 @@ pub(crate) fn derive_parse(input: TokenStream) -> Result<TokenStream, Fehler> {
                         None,
                         None,
                         None,
+                        None,
                         Some(&mut feld_lang_präfix),
-                        Some(&mut lang_namen),
+                        Some(&mut feld_lang_namen),
                         Some(&mut feld_kurz_präfix),
-                        Some(&mut kurz_namen),
+                        Some(&mut feld_kurz_namen),
                         Some(&mut feld_invertiere_präfix),
                         Some(&mut feld_invertiere_infix),
                         Some(&mut feld_wert_infix),
                         Some(&mut feld_meta_var),
                         Some(&mut standard),
                         Some(&mut feld_argument),
+                        Some(&mut aus),
                     ),
                     Some(ident_str)
                 );
-                let erster = match lang_namen.namen.as_ref() {
-                    Some((head, tail)) => {
-                        lang = quote!(
-                            #crate_name::NonEmpty {
-                                head: #head,
-                                tail: vec![#(#tail),*]
-                            }
-                        );
-                        head
-                    },
-                    None => {
-                        lang = quote!(#ident_str);
-                        &ident_str
-                    },
-                };
-                kurz = kurz_namen.to_vec_ts(erster, lang_namen.case);
             }
         }
+        let lang_präfix_str = match (&feld_lang_präfix.string, &sprache) {
+            (Some(string), _) => Some(string.as_str()),
+            (None, Deutsch | English) => Some("--"),
+            (None, Sprache::TokenStream(_)) => None,
+        };
+        if let Some(name) = feld_lang_namen.ungültiger_name(&ident_str, lang_präfix_str) {
+            let lang_präfix =
+                lang_präfix_str.filter(|präfix| !präfix.is_empty()).map(str::to_owned);
+            return Err(UngültigerLangName { feld: ident, name, lang_präfix });
+        }
+        let (lang, erster) = feld_lang_namen.to_ts(&ident_str);
+        let kurz = feld_kurz_namen.to_vec_ts(erster, feld_lang_namen.case);
+        if standard_werte {
+            let standard_init = match feld_argument {
+                FeldArgument::Parse => quote!(<#ty>::standard_werte()),
+                FeldArgument::Mehrfach if standard.0.is_empty() => quote!(Vec::new()),
+                _ => match standard.explizit() {
+                    Some(wert) => wert,
+                    None if standard.0.is_empty() && hat_bekannten_standard(&ty) => {
+                        quote!(<#ty as ::std::default::Default>::default())
+                    },
+                    None => return Err(StandardWertFehlt(ident)),
+                },
+            };
+            standard_inits.push(quote!(#ident: #standard_init));
+        }
+        if standard.0.is_empty() {
+            standard = match feld_argument {
+                // Ohne Werte wird eine leere Liste zurückgegeben.
+                FeldArgument::Mehrfach => Standard(quote!(None)),
+                _ => Standard(quote!(#crate_name::parse::ParseArgument::standard())),
+            };
+        }
         let feld_lang_präfix = feld_lang_präfix.token_stream(&sprache);
         let feld_kurz_präfix = feld_kurz_präfix.token_stream(&sprache);
         let feld_invertiere_präfix = feld_invertiere_präfix.token_stream(&sprache);
         let feld_invertiere_infix = feld_invertiere_infix.token_stream(&sprache);
         let feld_wert_infix = feld_wert_infix.token_stream(&sprache);
-        let feld_meta_var = if let Some(MetaVar(string)) = feld_meta_var {
-            quote!(#string)
+        let explizite_meta_var = if let Some(MetaVar(string)) = feld_meta_var {
+            Some(quote!(#string))
         } else {
             meta_var.clone()
         };
+        let feld_meta_var = if let Some(meta_var) = &explizite_meta_var {
+            meta_var.clone()
+        } else if let FeldArgument::EnumArgument = feld_argument {
+            quote!(
+                <#ty as #crate_name::ParseArgument>::meta_var_standard(#sprache_ts)
+                    .unwrap_or(#sprache_meta_var)
+            )
+        } else {
+            sprache_meta_var.clone()
+        };
         let mut hilfe_string = String::new();
         for teil_string in hilfe_lits {
             if !hilfe_string.is_empty() {
@@ -933,76 +1108,270 @@ pub(crate) fn derive_parse(input: TokenStream) -> Result<TokenStream, Fehler> {
             }
             hilfe_string.push_str(&teil_string);
         }
+        if let Some(name) = aus.name {
+            // Mehrere Felder lesen aus einem gemeinsamen Wert-Argument.
+            let gruppe = if let Some(gruppe) = gruppen.iter_mut().find(|gruppe| gruppe.name == name)
+            {
+                gruppe
+            } else {
+                let gruppe_ident = format_ident!("aus_{}", gruppen.len());
+                tuples.push((gruppe_ident.clone(), TokenStream::new()));
+                gruppen.push(Gruppe {
+                    name,
+                    ident: gruppe_ident,
+                    index: tuples.len() - 1,
+                    felder: Vec::new(),
+                    feld_idents: Vec::new(),
+                    trenner: None,
+                    hilfe: Vec::new(),
+                    lang_präfix: feld_lang_präfix,
+                    kurz_präfix: feld_kurz_präfix,
+                    wert_infix: feld_wert_infix,
+                    meta_var: explizite_meta_var.unwrap_or_else(|| sprache_meta_var.clone()),
+                });
+                gruppen.last_mut().expect("Gruppe wurde gerade hinzugefügt.")
+            };
+            if standard_werte {
+                return Err(StandardWertFehlt(ident));
+            }
+            let gruppe_ident = &gruppe.ident;
+            let feld_index = Index::from(gruppe.felder.len());
+            feld_inits.push(quote!(#ident: #gruppe_ident.#feld_index));
+            alle_felder.push(ident.clone());
+            gruppe.felder.push(ty);
+            gruppe.feld_idents.push(ident);
+            gruppe.trenner = aus.trenner.or(gruppe.trenner.take());
+            if !hilfe_string.is_empty() {
+                gruppe.hilfe.push(hilfe_string);
+            }
+            continue;
+        }
         let hilfe = if hilfe_string.is_empty() {
             quote!(None::<&str>)
         } else {
             quote!(Some(#hilfe_string))
         };
-        let erstelle_beschreibung = quote!(
+        let element_typ = match (&feld_argument, vec_element_typ(&ty)) {
+            (FeldArgument::Mehrfach, None) => {
+                return Err(MehrfachOhneVec { feld: ident, typ: ty.to_token_stream() })
+            },
+            (_, element_typ) => element_typ,
+        };
+        // `standard` ist der Standard-Wert des Feldes,
+        // `argumente` wird für geglättete Felder verwendet.
+        let erstelle_args = |standard: &TokenStream, argumente: TokenStream| {
+            let erstelle_beschreibung = quote!(
+                let beschreibung = #crate_name::Beschreibung::neu(
+                    #feld_lang_präfix,
+                    #lang,
+                    #feld_kurz_präfix,
+                    #kurz,
+                    #hilfe,
+                    #standard,
+                );
+            );
+            match (&feld_argument, &element_typ) {
+                (FeldArgument::EnumArgument, _) => {
+                    quote!({
+                        #erstelle_beschreibung
+                        #crate_name::ParseArgument::argumente_allgemein(
+                            beschreibung,
+                            #feld_invertiere_präfix,
+                            #feld_invertiere_infix,
+                            #feld_wert_infix,
+                            #feld_meta_var,
+                            #sprache_ts,
+                        )
+                    })
+                },
+                (FeldArgument::FromStr, _) => {
+                    quote!({
+                        #erstelle_beschreibung
+                        #crate_name::Argumente::wert_from_str_display(
+                            beschreibung,
+                            #feld_wert_infix,
+                            #feld_meta_var,
+                            None,
+                        )
+                    })
+                },
+                (FeldArgument::Parse, _) => argumente,
+                (FeldArgument::Mehrfach, element_typ) => {
+                    quote!({
+                        #erstelle_beschreibung
+                        #crate_name::Argumente::wert_mehrfach(
+                            beschreibung,
+                            #feld_wert_infix,
+                            #feld_meta_var,
+                            #crate_name::Mehrfach::Anhängen,
+                            #sprache_ts.mehrfach_genannt,
+                            |os_string| {
+                                let string = os_string
+                                    .into_string()
+                                    .map_err(#crate_name::ParseFehler::InvaliderString)?;
+                                <#element_typ as ::std::str::FromStr>::from_str(&string).map_err(
+                                    |fehler| {
+                                        #crate_name::ParseFehler::ParseFehler(fehler.to_string())
+                                    },
+                                )
+                            },
+                            ToString::to_string,
+                        )
+                    })
+                },
+            }
+        };
+        // Das Feld enthält den Wert aus `standards`, sofern vorhanden.
+        let standard = standard.0;
+        let args = erstelle_args(
+            &quote!(match #ident {
+                Some(standard) => Some(standard),
+                None => #standard,
+            }),
+            quote!(match #ident {
+                Some(standards) => {
+                    #crate_name::Parse::kommandozeilen_argumente_mit_standards(standards)
+                },
+                None => #crate_name::Parse::kommandozeilen_argumente(),
+            }),
+        );
+        feld_inits.push(quote!(#ident));
+        alle_felder.push(ident.clone());
+        tuples.push((ident, args));
+    }
+    for Gruppe {
+        name,
+        ident: _,
+        index,
+        felder,
+        feld_idents,
+        trenner,
+        hilfe,
+        lang_präfix,
+        kurz_präfix,
+        wert_infix,
+        meta_var,
+    } in gruppen
+    {
+        let anzahl = felder.len();
+        let trenner = trenner.unwrap_or_else(|| ",".to_owned());
+        let hilfe = if hilfe.is_empty() {
+            quote!(None::<&str>)
+        } else {
+            let hilfe_string = hilfe.join(" ");
+            quote!(Some(#hilfe_string))
+        };
+        let werte: Vec<_> = (0..anzahl).map(|index| format_ident!("wert_{index}")).collect();
+        let erstelle_args = |standard: TokenStream| {
+            quote!({
             let beschreibung = #crate_name::Beschreibung::neu(
-                #feld_lang_präfix,
-                #lang,
-                #feld_kurz_präfix,
-                #kurz,
+                #lang_präfix,
+                #name,
+                #kurz_präfix,
+                None::<&str>,
                 #hilfe,
                 #standard,
             );
-        );
-        let erstelle_args = match feld_argument {
-            FeldArgument::EnumArgument => {
-                quote!({
-                    #erstelle_beschreibung
-                    #crate_name::ParseArgument::argumente(
-                        beschreibung,
-                        #feld_invertiere_präfix,
-                        #feld_invertiere_infix,
-                        #feld_wert_infix,
-                        #feld_meta_var
-                    )
-                })
-            },
-            FeldArgument::FromStr => {
-                quote!({
-                    #erstelle_beschreibung
-                    #crate_name::Argumente::wert_from_str_display(
-                        beschreibung,
-                        #feld_wert_infix,
-                        #feld_meta_var,
-                        None,
-                    )
-                })
-            },
-            FeldArgument::Parse => {
-                quote!(#crate_name::Parse::kommandozeilen_argumente())
-            },
+            #crate_name::Argumente::wert(
+                beschreibung,
+                #wert_infix,
+                #meta_var,
+                None,
+                |os_string| {
+                    let string = os_string
+                        .into_string()
+                        .map_err(#crate_name::ParseFehler::InvaliderString)?;
+                    let mut teile = string.splitn(#anzahl, #trenner);
+                    Ok((#(
+                        match teile.next() {
+                            Some(teil) => <#felder as ::std::str::FromStr>::from_str(teil)
+                                .map_err(|fehler| {
+                                    #crate_name::ParseFehler::ParseFehler(fehler.to_string())
+                                })?,
+                            None => {
+                                return Err(#crate_name::ParseFehler::ParseFehler(format!(
+                                    "{} \"{}\": {}",
+                                    #sprache_ts.fehlender_trenner,
+                                    #trenner,
+                                    string
+                                )))
+                            },
+                        },
+                    )*))
+                },
+                |(#(#werte,)*)| [#(#werte.to_string()),*].join(#trenner),
+            )
+            })
         };
-        tuples.push((ident, erstelle_args));
+        // Aus `standards` sind entweder alle Felder vorhanden, oder keines.
+        tuples[index].1 = erstelle_args(quote!(match (#(#feld_idents,)*) {
+            (#(Some(#feld_idents),)*) => Some((#(#feld_idents,)*)),
+            _ => None,
+        }));
     }
     let (idents, erstelle_args): (Vec<_>, Vec<_>) = tuples.into_iter().unzip();
     let kombiniere = quote!(
         #(
             let #idents = #erstelle_args;
         )*
-        #crate_name::kombiniere!(|#(#idents),*| Self {#(#idents),*}, #(#idents),*)
+        #crate_name::kombiniere!(|#(#idents),*| Self {#(#feld_inits),*}, #(#idents),*)
     );
-    let nach_version = if let ErstelleVersion(Some(version_hinzufügen)) = erstelle_version {
-        version_hinzufügen(kombiniere, sprache)
+    let nach_version = if let ErstelleVersion(Some(version_hinzufügen)) = &erstelle_version {
+        version_hinzufügen(kombiniere, sprache.clone())
     } else {
         kombiniere
     };
-    let nach_hilfe = if let ErstelleHilfe(Some(hilfe_hinzufügen)) = erstelle_hilfe {
+    let nach_hilfe = if let ErstelleHilfe(Some(hilfe_hinzufügen)) = &erstelle_hilfe {
         hilfe_hinzufügen(nach_version)
     } else {
         nach_version
     };
+    let standard_werte_impl = if standard_werte {
+        quote! {
+            impl #ident {
+                /// Erzeuge einen Wert aus den Standard-Werten aller Felder.
+                ///
+                /// ## English
+                /// Create a value from the default values of all fields.
+                pub fn standard_werte() -> Self {
+                    Self {#(#standard_inits),*}
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+    let keine = alle_felder.iter().map(|_feld| quote!(None));
     let ts = quote! {
+        impl #ident {
+            /// Erzeuge die Kommandozeilen-Argumente, optional mit den Feldern von `standards`
+            /// als Standard-Werte.
+            fn kommandozeilen_argumente_allgemein<'t>(
+                standards: Option<Self>,
+            ) -> #crate_name::Argumente<'t, Self, String> {
+                let (#(#alle_felder,)*) = match standards {
+                    Some(Self { #(#alle_felder),* }) => (#(Some(#alle_felder),)*),
+                    None => (#(#keine,)*),
+                };
+                #nach_hilfe
+            }
+        }
+
         impl #crate_name::Parse for #ident {
             type Fehler = String;
 
             fn kommandozeilen_argumente<'t>() -> #crate_name::Argumente<'t, Self, Self::Fehler> {
-                #nach_hilfe
+                Self::kommandozeilen_argumente_allgemein(None)
+            }
+
+            fn kommandozeilen_argumente_mit_standards<'t>(
+                standards: Self,
+            ) -> #crate_name::Argumente<'t, Self, Self::Fehler> {
+                Self::kommandozeilen_argumente_allgemein(Some(standards))
             }
         }
+
+        #standard_werte_impl
     };
     Ok(ts)
 }
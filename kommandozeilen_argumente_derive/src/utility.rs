@@ -8,6 +8,7 @@ use std::{
 
 use proc_macro2::{Delimiter, Ident, Punct, Spacing, TokenStream, TokenTree};
 use quote::{format_ident, quote, ToTokens};
+use syn::{parse2, LitStr};
 
 ////////////////////////////////////////////////////////
 
@@ -86,6 +87,91 @@ impl Case {
     }
 }
 
+/// Der Wert eines String-Literals, ansonsten die String-Darstellung des [TokenStream]s.
+pub(crate) fn literal_oder_to_string(token_stream: &TokenStream) -> String {
+    if let Ok(lit_str) = parse2::<LitStr>(token_stream.clone()) {
+        lit_str.value()
+    } else {
+        token_stream.to_string()
+    }
+}
+
+/// Umbenennung aller Namen, ausgehend vom Rust-Bezeichner.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Umbenennung {
+    /// `DeleteAll` → `delete-all`
+    KebabCase,
+    /// `DeleteAll` → `delete_all`
+    SnakeCase,
+}
+
+impl Umbenennung {
+    pub(crate) fn parse(ts: &TokenStream) -> Option<Umbenennung> {
+        match ts.to_string().replace(' ', "").as_str() {
+            "kebab-case" => Some(Umbenennung::KebabCase),
+            "snake_case" => Some(Umbenennung::SnakeCase),
+            _ => None,
+        }
+    }
+
+    /// Benenne einen Bezeichner in `CamelCase` oder `snake_case` um.
+    pub(crate) fn anwenden(&self, bezeichner: &str) -> String {
+        let trenner = match self {
+            Umbenennung::KebabCase => "-",
+            Umbenennung::SnakeCase => "_",
+        };
+        wörter(bezeichner).join(trenner)
+    }
+}
+
+/// Teile einen Bezeichner in klein geschriebene Wörter auf,
+/// getrennt an `_` und vor Großbuchstaben (`HTTPServer` → `http`, `server`).
+fn wörter(bezeichner: &str) -> Vec<String> {
+    let mut wörter = Vec::new();
+    let mut wort = String::new();
+    let mut chars = bezeichner.chars().peekable();
+    let mut vorher: Option<char> = None;
+    while let Some(c) = chars.next() {
+        if c == '_' {
+            if !wort.is_empty() {
+                wörter.push(std::mem::take(&mut wort));
+            }
+            vorher = None;
+            continue;
+        }
+        if c.is_uppercase() && !wort.is_empty() {
+            let neues_wort = match vorher {
+                Some(v) if v.is_lowercase() || v.is_numeric() => true,
+                Some(v) if v.is_uppercase() => chars.peek().map_or(false, |n| n.is_lowercase()),
+                _ => false,
+            };
+            if neues_wort {
+                wörter.push(std::mem::take(&mut wort));
+            }
+        }
+        wort.extend(c.to_lowercase());
+        vorher = Some(c);
+    }
+    if !wort.is_empty() {
+        wörter.push(wort);
+    }
+    wörter
+}
+
+#[test]
+fn test_umbenennung() {
+    for (bezeichner, kebab, snake) in [
+        ("Create", "create", "create"),
+        ("DeleteAll", "delete-all", "delete_all"),
+        ("HTTPServer", "http-server", "http_server"),
+        ("dry_run", "dry-run", "dry_run"),
+        ("Version2Test", "version2-test", "version2_test"),
+    ] {
+        assert_eq!(Umbenennung::KebabCase.anwenden(bezeichner), kebab);
+        assert_eq!(Umbenennung::SnakeCase.anwenden(bezeichner), snake);
+    }
+}
+
 ////////////////////////////////////////////////////////
 
 #[inline(always)]
@@ -1,33 +1,80 @@
 //! Definition von akzeptierten Kommandozeilen-Argumenten.
 
 use std::{
+    borrow::Cow,
     collections::HashMap,
-    convert::identity,
     env,
     ffi::OsString,
     fmt::{Debug, Display},
     num::NonZeroI32,
     process,
+    rc::Rc,
 };
 
+use itertools::Itertools;
 use nonempty::NonEmpty;
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    beschreibung::{Configuration, Konfiguration},
-    ergebnis::{Ergebnis, Error, Fehler, Result},
+    argumente::wert::WertNamen,
+    beschreibung::{contains_str, Configuration, Konfiguration},
+    ergebnis::{Ergebnis, Error, Fehler, ParseAbbruch, ParseAbort, ParseFehler, Result},
     sprache::{Language, Sprache},
-    unicode::{Normalisiert, Vergleich},
+    unicode::{Compare, Normalisiert, Vergleich},
 };
 
+pub(crate) mod befehlszeile;
+pub(crate) mod dauer;
+pub(crate) mod eingabeaufforderung;
 pub(crate) mod flag;
 #[path = "argumente/frühes_beenden.rs"]
 pub(crate) mod frühes_beenden;
+pub(crate) mod gemeinsam;
+pub(crate) mod implikation;
 pub(crate) mod kombiniere;
+pub(crate) mod liste;
+#[cfg(feature = "regex")]
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "regex")))]
+pub(crate) mod muster;
+pub(crate) mod nachsichtig;
+pub(crate) mod namen_index;
+pub(crate) mod paare;
+#[cfg(feature = "tracing")]
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "tracing")))]
+pub(crate) mod protokoll;
+#[path = "argumente/prüfen.rs"]
+pub(crate) mod prüfen;
+pub(crate) mod rest;
+pub(crate) mod umgebung;
 pub(crate) mod wert;
+pub(crate) mod zahl;
 
+use self::{
+    frühes_beenden::kurze_nutzung,
+    namen_index::{IndexKandidaten, Kandidaten, KurzIndex, NamenIndex, NamenIndexEintrag},
+};
+
+pub use self::befehlszeile::{
+    split_command_line, zerlege_befehlszeile, OffenesAnführungszeichen, UnterminatedQuote,
+};
+pub use self::frühes_beenden::{HelpLayout, HilfeLayout};
+
+pub use self::gemeinsam::{
+    parse_gemeinsam, parse_together, GemeinsamerParser, PreprocessingPart, Vorverarbeitungsteil,
+};
+pub use self::liste::{Duplicates, Duplikate, EmptyElements, LeereElemente, Mehrfach, Multiple};
+#[cfg(feature = "tracing")]
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "tracing")))]
+pub use self::protokoll::{LOG_TARGET, PROTOKOLL_TARGET};
+pub use self::prüfen::{CheckReport, Prüfbericht};
+pub use self::umgebung::{
+    erweitere_umgebungsvariablen, erweitere_umgebungsvariablen_mit_sprache, expand_env_vars,
+    expand_env_vars_with_language,
+};
 #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "derive")))]
 pub use self::wert::EnumArgument;
+pub use self::wert::{Quelle, Source};
+pub use self::zahl::{NumberFormat, Zahlenformat};
 
 #[doc(inline)]
 pub use crate::{combine, kombiniere};
@@ -40,8 +87,6 @@ pub use crate::{combine, kombiniere};
 // TODO tests mit Unicode-namen
 // TODO OneOf/Either für alternative Parse-Möglichkeiten
 //      alternativ-Methode (analog kombinierte2), besondere Methode für Either-Typen?
-// TODO Standard-Wert, sofern nur der Name gegeben ist (unterschiedlich zu Name kommt nicht vor)
-//      z.B. nichts: -O0, -O: -O1, -O=N für explizite Angabe
 //      vgl. mit Flag-Argumenten, kann zu parse-Problemen wegen Mehrdeutigkeit führen
 //      kann durch alternativ-Methode erzeugt werden (erst Wert, dann Flag)
 //          dazu spezialisierte Methode bereitstellen
@@ -50,27 +95,560 @@ pub use crate::{combine, kombiniere};
 pub struct Argumente<'t, T, E> {
     pub(crate) konfigurationen: Vec<Konfiguration<'t>>,
     pub(crate) flag_kurzformen: HashMap<Vergleich<'t>, Vec<Vergleich<'t>>>,
-    pub(crate) parse:
-        Box<dyn 't + Fn(Vec<Option<OsString>>) -> (Ergebnis<'t, T, E>, Vec<Option<OsString>>)>,
+    pub(crate) einstellungen: ParseEinstellungen<'t>,
+    pub(crate) parse: ParseFunktion<'t, T, E>,
+}
+
+/// Parse-Funktion eines [Argumente], verwendete Argumente werden durch [None] ersetzt.
+/// Der Zustand des aktuellen Parsens wird über den [ParseKontext] übergeben.
+pub(crate) type ParseFunktion<'t, T, E> = Box<
+    dyn 't
+        + Fn(
+            Vec<Option<OsString>>,
+            &mut ParseKontext<'_, 't, E>,
+        ) -> (Ergebnis<'t, T, E>, Vec<Option<OsString>>),
+>;
+
+/// Zustand eines einzelnen Parse-Vorgangs, wird an jede [ParseFunktion] übergeben.
+pub(crate) struct ParseKontext<'a, 't, E> {
+    /// Bei [nachsichtigem Parsen](Argumente::parse_nachsichtig) werden hier die Fehler gesammelt,
+    /// für die ein Standard-Wert verwendet wurde.
+    pub(crate) nachsichtig: Option<&'a mut Vec<Fehler<'t, E>>>,
+    /// Die vom [NamenIndex] für die aktuellen Argumente bestimmten Kandidaten.
+    pub(crate) kandidaten: Option<&'a IndexKandidaten<'a, 't>>,
+    /// Konfigurationen aller geparsten Argumente, siehe [ist_bekannter_lang_name].
+    pub(crate) konfigurationen: &'a [Konfiguration<'t>],
+    /// Die Argumente nach dem Trenner, sofern sie von einem Argument erfasst werden
+    /// (siehe [Argumente::rest_nach_trenner]).
+    pub(crate) nach_trenner: &'a [OsString],
+    /// Beim Parsen gesammelte Informationen, unabhängig vom Fehler-Typ.
+    pub(crate) zustand: &'a mut ParseZustand<'t>,
+}
+
+/// Beim Parsen gesammelte Informationen, die nicht Teil des [Ergebnis] sind.
+#[derive(Debug, Default)]
+pub(crate) struct ParseZustand<'t> {
+    /// Warnungen genannter Argumente (z.B. einer [veralteten Flag](Argumente::veraltete_flag)).
+    /// Sie werden nur von [parse_vollständig](Argumente::parse_vollständig)
+    /// und verwandten Methoden in `stderr` geschrieben.
+    pub(crate) warnungen: Vec<&'t str>,
+    /// Während eines [Probelaufs](Argumente::prüfe) werden Aktionen und frühes Beenden
+    /// [aufgezeichnet](Aufzeichnung), statt sie auszuführen. Ansonsten ist er [None].
+    pub(crate) probelauf: Option<Vec<Aufzeichnung<'t>>>,
+    /// Dürfen [Eingabeaufforderungen](Argumente::wert_mit_eingabeaufforderung_fn)
+    /// aufgerufen werden? Ansonsten wird bei fehlendem Wert nur gezählt,
+    /// wie viele Eingabeaufforderungen `benötigt` werden.
+    pub(crate) eingabeaufforderung_erlaubt: bool,
+    /// Anzahl der Argumente, deren Eingabeaufforderung benötigt wird.
+    pub(crate) eingabeaufforderungen_benötigt: usize,
+}
+
+impl ParseZustand<'_> {
+    /// Verwirf alle gesammelten Informationen, z.B. vor einem erneuten Parsen.
+    /// Ein Probelauf bleibt dabei ein Probelauf, ebenso bleiben Eingabeaufforderungen erlaubt.
+    pub(crate) fn zurücksetzen(&mut self) {
+        let ParseZustand {
+            warnungen,
+            probelauf,
+            eingabeaufforderung_erlaubt: _,
+            eingabeaufforderungen_benötigt,
+        } = self;
+        warnungen.clear();
+        if let Some(aufzeichnungen) = probelauf {
+            aufzeichnungen.clear();
+        }
+        *eingabeaufforderungen_benötigt = 0;
+    }
+}
+
+impl<'t, E> ParseKontext<'_, 't, E> {
+    /// Die [Kandidaten](namen_index::Kandidaten) für `eintrag`,
+    /// siehe [IndexKandidaten::für].
+    pub(crate) fn kandidaten_für(
+        &self,
+        eintrag: &NamenIndexEintrag<'t>,
+        anzahl_args: usize,
+    ) -> Option<Kandidaten<'_>> {
+        self.kandidaten.and_then(|kandidaten| kandidaten.für(eintrag, anzahl_args))
+    }
+
+    /// Kontext für veränderte Argumente, deren Positionen nicht zu den
+    /// [Kandidaten](namen_index::Kandidaten) passen.
+    pub(crate) fn ohne_kandidaten(&mut self) -> ParseKontext<'_, 't, E> {
+        ParseKontext {
+            nachsichtig: self.nachsichtig.as_deref_mut(),
+            kandidaten: None,
+            konfigurationen: self.konfigurationen,
+            nach_trenner: self.nach_trenner,
+            zustand: self.zustand,
+        }
+    }
+
+    /// Kontext für eine Prüfung, deren Ergebnis nicht verwendet wird,
+    /// z.B. ob eine Flag aktiviert ist.
+    /// Es werden keine Fehler gesammelt und keine [Kandidaten](namen_index::Kandidaten) verwendet.
+    pub(crate) fn prüfung<F>(&mut self) -> ParseKontext<'_, 't, F> {
+        ParseKontext {
+            nachsichtig: None,
+            kandidaten: None,
+            konfigurationen: self.konfigurationen,
+            nach_trenner: self.nach_trenner,
+            zustand: self.zustand,
+        }
+    }
 }
 
 /// Command line [Arguments] and their [crate::beschreibung::Description].
 pub type Arguments<'t, T, E> = Argumente<'t, T, E>;
 
+/// Wie wird ein alleinstehender `kurz_präfix` (üblicherweise `-`) behandelt?
+///
+/// ## English synonym
+/// [LoneShortPrefix]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AlleinstehenderKurzPräfix {
+    /// Direkt nach dem Namen eines Wert-Arguments wird er als Wert verwendet,
+    /// z.B. als Stdin-Sentinel (siehe [Argumente::wert_oder_stdin]).
+    /// Ansonsten wird er als nicht verwendetes Argument zurückgegeben.
+    ///
+    /// ## English
+    /// Directly after the name of a value-argument it is used as value,
+    /// e.g. as stdin sentinel (see [Arguments::value_or_stdin]).
+    /// Otherwise, it is returned as an unused argument.
+    #[default]
+    Stdin,
+
+    /// Er wird nie als Wert verwendet, sondern immer als nicht verwendetes Argument zurückgegeben.
+    /// Ein direkt davor genanntes Wert-Argument erhält dadurch keinen Wert.
+    ///
+    /// ## English
+    /// It is never used as a value, but always returned as an unused argument.
+    /// Therefore, a value-argument named directly before doesn't receive a value.
+    NichtVerwendet,
+
+    /// Direkt nach dem Namen eines Wert-Arguments erzeugt er einen Fehler
+    /// ([ParseFehler::InvaliderString](crate::ParseFehler::InvaliderString)).
+    /// Ansonsten wird er als nicht verwendetes Argument zurückgegeben.
+    ///
+    /// ## English
+    /// Directly after the name of a value-argument it produces an error
+    /// ([ParseError::InvaliderString](crate::ParseError::InvaliderString)).
+    /// Otherwise, it is returned as an unused argument.
+    Fehler,
+}
+
+/// How is a lone `short_prefix` (usually `-`) handled?
+///
+/// ## Deutsches Synonym
+/// [AlleinstehenderKurzPräfix]
+pub type LoneShortPrefix = AlleinstehenderKurzPräfix;
+
+/// Einstellungen, die beim Aufruf von [Argumente::parse]
+/// und beim Erstellen des Hilfe-Textes berücksichtigt werden.
+#[derive(Clone, Default)]
+pub(crate) struct ParseEinstellungen<'t> {
+    /// Alle auf den Trenner folgenden Argumente werden nicht geparst.
+    pub(crate) trenner: Option<Vergleich<'t>>,
+    /// Behandlung eines alleinstehenden `kurz_präfix`.
+    /// Ohne explizite Einstellung wird [AlleinstehenderKurzPräfix::Stdin] verwendet.
+    pub(crate) alleinstehender_kurz_präfix: Option<AlleinstehenderKurzPräfix>,
+    /// Sollen verschmolzene Kurzformen (`-abc`) in einzelne Kurzformen aufgeteilt werden?
+    /// Ohne explizite Einstellung werden sie aufgeteilt.
+    pub(crate) kurzformen_verschmelzen: Option<bool>,
+    /// Dürfen Werte direkt an einen Kurznamen geklebt werden (`-oWert`)?
+    /// Ohne explizite Einstellung ist das erlaubt.
+    pub(crate) kurzwerte_verkleben: Option<bool>,
+    /// Sollen Nachrichten beim frühen Beenden (z.B. `--version`) in `stdout` unterdrückt werden?
+    /// Ohne explizite Einstellung werden sie angezeigt.
+    pub(crate) leise: Option<bool>,
+    /// Trenner zwischen mehreren Nachrichten beim frühen Beenden.
+    /// Ohne explizite Einstellung wird jede Nachricht in einer eigenen Zeile ausgegeben.
+    pub(crate) nachrichten_trenner: Option<&'t str>,
+    /// Beschreibung für den rekonstruierten Aufruf in Fehlermeldungen.
+    /// Ohne explizite Einstellung wird der Aufruf nicht angezeigt.
+    pub(crate) aufruf: Option<&'t str>,
+    /// Maximale Breite der Namen-Spalte im Hilfe-Text.
+    /// Ohne explizite Einstellung ist die Breite unbegrenzt.
+    pub(crate) max_namen_breite: Option<usize>,
+    /// Anordnung der Namen im Hilfe-Text.
+    /// Ohne explizite Einstellung wird [HilfeLayout::Standard] verwendet.
+    pub(crate) hilfe_layout: Option<HilfeLayout>,
+    /// Überschrift und dokumentierte Exit-Codes für einen eigenen Abschnitt im Hilfe-Text.
+    /// Ohne explizite Einstellung wird kein Abschnitt angezeigt.
+    pub(crate) exit_codes: Option<(&'t str, &'t [(i32, &'t str)])>,
+    /// Werden die Argumente nach dem Trenner von einem Argument erfasst
+    /// (siehe [Argumente::rest_nach_trenner])?
+    pub(crate) rest: bool,
+    /// Anzahl der Argumente mit Eingabeaufforderung
+    /// (siehe [wert_mit_eingabeaufforderung_fn](Argumente::wert_mit_eingabeaufforderung_fn)).
+    pub(crate) eingabeaufforderungen: usize,
+    /// Vor der Vorverarbeitung nacheinander auf die Argumente angewendete Funktionen
+    /// (siehe [Argumente::mit_vorverarbeitung]).
+    pub(crate) vorverarbeitung: Vec<Vorverarbeitung<'t>>,
+    /// Namen aller Argumente, die den [NamenIndex] verwenden (siehe [NamenIndexEintrag]).
+    pub(crate) namen_einträge: Vec<NamenIndexEintrag<'t>>,
+    /// Beim Kombinieren aus den `namen_einträge` erzeugter Index.
+    pub(crate) namen_index: Option<Rc<NamenIndex<'t>>>,
+    /// Soll der [NamenIndex] beim Parsen verwendet werden?
+    /// Ohne explizite Einstellung wird er verwendet.
+    pub(crate) namen_index_verwenden: Option<bool>,
+}
+
+/// Vom Nutzer festgelegte Funktion zum Umschreiben der Kommandozeilen-Argumente.
+#[derive(Clone)]
+pub(crate) struct Vorverarbeitung<'t>(Rc<dyn 't + Fn(Vec<OsString>) -> Vec<OsString>>);
+
+impl Debug for ParseEinstellungen<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ParseEinstellungen {
+            trenner,
+            alleinstehender_kurz_präfix,
+            kurzformen_verschmelzen,
+            kurzwerte_verkleben,
+            leise,
+            nachrichten_trenner,
+            aufruf,
+            max_namen_breite,
+            hilfe_layout,
+            exit_codes,
+            rest,
+            eingabeaufforderungen,
+            vorverarbeitung,
+            namen_einträge,
+            namen_index,
+            namen_index_verwenden,
+        } = self;
+        // Zusammenfassung ohne Vergleich-Details und gespeicherte Funktionen.
+        f.debug_struct("ParseEinstellungen")
+            .field("trenner", &trenner.as_ref().map(|trenner| trenner.string.as_ref()))
+            .field("alleinstehender_kurz_präfix", alleinstehender_kurz_präfix)
+            .field("kurzformen_verschmelzen", kurzformen_verschmelzen)
+            .field("kurzwerte_verkleben", kurzwerte_verkleben)
+            .field("leise", leise)
+            .field("nachrichten_trenner", nachrichten_trenner)
+            .field("aufruf", aufruf)
+            .field("max_namen_breite", max_namen_breite)
+            .field("hilfe_layout", hilfe_layout)
+            .field("exit_codes", exit_codes)
+            .field("rest", rest)
+            .field("eingabeaufforderungen", eingabeaufforderungen)
+            .field("vorverarbeitung", &vorverarbeitung.len())
+            .field("namen_einträge", &namen_einträge.len())
+            .field("namen_index", &namen_index.is_some())
+            .field("namen_index_verwenden", namen_index_verwenden)
+            .finish()
+    }
+}
+
+/// Während eines [Probelaufs](Argumente::prüfe) aufgezeichnetes Ereignis.
+#[derive(Debug, Clone)]
+pub(crate) enum Aufzeichnung<'t> {
+    /// Beschreibung einer nicht ausgeführten Aktion, jeweils Name und Wert.
+    Aktion(String),
+    /// Nachricht eines [frühen Beendens](Argumente::frühes_beenden).
+    FrühesBeenden(Cow<'t, str>),
+}
+
+/// Ist `normalisiert` der exakte Langname (inklusive Präfix) eines der geparsten Argumente?
+///
+/// Damit wird ein exakter Langname eines anderen Arguments (z.B. `--kein-cache`)
+/// gegenüber der invertierten Form (z.B. von `--cache`) bevorzugt.
+pub(crate) fn ist_bekannter_lang_name(
+    konfigurationen: &[Konfiguration<'_>],
+    normalisiert: &Normalisiert<'_>,
+) -> bool {
+    konfigurationen.iter().any(|konfiguration| match konfiguration {
+        Konfiguration::Flag { beschreibung, .. } | Konfiguration::Wert { beschreibung, .. } => {
+            beschreibung
+                .lang_präfix
+                .strip_als_präfix(normalisiert)
+                .is_some_and(|lang_str| contains_str(&beschreibung.lang, lang_str))
+        },
+    })
+}
+
+impl ParseEinstellungen<'_> {
+    /// Kombiniere die Einstellungen, bereits gesetzte Werte haben Vorrang.
+    pub(crate) fn kombiniere(self, andere: Self) -> Self {
+        ParseEinstellungen {
+            trenner: self.trenner.or(andere.trenner),
+            alleinstehender_kurz_präfix: self
+                .alleinstehender_kurz_präfix
+                .or(andere.alleinstehender_kurz_präfix),
+            kurzformen_verschmelzen: self
+                .kurzformen_verschmelzen
+                .or(andere.kurzformen_verschmelzen),
+            kurzwerte_verkleben: self.kurzwerte_verkleben.or(andere.kurzwerte_verkleben),
+            leise: self.leise.or(andere.leise),
+            nachrichten_trenner: self.nachrichten_trenner.or(andere.nachrichten_trenner),
+            aufruf: self.aufruf.or(andere.aufruf),
+            max_namen_breite: self.max_namen_breite.or(andere.max_namen_breite),
+            hilfe_layout: self.hilfe_layout.or(andere.hilfe_layout),
+            exit_codes: self.exit_codes.or(andere.exit_codes),
+            rest: self.rest || andere.rest,
+            eingabeaufforderungen: self.eingabeaufforderungen + andere.eingabeaufforderungen,
+            vorverarbeitung: self
+                .vorverarbeitung
+                .into_iter()
+                .chain(andere.vorverarbeitung)
+                .collect(),
+            namen_einträge: self.namen_einträge.into_iter().chain(andere.namen_einträge).collect(),
+            // Der Index wird nach dem Kombinieren für alle `namen_einträge` neu erzeugt.
+            namen_index: None,
+            namen_index_verwenden: self.namen_index_verwenden.or(andere.namen_index_verwenden),
+        }
+    }
+
+    /// Erzeuge den [NamenIndex] für alle `namen_einträge`.
+    pub(crate) fn erzeuge_namen_index(&mut self) {
+        self.namen_index = NamenIndex::neu(&self.namen_einträge);
+    }
+
+    /// Schreibe die Nachrichten beim frühen Beenden in `stdout`,
+    /// getrennt durch den [Nachrichten-Trenner](Argumente::mit_nachrichten_trenner).
+    fn zeige_nachrichten(&self, nachrichten: NonEmpty<Cow<'_, str>>) {
+        let trenner = self.nachrichten_trenner.unwrap_or("\n");
+        println!("{}", nachrichten.iter().join(trenner));
+    }
+}
+
+/// Zusammenfassung einer [Konfiguration] für die [Debug]-Implementierung von [Argumente],
+/// z.B. `--flag | -f: Flag`.
+struct KonfigurationZusammenfassung<'a, 't>(&'a Konfiguration<'t>);
+
+impl Debug for KonfigurationZusammenfassung<'_, '_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (beschreibung, art) = match self.0 {
+            Konfiguration::Flag { beschreibung, veraltet: Some(_), .. } => {
+                (beschreibung, "Veraltet")
+            },
+            Konfiguration::Flag {
+                beschreibung,
+                invertiere_präfix_infix: None,
+                ein_aus_präfix: None,
+                veraltet: None,
+                reihenfolge: _,
+                versteckte_namen: _,
+            } => (beschreibung, "FrühesBeenden"),
+            Konfiguration::Flag { beschreibung, .. } => (beschreibung, "Flag"),
+            Konfiguration::Wert { beschreibung, .. } => (beschreibung, "Wert"),
+        };
+        write!(f, "{}{}", beschreibung.lang_präfix.as_ref(), beschreibung.lang.head.as_ref())?;
+        if let Some(kurz) = beschreibung.kurz.first() {
+            write!(f, " | {}{}", beschreibung.kurz_präfix.as_ref(), kurz.as_ref())?;
+        }
+        write!(f, ": {art}")
+    }
+}
+
 impl<T, E> Debug for Argumente<'_, T, E> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let konfigurationen: Vec<_> =
+            self.konfigurationen.iter().map(KonfigurationZusammenfassung).collect();
+        let flag_kurzformen: Vec<_> = self
+            .flag_kurzformen
+            .iter()
+            .map(|(präfix, kurzformen)| {
+                format!("{}[{}]", präfix.as_ref(), kurzformen.iter().map(AsRef::as_ref).join(""))
+            })
+            .sorted()
+            .collect();
         f.debug_struct("Argumente")
-            .field("konfigurationen", &self.konfigurationen)
+            .field("konfigurationen", &konfigurationen)
+            .field("flag_kurzformen", &flag_kurzformen)
+            .field("einstellungen", &self.einstellungen)
             .field("parse", &"<function>")
             .finish()
     }
 }
 
+/// Beschreibungen der Fehlerarten in Fehlermeldungen von
+/// [parse_vollständig](Argumente::parse_vollständig).
+#[derive(Debug, Clone, Copy)]
+struct FehlerBeschreibungen<'a> {
+    fehlende_flag: &'a str,
+    fehlender_wert: &'a str,
+    parse_fehler: &'a str,
+    invalider_string: &'a str,
+    arg_nicht_verwendet: &'a str,
+}
+
 #[inline(always)]
 fn args_aus_env() -> impl Iterator<Item = OsString> {
     env::args_os().skip(1)
 }
 
+/// Rekonstruiere einen Shell-ähnlichen Aufruf aus den übergebenen Argumenten,
+/// z.B. für Fehlermeldungen.
+///
+/// Leere Argumente und Argumente mit Leer- oder Sonderzeichen werden in `'` eingeschlossen,
+/// nicht als UTF-8 darstellbare Zeichen werden ersetzt (siehe [OsStr::to_string_lossy](std::ffi::OsStr::to_string_lossy)).
+///
+/// ## Beispiel
+/// ```
+/// use std::ffi::OsString;
+/// use kommandozeilen_argumente::rekonstruiere_aufruf;
+///
+/// let args = [OsString::from("--name"), OsString::from("Max Mustermann")];
+/// assert_eq!(rekonstruiere_aufruf(&args), "--name 'Max Mustermann'");
+/// ```
+///
+/// ## English synonym
+/// [reconstruct_invocation]
+pub fn rekonstruiere_aufruf(args: &[OsString]) -> String {
+    let zitiere = |arg: &OsString| {
+        let string = arg.to_string_lossy();
+        let sicher = |c: char| c.is_alphanumeric() || "-_=.,/:@+%".contains(c);
+        if !string.is_empty() && string.chars().all(sicher) {
+            string.into_owned()
+        } else {
+            format!("'{}'", string.replace('\'', "'\\''"))
+        }
+    };
+    args.iter().map(zitiere).join(" ")
+}
+
+/// Reconstruct a shell-like invocation from the given arguments, e.g. for error messages.
+///
+/// Empty arguments and arguments with whitespace or special characters are enclosed in `'`,
+/// characters not representable as UTF-8 are replaced (see [OsStr::to_string_lossy](std::ffi::OsStr::to_string_lossy)).
+///
+/// ## Example
+/// ```
+/// use std::ffi::OsString;
+/// use kommandozeilen_argumente::reconstruct_invocation;
+///
+/// let args = [OsString::from("--name"), OsString::from("John Doe")];
+/// assert_eq!(reconstruct_invocation(&args), "--name 'John Doe'");
+/// ```
+///
+/// ## Deutsches Synonym
+/// [rekonstruiere_aufruf]
+#[inline(always)]
+pub fn reconstruct_invocation(args: &[OsString]) -> String {
+    rekonstruiere_aufruf(args)
+}
+
+/// Anzeige eines geheimen Wertes.
+const GEHEIM: &str = "***";
+
+/// Ersetze alle Werte des Wert-Arguments `wert_namen` durch [GEHEIM].
+fn schwärze_werte(wert_namen: &WertNamen<'_>, args: Vec<OsString>) -> Vec<OsString> {
+    let mut geschwärzt = Vec::with_capacity(args.len());
+    let mut folgender_wert = false;
+    for arg in args {
+        if folgender_wert {
+            folgender_wert = false;
+            geschwärzt.push(OsString::from(GEHEIM));
+            continue;
+        }
+        // Einzeln gesucht wird ein Name ohne Wert verwendet und `gefunden` mit [None] aufgerufen.
+        let mut wert = None;
+        let nicht_verwendet =
+            wert_namen.suche(vec![Some(arg.clone())], None, |gefunden| wert = gefunden);
+        if nicht_verwendet.iter().any(Option::is_some) {
+            geschwärzt.push(arg);
+        } else if let Some(wert) = wert {
+            let geschwärzter_arg = arg
+                .to_str()
+                .zip(wert.to_str())
+                .and_then(|(string, wert)| string.strip_suffix(wert))
+                .map_or_else(|| GEHEIM.to_owned(), |name| format!("{name}{GEHEIM}"));
+            geschwärzt.push(geschwärzter_arg.into());
+        } else {
+            folgender_wert = true;
+            geschwärzt.push(arg);
+        }
+    }
+    geschwärzt
+}
+
+/// Wende die Vorverarbeitung aller `teile` gemeinsam an, wie für kombinierte [Argumente]:
+/// Alle [Vorverarbeitungen](Argumente::mit_vorverarbeitung) werden nacheinander angewendet,
+/// verschmolzene Kurzformen werden aus den Kurznamen aller Teile aufgeteilt
+/// und die Argumente nach dem ersten festgelegten Trenner werden abgetrennt.
+pub(crate) fn vorverarbeite_teile<'t>(
+    teile: &[Vorverarbeitungsteil<'_, 't>],
+    args: impl Iterator<Item = OsString>,
+) -> (Vec<OsString>, Vec<OsString>) {
+    // Kurznamen mit gleichem Präfix werden zusammengefasst.
+    let mut flag_kurzformen: HashMap<&Vergleich<'t>, Vec<&Vergleich<'t>>> = HashMap::new();
+    for teil in teile {
+        for (präfix, kurzformen) in teil.flag_kurzformen {
+            flag_kurzformen.entry(präfix).or_default().extend(kurzformen);
+        }
+    }
+    // Nachschlagen statt jeden Kurznamen mit jedem Argument zu vergleichen.
+    let kurz_indizes: Vec<_> = flag_kurzformen
+        .into_iter()
+        .map(|(prefix, kurzformen)| (prefix, KurzIndex::neu(kurzformen)))
+        .collect();
+    let ersetze_verschmolzene_kurzformen = |arg: OsString| -> Vec<OsString> {
+        if let Some(string) = arg.to_str() {
+            // Nur einmal normalisieren, auch bei sehr langen Argumenten.
+            let normalisiert = Normalisiert::neu(string);
+            for (prefix, kurzformen) in kurz_indizes.iter() {
+                if prefix.as_ref().is_empty() {
+                    // Ohne Präfix wäre jedes Wort aus Kurznamen (z.B. `ab`) betroffen.
+                    continue;
+                }
+                if let Some(kurz_str) = prefix.strip_als_präfix(&normalisiert) {
+                    if kurzformen.enthält(kurz_str) {
+                        // Kurzname aus mehreren Graphemes, nicht aufteilen.
+                        return vec![arg];
+                    }
+                    // Erst alle Graphemes prüfen, damit ein unbekanntes Grapheme
+                    // ohne Allokationen zum Abbruch führt.
+                    let ist_kurzform = |grapheme| kurzformen.enthält(grapheme);
+                    if kurz_str.is_empty() {
+                        continue;
+                    } else if !kurz_str.graphemes(true).all(ist_kurzform) {
+                        return vec![arg];
+                    }
+                    let präfix_str = prefix.string.as_ref();
+                    return kurz_str
+                        .graphemes(true)
+                        .map(|grapheme| {
+                            let mut kurzform =
+                                OsString::with_capacity(präfix_str.len() + grapheme.len());
+                            kurzform.push(präfix_str);
+                            kurzform.push(grapheme);
+                            kurzform
+                        })
+                        .collect();
+                }
+            }
+        }
+        vec![arg]
+    };
+    let mut args = teile
+        .iter()
+        .flat_map(|teil| &teil.einstellungen.vorverarbeitung)
+        .fold(args.collect(), |args, Vorverarbeitung(f)| f(args))
+        .into_iter();
+    // Wie beim Kombinieren gilt die erste explizite Einstellung.
+    let trenner = teile.iter().find_map(|teil| teil.einstellungen.trenner.as_ref());
+    let kurzformen_verschmelzen =
+        teile.iter().find_map(|teil| teil.einstellungen.kurzformen_verschmelzen);
+    let mut vor_trenner = Vec::new();
+    if let Some(trenner) = trenner {
+        for arg in args.by_ref() {
+            if arg.to_str().map(|string| trenner.eq(string)).unwrap_or(false) {
+                break;
+            }
+            vor_trenner.push(arg)
+        }
+    } else {
+        vor_trenner.extend(&mut args)
+    }
+    let angepasste_args: Vec<_> = if kurzformen_verschmelzen.unwrap_or(true) {
+        vor_trenner.into_iter().flat_map(ersetze_verschmolzene_kurzformen).collect()
+    } else {
+        vor_trenner
+    };
+    (angepasste_args, args.collect())
+}
+
 impl<T, E: Display> Argumente<'_, T, E> {
     /// Parse [args_os](std::env::args_os) und versuche den gewünschten Typ zu erzeugen.
     /// Sofern ein frühes beenden gewünscht wird (z.B. `--version`) werden die
@@ -298,20 +876,74 @@ impl<T, E: Display> Argumente<'_, T, E> {
         invalider_string: &str,
         arg_nicht_verwendet: &str,
     ) -> T {
-        let (ergebnis, nicht_verwendet) = self.parse(args);
+        self.parse_vollständig_intern(
+            args,
+            fehler_code,
+            None,
+            false,
+            FehlerBeschreibungen {
+                fehlende_flag,
+                fehlender_wert,
+                parse_fehler,
+                invalider_string,
+                arg_nicht_verwendet,
+            },
+        )
+    }
+
+    /// Gemeinsame Implementierung von [parse_vollständig](Argumente::parse_vollständig)
+    /// und [parse_vollständig_mit_kurzer_nutzung](Argumente::parse_vollständig_mit_kurzer_nutzung).
+    /// Im Fehlerfall wird zuerst die `kurze_nutzung` angezeigt, sofern vorhanden.
+    /// Mit `leise` (oder der Einstellung [leise](Argumente::leise)) werden
+    /// Nachrichten beim frühen Beenden nicht angezeigt.
+    fn parse_vollständig_intern(
+        &self,
+        args: impl Iterator<Item = OsString>,
+        fehler_code: NonZeroI32,
+        kurze_nutzung: Option<String>,
+        leise: bool,
+        fehler_beschreibungen: FehlerBeschreibungen<'_>,
+    ) -> T {
+        let FehlerBeschreibungen {
+            fehlende_flag,
+            fehlender_wert,
+            parse_fehler,
+            invalider_string,
+            arg_nicht_verwendet,
+        } = fehler_beschreibungen;
+        let args: Vec<_> = args.collect();
+        let zeige_aufruf = || {
+            if let Some(kurze_nutzung) = &kurze_nutzung {
+                eprintln!("{kurze_nutzung}");
+            }
+            if let Some(aufruf) = self.einstellungen.aufruf {
+                eprintln!("{}: {}", aufruf, self.rekonstruierter_aufruf(args.iter().cloned()));
+            }
+        };
+        let mut zustand = ParseZustand::default();
+        let (ergebnis, nicht_verwendet) =
+            self.parse_mit_eingabeaufforderung(args.iter().cloned(), None, &mut zustand);
+        let leise = leise || self.einstellungen.leise.unwrap_or(false);
+        if !leise {
+            for warnung in zustand.warnungen {
+                eprintln!("{warnung}");
+            }
+        }
         match ergebnis {
             Ergebnis::Wert(wert) if nicht_verwendet.is_empty() => wert,
             Ergebnis::Wert(_wert) => {
+                zeige_aufruf();
                 eprintln!("{}: {:?}", arg_nicht_verwendet, nicht_verwendet);
                 process::exit(fehler_code.get())
             },
             Ergebnis::FrühesBeenden(nachrichten) => {
-                for nachricht in nachrichten {
-                    println!("{}", nachricht);
+                if !leise {
+                    self.einstellungen.zeige_nachrichten(nachrichten);
                 }
                 process::exit(0)
             },
             Ergebnis::Fehler(fehler_sammlung) => {
+                zeige_aufruf();
                 for fehler in fehler_sammlung {
                     eprintln!(
                         "{}",
@@ -328,6 +960,98 @@ impl<T, E: Display> Argumente<'_, T, E> {
         }
     }
 
+    /// Parse die übergebenen Kommandozeilen-Argumente und versuche den gewünschten Typ zu erzeugen.
+    /// Verhält sich wie [parse_vollständig_mit_sprache](Argumente::parse_vollständig_mit_sprache),
+    /// im Fehlerfall wird vor den Fehlermeldungen jedoch zusätzlich
+    /// eine kurze Nutzungs-Zeile (z.B. `programm [OPTIONEN]`) in `stderr` geschrieben.
+    ///
+    /// ## English synonym
+    /// [parse_complete_with_short_usage](Arguments::parse_complete_with_short_usage)
+    pub fn parse_vollständig_mit_kurzer_nutzung(
+        &self,
+        args: impl Iterator<Item = OsString>,
+        fehler_code: NonZeroI32,
+        programm_name: &str,
+        sprache: Sprache,
+    ) -> T {
+        self.parse_vollständig_intern(
+            args,
+            fehler_code,
+            Some(kurze_nutzung(programm_name, sprache.optionen)),
+            false,
+            FehlerBeschreibungen {
+                fehlende_flag: sprache.fehlende_flag,
+                fehlender_wert: sprache.fehlender_wert,
+                parse_fehler: sprache.parse_fehler,
+                invalider_string: sprache.invalider_string,
+                arg_nicht_verwendet: sprache.argument_nicht_verwendet,
+            },
+        )
+    }
+
+    /// Parse die übergebenen Kommandozeilen-Argumente und versuche den gewünschten Typ zu erzeugen.
+    /// Verhält sich wie [parse_vollständig_mit_sprache](Argumente::parse_vollständig_mit_sprache),
+    /// bei frühem Beenden (z.B. `--version`) wird das Programm jedoch ohne Ausgabe
+    /// in `stdout` beendet. Fehlermeldungen werden weiterhin in `stderr` geschrieben.
+    ///
+    /// ## English synonym
+    /// [parse_complete_quiet](Arguments::parse_complete_quiet)
+    pub fn parse_vollständig_leise(
+        &self,
+        args: impl Iterator<Item = OsString>,
+        fehler_code: NonZeroI32,
+        sprache: Sprache,
+    ) -> T {
+        self.parse_vollständig_intern(
+            args,
+            fehler_code,
+            None,
+            true,
+            FehlerBeschreibungen {
+                fehlende_flag: sprache.fehlende_flag,
+                fehlender_wert: sprache.fehlender_wert,
+                parse_fehler: sprache.parse_fehler,
+                invalider_string: sprache.invalider_string,
+                arg_nicht_verwendet: sprache.argument_nicht_verwendet,
+            },
+        )
+    }
+
+    /// Parse the given command line arguments to create the requested type.
+    /// Behaves like [parse_complete_with_language](Arguments::parse_complete_with_language),
+    /// but on an early exit (e.g. `--version`) the program stops without writing to `stdout`.
+    /// Error messages are still written to `stderr`.
+    ///
+    /// ## Deutsches Synonym
+    /// [parse_vollständig_leise](Argumente::parse_vollständig_leise)
+    #[inline(always)]
+    pub fn parse_complete_quiet(
+        &self,
+        args: impl Iterator<Item = OsString>,
+        error_code: NonZeroI32,
+        language: Language,
+    ) -> T {
+        self.parse_vollständig_leise(args, error_code, language)
+    }
+
+    /// Parse the given command line arguments to create the requested type.
+    /// Behaves like [parse_complete_with_language](Arguments::parse_complete_with_language),
+    /// but in case of an error, a short usage line (e.g. `program [OPTIONS]`)
+    /// is written to `stderr` before the error messages.
+    ///
+    /// ## Deutsches Synonym
+    /// [parse_vollständig_mit_kurzer_nutzung](Argumente::parse_vollständig_mit_kurzer_nutzung)
+    #[inline(always)]
+    pub fn parse_complete_with_short_usage(
+        &self,
+        args: impl Iterator<Item = OsString>,
+        error_code: NonZeroI32,
+        program_name: &str,
+        language: Language,
+    ) -> T {
+        self.parse_vollständig_mit_kurzer_nutzung(args, error_code, program_name, language)
+    }
+
     /// Parse the given command line arguments to create the requested type.
     /// If an early exit is desired (e.g. `--version`), the corresponding messages are written to
     /// `stdout` and the program stops via [exit](std::process::exit) with exit code `0`.
@@ -420,8 +1144,8 @@ impl<'t, T, E> Argumente<'t, T, E> {
         let result = match ergebnis {
             Ergebnis::Wert(wert) => Ok(wert),
             Ergebnis::FrühesBeenden(nachrichten) => {
-                for nachricht in nachrichten {
-                    println!("{}", nachricht);
+                if !self.einstellungen.leise.unwrap_or(false) {
+                    self.einstellungen.zeige_nachrichten(nachrichten);
                 }
                 process::exit(0)
             },
@@ -445,59 +1169,1146 @@ impl<'t, T, E> Argumente<'t, T, E> {
     }
 
     /// Parse die übergebenen Kommandozeilen-Argumente und versuche den gewünschten Typ zu erzeugen.
+    /// Sofern ein frühes beenden gewünscht wird (z.B. `--version`) werden die
+    /// entsprechenden Nachrichten nicht ausgegeben, sondern als
+    /// [ParseAbbruch::FrühesBeenden] zurückgegeben.
+    /// Nicht verwendete Argumente führen zu [ParseAbbruch::NichtVerwendet].
     ///
-    /// ## English
-    /// Parse the given command line arguments to create the requested type
-    pub fn parse(
+    /// ## English synonym
+    /// [parse_as_result](Arguments::parse_as_result)
+    #[allow(clippy::result_large_err)]
+    pub fn parse_als_result(
         &self,
         args: impl Iterator<Item = OsString>,
-    ) -> (Ergebnis<'t, T, E>, Vec<OsString>) {
-        let Argumente { konfigurationen: _, flag_kurzformen, parse } = self;
-        let ersetze_verschmolzene_kurzformen = |arg: OsString| -> Vec<OsString> {
-            if let Some(string) = arg.to_str() {
-                for (prefix, kurzformen) in flag_kurzformen.iter() {
-                    if let Some(kurz_str) = prefix.strip_als_präfix(&Normalisiert::neu(string)) {
-                        let präfix_str = prefix.string.as_ref();
-                        let mut gefundene_kurzformen = Vec::new();
-                        for grapheme in kurz_str.graphemes(true) {
-                            if kurzformen.iter().any(|vergleich| vergleich.eq(grapheme)) {
-                                gefundene_kurzformen.push(format!("{präfix_str}{grapheme}").into())
-                            } else {
-                                return vec![arg];
-                            }
-                        }
-                        if !gefundene_kurzformen.is_empty() {
-                            return gefundene_kurzformen;
-                        }
-                    }
-                }
+    ) -> std::result::Result<T, ParseAbbruch<'t, E>> {
+        let (ergebnis, nicht_verwendet) = self.parse(args);
+        match ergebnis {
+            Ergebnis::Wert(wert) => match NonEmpty::from_vec(nicht_verwendet) {
+                None => Ok(wert),
+                Some(nicht_verwendet) => Err(ParseAbbruch::NichtVerwendet(nicht_verwendet)),
+            },
+            Ergebnis::FrühesBeenden(nachrichten) => Err(ParseAbbruch::FrühesBeenden(nachrichten)),
+            Ergebnis::Fehler(fehler) => Err(ParseAbbruch::Fehler(fehler)),
+        }
+    }
+
+    /// Parse the given command line arguments to create the requested type.
+    /// If an early exit is desired (e.g. `--version`), the corresponding messages are not
+    /// printed, but returned as [ParseAbort::FrühesBeenden].
+    /// Unused arguments result in [ParseAbort::NichtVerwendet].
+    ///
+    /// ## Deutsches Synonym
+    /// [parse_als_result](Argumente::parse_als_result)
+    #[inline(always)]
+    #[allow(clippy::result_large_err)]
+    pub fn parse_as_result(
+        &self,
+        args: impl Iterator<Item = OsString>,
+    ) -> std::result::Result<T, ParseAbort<'t, E>> {
+        self.parse_als_result(args)
+    }
+
+    /// Parse die übergebenen Kommandozeilen-Argumente und gebe die Nachrichten zurück,
+    /// die bei einem gewünschten frühen Beenden (z.B. `--hilfe`) angezeigt würden.
+    /// Es wird nichts nach `stdout` geschrieben und das Programm nicht beendet.
+    ///
+    /// ## English synonym
+    /// [early_exit_messages](Arguments::early_exit_messages)
+    pub fn frühes_beenden_nachrichten(
+        &self,
+        args: impl Iterator<Item = OsString>,
+    ) -> Option<NonEmpty<Cow<'t, str>>> {
+        match self.parse(args).0 {
+            Ergebnis::FrühesBeenden(nachrichten) => Some(nachrichten),
+            Ergebnis::Wert(_) | Ergebnis::Fehler(_) => None,
+        }
+    }
+
+    /// Parse the given command line arguments and return the messages,
+    /// which would be shown for a requested early exit (e.g. `--help`).
+    /// Nothing is written to `stdout` and the program does not exit.
+    ///
+    /// ## Deutsches Synonym
+    /// [frühes_beenden_nachrichten](Argumente::frühes_beenden_nachrichten)
+    #[inline(always)]
+    pub fn early_exit_messages(
+        &self,
+        args: impl Iterator<Item = OsString>,
+    ) -> Option<NonEmpty<Cow<'t, str>>> {
+        self.frühes_beenden_nachrichten(args)
+    }
+
+    /// Die Kommandozeilen-Argumente, wie sie der Parser nach der Vorverarbeitung sieht,
+    /// d.h. nach dem Aufteilen verschmolzener Kurzformen und ohne Argumente nach dem Trenner.
+    ///
+    /// ## English synonym
+    /// [preprocessed_args](Arguments::preprocessed_args)
+    #[inline(always)]
+    pub fn vorverarbeitete_args(&self, args: impl Iterator<Item = OsString>) -> Vec<OsString> {
+        self.vorverarbeite(args).0
+    }
+
+    /// The command line arguments as seen by the parser after pre-processing,
+    /// i.e. after splitting merged short forms and without arguments after the separator.
+    ///
+    /// ## Deutsches Synonym
+    /// [vorverarbeitete_args](Argumente::vorverarbeitete_args)
+    #[inline(always)]
+    pub fn preprocessed_args(&self, args: impl Iterator<Item = OsString>) -> Vec<OsString> {
+        self.vorverarbeitete_args(args)
+    }
+
+    /// Der Aufruf, wie ihn der Parser nach der Vorverarbeitung sieht
+    /// (siehe [vorverarbeitete_args](Argumente::vorverarbeitete_args)),
+    /// als String für eine Shell (siehe [rekonstruiere_aufruf]).
+    /// Werte [geheimer](Argumente::geheim) Wert-Argumente werden als `***` angezeigt.
+    ///
+    /// ## English synonym
+    /// [reconstructed_invocation](Arguments::reconstructed_invocation)
+    pub fn rekonstruierter_aufruf(&self, args: impl Iterator<Item = OsString>) -> String {
+        let mut vorverarbeitet = self.vorverarbeitete_args(args);
+        for konfiguration in &self.konfigurationen {
+            if let Konfiguration::Wert {
+                beschreibung, wert_infix, meta_var, geheim: true, ..
+            } = konfiguration
+            {
+                let wert_namen = WertNamen::neu(beschreibung, wert_infix.clone(), meta_var);
+                vorverarbeitet = schwärze_werte(&wert_namen, vorverarbeitet);
             }
-            vec![arg]
-        };
-        let angepasste_args: Vec<_> =
-            args.flat_map(ersetze_verschmolzene_kurzformen).map(Some).collect();
-        let (ergebnis, nicht_verwendet) = parse(angepasste_args);
-        (ergebnis, nicht_verwendet.into_iter().filter_map(identity).collect())
+        }
+        rekonstruiere_aufruf(&vorverarbeitet)
     }
 
-    /// Alle konfigurierten Kommandozeilen-Argumente.
-    /// Hiermit ist es möglich einen eigenen,
-    /// auf den konfigurierten Argumenten basierenden Hilfetext zu erzeugen.
+    /// The invocation as seen by the parser after pre-processing
+    /// (see [preprocessed_args](Arguments::preprocessed_args)),
+    /// as a String for a shell (see [reconstruct_invocation]).
+    /// Values of [secret](Arguments::secret) value-arguments are shown as `***`.
     ///
     /// ## Deutsches Synonym
-    /// [configurations](Argumente::configurations)
+    /// [rekonstruierter_aufruf](Argumente::rekonstruierter_aufruf)
     #[inline(always)]
-    pub fn konfigurationen(&self) -> impl Iterator<Item = &Konfiguration<'_>> {
-        self.konfigurationen.iter()
+    pub fn reconstructed_invocation(&self, args: impl Iterator<Item = OsString>) -> String {
+        self.rekonstruierter_aufruf(args)
     }
 
-    /// All configured command line arguments.
-    /// This function allows creating your own help text based on the configured arguments.
+    /// Alle Kommandozeilen-Argumente, die als Name eines Arguments erkannt werden,
+    /// jeweils mit Präfix (z.B. `--flag`, `-f`).
+    /// Für Flag-Argumente ist zusätzlich die invertierte Form (z.B. `--kein-flag`) enthalten,
+    /// bzw. beide Formen bei Flags mit Ein- und Aus-Präfix (z.B. `+x`, `-x`).
+    ///
+    /// Gedacht für externe Validierung, z.B. um beim Fuzzing gültige Argumente zu erzeugen.
     ///
     /// ## English synonym
-    /// [konfigurationen](Arguments::konfigurationen)
+    /// [recognized_tokens](Arguments::recognized_tokens)
+    pub fn erkannte_tokens(&self) -> Vec<String> {
+        let mut tokens = Vec::new();
+        for konfiguration in &self.konfigurationen {
+            let (beschreibung, invertiere_präfix_infix, ein_aus_präfix) = match konfiguration {
+                Konfiguration::Flag {
+                    beschreibung,
+                    invertiere_präfix_infix,
+                    ein_aus_präfix,
+                    ..
+                } => (beschreibung, invertiere_präfix_infix.as_ref(), ein_aus_präfix.as_ref()),
+                Konfiguration::Wert { beschreibung, .. } => (beschreibung, None, None),
+            };
+            let lang: Vec<&str> = beschreibung.lang.iter().map(Vergleich::as_ref).collect();
+            let kurz = beschreibung.kurz.iter().map(Vergleich::as_ref);
+            if let Some((ein_präfix, aus_präfix)) = ein_aus_präfix {
+                for name in lang.into_iter().chain(kurz) {
+                    tokens.push(format!("{}{name}", ein_präfix.as_ref()));
+                    tokens.push(format!("{}{name}", aus_präfix.as_ref()));
+                }
+                continue;
+            }
+            let lang_präfix = beschreibung.lang_präfix.as_ref();
+            let kurz_präfix = beschreibung.kurz_präfix.as_ref();
+            tokens.extend(lang.iter().map(|name| format!("{lang_präfix}{name}")));
+            if let Some((invertiere_präfix, invertiere_infix)) = invertiere_präfix_infix {
+                let invertiere_präfix = invertiere_präfix.as_ref();
+                let invertiere_infix = invertiere_infix.as_ref();
+                tokens.extend(lang.iter().map(|name| {
+                    format!("{lang_präfix}{invertiere_präfix}{invertiere_infix}{name}")
+                }));
+            }
+            tokens.extend(kurz.map(|name| format!("{kurz_präfix}{name}")));
+        }
+        tokens
+    }
+
+    /// All command line arguments recognized as the name of an argument,
+    /// each with its prefix (e.g. `--flag`, `-f`).
+    /// For flag-arguments, the inverted form (e.g. `--no-flag`) is included as well,
+    /// or both forms for flags with an enable- and disable-prefix (e.g. `+x`, `-x`).
+    ///
+    /// Intended for external validation, e.g. to create valid arguments while fuzzing.
+    ///
+    /// ## Deutsches Synonym
+    /// [erkannte_tokens](Argumente::erkannte_tokens)
     #[inline(always)]
-    pub fn configurations(&self) -> impl Iterator<Item = &Configuration<'_>> {
-        self.konfigurationen.iter()
+    pub fn recognized_tokens(&self) -> Vec<String> {
+        self.erkannte_tokens()
+    }
+
+    /// Wende die [Vorverarbeitung](Argumente::mit_vorverarbeitung) an,
+    /// teile verschmolzene Kurzformen auf und trenne die Argumente nach dem Trenner ab.
+    fn vorverarbeite(
+        &self,
+        args: impl Iterator<Item = OsString>,
+    ) -> (Vec<OsString>, Vec<OsString>) {
+        vorverarbeite_teile(&[self.vorverarbeitungsteil()], args)
+    }
+
+    /// Die für die Vorverarbeitung benötigten Teile.
+    pub(crate) fn vorverarbeitungsteil(&self) -> Vorverarbeitungsteil<'_, 't> {
+        let Argumente { flag_kurzformen, einstellungen, .. } = self;
+        Vorverarbeitungsteil { flag_kurzformen, einstellungen }
+    }
+
+    /// Parse die übergebenen Kommandozeilen-Argumente und versuche den gewünschten Typ zu erzeugen.
+    ///
+    /// ## English
+    /// Parse the given command line arguments to create the requested type
+    #[inline(always)]
+    pub fn parse(
+        &self,
+        args: impl Iterator<Item = OsString>,
+    ) -> (Ergebnis<'t, T, E>, Vec<OsString>) {
+        self.parse_mit_eingabeaufforderung(args, None, &mut ParseZustand::default())
+    }
+
+    /// Parse die übergebenen Kommandozeilen-Argumente wie
+    /// [parse_mit_verwendeten](Argumente::parse_mit_verwendeten).
+    /// Fehlen ausschließlich Werte mit
+    /// [Eingabeaufforderung](Argumente::wert_mit_eingabeaufforderung_fn), wird mit erlaubter
+    /// Eingabeaufforderung erneut geparst. Bei frühem Beenden (z.B. `--hilfe`)
+    /// oder anderen Fehlern wird dadurch nie nachgefragt.
+    pub(crate) fn parse_mit_eingabeaufforderung(
+        &self,
+        args: impl Iterator<Item = OsString>,
+        mut verwendet: Option<&mut Vec<(usize, OsString)>>,
+        zustand: &mut ParseZustand<'t>,
+    ) -> (Ergebnis<'t, T, E>, Vec<OsString>) {
+        if self.einstellungen.eingabeaufforderungen == 0 {
+            return self.parse_mit_verwendeten(args, verwendet, None, zustand);
+        }
+        let args: Vec<_> = args.collect();
+        zustand.eingabeaufforderungen_benötigt = 0;
+        let (ergebnis, nicht_verwendet) = self.parse_mit_verwendeten(
+            args.iter().cloned(),
+            verwendet.as_deref_mut(),
+            None,
+            zustand,
+        );
+        let benötigt = zustand.eingabeaufforderungen_benötigt;
+        match ergebnis {
+            Ergebnis::Fehler(fehler) if benötigt > 0 && fehler.len() == benötigt => {
+                if let Some(verwendet) = verwendet.as_deref_mut() {
+                    verwendet.clear();
+                }
+                // Die Informationen des ersten Parsens werden nicht doppelt gesammelt.
+                zustand.zurücksetzen();
+                zustand.eingabeaufforderung_erlaubt = true;
+                let ergebnis =
+                    self.parse_mit_verwendeten(args.into_iter(), verwendet, None, zustand);
+                zustand.eingabeaufforderung_erlaubt = false;
+                ergebnis
+            },
+            ergebnis => (ergebnis, nicht_verwendet),
+        }
+    }
+
+    /// Parse die übergebenen Kommandozeilen-Argumente.
+    /// Ist `verwendet` vorhanden, werden dort alle (vorverarbeiteten) Argumente
+    /// vor dem Trenner mit ihrem Index gespeichert, die von einem Argument verwendet wurden.
+    /// Ist `nachsichtig` vorhanden, wird [nachsichtig geparst](Argumente::parse_nachsichtig).
+    /// Weitere beim Parsen gesammelte Informationen werden im `zustand` gespeichert.
+    pub(crate) fn parse_mit_verwendeten(
+        &self,
+        args: impl Iterator<Item = OsString>,
+        verwendet: Option<&mut Vec<(usize, OsString)>>,
+        nachsichtig: Option<&mut Vec<Fehler<'t, E>>>,
+        zustand: &mut ParseZustand<'t>,
+    ) -> (Ergebnis<'t, T, E>, Vec<OsString>) {
+        let (angepasste_args, mut nach_trenner) = self.vorverarbeite(args);
+        let (ergebnis, nicht_verwendet) = self.parse_vorverarbeitet(
+            angepasste_args.into_iter().map(Some).collect(),
+            &nach_trenner,
+            verwendet,
+            nachsichtig,
+            zustand,
+        );
+        if self.einstellungen.rest {
+            // Die Argumente nach dem Trenner werden von einem Argument erfasst.
+            nach_trenner.clear();
+        }
+        (ergebnis, nicht_verwendet.into_iter().flatten().chain(nach_trenner).collect())
+    }
+
+    /// Parse bereits [vorverarbeitete](Argumente::vorverarbeite) Argumente,
+    /// bereits verwendete Argumente sind [None].
+    /// Verklebte Kurzwerte und alleinstehende `kurz_präfix` werden hier behandelt,
+    /// damit verwendete Argumente im zurückgegebenen [Vec] ihre Position behalten.
+    /// Die Argumente `nach_trenner` werden nur von [Argumente::rest_nach_trenner] verwendet.
+    pub(crate) fn parse_vorverarbeitet(
+        &self,
+        args: Vec<Option<OsString>>,
+        nach_trenner: &[OsString],
+        verwendet: Option<&mut Vec<(usize, OsString)>>,
+        mut nachsichtig: Option<&mut Vec<Fehler<'t, E>>>,
+        zustand: &mut ParseZustand<'t>,
+    ) -> (Ergebnis<'t, T, E>, Vec<Option<OsString>>) {
+        let (mut args, mut verklebt) = self.entferne_verklebte_kurzwerte(args);
+        let (alleinstehend, kurz_präfix_fehler) = self.alleinstehende_kurz_präfixe(&mut args);
+        verklebt.extend(alleinstehend);
+        // Kopie nur, wenn die verwendeten Argumente benötigt werden.
+        let kopie = verwendet.is_some().then(|| args.clone());
+        let kandidaten = self
+            .einstellungen
+            .namen_index
+            .as_ref()
+            .filter(|_| self.einstellungen.namen_index_verwenden.unwrap_or(true))
+            .map(|namen_index| namen_index.bestimme_kandidaten(&args));
+        let mut kontext = ParseKontext {
+            nachsichtig: nachsichtig.as_deref_mut(),
+            kandidaten: kandidaten.as_ref(),
+            konfigurationen: &self.konfigurationen,
+            nach_trenner,
+            zustand,
+        };
+        let (mut ergebnis, mut nicht_verwendet) = (self.parse)(args, &mut kontext);
+        if let Some(kurz_präfix_fehler) = NonEmpty::from_vec(kurz_präfix_fehler) {
+            ergebnis = match (ergebnis, nachsichtig) {
+                (Ergebnis::Wert(wert), Some(gesammelte_fehler)) => {
+                    gesammelte_fehler.extend(kurz_präfix_fehler);
+                    Ergebnis::Wert(wert)
+                },
+                (Ergebnis::Wert(_wert), None) => Ergebnis::Fehler(kurz_präfix_fehler),
+                (Ergebnis::Fehler(mut fehler), _) => {
+                    fehler.tail.extend(kurz_präfix_fehler);
+                    Ergebnis::Fehler(fehler)
+                },
+                (Ergebnis::FrühesBeenden(nachrichten), _) => Ergebnis::FrühesBeenden(nachrichten),
+            };
+        }
+        if let (Some(verwendet), Some(kopie)) = (verwendet, kopie) {
+            verwendet.extend(kopie.into_iter().zip(&nicht_verwendet).enumerate().filter_map(
+                |(index, (arg, nicht_verwendet))| match (arg, nicht_verwendet) {
+                    (Some(arg), None) => Some((index, arg)),
+                    _ => None,
+                },
+            ));
+        }
+        for (index, arg) in verklebt {
+            // Verklebte Kurzwerte werden als nicht verwendete Argumente zurückgegeben.
+            if let Some(eintrag) = nicht_verwendet.get_mut(index) {
+                *eintrag = Some(arg);
+            }
+        }
+        (ergebnis, nicht_verwendet)
+    }
+
+    /// Ersetze direkt an den Kurznamen geklebte Werte (`-oWert`) durch [None],
+    /// sofern sie [nicht erlaubt](Argumente::ohne_verklebte_kurzwerte) sind.
+    /// Die entfernten Argumente werden mit ihrem Index zurückgegeben.
+    fn entferne_verklebte_kurzwerte(
+        &self,
+        mut args: Vec<Option<OsString>>,
+    ) -> (Vec<Option<OsString>>, Vec<(usize, OsString)>) {
+        let mut verklebt = Vec::new();
+        if self.einstellungen.kurzwerte_verkleben.unwrap_or(true) {
+            return (args, verklebt);
+        }
+        let wert_namen = self.wert_namen();
+        for (index, eintrag) in args.iter_mut().enumerate() {
+            let ist_verklebt =
+                eintrag.as_ref().and_then(|arg| arg.to_str()).is_some_and(|string| {
+                    wert_namen.iter().any(|namen| namen.ist_verklebter_kurzwert(string))
+                });
+            if ist_verklebt {
+                if let Some(arg) = eintrag.take() {
+                    verklebt.push((index, arg));
+                }
+            }
+        }
+        (args, verklebt)
+    }
+
+    /// Namen aller Wert-Argumente.
+    fn wert_namen(&self) -> Vec<WertNamen<'t>> {
+        self.konfigurationen
+            .iter()
+            .filter_map(|konfiguration| match konfiguration {
+                Konfiguration::Wert { beschreibung, wert_infix, meta_var, .. } => {
+                    Some(WertNamen::neu(beschreibung, wert_infix.clone(), meta_var))
+                },
+                Konfiguration::Flag { .. } => None,
+            })
+            .collect()
+    }
+
+    /// Behandle alleinstehende `kurz_präfix` (z.B. `-`) entsprechend der Einstellung
+    /// [mit_alleinstehendem_kurz_präfix](Argumente::mit_alleinstehendem_kurz_präfix).
+    ///
+    /// Mit [AlleinstehenderKurzPräfix::NichtVerwendet] werden sie in `args` durch [None] ersetzt
+    /// und zusammen mit ihrem Index zurückgegeben.
+    /// Mit [AlleinstehenderKurzPräfix::Fehler] werden die Fehler aller Wert-Argumente
+    /// zurückgegeben, die einen alleinstehenden `kurz_präfix` als Wert verwenden würden.
+    fn alleinstehende_kurz_präfixe(
+        &self,
+        args: &mut [Option<OsString>],
+    ) -> (Vec<(usize, OsString)>, Vec<Fehler<'t, E>>) {
+        let mut alleinstehend = Vec::new();
+        let mut fehler = Vec::new();
+        let verhalten = self.einstellungen.alleinstehender_kurz_präfix.unwrap_or_default();
+        if verhalten == AlleinstehenderKurzPräfix::Stdin {
+            return (alleinstehend, fehler);
+        }
+        let ist_kurz_präfix = |arg: &Option<OsString>| {
+            arg.as_ref().and_then(|os_string| os_string.to_str()).is_some_and(|string| {
+                self.konfigurationen.iter().any(|konfiguration| match konfiguration {
+                    Konfiguration::Flag { beschreibung, .. }
+                    | Konfiguration::Wert { beschreibung, .. } => {
+                        beschreibung.kurz_präfix.eq(string)
+                    },
+                })
+            })
+        };
+        let wert_namen = self.wert_namen();
+        for index in 0..args.len() {
+            if !ist_kurz_präfix(&args[index]) {
+                continue;
+            }
+            match verhalten {
+                AlleinstehenderKurzPräfix::Stdin => {},
+                AlleinstehenderKurzPräfix::NichtVerwendet => {
+                    if let Some(arg) = args[index].take() {
+                        alleinstehend.push((index, arg));
+                    }
+                },
+                AlleinstehenderKurzPräfix::Fehler => {
+                    let Some(vorheriges) = index.checked_sub(1).map(|vorher| &args[vorher]) else {
+                        continue;
+                    };
+                    for namen in &wert_namen {
+                        let nicht_verwendet = namen.suche(
+                            vec![vorheriges.clone(), args[index].clone()],
+                            None,
+                            |_wert| {},
+                        );
+                        // Nur ein als eigenes Argument folgender Wert (`--name -`) ist ein Fehler,
+                        // nicht ein bereits im vorherigen Argument angegebener (`--name=x -`).
+                        if let [_vorheriges, None] = nicht_verwendet.as_slice() {
+                            let wert = args[index].clone().unwrap_or_default();
+                            fehler.push(namen.fehler_parse(ParseFehler::InvaliderString(wert)));
+                        }
+                    }
+                },
+            }
+        }
+        (alleinstehend, fehler)
+    }
+
+    /// Parse die übergebenen Strings als Kommandozeilen-Argumente
+    /// und versuche den gewünschten Typ zu erzeugen, z.B. aus einem `Vec<String>` in Tests.
+    ///
+    /// ## English synonym
+    /// [parse_from_strings](Arguments::parse_from_strings)
+    #[inline(always)]
+    pub fn parse_aus_strings<S: AsRef<str>>(
+        &self,
+        args: impl IntoIterator<Item = S>,
+    ) -> (Ergebnis<'t, T, E>, Vec<OsString>) {
+        self.parse(args.into_iter().map(|arg| OsString::from(arg.as_ref())))
+    }
+
+    /// Parse the given strings as command line arguments to create the requested type,
+    /// e.g. from a `Vec<String>` in tests.
+    ///
+    /// ## Deutsches Synonym
+    /// [parse_aus_strings](Argumente::parse_aus_strings)
+    #[inline(always)]
+    pub fn parse_from_strings<S: AsRef<str>>(
+        &self,
+        args: impl IntoIterator<Item = S>,
+    ) -> (Result<'t, T, E>, Vec<OsString>) {
+        self.parse_aus_strings(args)
+    }
+
+    /// Parse die übergebenen Kommandozeilen-Argumente und versuche den gewünschten Typ zu erzeugen.
+    /// Zusätzlich wird zurückgegeben, ob alle Argumente verwendet wurden.
+    ///
+    /// ## English synonym
+    /// [parse_completely_parsed](Arguments::parse_completely_parsed)
+    pub fn parse_vollständig_geparst(
+        &self,
+        args: impl Iterator<Item = OsString>,
+    ) -> (Ergebnis<'t, T, E>, bool) {
+        let (ergebnis, nicht_verwendet) = self.parse(args);
+        (ergebnis, nicht_verwendet.is_empty())
+    }
+
+    /// Parse the given command line arguments to create the requested type.
+    /// Additionally, return if all arguments were used.
+    ///
+    /// ## Deutsches Synonym
+    /// [parse_vollständig_geparst](Argumente::parse_vollständig_geparst)
+    #[inline(always)]
+    pub fn parse_completely_parsed(
+        &self,
+        args: impl Iterator<Item = OsString>,
+    ) -> (Result<'t, T, E>, bool) {
+        self.parse_vollständig_geparst(args)
+    }
+
+    /// Parse die übergebenen Kommandozeilen-Argumente und gebe alle aufgetretenen Fehler
+    /// zusammen mit dem (ersten) LangNamen des verursachenden Arguments zurück.
+    /// Bei Erfolg oder frühem Beenden ist das Ergebnis leer.
+    ///
+    /// Gedacht für Tests, z.B. um zu prüfen, dass ein bestimmtes Argument
+    /// einen [Fehler::FehlenderWert] erzeugt hat.
+    ///
+    /// ## English synonym
+    /// [parse_with_error_report](Arguments::parse_with_error_report)
+    pub fn parse_mit_fehlerbericht(
+        &self,
+        args: impl Iterator<Item = OsString>,
+    ) -> Vec<(String, Fehler<'t, E>)> {
+        match self.parse(args).0 {
+            Ergebnis::Fehler(fehler) => fehler
+                .into_iter()
+                .map(|fehler| (fehler.namen().lang.head.as_ref().to_owned(), fehler))
+                .collect(),
+            Ergebnis::Wert(_) | Ergebnis::FrühesBeenden(_) => Vec::new(),
+        }
+    }
+
+    /// Parse the given command line arguments and return all errors,
+    /// together with the (first) long name of the responsible argument.
+    /// The result is empty on success or early exit.
+    ///
+    /// Intended for tests, e.g. to check that a specific argument produced
+    /// an [Error::FehlenderWert].
+    ///
+    /// ## Deutsches Synonym
+    /// [parse_mit_fehlerbericht](Argumente::parse_mit_fehlerbericht)
+    #[inline(always)]
+    pub fn parse_with_error_report(
+        &self,
+        args: impl Iterator<Item = OsString>,
+    ) -> Vec<(String, Error<'t, E>)> {
+        self.parse_mit_fehlerbericht(args)
+    }
+
+    /// Verwende `trenner` (üblicherweise `--`) als Trenner:
+    /// Alle folgenden Argumente werden nicht geparst,
+    /// sondern unverändert als nicht verwendete Argumente zurückgegeben.
+    /// Der Trenner selbst wird entfernt.
+    ///
+    /// Ohne Trenner werden ein alleinstehender `lang_präfix` (`--`) und
+    /// `kurz_präfix` (`-`) wie jedes andere Argument behandelt.
+    /// Ein alleinstehender `kurz_präfix` wird dadurch nur als Wert verwendet,
+    /// wenn er direkt auf den Namen eines Wert-Arguments folgt (z.B. `--input -`),
+    /// ansonsten wird er als nicht verwendetes Argument zurückgegeben
+    /// (siehe [mit_alleinstehendem_kurz_präfix](Argumente::mit_alleinstehendem_kurz_präfix)).
+    ///
+    /// ## English synonym
+    /// [with_separator](Arguments::with_separator)
+    pub fn mit_trenner(mut self, trenner: impl Into<Vergleich<'t>>) -> Argumente<'t, T, E> {
+        self.einstellungen.trenner = Some(trenner.into());
+        self
+    }
+
+    /// Use `separator` (usually `--`) as separator:
+    /// All following arguments are not parsed,
+    /// but returned unchanged as unused arguments.
+    /// The separator itself is removed.
+    ///
+    /// Without separator, a lone `lang_präfix` (`--`) and `kurz_präfix` (`-`)
+    /// are treated like every other argument.
+    /// Therefore, a lone `kurz_präfix` is only used as a value,
+    /// if it directly follows the name of a value-argument (e.g. `--input -`),
+    /// otherwise it is returned as an unused argument
+    /// (see [with_lone_short_prefix](Arguments::with_lone_short_prefix)).
+    ///
+    /// ## Deutsches Synonym
+    /// [mit_trenner](Argumente::mit_trenner)
+    #[inline(always)]
+    pub fn with_separator(self, separator: impl Into<Compare<'t>>) -> Arguments<'t, T, E> {
+        self.mit_trenner(separator)
+    }
+
+    /// Lege fest, wie ein alleinstehender `kurz_präfix` (üblicherweise `-`) behandelt wird.
+    /// Ohne explizite Einstellung wird [AlleinstehenderKurzPräfix::Stdin] verwendet.
+    ///
+    /// ## English synonym
+    /// [with_lone_short_prefix](Arguments::with_lone_short_prefix)
+    pub fn mit_alleinstehendem_kurz_präfix(
+        mut self,
+        verhalten: AlleinstehenderKurzPräfix,
+    ) -> Argumente<'t, T, E> {
+        self.einstellungen.alleinstehender_kurz_präfix = Some(verhalten);
+        self
+    }
+
+    /// Specify how a lone `short_prefix` (usually `-`) is handled.
+    /// Without explicit setting, [LoneShortPrefix::Stdin] is used.
+    ///
+    /// ## Deutsches Synonym
+    /// [mit_alleinstehendem_kurz_präfix](Argumente::mit_alleinstehendem_kurz_präfix)
+    #[inline(always)]
+    pub fn with_lone_short_prefix(self, behaviour: LoneShortPrefix) -> Arguments<'t, T, E> {
+        self.mit_alleinstehendem_kurz_präfix(behaviour)
+    }
+
+    /// Deaktiviere das Aufteilen verschmolzener Kurzformen beim Parsen.
+    ///
+    /// Standardmäßig wird z.B. `-abc` als `-a -b -c` interpretiert,
+    /// sofern `a`, `b` und `c` Kurzformen von Flag-Argumenten sind.
+    /// Ohne Aufteilen wird `-abc` nur als einzelne Kurzform `abc` behandelt,
+    /// z.B. wenn ein Wert zufällig wie verschmolzene Kurzformen aussieht.
+    ///
+    /// ## English synonym
+    /// [without_merged_short_forms](Arguments::without_merged_short_forms)
+    pub fn ohne_verschmolzene_kurzformen(mut self) -> Argumente<'t, T, E> {
+        self.einstellungen.kurzformen_verschmelzen = Some(false);
+        self
+    }
+
+    /// Disable splitting of merged short forms while parsing.
+    ///
+    /// By default, e.g. `-abc` is interpreted as `-a -b -c`,
+    /// if `a`, `b` and `c` are short forms of flag-arguments.
+    /// Without splitting, `-abc` is only treated as a single short form `abc`,
+    /// e.g. if a value coincidentally looks like merged short forms.
+    ///
+    /// ## Deutsches Synonym
+    /// [ohne_verschmolzene_kurzformen](Argumente::ohne_verschmolzene_kurzformen)
+    #[inline(always)]
+    pub fn without_merged_short_forms(self) -> Arguments<'t, T, E> {
+        self.ohne_verschmolzene_kurzformen()
+    }
+
+    /// Erlaube Werte für Kurznamen nur als eigenes Argument (`-o Wert`)
+    /// oder mit `wert_infix` (`-o=Wert`), nicht direkt an den Kurznamen geklebt (`-oWert`).
+    ///
+    /// Ein geklebter Wert wird weder als Wert verwendet,
+    /// noch in verschmolzene Kurzformen aufgeteilt,
+    /// sondern als nicht verwendetes Argument zurückgegeben.
+    /// Dadurch ist z.B. `-oval` nicht mehrdeutig zu verschmolzenen Flags.
+    ///
+    /// ## English synonym
+    /// [without_glued_short_values](Arguments::without_glued_short_values)
+    pub fn ohne_verklebte_kurzwerte(mut self) -> Argumente<'t, T, E> {
+        self.einstellungen.kurzwerte_verkleben = Some(false);
+        self
+    }
+
+    /// Only allow values for short names as a separate argument (`-o value`)
+    /// or with `value_infix` (`-o=value`), not glued to the short name (`-ovalue`).
+    ///
+    /// A glued value is neither used as value,
+    /// nor split into merged short forms,
+    /// but returned as an unused argument.
+    /// This way, e.g. `-oval` is not ambiguous with merged flags.
+    ///
+    /// ## Deutsches Synonym
+    /// [ohne_verklebte_kurzwerte](Argumente::ohne_verklebte_kurzwerte)
+    #[inline(always)]
+    pub fn without_glued_short_values(self) -> Arguments<'t, T, E> {
+        self.ohne_verklebte_kurzwerte()
+    }
+
+    /// Vergleiche beim Parsen jedes Kommandozeilen-Argument mit den Namen aller Argumente,
+    /// ohne den beim [Kombinieren](crate::kombiniere!) erzeugten Index der Namen.
+    ///
+    /// Das Ergebnis ist identisch, ohne Index steigt die Laufzeit aber quadratisch
+    /// mit der Anzahl der Argumente. Nützlich z.B. zum Vergleich der Laufzeit.
+    ///
+    /// ## English synonym
+    /// [without_name_index](Arguments::without_name_index)
+    pub fn ohne_namen_index(mut self) -> Argumente<'t, T, E> {
+        self.einstellungen.namen_index_verwenden = Some(false);
+        self
+    }
+
+    /// Compare each command line argument with the names of all arguments while parsing,
+    /// without the name index created when [combining](crate::combine!).
+    ///
+    /// The result is identical, but without index the runtime grows quadratically
+    /// with the number of arguments. Useful e.g. for comparing runtimes.
+    ///
+    /// ## Deutsches Synonym
+    /// [ohne_namen_index](Argumente::ohne_namen_index)
+    #[inline(always)]
+    pub fn without_name_index(self) -> Arguments<'t, T, E> {
+        self.ohne_namen_index()
+    }
+
+    /// Schreibe die Kommandozeilen-Argumente mit `f` um, bevor sie vorverarbeitet werden,
+    /// z.B. für zur Laufzeit definierte Aliase (`co` → `checkout`)
+    /// oder die Übersetzung veralteter Argumente.
+    /// `f` wird vor dem Abtrennen des Trenners und dem Aufteilen verschmolzener Kurzformen
+    /// aufgerufen, mehrere Funktionen werden in der Reihenfolge ihres Hinzufügens angewendet.
+    ///
+    /// ## English synonym
+    /// [with_preprocessing](Arguments::with_preprocessing)
+    pub fn mit_vorverarbeitung(
+        mut self,
+        f: impl 't + Fn(Vec<OsString>) -> Vec<OsString>,
+    ) -> Argumente<'t, T, E> {
+        self.einstellungen.vorverarbeitung.push(Vorverarbeitung(Rc::new(f)));
+        self
+    }
+
+    /// Rewrite the command line arguments with `f` before they are preprocessed,
+    /// e.g. for aliases defined at runtime (`co` → `checkout`)
+    /// or the translation of deprecated arguments.
+    /// `f` is called before splitting off the separator and splitting merged short forms,
+    /// multiple functions are applied in the order they were added.
+    ///
+    /// ## Deutsches Synonym
+    /// [mit_vorverarbeitung](Argumente::mit_vorverarbeitung)
+    #[inline(always)]
+    pub fn with_preprocessing(
+        self,
+        f: impl 't + Fn(Vec<OsString>) -> Vec<OsString>,
+    ) -> Arguments<'t, T, E> {
+        self.mit_vorverarbeitung(f)
+    }
+
+    /// Unterdrücke die Nachrichten beim frühen Beenden (z.B. `--version`)
+    /// und Warnungen (z.B. einer [veralteten Flag](Argumente::veraltete_flag))
+    /// in [parse_vollständig](Argumente::parse_vollständig) und verwandten Methoden,
+    /// z.B. wenn ein globales `--leise` gesetzt ist.
+    /// Fehlermeldungen werden weiterhin in `stderr` geschrieben.
+    ///
+    /// ## English synonym
+    /// [quiet](Arguments::quiet)
+    pub fn leise(mut self) -> Argumente<'t, T, E> {
+        self.einstellungen.leise = Some(true);
+        self
+    }
+
+    /// Suppress the messages on an early exit (e.g. `--version`)
+    /// and warnings (e.g. of a [deprecated flag](Arguments::deprecated_flag))
+    /// in [parse_complete](Arguments::parse_complete) and related methods,
+    /// e.g. if a global `--quiet` is set.
+    /// Error messages are still written to `stderr`.
+    ///
+    /// ## Deutsches Synonym
+    /// [leise](Argumente::leise)
+    #[inline(always)]
+    pub fn quiet(self) -> Arguments<'t, T, E> {
+        self.leise()
+    }
+
+    /// Trenne mehrere Nachrichten beim frühen Beenden (z.B. `--hilfe --version`)
+    /// in [parse_vollständig](Argumente::parse_vollständig) und verwandten Methoden durch
+    /// `trenner`, z.B. eine Trennlinie.
+    /// Ohne explizite Einstellung wird jede Nachricht in einer eigenen Zeile ausgegeben,
+    /// was dem Trenner `"\n"` entspricht.
+    ///
+    /// ## English synonym
+    /// [with_message_separator](Arguments::with_message_separator)
+    pub fn mit_nachrichten_trenner(mut self, trenner: &'t str) -> Argumente<'t, T, E> {
+        self.einstellungen.nachrichten_trenner = Some(trenner);
+        self
+    }
+
+    /// Separate multiple messages on an early exit (e.g. `--help --version`)
+    /// in [parse_complete](Arguments::parse_complete) and related methods by
+    /// `separator`, e.g. a divider line.
+    /// Without explicit setting, every message is printed on its own line,
+    /// which is equivalent to the separator `"\n"`.
+    ///
+    /// ## Deutsches Synonym
+    /// [mit_nachrichten_trenner](Argumente::mit_nachrichten_trenner)
+    #[inline(always)]
+    pub fn with_message_separator(self, separator: &'t str) -> Arguments<'t, T, E> {
+        self.mit_nachrichten_trenner(separator)
+    }
+
+    /// Zeige bei Fehlern in [parse_vollständig](Argumente::parse_vollständig)
+    /// zusätzlich den Aufruf an, wie ihn der Parser nach der Vorverarbeitung sieht
+    /// (siehe [vorverarbeitete_args](Argumente::vorverarbeitete_args)).
+    ///
+    /// ## English synonym
+    /// [show_invocation_on_error_with_language](Arguments::show_invocation_on_error_with_language)
+    #[inline(always)]
+    pub fn zeige_aufruf_bei_fehler_mit_sprache(self, sprache: Sprache) -> Argumente<'t, T, E> {
+        self.zeige_aufruf_bei_fehler(sprache.aufruf)
+    }
+
+    /// Additionally show the invocation as seen by the parser after pre-processing
+    /// (see [preprocessed_args](Arguments::preprocessed_args))
+    /// for errors in [parse_complete](Arguments::parse_complete).
+    ///
+    /// ## Deutsches Synonym
+    /// [zeige_aufruf_bei_fehler_mit_sprache](Argumente::zeige_aufruf_bei_fehler_mit_sprache)
+    #[inline(always)]
+    pub fn show_invocation_on_error_with_language(self, language: Language) -> Arguments<'t, T, E> {
+        self.zeige_aufruf_bei_fehler_mit_sprache(language)
+    }
+
+    /// Zeige bei Fehlern in [parse_vollständig](Argumente::parse_vollständig)
+    /// zusätzlich den Aufruf an, wie ihn der Parser nach der Vorverarbeitung sieht
+    /// (siehe [vorverarbeitete_args](Argumente::vorverarbeitete_args)),
+    /// z.B. `Aufruf: --nachricht 'hallo welt' -a -b`.
+    /// Die Zeile beginnt mit der Beschreibung `aufruf`.
+    ///
+    /// ## English synonym
+    /// [show_invocation_on_error](Arguments::show_invocation_on_error)
+    pub fn zeige_aufruf_bei_fehler(mut self, aufruf: &'t str) -> Argumente<'t, T, E> {
+        self.einstellungen.aufruf = Some(aufruf);
+        self
+    }
+
+    /// Additionally show the invocation as seen by the parser after pre-processing
+    /// (see [preprocessed_args](Arguments::preprocessed_args))
+    /// for errors in [parse_complete](Arguments::parse_complete),
+    /// e.g. `Invocation: --message 'hello world' -a -b`.
+    /// The line starts with the description `invocation`.
+    ///
+    /// ## Deutsches Synonym
+    /// [zeige_aufruf_bei_fehler](Argumente::zeige_aufruf_bei_fehler)
+    #[inline(always)]
+    pub fn show_invocation_on_error(self, invocation: &'t str) -> Arguments<'t, T, E> {
+        self.zeige_aufruf_bei_fehler(invocation)
+    }
+
+    /// Begrenze die Breite der Namen-Spalte im Hilfe-Text auf `breite` Grapheme.
+    ///
+    /// Längere Namen werden auf mehrere Zeilen aufgeteilt,
+    /// die Beschreibung folgt dann eingerückt in der nächsten Zeile.
+    /// Die Einstellung muss vor dem Erstellen der Hilfe
+    /// (z.B. über [hilfe](Argumente::hilfe)) gesetzt werden.
+    ///
+    /// ## English synonym
+    /// [with_max_name_width](Arguments::with_max_name_width)
+    pub fn mit_max_namen_breite(mut self, breite: usize) -> Argumente<'t, T, E> {
+        self.einstellungen.max_namen_breite = Some(breite);
+        self
+    }
+
+    /// Limit the width of the name column in the help text to `width` graphemes.
+    ///
+    /// Longer names are split onto multiple lines,
+    /// the description then follows indented on the next line.
+    /// The setting has to be set before the help is created
+    /// (e.g. using [help](Arguments::help)).
+    ///
+    /// ## Deutsches Synonym
+    /// [mit_max_namen_breite](Argumente::mit_max_namen_breite)
+    #[inline(always)]
+    pub fn with_max_name_width(self, width: usize) -> Arguments<'t, T, E> {
+        self.mit_max_namen_breite(width)
+    }
+
+    /// Verwende `layout` für die Anordnung der Namen im Hilfe-Text.
+    /// Die Einstellung muss vor dem Erstellen der Hilfe
+    /// (z.B. über [hilfe](Argumente::hilfe)) gesetzt werden.
+    ///
+    /// ## English synonym
+    /// [with_help_layout](Arguments::with_help_layout)
+    pub fn mit_hilfe_layout(mut self, layout: HilfeLayout) -> Argumente<'t, T, E> {
+        self.einstellungen.hilfe_layout = Some(layout);
+        self
+    }
+
+    /// Use `layout` for the arrangement of names in the help text.
+    /// The setting has to be set before the help is created
+    /// (e.g. using [help](Arguments::help)).
+    ///
+    /// ## Deutsches Synonym
+    /// [mit_hilfe_layout](Argumente::mit_hilfe_layout)
+    #[inline(always)]
+    pub fn with_help_layout(self, layout: HelpLayout) -> Arguments<'t, T, E> {
+        self.mit_hilfe_layout(layout)
+    }
+
+    /// Dokumentiere die Exit-Codes des Programms in einem eigenen Abschnitt im Hilfe-Text,
+    /// mit deutscher Überschrift.
+    /// Die Einstellung muss vor dem Erstellen der Hilfe
+    /// (z.B. über [hilfe](Argumente::hilfe)) gesetzt werden.
+    ///
+    /// ## English version
+    /// [with_exit_codes](Arguments::with_exit_codes)
+    #[inline(always)]
+    pub fn mit_exit_codes(self, exit_codes: &'t [(i32, &'t str)]) -> Argumente<'t, T, E> {
+        self.mit_exit_codes_und_sprache(exit_codes, Sprache::DEUTSCH)
+    }
+
+    /// Document the exit codes of the program in a separate section of the help text,
+    /// with an english heading.
+    /// The setting has to be set before the help is created
+    /// (e.g. using [help](Arguments::help)).
+    ///
+    /// ## Deutsche Version
+    /// [mit_exit_codes](Argumente::mit_exit_codes)
+    #[inline(always)]
+    pub fn with_exit_codes(self, exit_codes: &'t [(i32, &'t str)]) -> Arguments<'t, T, E> {
+        self.mit_exit_codes_und_sprache(exit_codes, Sprache::ENGLISH)
+    }
+
+    /// Dokumentiere die Exit-Codes des Programms in einem eigenen Abschnitt im Hilfe-Text,
+    /// jeweils mit Code und Beschreibung.
+    /// Die Überschrift des Abschnitts wird aus der [Sprache] übernommen.
+    ///
+    /// Die Angabe dient nur der Dokumentation, das Verhalten beim Beenden bleibt unverändert.
+    /// Die Einstellung muss vor dem Erstellen der Hilfe
+    /// (z.B. über [hilfe](Argumente::hilfe)) gesetzt werden.
+    ///
+    /// ## English synonym
+    /// [with_exit_codes_and_language](Arguments::with_exit_codes_and_language)
+    pub fn mit_exit_codes_und_sprache(
+        mut self,
+        exit_codes: &'t [(i32, &'t str)],
+        sprache: Sprache,
+    ) -> Argumente<'t, T, E> {
+        self.einstellungen.exit_codes = Some((sprache.exit_codes, exit_codes));
+        self
+    }
+
+    /// Document the exit codes of the program in a separate section of the help text,
+    /// each with code and description.
+    /// The heading of the section is taken from the [Language].
+    ///
+    /// This is only documentation, the behaviour when exiting remains unchanged.
+    /// The setting has to be set before the help is created
+    /// (e.g. using [help](Arguments::help)).
+    ///
+    /// ## Deutsches Synonym
+    /// [mit_exit_codes_und_sprache](Argumente::mit_exit_codes_und_sprache)
+    #[inline(always)]
+    pub fn with_exit_codes_and_language(
+        self,
+        exit_codes: &'t [(i32, &'t str)],
+        language: Language,
+    ) -> Arguments<'t, T, E> {
+        self.mit_exit_codes_und_sprache(exit_codes, language)
+    }
+
+    /// Zeige im Hilfe-Text `anzeige` anstelle des `wert_infix` aller Wert-Argumente an,
+    /// z.B. `--name=WERT` anstelle von `--name(=| )WERT` für `anzeige = "="`.
+    ///
+    /// Vor dem Kombinieren aufgerufen betrifft die Einstellung nur ein einzelnes Argument.
+    /// Das Parsen wird dadurch nicht beeinflusst,
+    /// der Wert kann weiterhin nach dem `wert_infix` oder im folgenden Argument angegeben werden.
+    /// Die Einstellung muss vor dem Erstellen der Hilfe
+    /// (z.B. über [hilfe](Argumente::hilfe)) gesetzt werden.
+    ///
+    /// ## English synonym
+    /// [with_value_infix_display](Arguments::with_value_infix_display)
+    pub fn mit_wert_infix_anzeige(mut self, anzeige: &'t str) -> Argumente<'t, T, E> {
+        for konfiguration in self.konfigurationen.iter_mut() {
+            if let Konfiguration::Wert { wert_infix_anzeige, .. } = konfiguration {
+                *wert_infix_anzeige = Some(anzeige);
+            }
+        }
+        self
+    }
+
+    /// Show `display` instead of the `value_infix` of all value-arguments in the help-text,
+    /// e.g. `--name=VALUE` instead of `--name(=| )VALUE` for `display = "="`.
+    ///
+    /// Called before combining, the setting only affects a single argument.
+    /// Parsing is not affected by this,
+    /// the value can still be given after the `value_infix` or in the following argument.
+    /// The setting has to be set before the help is created
+    /// (e.g. using [help](Arguments::help)).
+    ///
+    /// ## Deutsches Synonym
+    /// [mit_wert_infix_anzeige](Argumente::mit_wert_infix_anzeige)
+    #[inline(always)]
+    pub fn with_value_infix_display(self, display: &'t str) -> Arguments<'t, T, E> {
+        self.mit_wert_infix_anzeige(display)
+    }
+
+    /// Markiere alle Wert-Argumente als geheim (z.B. für Passwörter).
+    /// Ihre Werte werden im [rekonstruierten Aufruf](Argumente::rekonstruierter_aufruf)
+    /// und ein Standard-Wert im Hilfe-Text und der [Debug]-Ausgabe als `***` angezeigt.
+    ///
+    /// Vor dem Kombinieren aufgerufen betrifft die Einstellung nur ein einzelnes Argument.
+    ///
+    /// ## English synonym
+    /// [secret](Arguments::secret)
+    pub fn geheim(mut self) -> Argumente<'t, T, E> {
+        for konfiguration in self.konfigurationen.iter_mut() {
+            if let Konfiguration::Wert { beschreibung, geheim, .. } = konfiguration {
+                *geheim = true;
+                if let Some(standard) = &mut beschreibung.standard {
+                    *standard = GEHEIM.to_owned();
+                }
+            }
+        }
+        self
+    }
+
+    /// Mark all value-arguments as secret (e.g. for passwords).
+    /// Their values are shown as `***` in the [reconstructed invocation](Arguments::reconstructed_invocation)
+    /// and a default value in the help-text and [Debug]-output.
+    ///
+    /// Called before combining, the setting only affects a single argument.
+    ///
+    /// ## Deutsches Synonym
+    /// [geheim](Argumente::geheim)
+    #[inline(always)]
+    pub fn secret(self) -> Arguments<'t, T, E> {
+        self.geheim()
+    }
+
+    /// Setze den Sortier-Schlüssel aller Argumente für die Reihenfolge im Hilfe-Text.
+    /// Kleinere Werte werden zuerst angezeigt, ohne explizite Einstellung ist der Schlüssel `0`.
+    /// Argumente mit gleichem Schlüssel werden in der Reihenfolge ihres Hinzufügens angezeigt.
+    /// Flags zum frühen Beenden (z.B. [hilfe](Argumente::hilfe), [version](Argumente::version_deutsch))
+    /// haben den Schlüssel [i32::MAX] und stehen daher zuletzt.
+    ///
+    /// Vor dem Kombinieren aufgerufen betrifft die Einstellung nur ein einzelnes Argument.
+    /// Nach dem Hinzufügen der Hilfe aufgerufen, betrifft sie auch die Hilfe- und Version-Flags.
+    /// Der Hilfe-Text wird beim Hinzufügen der Hilfe erstellt,
+    /// daher muss `reihenfolge` vorher aufgerufen werden.
+    ///
+    /// ## English synonym
+    /// [order](Arguments::order)
+    pub fn reihenfolge(mut self, reihenfolge: i32) -> Argumente<'t, T, E> {
+        for konfiguration in self.konfigurationen.iter_mut() {
+            match konfiguration {
+                Konfiguration::Flag { reihenfolge: schlüssel, .. }
+                | Konfiguration::Wert { reihenfolge: schlüssel, .. } => *schlüssel = reihenfolge,
+            }
+        }
+        self
+    }
+
+    /// Set the sort key of all arguments for the order in the help-text.
+    /// Smaller values are shown first, without explicit setting the key is `0`.
+    /// Arguments with the same key are shown in the order they were added.
+    /// Flags causing an early exit (e.g. [help](Arguments::help), [version](Arguments::version_english))
+    /// have the key [i32::MAX] and are therefore shown last.
+    ///
+    /// Called before combining, the setting only affects a single argument.
+    /// Called after adding the help, it also affects the help and version flags.
+    /// The help text is created when adding the help,
+    /// so `order` has to be called beforehand.
+    ///
+    /// ## Deutsches Synonym
+    /// [reihenfolge](Argumente::reihenfolge)
+    #[inline(always)]
+    pub fn order(self, order: i32) -> Arguments<'t, T, E> {
+        self.reihenfolge(order)
+    }
+
+    /// Verstecke den Lang- oder Kurznamen `alias` im Hilfe-Text, er wird aber weiterhin erkannt.
+    /// Betroffen sind alle Argumente mit dem Namen `alias`, andere Argumente werden nicht verändert.
+    /// Der erste Langname eines Arguments wird immer angezeigt.
+    ///
+    /// ## English synonym
+    /// [alias_hidden](Arguments::alias_hidden)
+    pub fn alias_versteckt(mut self, alias: &str) -> Argumente<'t, T, E> {
+        for konfiguration in self.konfigurationen.iter_mut() {
+            match konfiguration {
+                Konfiguration::Flag { beschreibung, versteckte_namen, .. }
+                | Konfiguration::Wert { beschreibung, versteckte_namen, .. } => {
+                    let hat_namen =
+                        beschreibung.lang.iter().chain(&beschreibung.kurz).any(|name| {
+                            let name: &str = name.as_ref();
+                            name == alias
+                        });
+                    if hat_namen {
+                        versteckte_namen.push(alias.to_owned())
+                    }
+                },
+            }
+        }
+        self
+    }
+
+    /// Hide the long or short name `alias` in the help-text, but still match it.
+    /// All arguments with the name `alias` are affected, other arguments stay unchanged.
+    /// The first long name of an argument is always shown.
+    ///
+    /// ## Deutsches Synonym
+    /// [alias_versteckt](Argumente::alias_versteckt)
+    #[inline(always)]
+    pub fn alias_hidden(self, alias: &str) -> Arguments<'t, T, E> {
+        self.alias_versteckt(alias)
+    }
+
+    /// Alle konfigurierten Kommandozeilen-Argumente.
+    /// Hiermit ist es möglich einen eigenen,
+    /// auf den konfigurierten Argumenten basierenden Hilfetext zu erzeugen.
+    ///
+    /// ## Deutsches Synonym
+    /// [configurations](Argumente::configurations)
+    #[inline(always)]
+    pub fn konfigurationen(&self) -> impl Iterator<Item = &Konfiguration<'_>> {
+        self.konfigurationen.iter()
+    }
+
+    /// All configured command line arguments.
+    /// This function allows creating your own help text based on the configured arguments.
+    ///
+    /// ## English synonym
+    /// [konfigurationen](Arguments::konfigurationen)
+    #[inline(always)]
+    pub fn configurations(&self) -> impl Iterator<Item = &Configuration<'_>> {
+        self.konfigurationen.iter()
+    }
+}
+
+impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
+    /// Verwende `fehlermeldung` statt der generischen Fehlermeldung,
+    /// wenn ein benötigtes Argument nicht genannt wurde
+    /// ([Fehler::FehlendeFlag] und [Fehler::FehlenderWert]).
+    /// Ein genannter Name ohne folgenden Wert (z.B. `--config` als letztes Argument)
+    /// erzeugt weiterhin die generische Fehlermeldung.
+    ///
+    /// Vor dem Kombinieren aufgerufen betrifft die Einstellung nur ein einzelnes Argument.
+    /// Bereits angepasste Fehlermeldungen einzelner Argumente bleiben erhalten.
+    ///
+    /// ## English synonym
+    /// [missing_error_message](Arguments::missing_error_message)
+    pub fn fehlermeldung_fehlend(self, fehlermeldung: &'t str) -> Argumente<'t, T, E> {
+        let Argumente { konfigurationen, flag_kurzformen, einstellungen, parse } = self;
+        let passe_an = move |fehler: &mut Fehler<'t, E>| match fehler {
+            Fehler::FehlendeFlag { fehlermeldung: angepasst @ None, .. }
+            | Fehler::FehlenderWert { genannt: false, fehlermeldung: angepasst @ None, .. } => {
+                *angepasst = Some(fehlermeldung)
+            },
+            _ => {},
+        };
+        Argumente {
+            konfigurationen,
+            flag_kurzformen,
+            einstellungen,
+            parse: Box::new(move |args, kontext| {
+                // Beim nachsichtigen Parsen gesammelte Fehler werden ebenfalls angepasst.
+                let bisherige_fehler =
+                    kontext.nachsichtig.as_ref().map_or(0, |fehler| fehler.len());
+                let (mut ergebnis, nicht_verwendet) = parse(args, kontext);
+                if let Some(gesammelt) = kontext.nachsichtig.as_deref_mut() {
+                    gesammelt.iter_mut().skip(bisherige_fehler).for_each(passe_an);
+                }
+                if let Ergebnis::Fehler(fehler) = &mut ergebnis {
+                    fehler.iter_mut().for_each(passe_an);
+                }
+                (ergebnis, nicht_verwendet)
+            }),
+        }
+    }
+
+    /// Use `error_message` instead of the generic error message,
+    /// if a required argument is missing ([Error::FehlendeFlag] and [Error::FehlenderWert]).
+    /// A given name without a following value (e.g. `--config` as the last argument)
+    /// still produces the generic error message.
+    ///
+    /// Called before combining, the setting only affects a single argument.
+    ///
+    /// ## Deutsches Synonym
+    /// [fehlermeldung_fehlend](Argumente::fehlermeldung_fehlend)
+    #[inline(always)]
+    pub fn missing_error_message(self, error_message: &'t str) -> Arguments<'t, T, E> {
+        self.fehlermeldung_fehlend(error_message)
     }
 }
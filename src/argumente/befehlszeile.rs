@@ -0,0 +1,158 @@
+//! Zerlegen einer kompletten Befehlszeile in einzelne Argumente.
+
+use std::{ffi::OsString, iter::Peekable, str::CharIndices};
+
+use crate::{
+    argumente::Argumente,
+    ergebnis::{Ergebnis, Result},
+};
+
+/// Ein Anführungszeichen einer Befehlszeile wurde nicht geschlossen,
+/// z.B. in `a "b c` (siehe [zerlege_befehlszeile]).
+///
+/// ## English synonym
+/// [UnterminatedQuote]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OffenesAnführungszeichen {
+    /// Das nicht geschlossene Anführungszeichen, `'` oder `"`.
+    ///
+    /// ## English
+    /// The unterminated quote, `'` or `"`.
+    pub anführungszeichen: char,
+
+    /// Byte-Index des Anführungszeichens in der Befehlszeile.
+    ///
+    /// ## English
+    /// Byte index of the quote in the command line.
+    pub position: usize,
+}
+
+/// A quote of a command line was not closed, e.g. in `a "b c` (see [split_command_line]).
+///
+/// ## Deutsches Synonym
+/// [OffenesAnführungszeichen]
+pub type UnterminatedQuote = OffenesAnführungszeichen;
+
+/// Zerlege eine Befehlszeile in einzelne Argumente, ähnlich einer POSIX-Shell.
+///
+/// Argumente werden durch Leerzeichen getrennt.
+/// Innerhalb von einfachen Anführungszeichen (`'...'`) werden alle Zeichen unverändert übernommen.
+/// Innerhalb von doppelten Anführungszeichen (`"..."`) kann mit `\` ein `"` oder `\` maskiert werden.
+/// Außerhalb von Anführungszeichen maskiert `\` das folgende Zeichen, z.B. ein Leerzeichen.
+/// Ein nicht geschlossenes Anführungszeichen führt zu einem [OffenesAnführungszeichen]-Fehler.
+///
+/// ## English synonym
+/// [split_command_line]
+pub fn zerlege_befehlszeile(
+    zeile: &str,
+) -> std::result::Result<Vec<OsString>, OffenesAnführungszeichen> {
+    let mut args = Vec::new();
+    let mut aktuell = String::new();
+    // Auch leere Anführungszeichen (`""`) erzeugen ein Argument.
+    let mut hat_argument = false;
+    let mut zeichen = zeile.char_indices().peekable();
+    while let Some((position, c)) = zeichen.next() {
+        match c {
+            '\'' => {
+                hat_argument = true;
+                let geschlossen = zeichen.by_ref().any(|(_position, c)| {
+                    let ende = c == '\'';
+                    if !ende {
+                        aktuell.push(c);
+                    }
+                    ende
+                });
+                if !geschlossen {
+                    return Err(OffenesAnführungszeichen { anführungszeichen: c, position });
+                }
+            },
+            '"' => {
+                hat_argument = true;
+                if !doppelte_anführungszeichen(&mut zeichen, &mut aktuell) {
+                    return Err(OffenesAnführungszeichen { anführungszeichen: c, position });
+                }
+            },
+            '\\' => {
+                hat_argument = true;
+                aktuell.push(zeichen.next().map_or('\\', |(_position, c)| c));
+            },
+            c if c.is_whitespace() => {
+                if hat_argument {
+                    args.push(OsString::from(std::mem::take(&mut aktuell)));
+                    hat_argument = false;
+                }
+            },
+            c => {
+                hat_argument = true;
+                aktuell.push(c);
+            },
+        }
+    }
+    if hat_argument {
+        args.push(OsString::from(aktuell));
+    }
+    Ok(args)
+}
+
+/// Split a command line into separate arguments, similar to a POSIX-shell.
+///
+/// Arguments are separated by whitespace.
+/// Inside single quotes (`'...'`) all characters are kept verbatim.
+/// Inside double quotes (`"..."`) a `"` or `\` can be escaped with `\`.
+/// Outside of quotes `\` escapes the following character, e.g. a space.
+/// A quote without a closing quote results in an [UnterminatedQuote] error.
+///
+/// ## Deutsches Synonym
+/// [zerlege_befehlszeile]
+#[inline(always)]
+pub fn split_command_line(line: &str) -> std::result::Result<Vec<OsString>, UnterminatedQuote> {
+    zerlege_befehlszeile(line)
+}
+
+/// Übernehme alle Zeichen bis zum schließenden doppelten Anführungszeichen.
+/// Gibt zurück, ob das schließende Anführungszeichen gefunden wurde.
+fn doppelte_anführungszeichen(
+    zeichen: &mut Peekable<CharIndices<'_>>,
+    aktuell: &mut String,
+) -> bool {
+    while let Some((_position, c)) = zeichen.next() {
+        match c {
+            '"' => return true,
+            '\\' if matches!(zeichen.peek(), Some((_, '"' | '\\'))) => {
+                aktuell.extend(zeichen.next().map(|(_position, c)| c))
+            },
+            c => aktuell.push(c),
+        }
+    }
+    false
+}
+
+impl<'t, T, E> Argumente<'t, T, E> {
+    /// Zerlege eine komplette Befehlszeile über [zerlege_befehlszeile]
+    /// und versuche aus den Argumenten den gewünschten Typ zu erzeugen,
+    /// z.B. für REPL-artige Programme.
+    /// Bei einem nicht geschlossenen Anführungszeichen wird nicht geparst.
+    ///
+    /// ## English synonym
+    /// [parse_command_line](crate::Arguments::parse_command_line)
+    pub fn parse_befehlszeile(
+        &self,
+        zeile: &str,
+    ) -> std::result::Result<(Ergebnis<'t, T, E>, Vec<OsString>), OffenesAnführungszeichen> {
+        Ok(self.parse(zerlege_befehlszeile(zeile)?.into_iter()))
+    }
+
+    /// Split a whole command line using [split_command_line]
+    /// and try to create the requested type from the arguments, e.g. for REPL-style programs.
+    /// With an unterminated quote nothing is parsed.
+    ///
+    /// ## Deutsches Synonym
+    /// [parse_befehlszeile](Argumente::parse_befehlszeile)
+    #[inline(always)]
+    pub fn parse_command_line(
+        &self,
+        line: &str,
+    ) -> std::result::Result<(Result<'t, T, E>, Vec<OsString>), UnterminatedQuote> {
+        self.parse_befehlszeile(line)
+    }
+}
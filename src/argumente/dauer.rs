@@ -0,0 +1,145 @@
+//! Wert-Argumente für eine Dauer, z.B. `2h30m`.
+
+use std::time::Duration;
+
+use crate::{
+    argumente::{Argumente, Arguments},
+    beschreibung::{Beschreibung, Description},
+    ergebnis::ParseFehler,
+    sprache::{Language, Sprache},
+    unicode::{Compare, Vergleich},
+};
+
+/// Unterstützte Einheiten mit ihrer Dauer in Nanosekunden.
+/// Die Reihenfolge wird beim Anzeigen verwendet.
+const EINHEITEN: [(&str, u128); 8] = [
+    ("d", 86_400_000_000_000),
+    ("h", 3_600_000_000_000),
+    ("m", 60_000_000_000),
+    ("s", 1_000_000_000),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("µs", 1_000),
+    ("ns", 1),
+];
+
+/// Parse eine Dauer aus einer Folge von Zahlen mit Einheit, z.B. `2h30m`, `500ms` oder `1d 12h`.
+/// Für ungültige Strings oder zu große Werte wird [None] zurückgegeben.
+fn parse_dauer(string: &str) -> Option<Duration> {
+    let mut nanosekunden: u128 = 0;
+    let mut rest = string.trim_start();
+    if rest.is_empty() {
+        return None;
+    }
+    while !rest.is_empty() {
+        let ziffern_ende = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let (zahl_str, nach_zahl) = rest.split_at(ziffern_ende);
+        let zahl: u128 = zahl_str.parse().ok()?;
+        let einheit_ende = nach_zahl
+            .find(|c: char| c.is_ascii_digit() || c.is_whitespace())
+            .unwrap_or(nach_zahl.len());
+        let (einheit, nach_einheit) = nach_zahl.split_at(einheit_ende);
+        let (_name, faktor) = EINHEITEN.iter().find(|(name, _faktor)| *name == einheit)?;
+        nanosekunden = nanosekunden.checked_add(zahl.checked_mul(*faktor)?)?;
+        rest = nach_einheit.trim_start();
+    }
+    let sekunden = u64::try_from(nanosekunden / 1_000_000_000).ok()?;
+    let rest_nanosekunden = u32::try_from(nanosekunden % 1_000_000_000).ok()?;
+    Some(Duration::new(sekunden, rest_nanosekunden))
+}
+
+/// Zeige eine Dauer im von [parse_dauer] akzeptierten Format an, z.B. `2h30m`.
+fn formatiere_dauer(dauer: &Duration) -> String {
+    let mut nanosekunden = dauer.as_nanos();
+    if nanosekunden == 0 {
+        return "0s".to_owned();
+    }
+    let mut string = String::new();
+    for (name, faktor) in EINHEITEN.iter().filter(|(name, _faktor)| *name != "µs") {
+        let anzahl = nanosekunden / faktor;
+        if anzahl > 0 {
+            string.push_str(&format!("{anzahl}{name}"));
+            nanosekunden %= faktor;
+        }
+    }
+    string
+}
+
+impl<'t> Argumente<'t, Duration, String> {
+    /// Erzeuge ein Wert-Argument für eine Dauer, z.B. `2h30m`, `500ms` oder `1d`,
+    /// mit der Meta-Variable für eine Dauer aus der [Sprache].
+    ///
+    /// ## English synonym
+    /// [value_duration_with_language](Arguments::value_duration_with_language)
+    #[inline(always)]
+    pub fn wert_dauer_mit_sprache(
+        beschreibung: Beschreibung<'t, Duration>,
+        sprache: Sprache,
+    ) -> Argumente<'t, Duration, String> {
+        Argumente::wert_dauer(beschreibung, sprache.wert_infix, sprache.meta_var_dauer)
+    }
+
+    /// Create a value-argument for a duration, e.g. `2h30m`, `500ms` or `1d`,
+    /// with the meta-variable for a duration from the [Language].
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_dauer_mit_sprache](Argumente::wert_dauer_mit_sprache)
+    #[inline(always)]
+    pub fn value_duration_with_language(
+        description: Description<'t, Duration>,
+        language: Language,
+    ) -> Arguments<'t, Duration, String> {
+        Argumente::wert_dauer_mit_sprache(description, language)
+    }
+
+    /// Erzeuge ein Wert-Argument für eine Dauer.
+    ///
+    /// Der Wert besteht aus einer Folge ganzer Zahlen mit Einheit,
+    /// optional durch Leerzeichen getrennt, z.B. `2h30m`, `500ms` oder `1d 12h`.
+    /// Unterstützte Einheiten sind `d`, `h`, `m`, `s`, `ms`, `us` (bzw. `µs`) und `ns`.
+    /// Im Hilfe-Text wird der Standard-Wert im selben Format angezeigt.
+    ///
+    /// ## English synonym
+    /// [value_duration](Arguments::value_duration)
+    pub fn wert_dauer(
+        beschreibung: Beschreibung<'t, Duration>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+    ) -> Argumente<'t, Duration, String> {
+        Argumente::wert(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            None,
+            |os_string| {
+                let string = os_string.into_string().map_err(ParseFehler::InvaliderString)?;
+                parse_dauer(&string).ok_or_else(|| {
+                    let einheiten = EINHEITEN.iter().map(|(name, _faktor)| *name);
+                    ParseFehler::ParseFehler(format!(
+                        "\"{string}\" ∉ ([0-9]+({}))+",
+                        einheiten.collect::<Vec<_>>().join("|")
+                    ))
+                })
+            },
+            formatiere_dauer,
+        )
+    }
+
+    /// Create a value-argument for a duration.
+    ///
+    /// The value consists of a sequence of integers with unit,
+    /// optionally separated by spaces, e.g. `2h30m`, `500ms` or `1d 12h`.
+    /// Supported units are `d`, `h`, `m`, `s`, `ms`, `us` (or `µs`) and `ns`.
+    /// The help text shows the default value in the same format.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_dauer](Argumente::wert_dauer)
+    #[inline(always)]
+    pub fn value_duration(
+        description: Description<'t, Duration>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+    ) -> Arguments<'t, Duration, String> {
+        Argumente::wert_dauer(description, value_infix, meta_var)
+    }
+}
@@ -0,0 +1,288 @@
+//! Wert-Argumente mit interaktiver Eingabeaufforderung, z.B. ohne Echo über das Terminal.
+
+#[cfg(feature = "rpassword")]
+use std::io::{self, IsTerminal};
+use std::{collections::HashMap, env, ffi::OsString};
+
+use nonempty::NonEmpty;
+
+#[cfg(feature = "rpassword")]
+use crate::sprache::{Language, Sprache};
+use crate::{
+    argumente::{nachsichtig::nachsichtiger_standard, wert::WertNamen, Argumente, Arguments},
+    beschreibung::{Beschreibung, Description, Konfiguration},
+    ergebnis::{Ergebnis, ParseError},
+    unicode::{Compare, Vergleich},
+};
+
+impl<'t, T: 't + Clone, E: 't> Argumente<'t, T, E> {
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Fehlt das Argument, wird der Wert ohne Echo über das Terminal abgefragt.
+    ///
+    /// ## English synonym
+    /// [value_with_prompt_and_language](Arguments::value_with_prompt_and_language)
+    #[cfg(feature = "rpassword")]
+    #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "rpassword")))]
+    #[inline(always)]
+    pub fn wert_mit_eingabeaufforderung_und_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        umgebungsvariable: Option<&'t str>,
+        eingabeaufforderung: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_mit_eingabeaufforderung(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            umgebungsvariable,
+            eingabeaufforderung,
+            parse,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// If the argument is missing, the value is read from the terminal without echo.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_eingabeaufforderung_und_sprache](Argumente::wert_mit_eingabeaufforderung_und_sprache)
+    #[cfg(feature = "rpassword")]
+    #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "rpassword")))]
+    #[inline(always)]
+    pub fn value_with_prompt_and_language(
+        description: Description<'t, T>,
+        environment_variable: Option<&'t str>,
+        prompt: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_eingabeaufforderung_und_sprache(
+            description,
+            environment_variable,
+            prompt,
+            parse,
+            display,
+            language,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    ///
+    /// Der Wert wird in folgender Reihenfolge bestimmt:
+    /// 1. Wert aus den Kommandozeilen-Argumenten.
+    /// 2. Wert der `umgebungsvariable`, sofern gesetzt.
+    /// 3. Standard-Wert der Beschreibung.
+    /// 4. Eingabe ohne Echo nach Anzeige der `eingabeaufforderung`,
+    ///    sofern die Standard-Eingabe ein Terminal ist.
+    ///
+    /// Ansonsten wird ein Fehler für einen fehlenden Wert zurückgegeben.
+    /// Die Eingabe wird wie bei [wert_mit_eingabeaufforderung_fn](Argumente::wert_mit_eingabeaufforderung_fn)
+    /// erst abgefragt, nachdem alle anderen Argumente erfolgreich geparst wurden.
+    ///
+    /// Benötigt das `rpassword`-Feature.
+    ///
+    /// ## English synonym
+    /// [value_with_prompt](Arguments::value_with_prompt)
+    #[cfg(feature = "rpassword")]
+    #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "rpassword")))]
+    pub fn wert_mit_eingabeaufforderung(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        umgebungsvariable: Option<&'t str>,
+        eingabeaufforderung: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_mit_eingabeaufforderung_fn(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            umgebungsvariable,
+            move || {
+                if io::stdin().is_terminal() {
+                    rpassword::prompt_password(eingabeaufforderung).ok().map(OsString::from)
+                } else {
+                    None
+                }
+            },
+            parse,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    ///
+    /// The value is determined in the following order:
+    /// 1. Value from the command line arguments.
+    /// 2. Value of the `environment_variable`, if set.
+    /// 3. Default value of the description.
+    /// 4. Input without echo after showing the `prompt`, if stdin is a terminal.
+    ///
+    /// Otherwise, an error for a missing value is returned.
+    ///
+    /// The input is only requested like for [value_with_prompt_fn](Arguments::value_with_prompt_fn)
+    /// after all other arguments were parsed successfully.
+    ///
+    /// Requires the `rpassword`-feature.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_eingabeaufforderung](Argumente::wert_mit_eingabeaufforderung)
+    #[cfg(feature = "rpassword")]
+    #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "rpassword")))]
+    #[inline(always)]
+    pub fn value_with_prompt(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        environment_variable: Option<&'t str>,
+        prompt: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_eingabeaufforderung(
+            description,
+            value_infix,
+            meta_var,
+            environment_variable,
+            prompt,
+            parse,
+            display,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    ///
+    /// Der Wert wird in folgender Reihenfolge bestimmt:
+    /// 1. Wert aus den Kommandozeilen-Argumenten.
+    /// 2. Wert der `umgebungsvariable`, sofern gesetzt.
+    /// 3. Standard-Wert der Beschreibung.
+    /// 4. Ergebnis der `eingabeaufforderung`-Funktion, z.B. eine Eingabe über das Terminal.
+    ///
+    /// Ansonsten wird ein Fehler für einen fehlenden Wert zurückgegeben.
+    ///
+    /// Die `eingabeaufforderung` wird nur von [parse](Argumente::parse) und darauf aufbauenden
+    /// Methoden aufgerufen, nachdem alle anderen Argumente erfolgreich geparst wurden.
+    /// Bei frühem Beenden (z.B. `--hilfe`), anderen Fehlern, einem [Probelauf](Argumente::prüfe)
+    /// oder [nachsichtigem Parsen](Argumente::parse_nachsichtig) wird sie nicht aufgerufen.
+    ///
+    /// ## English synonym
+    /// [value_with_prompt_fn](Arguments::value_with_prompt_fn)
+    pub fn wert_mit_eingabeaufforderung_fn(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        umgebungsvariable: Option<&'t str>,
+        eingabeaufforderung: impl 't + Fn() -> Option<OsString>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, E> {
+        let wert_namen = WertNamen::neu(&beschreibung, wert_infix.into(), meta_var);
+        let (beschreibung, standard) = beschreibung.als_string_beschreibung_allgemein(anzeige);
+        let mut einstellungen = wert_namen.einstellungen();
+        einstellungen.eingabeaufforderungen += 1;
+        Argumente {
+            konfigurationen: vec![Konfiguration::Wert {
+                beschreibung,
+                wert_infix: wert_namen.wert_infix.clone(),
+                wert_infix_anzeige: None,
+                geheim: false,
+                meta_var,
+                mögliche_werte: None,
+                reihenfolge: 0,
+                versteckte_namen: Vec::new(),
+            }],
+            flag_kurzformen: HashMap::new(),
+            einstellungen,
+            parse: Box::new(move |args, kontext| {
+                let mut ergebnis = None;
+                let mut fehler = Vec::new();
+                let nicht_verwendet =
+                    wert_namen.suche(args, kontext.kandidaten, |arg| match arg.map(&parse) {
+                        Some(Ok(wert)) => ergebnis = Some(wert),
+                        Some(Err(parse_fehler)) => {
+                            fehler.push(wert_namen.fehler_parse(parse_fehler))
+                        },
+                        None => fehler.push(wert_namen.fehler_kein_wert()),
+                    });
+                if ergebnis.is_none() && fehler.is_empty() {
+                    let ersatz = if let Some(wert_os_str) = umgebungsvariable.and_then(env::var_os)
+                    {
+                        Some(parse(wert_os_str))
+                    } else if let Some(wert) = &standard {
+                        Some(Ok(wert.clone()))
+                    } else if kontext.zustand.eingabeaufforderung_erlaubt {
+                        eingabeaufforderung().map(&parse)
+                    } else {
+                        // Die Abfrage erfolgt erst, wenn alle anderen Argumente erfolgreich sind.
+                        kontext.zustand.eingabeaufforderungen_benötigt += 1;
+                        None
+                    };
+                    match ersatz {
+                        Some(Ok(wert)) => ergebnis = Some(wert),
+                        Some(Err(parse_fehler)) => {
+                            fehler.push(wert_namen.fehler_parse(parse_fehler))
+                        },
+                        None => {},
+                    }
+                }
+                if let Some(fehler) = NonEmpty::from_vec(fehler) {
+                    let ergebnis = nachsichtiger_standard(
+                        Ergebnis::Fehler(fehler),
+                        standard.as_ref(),
+                        kontext.nachsichtig.as_deref_mut(),
+                    );
+                    (ergebnis, nicht_verwendet)
+                } else if let Some(wert) = ergebnis {
+                    (Ergebnis::Wert(wert), nicht_verwendet)
+                } else {
+                    (
+                        Ergebnis::Fehler(NonEmpty::singleton(wert_namen.fehler_fehlend())),
+                        nicht_verwendet,
+                    )
+                }
+            }),
+        }
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    ///
+    /// The value is determined in the following order:
+    /// 1. Value from the command line arguments.
+    /// 2. Value of the `environment_variable`, if set.
+    /// 3. Default value of the description.
+    /// 4. Result of the `prompt`-function, e.g. an input from the terminal.
+    ///
+    /// Otherwise, an error for a missing value is returned.
+    ///
+    /// The `prompt` is only called by [parse](Arguments::parse) and methods based on it,
+    /// after all other arguments were parsed successfully.
+    /// It is not called for an early exit (e.g. `--help`), other errors,
+    /// a [dry run](Arguments::check) or [lenient parsing](Arguments::parse_lenient).
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_eingabeaufforderung_fn](Argumente::wert_mit_eingabeaufforderung_fn)
+    #[inline(always)]
+    pub fn value_with_prompt_fn(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        environment_variable: Option<&'t str>,
+        prompt: impl 't + Fn() -> Option<OsString>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_eingabeaufforderung_fn(
+            description,
+            value_infix,
+            meta_var,
+            environment_variable,
+            prompt,
+            parse,
+            display,
+        )
+    }
+}
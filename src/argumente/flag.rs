@@ -1,17 +1,18 @@
 //! Flag-Argumente.
 
-use std::{convert::identity, fmt::Display, iter};
+use std::{collections::HashMap, convert::identity, fmt::Display, iter};
 
-use itertools::Itertools;
 use nonempty::NonEmpty;
-use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    argumente::{Argumente, Arguments},
+    argumente::{
+        ist_bekannter_lang_name, nachsichtig::nachsichtiger_standard, namen_index::NamenEintrag,
+        Argumente, Arguments, ParseEinstellungen,
+    },
     beschreibung::{contains_str, Beschreibung, Description, Konfiguration},
     ergebnis::{Ergebnis, Fehler, Namen},
     sprache::{Language, Sprache},
-    unicode::{Normalisiert, Vergleich},
+    unicode::{Compare, Normalisiert, Vergleich},
 };
 
 impl<'t, E> Argumente<'t, bool, E> {
@@ -69,6 +70,333 @@ impl<'t, E> Argumente<'t, bool, E> {
     ) -> Argumente<'t, bool, E> {
         Argumente::flag_display(beschreibung, identity, invertiere_präfix, invertiere_infix)
     }
+
+    /// Erzeuge ein Flag-Argument, dass mit dem konfigurierten Präfix deaktiviert werden kann.
+    /// Werden beide Formen genannt, wird ein [Fehler::WidersprüchlicheFlags] zurückgegeben.
+    ///
+    /// ## English synonym
+    /// [flag_bool_strict_with_language](Arguments::flag_bool_strict_with_language)
+    #[inline(always)]
+    pub fn flag_bool_streng_mit_sprache(
+        beschreibung: Beschreibung<'t, bool>,
+        sprache: Sprache,
+    ) -> Argumente<'t, bool, E> {
+        Argumente::flag_bool_streng(
+            beschreibung,
+            sprache.invertiere_präfix,
+            sprache.invertiere_infix,
+            sprache.widersprüchliche_flags,
+        )
+    }
+
+    /// Create a flag-argument which can be deactivated with the configured prefix.
+    /// If both forms are given, an [Error::WidersprüchlicheFlags](crate::Error::WidersprüchlicheFlags) is returned.
+    ///
+    /// ## Deutsches Synonym
+    /// [flag_bool_streng_mit_sprache](Argumente::flag_bool_streng_mit_sprache)
+    #[inline(always)]
+    pub fn flag_bool_strict_with_language(
+        description: Description<'t, bool>,
+        language: Language,
+    ) -> Arguments<'t, bool, E> {
+        Argumente::flag_bool_streng_mit_sprache(description, language)
+    }
+
+    /// Erzeuge ein Flag-Argument, dass mit dem konfigurierten Präfix deaktiviert werden kann.
+    /// Werden beide Formen genannt, wird ein [Fehler::WidersprüchlicheFlags]
+    /// mit Beschreibung `widersprüchliche_flags` zurückgegeben.
+    ///
+    /// ## English synonym
+    /// [flag_bool_strict](Arguments::flag_bool_strict)
+    #[inline(always)]
+    pub fn flag_bool_streng(
+        beschreibung: Beschreibung<'t, bool>,
+        invertiere_präfix: impl Into<Vergleich<'t>>,
+        invertiere_infix: impl Into<Vergleich<'t>>,
+        widersprüchliche_flags: &'t str,
+    ) -> Argumente<'t, bool, E> {
+        Argumente::flag_streng(
+            beschreibung,
+            identity,
+            invertiere_präfix,
+            invertiere_infix,
+            bool::to_string,
+            widersprüchliche_flags,
+        )
+    }
+
+    /// Create a flag-argument which can be deactivated with the configured prefix.
+    /// If both forms are given, an [Error::WidersprüchlicheFlags](crate::Error::WidersprüchlicheFlags)
+    /// with description `contradictory_flags` is returned.
+    ///
+    /// ## Deutsches Synonym
+    /// [flag_bool_streng](Argumente::flag_bool_streng)
+    #[inline(always)]
+    pub fn flag_bool_strict(
+        description: Description<'t, bool>,
+        invert_prefix: impl Into<Compare<'t>>,
+        invert_infix: impl Into<Compare<'t>>,
+        contradictory_flags: &'t str,
+    ) -> Arguments<'t, bool, E> {
+        Argumente::flag_bool_streng(description, invert_prefix, invert_infix, contradictory_flags)
+    }
+
+    /// Erzeuge ein Flag-Argument, dass mit `ein_präfix` aktiviert
+    /// und mit `aus_präfix` deaktiviert wird, z.B. `+x`/`-x`.
+    ///
+    /// ## English synonym
+    /// [flag_bool_enable_disable](Arguments::flag_bool_enable_disable)
+    #[inline(always)]
+    pub fn flag_bool_ein_aus(
+        beschreibung: Beschreibung<'t, bool>,
+        ein_präfix: impl Into<Vergleich<'t>>,
+        aus_präfix: impl Into<Vergleich<'t>>,
+    ) -> Argumente<'t, bool, E> {
+        Argumente::flag_ein_aus(beschreibung, identity, ein_präfix, aus_präfix, bool::to_string)
+    }
+
+    /// Create a flag-argument, which is enabled with `enable_prefix`
+    /// and disabled with `disable_prefix`, e.g. `+x`/`-x`.
+    ///
+    /// ## Deutsches Synonym
+    /// [flag_bool_ein_aus](Argumente::flag_bool_ein_aus)
+    #[inline(always)]
+    pub fn flag_bool_enable_disable(
+        description: Description<'t, bool>,
+        enable_prefix: impl Into<Compare<'t>>,
+        disable_prefix: impl Into<Compare<'t>>,
+    ) -> Arguments<'t, bool, E> {
+        Argumente::flag_bool_ein_aus(description, enable_prefix, disable_prefix)
+    }
+}
+
+impl<'t, E> Argumente<'t, i64, E> {
+    /// Erzeuge ein Zähler-Flag, dessen Wert bei jeder Verwendung eines Namens aus `erhöhen`
+    /// um eins erhöht und bei jeder Verwendung eines Namens aus `verringern` um eins verringert wird.
+    /// Der Zähler beginnt beim Standard-Wert von `erhöhen`, bzw. 0;
+    /// der Standard-Wert von `verringern` wird ignoriert.
+    ///
+    /// KurzNamen beider Beschreibungen können zusammen angegeben werden,
+    /// z.B. ergibt `-vvq` den Wert 1, wenn `v` erhöht und `q` verringert.
+    ///
+    /// ## English synonym
+    /// [flag_counter_with_counterpart](Arguments::flag_counter_with_counterpart)
+    pub fn flag_zähler_mit_gegenstück(
+        erhöhen: Beschreibung<'t, i64>,
+        verringern: Beschreibung<'t, i64>,
+    ) -> Argumente<'t, i64, E> {
+        let mut flag_kurzformen = HashMap::new();
+        for beschreibung in [&erhöhen, &verringern] {
+            flag_kurzformen
+                .entry(beschreibung.kurz_präfix.clone())
+                .or_insert_with(Vec::new)
+                .extend(beschreibung.kurz.iter().cloned());
+        }
+        let start = erhöhen.standard.unwrap_or(0);
+        let namen = |beschreibung: &Beschreibung<'t, i64>| {
+            (
+                beschreibung.lang_präfix.clone(),
+                beschreibung.lang.clone(),
+                beschreibung.kurz_präfix.clone(),
+                beschreibung.kurz.clone(),
+            )
+        };
+        let namen_erhöhen = namen(&erhöhen);
+        let namen_verringern = namen(&verringern);
+        let (beschreibung_erhöhen, _standard) =
+            erhöhen.als_string_beschreibung_allgemein(i64::to_string);
+        let (mut beschreibung_verringern, _standard) =
+            verringern.als_string_beschreibung_allgemein(i64::to_string);
+        beschreibung_verringern.standard = None;
+        Argumente {
+            konfigurationen: vec![
+                Konfiguration::Flag {
+                    beschreibung: beschreibung_erhöhen,
+                    invertiere_präfix_infix: None,
+                    ein_aus_präfix: None,
+                    veraltet: None,
+                    reihenfolge: 0,
+                    versteckte_namen: Vec::new(),
+                },
+                Konfiguration::Flag {
+                    beschreibung: beschreibung_verringern,
+                    invertiere_präfix_infix: None,
+                    ein_aus_präfix: None,
+                    veraltet: None,
+                    reihenfolge: 0,
+                    versteckte_namen: Vec::new(),
+                },
+            ],
+            flag_kurzformen,
+            einstellungen: ParseEinstellungen::default(),
+            parse: Box::new(move |args, _kontext| {
+                let ist_name = |(lang_präfix, lang, kurz_präfix, kurz): &(
+                    Vergleich<'t>,
+                    NonEmpty<Vergleich<'t>>,
+                    Vergleich<'t>,
+                    Vec<Vergleich<'t>>,
+                ),
+                                normalisiert: &Normalisiert<'_>| {
+                    if let Some(lang_str) = lang_präfix.strip_als_präfix(normalisiert) {
+                        if contains_str(lang, lang_str) {
+                            return true;
+                        }
+                    }
+                    kurz_präfix
+                        .strip_als_präfix(normalisiert)
+                        .map(|kurz_str| contains_str(kurz, kurz_str))
+                        .unwrap_or(false)
+                };
+                let mut zähler = start;
+                let mut nicht_verwendet = Vec::new();
+                for arg in args {
+                    if let Some(string) = arg.as_ref().and_then(|os_string| os_string.to_str()) {
+                        let normalisiert = Normalisiert::neu(string);
+                        if ist_name(&namen_erhöhen, &normalisiert) {
+                            zähler = zähler.saturating_add(1);
+                            nicht_verwendet.push(None);
+                            continue;
+                        } else if ist_name(&namen_verringern, &normalisiert) {
+                            zähler = zähler.saturating_sub(1);
+                            nicht_verwendet.push(None);
+                            continue;
+                        }
+                    }
+                    nicht_verwendet.push(arg);
+                }
+                (Ergebnis::Wert(zähler), nicht_verwendet)
+            }),
+        }
+    }
+
+    /// Create a counter flag, whose value is increased by one for every usage of a name
+    /// from `increase` and decreased by one for every usage of a name from `decrease`.
+    /// The counter starts at the default value of `increase`, or 0;
+    /// the default value of `decrease` is ignored.
+    ///
+    /// Short names of both descriptions may be given together,
+    /// e.g. `-vvq` results in the value 1, if `v` increases and `q` decreases.
+    ///
+    /// ## Deutsches Synonym
+    /// [flag_zähler_mit_gegenstück](Argumente::flag_zähler_mit_gegenstück)
+    #[inline(always)]
+    pub fn flag_counter_with_counterpart(
+        increase: Description<'t, i64>,
+        decrease: Description<'t, i64>,
+    ) -> Arguments<'t, i64, E> {
+        Argumente::flag_zähler_mit_gegenstück(increase, decrease)
+    }
+}
+
+impl<'t, E> Argumente<'t, (), E> {
+    /// Erzeuge eine veraltete Flag, die akzeptiert wird, aber keine Auswirkung hat.
+    /// Im Hilfe-Text wird sie mit der Markierung aus der [Sprache] angezeigt.
+    ///
+    /// ## English synonym
+    /// [deprecated_flag_with_language](Arguments::deprecated_flag_with_language)
+    #[inline(always)]
+    pub fn veraltete_flag_mit_sprache(
+        beschreibung: Beschreibung<'t, ()>,
+        warnung: Option<&'t str>,
+        sprache: Sprache,
+    ) -> Argumente<'t, (), E> {
+        Argumente::veraltete_flag(beschreibung, warnung, sprache.veraltet)
+    }
+
+    /// Create a deprecated flag, which is accepted but doesn't have any effect.
+    /// The help text shows it with the marker from the [Language].
+    ///
+    /// ## Deutsches Synonym
+    /// [veraltete_flag_mit_sprache](Argumente::veraltete_flag_mit_sprache)
+    #[inline(always)]
+    pub fn deprecated_flag_with_language(
+        description: Description<'t, ()>,
+        warning: Option<&'t str>,
+        language: Language,
+    ) -> Arguments<'t, (), E> {
+        Argumente::veraltete_flag_mit_sprache(description, warning, language)
+    }
+
+    /// Erzeuge eine veraltete Flag, die akzeptiert wird, aber keine Auswirkung hat.
+    /// Ist eine `warnung` gesetzt, wird sie von [parse_vollständig](Argumente::parse_vollständig)
+    /// und verwandten Methoden über `stderr` ausgegeben, sofern die Flag genannt wurde
+    /// und die Einstellung [leise](Argumente::leise) nicht gesetzt ist.
+    /// Andere Methoden, z.B. [parse](Argumente::parse), erzeugen keine Ausgabe.
+    /// Im Hilfe-Text wird die Flag mit der Markierung `veraltet` angezeigt.
+    /// Das Ergebnis ist immer `()`, ein Standard-Wert wird nicht benötigt.
+    ///
+    /// ## English synonym
+    /// [deprecated_flag](Arguments::deprecated_flag)
+    pub fn veraltete_flag(
+        beschreibung: Beschreibung<'t, ()>,
+        warnung: Option<&'t str>,
+        veraltet: &'t str,
+    ) -> Argumente<'t, (), E> {
+        let name_lang_präfix = beschreibung.lang_präfix.clone();
+        let name_lang = beschreibung.lang.clone();
+        let name_kurz_präfix = beschreibung.kurz_präfix.clone();
+        let name_kurz = beschreibung.kurz.clone();
+        let flag_kurzformen =
+            iter::once((beschreibung.kurz_präfix.clone(), beschreibung.kurz.clone())).collect();
+        let (mut beschreibung, _standard) =
+            beschreibung.als_string_beschreibung_allgemein(|()| String::new());
+        beschreibung.standard = None;
+        Argumente {
+            konfigurationen: vec![Konfiguration::Flag {
+                beschreibung,
+                invertiere_präfix_infix: None,
+                ein_aus_präfix: None,
+                veraltet: Some(veraltet),
+                reihenfolge: 0,
+                versteckte_namen: Vec::new(),
+            }],
+            flag_kurzformen,
+            einstellungen: ParseEinstellungen::default(),
+            parse: Box::new(move |args, kontext| {
+                let mut genannt = false;
+                let mut nicht_verwendet = Vec::new();
+                for arg in args {
+                    if let Some(string) = arg.as_ref().and_then(|os_string| os_string.to_str()) {
+                        let normalisiert = Normalisiert::neu(string);
+                        let ist_name = name_lang_präfix
+                            .strip_als_präfix(&normalisiert)
+                            .is_some_and(|lang_str| contains_str(&name_lang, lang_str))
+                            || name_kurz_präfix
+                                .strip_als_präfix(&normalisiert)
+                                .is_some_and(|kurz_str| contains_str(&name_kurz, kurz_str));
+                        if ist_name {
+                            genannt = true;
+                            nicht_verwendet.push(None);
+                            continue;
+                        }
+                    }
+                    nicht_verwendet.push(arg);
+                }
+                if let Some(warnung) = warnung.filter(|_| genannt) {
+                    kontext.zustand.warnungen.push(warnung);
+                }
+                (Ergebnis::Wert(()), nicht_verwendet)
+            }),
+        }
+    }
+
+    /// Create a deprecated flag, which is accepted but doesn't have any effect.
+    /// If a `warning` is set, it is written to `stderr` by [parse_complete](Arguments::parse_complete)
+    /// and related methods, if the flag was given and the setting [quiet](Arguments::quiet) is not set.
+    /// Other methods, e.g. [parse](Arguments::parse), don't produce any output.
+    /// The help text shows the flag with the marker `deprecated`.
+    /// The result is always `()`, a default value is not required.
+    ///
+    /// ## Deutsches Synonym
+    /// [veraltete_flag](Argumente::veraltete_flag)
+    #[inline(always)]
+    pub fn deprecated_flag(
+        description: Description<'t, ()>,
+        warning: Option<&'t str>,
+        deprecated: &'t str,
+    ) -> Arguments<'t, (), E> {
+        Argumente::veraltete_flag(description, warning, deprecated)
+    }
 }
 
 impl<'t, T: 't + Display + Clone, E> Argumente<'t, T, E> {
@@ -214,12 +542,85 @@ impl<'t, T: 't + Clone, E> Argumente<'t, T, E> {
     ///
     /// ## English
     /// Create a flag-argument which can be deactivated with the configured prefix.
+    #[inline(always)]
     pub fn flag(
         beschreibung: Beschreibung<'t, T>,
         konvertiere: impl 't + Fn(bool) -> T,
         invertiere_präfix: impl Into<Vergleich<'t>>,
         invertiere_infix: impl Into<Vergleich<'t>>,
         anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, E> {
+        Argumente::flag_allgemein(
+            beschreibung,
+            konvertiere,
+            invertiere_präfix,
+            invertiere_infix,
+            anzeige,
+            None,
+        )
+    }
+
+    /// Erzeuge ein Flag-Argument, dass mit dem konfigurierten Präfix deaktiviert werden kann.
+    /// Werden sowohl die aktivierte, als auch die deaktivierte Form genannt
+    /// (z.B. `--verbose --kein-verbose`), wird ein
+    /// [Fehler::WidersprüchlicheFlags] mit Beschreibung `widersprüchliche_flags` zurückgegeben.
+    ///
+    /// ## English synonym
+    /// [flag_strict](Arguments::flag_strict)
+    #[inline(always)]
+    pub fn flag_streng(
+        beschreibung: Beschreibung<'t, T>,
+        konvertiere: impl 't + Fn(bool) -> T,
+        invertiere_präfix: impl Into<Vergleich<'t>>,
+        invertiere_infix: impl Into<Vergleich<'t>>,
+        anzeige: impl Fn(&T) -> String,
+        widersprüchliche_flags: &'t str,
+    ) -> Argumente<'t, T, E> {
+        Argumente::flag_allgemein(
+            beschreibung,
+            konvertiere,
+            invertiere_präfix,
+            invertiere_infix,
+            anzeige,
+            Some(widersprüchliche_flags),
+        )
+    }
+
+    /// Create a flag-argument which can be deactivated with the configured prefix.
+    /// If both the enabled and the disabled form are given
+    /// (e.g. `--verbose --no-verbose`), an
+    /// [Error::WidersprüchlicheFlags](crate::Error::WidersprüchlicheFlags) with description `contradictory_flags` is returned.
+    ///
+    /// ## Deutsches Synonym
+    /// [flag_streng](Argumente::flag_streng)
+    #[inline(always)]
+    pub fn flag_strict(
+        description: Description<'t, T>,
+        convert: impl 't + Fn(bool) -> T,
+        invert_prefix: impl Into<Compare<'t>>,
+        invert_infix: impl Into<Compare<'t>>,
+        display: impl Fn(&T) -> String,
+        contradictory_flags: &'t str,
+    ) -> Arguments<'t, T, E> {
+        Argumente::flag_streng(
+            description,
+            convert,
+            invert_prefix,
+            invert_infix,
+            display,
+            contradictory_flags,
+        )
+    }
+
+    /// Gemeinsame Implementierung für Flag-Argumente mit Präfix zum Deaktivieren.
+    /// Ist `widersprüchliche_flags` gesetzt, führt die Nennung beider Formen zu einem Fehler.
+    fn flag_allgemein(
+        beschreibung: Beschreibung<'t, T>,
+        konvertiere: impl 't + Fn(bool) -> T,
+        invertiere_präfix: impl Into<Vergleich<'t>>,
+        invertiere_infix: impl Into<Vergleich<'t>>,
+        anzeige: impl Fn(&T) -> String,
+        widersprüchliche_flags: Option<&'t str>,
     ) -> Argumente<'t, T, E> {
         let name_lang_präfix = beschreibung.lang_präfix.clone();
         let name_lang = beschreibung.lang.clone();
@@ -229,6 +630,18 @@ impl<'t, T: 't + Clone, E> Argumente<'t, T, E> {
             iter::once((beschreibung.kurz_präfix.clone(), beschreibung.kurz.clone())).collect();
         let invertiere_präfix_vergleich = invertiere_präfix.into();
         let invertiere_infix_vergleich = invertiere_infix.into();
+        let namen_eintrag = NamenEintrag::neu(
+            vec![
+                vec![name_lang_präfix.clone()],
+                vec![
+                    name_lang_präfix.clone(),
+                    invertiere_präfix_vergleich.clone(),
+                    invertiere_infix_vergleich.clone(),
+                ],
+                vec![name_kurz_präfix.clone()],
+            ],
+            name_lang.iter().chain(&name_kurz).cloned(),
+        );
         let (beschreibung, standard) = beschreibung.als_string_beschreibung_allgemein(anzeige);
         Argumente {
             konfigurationen: vec![Konfiguration::Flag {
@@ -237,18 +650,35 @@ impl<'t, T: 't + Clone, E> Argumente<'t, T, E> {
                     invertiere_präfix_vergleich.clone(),
                     invertiere_infix_vergleich.clone(),
                 )),
+                ein_aus_präfix: None,
+                veraltet: None,
+                reihenfolge: 0,
+                versteckte_namen: Vec::new(),
             }],
             flag_kurzformen,
-            parse: Box::new(move |args| {
+            einstellungen: ParseEinstellungen {
+                namen_einträge: vec![namen_eintrag.clone()],
+                ..ParseEinstellungen::default()
+            },
+            parse: Box::new(move |args, kontext| {
                 let name_kurz_existiert = !name_kurz.is_empty();
+                let kandidaten = kontext.kandidaten_für(&namen_eintrag, args.len());
                 let mut ergebnis = None;
+                let mut aktiviert = false;
+                let mut deaktiviert = false;
                 let mut nicht_verwendet = Vec::new();
-                for arg in args {
-                    if let Some(string) = arg.as_ref().and_then(|os_string| os_string.to_str()) {
+                for (position, arg) in args.into_iter().enumerate() {
+                    if kandidaten.as_ref().is_some_and(|kandidaten| !kandidaten.möglich(position))
+                    {
+                        // Laut Index kann an dieser Position kein Name stehen.
+                    } else if let Some(string) =
+                        arg.as_ref().and_then(|os_string| os_string.to_str())
+                    {
                         let normalisiert = Normalisiert::neu(string);
                         if let Some(lang_str) = name_lang_präfix.strip_als_präfix(&normalisiert) {
                             if contains_str(&name_lang, lang_str) {
                                 ergebnis = Some(konvertiere(true));
+                                aktiviert = true;
                                 nicht_verwendet.push(None);
                                 continue;
                             } else if let Some(infix_name) = invertiere_präfix_vergleich
@@ -259,24 +689,31 @@ impl<'t, T: 't + Clone, E> Argumente<'t, T, E> {
                                 if let Some(negiert) = invertiere_infix_vergleich
                                     .strip_als_präfix(&infix_name_normalisiert)
                                 {
-                                    if contains_str(&name_lang, negiert) {
+                                    // Ein exakter Langname eines anderen Arguments
+                                    // (z.B. `--kein-cache`) hat Vorrang.
+                                    if contains_str(&name_lang, negiert)
+                                        && !ist_bekannter_lang_name(
+                                            kontext.konfigurationen,
+                                            &normalisiert,
+                                        )
+                                    {
                                         ergebnis = Some(konvertiere(false));
+                                        deaktiviert = true;
                                         nicht_verwendet.push(None);
                                         continue;
                                     }
                                 }
                             }
-                        } else if name_kurz_existiert {
-                            if let Some(kurz_graphemes) =
+                        }
+                        // Mit leerem `lang_präfix` (z.B. `start`) beginnt jedes Argument
+                        // mit dem Präfix, daher werden anschließend die Kurznamen geprüft.
+                        if name_kurz_existiert {
+                            if let Some(kurz_str) =
                                 name_kurz_präfix.strip_als_präfix(&normalisiert)
                             {
-                                if kurz_graphemes
-                                    .graphemes(true)
-                                    .exactly_one()
-                                    .map(|name| contains_str(&name_kurz, name))
-                                    .unwrap_or(false)
-                                {
+                                if contains_str(&name_kurz, kurz_str) {
                                     ergebnis = Some(konvertiere(true));
+                                    aktiviert = true;
                                     nicht_verwendet.push(None);
                                     continue;
                                 }
@@ -285,23 +722,130 @@ impl<'t, T: 't + Clone, E> Argumente<'t, T, E> {
                     }
                     nicht_verwendet.push(arg);
                 }
+                let namen = || Namen {
+                    lang_präfix: name_lang_präfix.string.clone(),
+                    lang: name_lang.clone().map(|Vergleich { string, .. }| string),
+                    kurz_präfix: name_kurz_präfix.string.clone(),
+                    kurz: name_kurz.iter().map(|Vergleich { string, .. }| string.clone()).collect(),
+                };
+                let ergebnis = if let Some(widersprüchliche_flags) =
+                    widersprüchliche_flags.filter(|_| aktiviert && deaktiviert)
+                {
+                    let fehler = Fehler::WidersprüchlicheFlags {
+                        namen: namen(),
+                        invertiere_präfix: invertiere_präfix_vergleich.string.clone(),
+                        invertiere_infix: invertiere_infix_vergleich.string.clone(),
+                        widersprüchliche_flags,
+                    };
+                    nachsichtiger_standard(
+                        Ergebnis::Fehler(NonEmpty::singleton(fehler)),
+                        standard.as_ref(),
+                        kontext.nachsichtig.as_deref_mut(),
+                    )
+                } else if let Some(wert) = ergebnis {
+                    Ergebnis::Wert(wert)
+                } else if let Some(wert) = &standard {
+                    Ergebnis::Wert(wert.clone())
+                } else {
+                    let fehler = Fehler::FehlendeFlag {
+                        namen: namen(),
+                        invertiere_präfix: invertiere_präfix_vergleich.string.clone(),
+                        invertiere_infix: invertiere_infix_vergleich.string.clone(),
+                        fehlermeldung: None,
+                    };
+                    Ergebnis::Fehler(NonEmpty::singleton(fehler))
+                };
+                (ergebnis, nicht_verwendet)
+            }),
+        }
+    }
+
+    /// Erzeuge ein Flag-Argument, dass mit `ein_präfix` aktiviert
+    /// und mit `aus_präfix` deaktiviert wird, z.B. `+x`/`-x`.
+    ///
+    /// Nach dem Präfix kann jeder Lang- oder KurzName folgen,
+    /// `lang_präfix` und `kurz_präfix` der Beschreibung werden nicht verwendet.
+    /// KurzNamen können nicht mit anderen Flags zusammen angegeben werden.
+    ///
+    /// ## English synonym
+    /// [flag_enable_disable](Arguments::flag_enable_disable)
+    pub fn flag_ein_aus(
+        beschreibung: Beschreibung<'t, T>,
+        konvertiere: impl 't + Fn(bool) -> T,
+        ein_präfix: impl Into<Vergleich<'t>>,
+        aus_präfix: impl Into<Vergleich<'t>>,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, E> {
+        let name_lang = beschreibung.lang.clone();
+        let name_kurz = beschreibung.kurz.clone();
+        let ein_präfix_vergleich = ein_präfix.into();
+        let aus_präfix_vergleich = aus_präfix.into();
+        let (beschreibung, standard) = beschreibung.als_string_beschreibung_allgemein(anzeige);
+        Argumente {
+            konfigurationen: vec![Konfiguration::Flag {
+                beschreibung,
+                invertiere_präfix_infix: None,
+                ein_aus_präfix: Some((ein_präfix_vergleich.clone(), aus_präfix_vergleich.clone())),
+                veraltet: None,
+                reihenfolge: 0,
+                versteckte_namen: Vec::new(),
+            }],
+            flag_kurzformen: HashMap::new(),
+            einstellungen: ParseEinstellungen::default(),
+            parse: Box::new(move |args, _kontext| {
+                let ist_name =
+                    |name: &str| contains_str(&name_lang, name) || contains_str(&name_kurz, name);
+                let mut ergebnis = None;
+                let mut nicht_verwendet = Vec::new();
+                for arg in args {
+                    if let Some(string) = arg.as_ref().and_then(|os_string| os_string.to_str()) {
+                        let normalisiert = Normalisiert::neu(string);
+                        let wert = if ein_präfix_vergleich
+                            .strip_als_präfix(&normalisiert)
+                            .map(ist_name)
+                            .unwrap_or(false)
+                        {
+                            Some(true)
+                        } else if aus_präfix_vergleich
+                            .strip_als_präfix(&normalisiert)
+                            .map(ist_name)
+                            .unwrap_or(false)
+                        {
+                            Some(false)
+                        } else {
+                            None
+                        };
+                        if let Some(wert) = wert {
+                            ergebnis = Some(konvertiere(wert));
+                            nicht_verwendet.push(None);
+                            continue;
+                        }
+                    }
+                    nicht_verwendet.push(arg);
+                }
                 let ergebnis = if let Some(wert) = ergebnis {
                     Ergebnis::Wert(wert)
                 } else if let Some(wert) = &standard {
                     Ergebnis::Wert(wert.clone())
                 } else {
+                    let präfix = Normalisiert::neu(format!(
+                        "({}|{})",
+                        ein_präfix_vergleich.as_ref(),
+                        aus_präfix_vergleich.as_ref()
+                    ));
                     let fehler = Fehler::FehlendeFlag {
                         namen: Namen {
-                            lang_präfix: name_lang_präfix.string.clone(),
-                            lang: name_lang.clone().map(|Vergleich { string, case: _ }| string),
-                            kurz_präfix: name_kurz_präfix.string.clone(),
+                            lang_präfix: präfix.clone(),
+                            lang: name_lang.clone().map(|Vergleich { string, .. }| string),
+                            kurz_präfix: präfix,
                             kurz: name_kurz
                                 .iter()
-                                .map(|Vergleich { string, case: _ }| string.clone())
+                                .map(|Vergleich { string, .. }| string.clone())
                                 .collect(),
                         },
-                        invertiere_präfix: invertiere_präfix_vergleich.string.clone(),
-                        invertiere_infix: invertiere_infix_vergleich.string.clone(),
+                        invertiere_präfix: Normalisiert::neu(""),
+                        invertiere_infix: Normalisiert::neu(""),
+                        fehlermeldung: None,
                     };
                     Ergebnis::Fehler(NonEmpty::singleton(fehler))
                 };
@@ -309,4 +853,24 @@ impl<'t, T: 't + Clone, E> Argumente<'t, T, E> {
             }),
         }
     }
+
+    /// Create a flag-argument, which is enabled with `enable_prefix`
+    /// and disabled with `disable_prefix`, e.g. `+x`/`-x`.
+    ///
+    /// Any long or short name may follow the prefix,
+    /// `lang_präfix` and `kurz_präfix` of the description are not used.
+    /// Short names can't be given together with other flags.
+    ///
+    /// ## Deutsches Synonym
+    /// [flag_ein_aus](Argumente::flag_ein_aus)
+    #[inline(always)]
+    pub fn flag_enable_disable(
+        description: Description<'t, T>,
+        convert: impl 't + Fn(bool) -> T,
+        enable_prefix: impl Into<Compare<'t>>,
+        disable_prefix: impl Into<Compare<'t>>,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, T, E> {
+        Argumente::flag_ein_aus(description, convert, enable_prefix, disable_prefix, display)
+    }
 }
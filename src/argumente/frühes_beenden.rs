@@ -15,12 +15,128 @@ use unicode_segmentation::UnicodeSegmentation;
 use void::Void;
 
 use crate::{
-    argumente::{Argumente, Arguments},
-    beschreibung::{contains_str, Beschreibung, Description, Konfiguration, KurzNamen, LangNamen},
+    argumente::{namen_index::NamenEintrag, Argumente, Arguments, Aufzeichnung},
+    beschreibung::{
+        contains_str, Beschreibung, Description, Konfiguration, KurzNamen, LangNamen, MöglicheWerte,
+    },
     ergebnis::{namen_regex_hinzufügen, Ergebnis},
     sprache::{Language, Sprache},
     unicode::{Normalisiert, Vergleich},
 };
+/// Anordnung der Namen im Hilfe-Text.
+///
+/// ## English synonym
+/// [HelpLayout]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HilfeLayout {
+    /// Eine Spalte mit LangNamen, gefolgt von den KurzNamen: `--flag | -f`.
+    ///
+    /// ## English
+    /// One column with long names, followed by the short names: `--flag | -f`.
+    #[default]
+    Standard,
+
+    /// Rechtsbündige KurzNamen und linksbündige LangNamen in eigenen Spalten: `-f  --flag`.
+    ///
+    /// ## English
+    /// Right-aligned short names and left-aligned long names in separate columns: `-f  --flag`.
+    Tabelle,
+}
+
+/// Layout of the names in the help text.
+///
+/// ## Deutsches Synonym
+/// [HilfeLayout]
+pub type HelpLayout = HilfeLayout;
+
+/// Präfix und Infix zum Invertieren eines Flag-Arguments,
+/// oder `wert_infix`, Meta-Variable und `wert_infix_anzeige` eines Wert-Arguments.
+type FlagOderWert<'a, 't> = Either<
+    &'a Option<(Vergleich<'t>, Vergleich<'t>)>,
+    (&'a Vergleich<'t>, &'a str, Option<&'a str>),
+>;
+
+/// Beschreibungen im Hilfe-Text, siehe [erstelle_hilfe_text](Argumente::erstelle_hilfe_text).
+#[derive(Debug, Clone, Copy)]
+struct HilfeBeschreibungen<'a> {
+    optionen: &'a str,
+    standard: &'a str,
+    erlaubte_werte: &'a str,
+}
+
+/// Sortier-Schlüssel für Flags zum frühen Beenden (z.B. `--hilfe`, `--version`),
+/// damit sie im Hilfe-Text nach allen anderen Argumenten stehen.
+pub(crate) const REIHENFOLGE_FRÜHES_BEENDEN: i32 = i32::MAX;
+
+/// Minimale Breite der Beschreibungen, ab der sie im Hilfe-Text umgebrochen werden.
+const MIN_BESCHREIBUNG_BREITE: usize = 10;
+
+/// Breite des Terminals laut der Umgebungsvariable `COLUMNS`, sofern gesetzt und gültig.
+fn terminal_breite() -> Option<usize> {
+    env::var("COLUMNS").ok()?.trim().parse().ok().filter(|breite| *breite > 0)
+}
+
+/// Breche `text` an Leerzeichen in Zeilen mit höchstens `breite` Graphemes um.
+/// Zu lange Wörter werden nicht getrennt und stehen in einer eigenen Zeile.
+fn umbrechen(text: &str, breite: usize) -> Vec<String> {
+    let mut zeilen = Vec::new();
+    let mut zeile = String::new();
+    let mut zeile_breite = 0;
+    for wort in text.split_whitespace() {
+        let wort_breite = wort.graphemes(true).count();
+        if zeile_breite > 0 && zeile_breite + 1 + wort_breite > breite {
+            zeilen.push(std::mem::take(&mut zeile));
+            zeile_breite = 0;
+        }
+        if zeile_breite > 0 {
+            zeile.push(' ');
+            zeile_breite += 1;
+        }
+        zeile.push_str(wort);
+        zeile_breite += wort_breite;
+    }
+    if !zeile.is_empty() {
+        zeilen.push(zeile);
+    }
+    zeilen
+}
+
+/// Entferne alle im Hilfe-Text versteckten Namen aus der Beschreibung.
+/// Der erste Langname bleibt erhalten, falls alle Langnamen versteckt sind.
+fn sichtbare_namen<'b, 't>(
+    beschreibung: &'b Beschreibung<'t, String>,
+    versteckte_namen: &[String],
+) -> Cow<'b, Beschreibung<'t, String>> {
+    if versteckte_namen.is_empty() {
+        return Cow::Borrowed(beschreibung);
+    }
+    let ist_sichtbar = |name: &Vergleich<'_>| {
+        let name: &str = name.as_ref();
+        !versteckte_namen.iter().any(|versteckt| versteckt == name)
+    };
+    let mut sichtbar = beschreibung.clone();
+    let lang = beschreibung.lang.iter().filter(|name| ist_sichtbar(name)).cloned().collect();
+    sichtbar.lang = NonEmpty::from_vec(lang)
+        .unwrap_or_else(|| NonEmpty::singleton(beschreibung.lang.head.clone()));
+    sichtbar.kurz.retain(ist_sichtbar);
+    Cow::Owned(sichtbar)
+}
+
+/// Kurze Nutzungs-Zeile, z.B. `programm [OPTIONEN]`.
+///
+/// Als Name wird der Dateiname der ausgeführten Datei verwendet,
+/// sofern er bestimmt werden kann, ansonsten `programm_name`.
+pub(crate) fn kurze_nutzung(programm_name: &str, optionen: &str) -> String {
+    let current_exe = env::current_exe().ok();
+    let exe_name = current_exe
+        .as_ref()
+        .map(PathBuf::as_path)
+        .and_then(Path::file_name)
+        .and_then(OsStr::to_str)
+        .unwrap_or(programm_name);
+    format!("{exe_name} [{optionen}]")
+}
+
 impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
     /// Erzeuge `--version`- und `--hilfe`-Flags, die zu vorzeitigem Beenden führen.
     /// Wie [version_deutsch](Argumente::version_deutsch) und [hilfe](Argumente::hilfe)
@@ -251,7 +367,8 @@ impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
         programm_name: &str,
         version: &str,
     ) -> Argumente<'t, T, E> {
-        self.frühes_beenden(beschreibung, format!("{} {}", programm_name, version))
+        let version_text = self.version_text(programm_name, version);
+        self.frühes_beenden(beschreibung, version_text)
     }
 
     /// Create a flag causing an early exit which shows the configured program version.
@@ -268,6 +385,15 @@ impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
         self.zeige_version(description, program_name, version)
     }
 
+    /// Erstelle den Text, der von einer [Version-Flag](Argumente::zeige_version) angezeigt wird.
+    ///
+    /// ## English
+    /// Create the text shown by a [version flag](Arguments::show_version).
+    #[inline(always)]
+    pub fn version_text(&self, programm_name: &str, version: &str) -> String {
+        format!("{programm_name} {version}")
+    }
+
     /// Erzeuge eine `--hilfe`-Flag, die zu vorzeitigem Beenden führt.
     /// Zeige dabei eine automatisch generierte Hilfe.
     ///
@@ -506,9 +632,8 @@ impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
             programm_name,
             programm_beschreibung,
             version,
-            optionen,
-            standard,
-            erlaubte_werte,
+            HilfeBeschreibungen { optionen, standard, erlaubte_werte },
+            None,
         );
         self.frühes_beenden(eigene_beschreibung, hilfe_text)
     }
@@ -633,9 +758,8 @@ impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
             programm_name,
             programm_beschreibung,
             version,
-            optionen,
-            standard,
-            erlaubte_werte,
+            HilfeBeschreibungen { optionen, standard, erlaubte_werte },
+            None,
         )
     }
 
@@ -663,43 +787,90 @@ impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
         )
     }
 
+    /// Erstelle den Hilfe-Text für alle konfigurierten Argumente, angepasst an die Breite
+    /// des Terminals laut der Umgebungsvariable `COLUMNS`.
+    /// Beschreibungen werden dabei an Leerzeichen umgebrochen und eingerückt fortgesetzt.
+    ///
+    /// Ist `COLUMNS` nicht gesetzt, ungültig oder bleibt zu wenig Platz für die Beschreibungen
+    /// (weniger als 10 Zeichen), wird nicht umgebrochen.
+    ///
+    /// ## English synonym
+    /// [help_text_adapted](Arguments::help_text_adapted)
+    pub fn hilfe_text_angepasst(
+        &self,
+        programm_name: &str,
+        programm_beschreibung: Option<&str>,
+        version: Option<&str>,
+        sprache: Sprache,
+    ) -> String {
+        self.erstelle_hilfe_text_intern(
+            None,
+            programm_name,
+            programm_beschreibung,
+            version,
+            HilfeBeschreibungen {
+                optionen: sprache.optionen,
+                standard: sprache.standard,
+                erlaubte_werte: sprache.erlaubte_werte,
+            },
+            terminal_breite(),
+        )
+    }
+
+    /// Create the help-text for all configured arguments, adapted to the width
+    /// of the terminal according to the environment variable `COLUMNS`.
+    /// Descriptions are wrapped at spaces and continued indented.
+    ///
+    /// If `COLUMNS` is not set, invalid or there is too little space for the descriptions
+    /// (less than 10 characters), no wrapping happens.
+    ///
+    /// ## Deutsches Synonym
+    /// [hilfe_text_angepasst](Argumente::hilfe_text_angepasst)
+    #[inline(always)]
+    pub fn help_text_adapted(
+        &self,
+        program_name: &str,
+        program_description: Option<&str>,
+        version: Option<&str>,
+        language: Language,
+    ) -> String {
+        self.hilfe_text_angepasst(program_name, program_description, version, language)
+    }
+
     fn erstelle_hilfe_text_intern(
         &self,
         eigene_beschreibung: Option<&Beschreibung<'_, Void>>,
         programm_name: &str,
         programm_beschreibung: Option<&str>,
         version: Option<&str>,
-        optionen: &str,
-        standard: &str,
-        erlaubte_werte: &str,
+        hilfe_beschreibungen: HilfeBeschreibungen<'_>,
+        breite: Option<usize>,
     ) -> String {
-        let current_exe = env::current_exe().ok();
-        let exe_name = current_exe
-            .as_ref()
-            .map(PathBuf::as_path)
-            .and_then(Path::file_name)
-            .and_then(OsStr::to_str)
-            .unwrap_or(programm_name);
-        let mut name = programm_name.to_owned();
-        if let Some(version) = version {
-            name.push(' ');
-            name.push_str(version);
-        }
+        let HilfeBeschreibungen { optionen, standard, erlaubte_werte } = hilfe_beschreibungen;
+        let name = match version {
+            Some(version) => self.version_text(programm_name, version),
+            None => programm_name.to_owned(),
+        };
         let programm_beschreibung = programm_beschreibung
             .map(|programm_beschreibung| format!("\n{programm_beschreibung}"))
             .unwrap_or_default();
+        let kurze_nutzung = kurze_nutzung(programm_name, optionen);
         let mut hilfe_text =
-            format!("{name}{programm_beschreibung}\n\n{exe_name} [{optionen}]\n\n{optionen}:\n");
+            format!("{name}{programm_beschreibung}\n\n{kurze_nutzung}\n\n{optionen}:\n");
         let eigener_arg_string = eigene_beschreibung.map(|beschreibung| Konfiguration::Flag {
             beschreibung: beschreibung.clone().als_string_beschreibung().0,
             invertiere_präfix_infix: None,
+            ein_aus_präfix: None,
+            veraltet: None,
+            reihenfolge: REIHENFOLGE_FRÜHES_BEENDEN,
+            versteckte_namen: Vec::new(),
         });
         fn lang_regex(
-            lang_präfix: &Vergleich<'_>,
+            lang_präfix: &str,
             lang_namen: &NonEmpty<Vergleich<'_>>,
-            flag_oder_wert: Either<&Option<(Vergleich<'_>, Vergleich<'_>)>, (&Vergleich<'_>, &str)>,
+            flag_oder_wert: FlagOderWert<'_, '_>,
         ) -> String {
-            let mut lang_regex = lang_präfix.as_ref().to_owned();
+            let mut lang_regex = lang_präfix.to_owned();
             match flag_oder_wert {
                 Either::Left(invertiere_präfix_infix) => {
                     if let Some((präfix, infix)) = invertiere_präfix_infix {
@@ -710,147 +881,284 @@ impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
                     }
                     namen_regex_hinzufügen(&mut lang_regex, &lang_namen.head, &lang_namen.tail);
                 },
-                Either::Right((wert_infix, meta_var)) => {
+                Either::Right((wert_infix, meta_var, wert_infix_anzeige)) => {
                     namen_regex_hinzufügen(&mut lang_regex, &lang_namen.head, &lang_namen.tail);
-                    lang_regex.push('(');
-                    lang_regex.push_str(wert_infix.as_ref());
-                    lang_regex.push_str("| )");
+                    if let Some(wert_infix_anzeige) = wert_infix_anzeige {
+                        lang_regex.push_str(wert_infix_anzeige);
+                    } else {
+                        lang_regex.push('(');
+                        lang_regex.push_str(wert_infix.as_ref());
+                        lang_regex.push_str("| )");
+                    }
                     lang_regex.push_str(meta_var);
                 },
             }
             lang_regex
         }
-        let none = None;
         let mut max_lang_regex_breite = 0;
         let mut lang_regex_vec = Vec::new();
-        for arg_string in self.konfigurationen().chain(eigener_arg_string.iter()) {
-            let (beschreibung, flag_oder_wert, mögliche_werte) = match arg_string {
-                Konfiguration::Flag { beschreibung, invertiere_präfix_infix } => {
-                    (beschreibung, Either::Left(invertiere_präfix_infix), &none)
-                },
-                Konfiguration::Wert { beschreibung, wert_infix, meta_var, mögliche_werte } => {
-                    (beschreibung, Either::Right((wert_infix, *meta_var)), mögliche_werte)
-                },
+        let mut konfigurationen: Vec<_> =
+            self.konfigurationen().chain(eigener_arg_string.iter()).collect();
+        // Stabile Sortierung, damit bei gleichem Schlüssel die ursprüngliche Reihenfolge bleibt.
+        konfigurationen.sort_by_key(|konfiguration| match konfiguration {
+            Konfiguration::Flag { reihenfolge, .. } | Konfiguration::Wert { reihenfolge, .. } => {
+                *reihenfolge
+            },
+        });
+        for arg_string in konfigurationen {
+            let (beschreibung, flag_oder_wert, mögliche_werte, ein_aus_präfix, veraltet) =
+                match arg_string {
+                    Konfiguration::Flag {
+                        beschreibung,
+                        invertiere_präfix_infix,
+                        ein_aus_präfix,
+                        veraltet,
+                        reihenfolge: _,
+                        versteckte_namen: _,
+                    } => (
+                        beschreibung,
+                        Either::Left(invertiere_präfix_infix),
+                        None,
+                        ein_aus_präfix,
+                        *veraltet,
+                    ),
+                    Konfiguration::Wert {
+                        beschreibung,
+                        wert_infix,
+                        wert_infix_anzeige,
+                        meta_var,
+                        mögliche_werte,
+                        geheim: _,
+                        reihenfolge: _,
+                        versteckte_namen: _,
+                    } => {
+                        let mögliche_werte = mögliche_werte.as_ref().and_then(MöglicheWerte::werte);
+                        let wert = (wert_infix, *meta_var, *wert_infix_anzeige);
+                        (beschreibung, Either::Right(wert), mögliche_werte, &None, None)
+                    },
+                };
+            let (lang_präfix, kurz_präfix) = if let Some((ein, aus)) = ein_aus_präfix {
+                let präfix = format!("({}|{})", ein.as_ref(), aus.as_ref());
+                (Cow::Owned(präfix.clone()), Cow::Owned(präfix))
+            } else {
+                (
+                    Cow::Borrowed(beschreibung.lang_präfix.as_ref()),
+                    Cow::Borrowed(beschreibung.kurz_präfix.as_ref()),
+                )
+            };
+            let versteckte_namen = match arg_string {
+                Konfiguration::Flag { versteckte_namen, .. }
+                | Konfiguration::Wert { versteckte_namen, .. } => versteckte_namen,
             };
-            let lang_regex =
-                lang_regex(&beschreibung.lang_präfix, &beschreibung.lang, flag_oder_wert);
+            let beschreibung = sichtbare_namen(beschreibung, versteckte_namen);
+            let lang_regex = lang_regex(&lang_präfix, &beschreibung.lang, flag_oder_wert);
             let lang_regex_breite = lang_regex.graphemes(true).count();
-            max_lang_regex_breite = max_lang_regex_breite.max(lang_regex_breite);
+            // Zu lange Namen werden bei der Ausrichtung der KurzNamen nicht berücksichtigt.
+            match self.einstellungen.max_namen_breite {
+                Some(max_namen_breite) if lang_regex_breite > max_namen_breite => {},
+                _ => max_lang_regex_breite = max_lang_regex_breite.max(lang_regex_breite),
+            }
             lang_regex_vec.push((
                 lang_regex,
                 lang_regex_breite,
+                kurz_präfix,
                 beschreibung,
                 flag_oder_wert,
                 mögliche_werte,
+                veraltet,
             ))
         }
+        fn kurz_regex(
+            kurz_präfix: &str,
+            kurz_namen: &[Vergleich<'_>],
+            flag_oder_wert: FlagOderWert<'_, '_>,
+        ) -> Option<String> {
+            let (head, tail) = kurz_namen.split_first()?;
+            let mut kurz_regex = kurz_präfix.to_owned();
+            namen_regex_hinzufügen(&mut kurz_regex, head, tail);
+            if let Either::Right((wert_infix, meta_var, wert_infix_anzeige)) = flag_oder_wert {
+                if let Some(wert_infix_anzeige) = wert_infix_anzeige {
+                    kurz_regex.push_str(wert_infix_anzeige);
+                } else {
+                    kurz_regex.push('[');
+                    kurz_regex.push_str(wert_infix.as_ref());
+                    kurz_regex.push_str("| ]");
+                }
+                kurz_regex.push_str(meta_var.as_ref());
+            }
+            Some(kurz_regex)
+        }
         fn kurz_regex_hinzufügen(
             max_lang_regex_breite: usize,
             mut name_regex: String,
             lang_regex_breite: usize,
-            kurz_präfix: &Vergleich<'_>,
-            kurz_namen: &Vec<Vergleich<'_>>,
-            flag_oder_wert: Either<&Option<(Vergleich<'_>, Vergleich<'_>)>, (&Vergleich<'_>, &str)>,
+            kurz_präfix: &str,
+            kurz_namen: &[Vergleich<'_>],
+            flag_oder_wert: FlagOderWert<'_, '_>,
         ) -> String {
-            if let Some((head, tail)) = kurz_namen.split_first() {
-                let einrücken = " ".repeat(max_lang_regex_breite - lang_regex_breite);
+            if let Some(kurz_regex) = kurz_regex(kurz_präfix, kurz_namen, flag_oder_wert) {
+                let einrücken = " ".repeat(max_lang_regex_breite.saturating_sub(lang_regex_breite));
                 name_regex.push_str(&einrücken);
                 name_regex.push_str(" | ");
-                name_regex.push_str(kurz_präfix.as_ref());
-                namen_regex_hinzufügen(&mut name_regex, head, tail);
-                if let Either::Right((wert_infix, meta_var)) = flag_oder_wert {
-                    name_regex.push('[');
-                    name_regex.push_str(wert_infix.as_ref());
-                    name_regex.push_str("| ]");
-                    name_regex.push_str(meta_var.as_ref());
-                }
+                name_regex.push_str(&kurz_regex);
             }
             name_regex
         }
+        let layout = self.einstellungen.hilfe_layout.unwrap_or_default();
+        let max_kurz_regex_breite = lang_regex_vec
+            .iter()
+            .filter_map(|(_, _, kurz_präfix, beschreibung, flag_oder_wert, _, _)| {
+                kurz_regex(kurz_präfix, &beschreibung.kurz, *flag_oder_wert)
+            })
+            .map(|kurz_regex| kurz_regex.graphemes(true).count())
+            .max();
         let mut max_name_regex_breite = 0;
         let mut name_regex_vec = Vec::new();
-        for (lang_regex, lang_regex_breite, beschreibung, flag_oder_wert, mögliche_werte) in
-            lang_regex_vec
+        for (
+            lang_regex,
+            lang_regex_breite,
+            kurz_präfix,
+            beschreibung,
+            flag_oder_wert,
+            mögliche_werte,
+            veraltet,
+        ) in lang_regex_vec
         {
-            let name_regex = kurz_regex_hinzufügen(
-                max_lang_regex_breite,
-                lang_regex,
-                lang_regex_breite,
-                &beschreibung.kurz_präfix,
-                &beschreibung.kurz,
-                flag_oder_wert,
-            );
+            let name_regex = match (layout, max_kurz_regex_breite) {
+                (HilfeLayout::Tabelle, Some(max_kurz_regex_breite)) => {
+                    let kurz_regex = kurz_regex(&kurz_präfix, &beschreibung.kurz, flag_oder_wert)
+                        .unwrap_or_default();
+                    let einrücken = max_kurz_regex_breite - kurz_regex.graphemes(true).count();
+                    format!("{}{kurz_regex}  {lang_regex}", " ".repeat(einrücken))
+                },
+                (HilfeLayout::Tabelle, None) => lang_regex,
+                (HilfeLayout::Standard, _) => kurz_regex_hinzufügen(
+                    max_lang_regex_breite,
+                    lang_regex,
+                    lang_regex_breite,
+                    &kurz_präfix,
+                    &beschreibung.kurz,
+                    flag_oder_wert,
+                ),
+            };
             let name_regex_breite = name_regex.graphemes(true).count();
             max_name_regex_breite = max_name_regex_breite.max(name_regex_breite);
-            name_regex_vec.push((name_regex, name_regex_breite, beschreibung, mögliche_werte))
+            name_regex_vec.push((
+                name_regex,
+                name_regex_breite,
+                beschreibung,
+                mögliche_werte,
+                veraltet,
+            ))
+        }
+        if let Some(max_namen_breite) = self.einstellungen.max_namen_breite {
+            max_name_regex_breite = max_name_regex_breite.min(max_namen_breite.max(1));
         }
         fn hilfe_zeile(
             standard: &str,
             erlaubte_werte: &str,
+            breite: Option<usize>,
             max_name_regex_breite: usize,
             hilfe_text: &mut String,
             name_regex: String,
             name_regex_breite: usize,
             beschreibung: &Beschreibung<'_, String>,
-            mögliche_werte: &Option<NonEmpty<String>>,
+            mögliche_werte: Option<Cow<'_, NonEmpty<String>>>,
+            veraltet: Option<&str>,
         ) {
-            hilfe_text.push_str("  ");
-            hilfe_text.push_str(&name_regex);
-            let einrücken = " ".repeat(2 + max_name_regex_breite - name_regex_breite);
-            hilfe_text.push_str(&einrücken);
+            let mut beschreibung_text = String::new();
+            if let Some(veraltet) = veraltet {
+                beschreibung_text.push('[');
+                beschreibung_text.push_str(veraltet);
+                beschreibung_text.push(']');
+                if beschreibung.hilfe.is_some() {
+                    beschreibung_text.push(' ');
+                }
+            }
             if let Some(hilfe) = &beschreibung.hilfe {
-                hilfe_text.push_str(hilfe);
+                beschreibung_text.push_str(hilfe);
             }
+            // Anmerkungen werden gemeinsam in eckigen Klammern angezeigt,
+            // z.B. `[Erlaubte Werte: a, b | Standard: a]`.
+            let mut anmerkungen = Vec::new();
             if let Some(werte) = mögliche_werte {
-                if beschreibung.hilfe.is_some() {
-                    hilfe_text.push(' ');
+                anmerkungen.push(format!("{erlaubte_werte}: {}", werte.iter().join(", ")));
+            }
+            if let Some(standard_wert) = &beschreibung.standard {
+                anmerkungen.push(format!("{standard}: {standard_wert}"));
+            }
+            if !anmerkungen.is_empty() {
+                if !beschreibung_text.is_empty() {
+                    beschreibung_text.push(' ');
                 }
-                hilfe_text.push('[');
-                hilfe_text.push_str(erlaubte_werte);
-                hilfe_text.push_str(": ");
-                hilfe_text.push_str(&werte.head);
-                for wert in &werte.tail {
-                    hilfe_text.push_str(", ");
-                    hilfe_text.push_str(wert);
+                beschreibung_text.push('[');
+                beschreibung_text.push_str(&anmerkungen.join(" | "));
+                beschreibung_text.push(']');
+            }
+            if name_regex_breite <= max_name_regex_breite {
+                hilfe_text.push_str("  ");
+                hilfe_text.push_str(&name_regex);
+                let einrücken = " ".repeat(2 + max_name_regex_breite - name_regex_breite);
+                hilfe_text.push_str(&einrücken);
+            } else {
+                // Zu lange Namen werden auf mehrere Zeilen aufgeteilt,
+                // die Beschreibung folgt eingerückt in der nächsten Zeile.
+                let graphemes: Vec<_> = name_regex.graphemes(true).collect();
+                for zeile in graphemes.chunks(max_name_regex_breite) {
+                    hilfe_text.push_str("  ");
+                    hilfe_text.push_str(&zeile.concat());
+                    hilfe_text.push('\n');
                 }
-                if beschreibung.standard.is_some() {
-                    hilfe_text.push_str(" | ");
-                } else {
-                    hilfe_text.push(']');
+                if beschreibung_text.is_empty() {
+                    return;
                 }
+                hilfe_text.push_str(&" ".repeat(4 + max_name_regex_breite));
             }
-            if let Some(standard_wert) = &beschreibung.standard {
-                if !mögliche_werte.is_some() {
-                    if beschreibung.hilfe.is_some() {
-                        hilfe_text.push(' ');
-                    }
-                    hilfe_text.push('[');
-                }
-                hilfe_text.push_str(standard);
-                hilfe_text.push_str(": ");
-                hilfe_text.push_str(standard_wert);
-                hilfe_text.push(']');
+            // Die Beschreibung beginnt immer in derselben Spalte.
+            let einrückung = 4 + max_name_regex_breite;
+            match breite.map(|breite| breite.saturating_sub(einrückung)) {
+                Some(beschreibung_breite) if beschreibung_breite >= MIN_BESCHREIBUNG_BREITE => {
+                    let trenner = format!("\n{}", " ".repeat(einrückung));
+                    let zeilen = umbrechen(&beschreibung_text, beschreibung_breite);
+                    hilfe_text.push_str(&zeilen.join(&trenner));
+                },
+                _ => hilfe_text.push_str(&beschreibung_text),
             }
             hilfe_text.push('\n');
         }
-        for (name_regex, name_regex_breite, beschreibung, mögliche_werte) in name_regex_vec {
+        for (name_regex, name_regex_breite, beschreibung, mögliche_werte, veraltet) in
+            name_regex_vec
+        {
             hilfe_zeile(
                 standard,
                 erlaubte_werte,
+                breite,
                 max_name_regex_breite,
                 &mut hilfe_text,
                 name_regex,
                 name_regex_breite,
-                beschreibung,
+                &beschreibung,
                 mögliche_werte,
+                veraltet,
             )
         }
+        if let Some((überschrift, exit_codes)) = self.einstellungen.exit_codes {
+            let codes: Vec<_> = exit_codes.iter().map(|(code, _)| code.to_string()).collect();
+            let max_code_breite = codes.iter().map(String::len).max().unwrap_or(0);
+            hilfe_text.push_str(&format!("\n{überschrift}:\n"));
+            for (code, (_, beschreibung)) in codes.iter().zip(exit_codes) {
+                hilfe_text.push_str(&format!("  {code:<max_code_breite$}  {beschreibung}\n"));
+            }
+        }
         hilfe_text
     }
 
     /// Erstelle eine Flag, die zu vorzeitigem Beenden führt.
     /// Zeige dabei die übergebene Nachricht an.
     ///
+    /// Im Hilfe-Text steht die Flag nach allen anderen Argumenten,
+    /// außer ihr Sortier-Schlüssel wird über [reihenfolge](Argumente::reihenfolge) geändert.
+    ///
     /// ## English synonym
     /// [early_exit](Arguments::early_exit)
     pub fn frühes_beenden(
@@ -858,7 +1166,7 @@ impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
         beschreibung: Beschreibung<'t, Void>,
         nachricht: impl Into<Cow<'t, str>>,
     ) -> Argumente<'t, T, E> {
-        let Argumente { mut konfigurationen, mut flag_kurzformen, parse } = self;
+        let Argumente { mut konfigurationen, mut flag_kurzformen, mut einstellungen, parse } = self;
         let name_lang_präfix = beschreibung.lang_präfix.clone();
         let name_lang = beschreibung.lang.clone();
         let name_kurz_präfix = beschreibung.kurz_präfix.clone();
@@ -871,18 +1179,38 @@ impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
         konfigurationen.push(Konfiguration::Flag {
             beschreibung: beschreibung_string,
             invertiere_präfix_infix: None,
+            ein_aus_präfix: None,
+            veraltet: None,
+            reihenfolge: REIHENFOLGE_FRÜHES_BEENDEN,
+            versteckte_namen: Vec::new(),
         });
         let nachricht_cow = nachricht.into();
+        let namen_eintrag = NamenEintrag::neu(
+            vec![vec![name_lang_präfix.clone()], vec![name_kurz_präfix.clone()]],
+            name_lang.iter().chain(&name_kurz).cloned(),
+        );
+        einstellungen.namen_einträge.push(namen_eintrag.clone());
+        if einstellungen.namen_index.is_some() {
+            // Das frühe Beenden gehört zu den bereits kombinierten Argumenten.
+            einstellungen.erzeuge_namen_index();
+        }
         Argumente {
             konfigurationen,
             flag_kurzformen,
-            parse: Box::new(move |args| {
+            einstellungen,
+            parse: Box::new(move |args, kontext| {
                 let name_kurz_existiert = !name_kurz.is_empty();
+                let kandidaten = kontext.kandidaten_für(&namen_eintrag, args.len());
                 let mut nicht_selbst_verwendet = Vec::new();
                 let mut nachrichten: Vec<Cow<'t, str>> = Vec::new();
                 let mut zeige_nachricht = || nachrichten.push(nachricht_cow.clone());
-                for arg in args {
-                    if let Some(string) = arg.as_ref().and_then(|os_string| os_string.to_str()) {
+                for (position, arg) in args.into_iter().enumerate() {
+                    if kandidaten.as_ref().is_some_and(|kandidaten| !kandidaten.möglich(position))
+                    {
+                        // Laut Index kann an dieser Position kein Name stehen.
+                    } else if let Some(string) =
+                        arg.as_ref().and_then(|os_string| os_string.to_str())
+                    {
                         let normalisiert = Normalisiert::neu(string);
                         if let Some(lang_str) = name_lang_präfix.strip_als_präfix(&normalisiert) {
                             if contains_str(&name_lang, lang_str) {
@@ -890,16 +1218,14 @@ impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
                                 nicht_selbst_verwendet.push(None);
                                 continue;
                             }
-                        } else if name_kurz_existiert {
+                        }
+                        // Mit leerem `lang_präfix` (z.B. `start`) beginnt jedes Argument
+                        // mit dem Präfix, daher werden anschließend die Kurznamen geprüft.
+                        if name_kurz_existiert {
                             if let Some(kurz_str) =
                                 name_kurz_präfix.strip_als_präfix(&normalisiert)
                             {
-                                if kurz_str
-                                    .graphemes(true)
-                                    .exactly_one()
-                                    .map(|name| contains_str(&name_kurz, name))
-                                    .unwrap_or(false)
-                                {
+                                if contains_str(&name_kurz, kurz_str) {
                                     zeige_nachricht();
                                     nicht_selbst_verwendet.push(None);
                                     continue;
@@ -909,8 +1235,16 @@ impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
                     }
                     nicht_selbst_verwendet.push(arg);
                 }
-                let (ergebnis, nicht_verwendet) = parse(nicht_selbst_verwendet);
+                let (ergebnis, nicht_verwendet) = parse(nicht_selbst_verwendet, kontext);
+                if let Some(aufzeichnungen) = &mut kontext.zustand.probelauf {
+                    aufzeichnungen
+                        .extend(nachrichten.iter().cloned().map(Aufzeichnung::FrühesBeenden));
+                }
                 let finales_ergebnis = match ergebnis {
+                    // Im Probelauf sind die Nachrichten bereits aufgezeichnet.
+                    Ergebnis::Fehler(fehler) if kontext.zustand.probelauf.is_some() => {
+                        Ergebnis::Fehler(fehler)
+                    },
                     Ergebnis::FrühesBeenden(mut frühes_beenden) => {
                         frühes_beenden.tail.extend(nachrichten);
                         Ergebnis::FrühesBeenden(frühes_beenden)
@@ -930,6 +1264,9 @@ impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
 
     /// Create a flag which causes an early exit and shows the given message.
     ///
+    /// In the help text the flag is shown after all other arguments,
+    /// unless its sort key is changed using [order](Arguments::order).
+    ///
     /// ## Deutsches Synonym
     /// [frühes_beenden](Argumente::frühes_beenden)
     #[inline(always)]
@@ -0,0 +1,141 @@
+//! Gemeinsames Parsen mit mehreren unabhängigen [Argumente].
+
+use std::{any::Any, collections::HashMap, ffi::OsString};
+
+use crate::{
+    argumente::{vorverarbeite_teile, Argumente, ParseEinstellungen, ParseZustand},
+    unicode::Vergleich,
+};
+
+/// Für die gemeinsame Vorverarbeitung benötigte Teile eines [GemeinsamerParser],
+/// z.B. die Kurznamen von Flags und ein Trenner.
+///
+/// ## English synonym
+/// [PreprocessingPart]
+#[derive(Debug, Clone, Copy)]
+pub struct Vorverarbeitungsteil<'a, 't> {
+    pub(crate) flag_kurzformen: &'a HashMap<Vergleich<'t>, Vec<Vergleich<'t>>>,
+    pub(crate) einstellungen: &'a ParseEinstellungen<'t>,
+}
+
+/// Parts of a [GemeinsamerParser] required for common pre-processing,
+/// e.g. the short names of flags and a separator.
+///
+/// ## Deutsches Synonym
+/// [Vorverarbeitungsteil]
+pub type PreprocessingPart<'a, 't> = Vorverarbeitungsteil<'a, 't>;
+
+/// Typ-unabhängige Schnittstelle für [parse_gemeinsam].
+///
+/// Implementiert für [Argumente] mit `'static`-Lebenszeit,
+/// da das Ergebnis als [Any] zurückgegeben wird.
+/// Für kürzere Lebenszeiten können die [Argumente] stattdessen
+/// über [kombiniere!](crate::kombiniere!) zusammengefasst werden.
+///
+/// ## English
+/// Type-erased interface for [parse_gemeinsam].
+///
+/// Implemented for [Arguments](crate::Arguments) with `'static` lifetime,
+/// since the result is returned as [Any].
+/// For shorter lifetimes, the [Arguments](crate::Arguments) can be merged
+/// using [combine!](crate::combine!) instead.
+pub trait GemeinsamerParser {
+    /// Die für die gemeinsame Vorverarbeitung benötigten Teile.
+    ///
+    /// ## English
+    /// The parts required for common pre-processing.
+    fn vorverarbeitungsteil(&self) -> Vorverarbeitungsteil<'_, 'static>;
+
+    /// Parse die bereits vorverarbeiteten Argumente, bereits verwendete Argumente sind [None].
+    /// Die Argumente `nach_trenner` werden nur von
+    /// [rest_nach_trenner](Argumente::rest_nach_trenner) verwendet.
+    /// Das Ergebnis wird ohne Typ-Information zurückgegeben,
+    /// für [Argumente] handelt es sich um ein [Ergebnis](crate::Ergebnis).
+    /// Im zurückgegebenen [Vec] sind verwendete Argumente durch [None] ersetzt.
+    ///
+    /// ## English
+    /// Parse the already pre-processed arguments, already used arguments are [None].
+    /// The arguments after the separator (`nach_trenner`) are only used by
+    /// [rest_after_separator](crate::Arguments::rest_after_separator).
+    /// The result is returned without type information,
+    /// for [Arguments](crate::Arguments) it is a [Result](crate::Result).
+    /// In the returned [Vec], used arguments are replaced by [None].
+    fn parse_teil(
+        &self,
+        args: Vec<Option<OsString>>,
+        nach_trenner: &[OsString],
+    ) -> (Box<dyn Any>, Vec<Option<OsString>>);
+}
+
+impl<T: 'static, E: 'static> GemeinsamerParser for Argumente<'static, T, E> {
+    fn vorverarbeitungsteil(&self) -> Vorverarbeitungsteil<'_, 'static> {
+        Argumente::vorverarbeitungsteil(self)
+    }
+
+    fn parse_teil(
+        &self,
+        args: Vec<Option<OsString>>,
+        nach_trenner: &[OsString],
+    ) -> (Box<dyn Any>, Vec<Option<OsString>>) {
+        let (ergebnis, nicht_verwendet) =
+            self.parse_vorverarbeitet(args, nach_trenner, None, None, &mut ParseZustand::default());
+        (Box::new(ergebnis), nicht_verwendet)
+    }
+}
+
+/// Parse die Kommandozeilen-Argumente nacheinander mit allen `parser`,
+/// z.B. für eine Plugin-Architektur mit unabhängigen [Argumente].
+///
+/// Die Argumente werden vorher einmal gemeinsam vorverarbeitet, wie für kombinierte [Argumente]:
+/// Verschmolzene Kurzformen werden mit den Kurznamen aller `parser` aufgeteilt
+/// und Argumente nach einem Trenner werden nicht geparst.
+/// Jeder Parser erhält nur die von vorherigen Parsern nicht verwendeten Argumente.
+/// Ein Argument wird nur als nicht verwendet zurückgegeben,
+/// wenn es von keinem Parser verwendet wurde.
+/// Die Ergebnisse werden in der Reihenfolge der `parser` zurückgegeben
+/// und können über [downcast](Box::downcast) in das jeweilige
+/// [Ergebnis](crate::Ergebnis) umgewandelt werden.
+///
+/// ## English synonym
+/// [parse_together]
+pub fn parse_gemeinsam(
+    args: impl Iterator<Item = OsString>,
+    parser: &[&dyn GemeinsamerParser],
+) -> (Vec<Box<dyn Any>>, Vec<OsString>) {
+    let teile: Vec<_> = parser.iter().map(|parser| parser.vorverarbeitungsteil()).collect();
+    let (angepasste_args, mut nach_trenner) = vorverarbeite_teile(&teile, args);
+    let mut nicht_verwendet: Vec<_> = angepasste_args.into_iter().map(Some).collect();
+    let mut ergebnisse = Vec::with_capacity(parser.len());
+    for parser in parser {
+        let (ergebnis, übrig) = parser.parse_teil(nicht_verwendet, &nach_trenner);
+        ergebnisse.push(ergebnis);
+        nicht_verwendet = übrig;
+    }
+    if teile.iter().any(|teil| teil.einstellungen.rest) {
+        // Die Argumente nach dem Trenner werden von einem Argument erfasst.
+        nach_trenner.clear();
+    }
+    (ergebnisse, nicht_verwendet.into_iter().flatten().chain(nach_trenner).collect())
+}
+
+/// Parse the command line arguments sequentially with all `parsers`,
+/// e.g. for a plugin architecture with independent [Arguments](crate::Arguments).
+///
+/// The arguments are pre-processed once beforehand, as for combined
+/// [Arguments](crate::Arguments): Merged short forms are split using the short names
+/// of all `parsers` and arguments after a separator are not parsed.
+/// Every parser only receives the arguments not used by previous parsers.
+/// An argument is only returned as unused, if no parser used it.
+/// The results are returned in the order of the `parsers`
+/// and can be converted to the respective [Result](crate::Result)
+/// using [downcast](Box::downcast).
+///
+/// ## Deutsches Synonym
+/// [parse_gemeinsam]
+#[inline(always)]
+pub fn parse_together(
+    args: impl Iterator<Item = OsString>,
+    parsers: &[&dyn GemeinsamerParser],
+) -> (Vec<Box<dyn Any>>, Vec<OsString>) {
+    parse_gemeinsam(args, parsers)
+}
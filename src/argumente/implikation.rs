@@ -0,0 +1,148 @@
+//! Flag-Argumente, die weitere Flag-Argumente implizieren.
+
+use std::{convert::identity, ffi::OsString};
+
+use void::Void;
+
+use crate::{
+    argumente::{Argumente, Arguments, ParseKontext},
+    beschreibung::{contains_str, Beschreibung, Konfiguration},
+    ergebnis::Ergebnis,
+};
+
+/// Ein Flag-Argument zum Prüfen, ob es genannt wurde,
+/// zusammen mit dem Kommandozeilen-Argument um es zu aktivieren.
+type FlagPrüfung<'t> = (Argumente<'t, bool, Void>, OsString);
+
+/// Erzeuge eine [FlagPrüfung] für das Flag-Argument mit dem LangNamen `name`.
+fn flag_prüfung<'t>(
+    konfigurationen: &[Konfiguration<'t>],
+    name: &str,
+) -> Option<FlagPrüfung<'t>> {
+    konfigurationen.iter().find_map(|konfiguration| match konfiguration {
+        Konfiguration::Flag { beschreibung, invertiere_präfix_infix, ein_aus_präfix, .. }
+            if contains_str(&beschreibung.lang, name) =>
+        {
+            let Beschreibung { lang_präfix, lang, kurz_präfix, kurz, hilfe, standard: _ } =
+                beschreibung.clone();
+            let ein_arg = |präfix: &str| OsString::from(format!("{präfix}{}", lang.head.as_ref()));
+            let prüf_beschreibung = Beschreibung {
+                lang_präfix: lang_präfix.clone(),
+                lang: lang.clone(),
+                kurz_präfix,
+                kurz,
+                hilfe,
+                standard: None,
+            };
+            match (ein_aus_präfix, invertiere_präfix_infix) {
+                (Some((ein_präfix, aus_präfix)), _) => Some((
+                    Argumente::flag_ein_aus(
+                        prüf_beschreibung,
+                        identity,
+                        ein_präfix.clone(),
+                        aus_präfix.clone(),
+                        bool::to_string,
+                    ),
+                    ein_arg(ein_präfix.as_ref()),
+                )),
+                (None, Some((invertiere_präfix, invertiere_infix))) => Some((
+                    Argumente::flag(
+                        prüf_beschreibung,
+                        identity,
+                        invertiere_präfix.clone(),
+                        invertiere_infix.clone(),
+                        bool::to_string,
+                    ),
+                    ein_arg(lang_präfix.as_ref()),
+                )),
+                // Flag zum frühen Beenden
+                (None, None) => None,
+            }
+        },
+        _ => None,
+    })
+}
+
+impl<'t, T: 't, E: 't> Argumente<'t, T, E> {
+    /// Das Flag-Argument mit LangNamen `quelle` impliziert die Flag-Argumente
+    /// mit LangNamen `ziele`, z.B. aktiviert `--alle` zusätzlich `--a --b --c`.
+    ///
+    /// Wird `quelle` aktiviert, werden alle `ziele`, die nicht explizit genannt wurden,
+    /// vor dem Parsen aktiviert. Explizit genannte `ziele` (z.B. `--kein-b`) haben Vorrang.
+    /// Nur Flag-Argumente (ohne frühes Beenden) werden unterstützt,
+    /// andere Namen werden ignoriert.
+    ///
+    /// ## English synonym
+    /// [implies](Arguments::implies)
+    pub fn impliziert(
+        self,
+        quelle: &str,
+        ziele: impl IntoIterator<Item = &'t str>,
+    ) -> Argumente<'t, T, E> {
+        let Argumente { konfigurationen, flag_kurzformen, einstellungen, parse } = self;
+        let quelle_prüfung = flag_prüfung(&konfigurationen, quelle);
+        debug_assert!(quelle_prüfung.is_some(), "Unbekannte Flag: {quelle}");
+        let ziel_prüfungen: Vec<_> = ziele
+            .into_iter()
+            .filter_map(|ziel| {
+                let prüfung = flag_prüfung(&konfigurationen, ziel);
+                debug_assert!(prüfung.is_some(), "Unbekannte Flag: {ziel}");
+                prüfung
+            })
+            .collect();
+        let parse = match quelle_prüfung {
+            Some((quelle_prüfung, _quelle_arg)) => Box::new(
+                move |args: Vec<Option<OsString>>, kontext: &mut ParseKontext<'_, 't, E>| {
+                    let quelle_aktiv = matches!(
+                        (quelle_prüfung.parse)(args.clone(), &mut kontext.prüfung()).0,
+                        Ergebnis::Wert(true)
+                    );
+                    if quelle_aktiv {
+                        let implizit: Vec<_> = ziel_prüfungen
+                            .iter()
+                            .filter(|(ziel_prüfung, _ziel_arg)| {
+                                !matches!(
+                                    (ziel_prüfung.parse)(args.clone(), &mut kontext.prüfung()).0,
+                                    Ergebnis::Wert(_)
+                                )
+                            })
+                            .map(|(_ziel_prüfung, ziel_arg)| Some(ziel_arg.clone()))
+                            .collect();
+                        let anzahl_implizit = implizit.len();
+                        // Durch die implizit hinzugefügten Argumente am Anfang
+                        // passen die Positionen nicht mehr zum Namen-Index.
+                        let (ergebnis, nicht_verwendet) = parse(
+                            implizit.into_iter().chain(args).collect(),
+                            &mut kontext.ohne_kandidaten(),
+                        );
+                        // Ohne die implizit hinzugefügten Argumente
+                        // bleibt jedes Argument an seiner ursprünglichen Position.
+                        (ergebnis, nicht_verwendet.into_iter().skip(anzahl_implizit).collect())
+                    } else {
+                        parse(args, kontext)
+                    }
+                },
+            ),
+            None => parse,
+        };
+        Argumente { konfigurationen, flag_kurzformen, einstellungen, parse }
+    }
+
+    /// The flag-argument with long name `source` implies the flag-arguments
+    /// with long names `targets`, e.g. `--all` additionally activates `--a --b --c`.
+    ///
+    /// If `source` is activated, all `targets` that are not explicitly given
+    /// are activated before parsing. Explicitly given `targets` (e.g. `--no-b`) take precedence.
+    /// Only flag-arguments (without early exit) are supported, other names are ignored.
+    ///
+    /// ## Deutsches Synonym
+    /// [impliziert](Argumente::impliziert)
+    #[inline(always)]
+    pub fn implies(
+        self,
+        source: &str,
+        targets: impl IntoIterator<Item = &'t str>,
+    ) -> Arguments<'t, T, E> {
+        self.impliziert(source, targets)
+    }
+}
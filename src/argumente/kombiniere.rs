@@ -4,7 +4,10 @@ use std::collections::HashMap;
 
 use nonempty::NonEmpty;
 
-use crate::{argumente::Argumente, ergebnis::Ergebnis};
+use crate::{
+    argumente::{Argumente, ParseEinstellungen},
+    ergebnis::Ergebnis,
+};
 
 #[macro_export]
 /// Parse mehrere Kommandozeilen-Argumente und kombiniere die Ergebnisse mit der übergebenen Funktion.
@@ -129,37 +132,56 @@ macro_rules! impl_kombiniere_n {
                     flag_kurzformen.entry(präfix).or_insert(Vec::new()).extend(kurz_namen);
                 }
             )+
+            let mut einstellungen = ParseEinstellungen::default();
+            $(einstellungen = einstellungen.kombiniere($var.einstellungen);)+
+            einstellungen.erzeuge_namen_index();
             Argumente {
                 konfigurationen,
                 flag_kurzformen,
-                parse: Box::new(move |args| {
+                einstellungen,
+                parse: Box::new(move |args, kontext| {
                     let mut fehler = Vec::new();
                     let mut frühes_beenden = Vec::new();
+                    let anzahl_args = args.len();
                     let nicht_verwendet = args;
                     $(
-                        let (ergebnis, nicht_verwendet) = ($var.parse)(nicht_verwendet);
+                        let (ergebnis, nicht_verwendet) =
+                            ($var.parse)(nicht_verwendet, kontext);
+                        // Jedes Argument bleibt an seiner ursprünglichen Position,
+                        // verwendete Argumente werden durch `None` ersetzt.
+                        // Dadurch taucht jedes nicht verwendete Argument genau einmal auf.
+                        debug_assert_eq!(anzahl_args, nicht_verwendet.len());
                         let $var = match ergebnis {
                             Ergebnis::Wert(wert) => Some(wert),
                             Ergebnis::FrühesBeenden(nachrichten) => {
                                 frühes_beenden.extend(nachrichten);
                                 None
                             },
+                            // Bei nachsichtigem Parsen haben Argumente mit Standard-Wert
+                            // ihre Fehler bereits gesammelt und liefern einen Wert.
                             Ergebnis::Fehler(parse_fehler) => {
                                 fehler.extend(parse_fehler);
                                 None
                             },
                         };
                     )+
-                    let ergebnis = if let Some(fehler) = NonEmpty::from_vec(fehler) {
-                        Ergebnis::Fehler(fehler)
-                    } else if let Some(nachrichten) = NonEmpty::from_vec(frühes_beenden) {
-                        Ergebnis::FrühesBeenden(nachrichten)
-                    } else {
-                        // Werte werden nur auf None gesetzt, wenn ein Element zu
-                        // `fehler` oder `frühes_beenden` hinzugefügt wird,
-                        // diese demnach nicht-leer sind.
-                        // In dieser Verzweigung sind beide leer, es sind also alle Werte Some
-                        Ergebnis::Wert(f($($var.expect("Kein Wert ohne Fehler.")),+))
+                    let frühes_beenden = NonEmpty::from_vec(frühes_beenden);
+                    let ergebnis = match (frühes_beenden, NonEmpty::from_vec(fehler)) {
+                        // Im Probelauf sind die Nachrichten bereits aufgezeichnet,
+                        // Fehler anderer Argumente sollen trotzdem gemeldet werden.
+                        (Some(_nachrichten), Some(fehler)) if kontext.zustand.probelauf.is_some() => {
+                            Ergebnis::Fehler(fehler)
+                        },
+                        // Frühes Beenden (z.B. `--hilfe`) hat Vorrang vor Fehlern anderer Argumente.
+                        (Some(nachrichten), _fehler) => Ergebnis::FrühesBeenden(nachrichten),
+                        (None, Some(fehler)) => Ergebnis::Fehler(fehler),
+                        (None, None) => {
+                            // Werte werden nur auf None gesetzt, wenn ein Element zu
+                            // `fehler` oder `frühes_beenden` hinzugefügt wird,
+                            // diese demnach nicht-leer sind.
+                            // In dieser Verzweigung sind beide leer, es sind also alle Werte Some
+                            Ergebnis::Wert(f($($var.expect("Kein Wert ohne Fehler.")),+))
+                        },
                     };
                     (ergebnis, nicht_verwendet)
                 }),
@@ -190,7 +212,8 @@ impl<'t, T, Error: 't> Argumente<'t, T, Error> {
         Argumente {
             konfigurationen: Vec::new(),
             flag_kurzformen: HashMap::new(),
-            parse: Box::new(move |args| (Ergebnis::Wert(f()), args)),
+            einstellungen: ParseEinstellungen::default(),
+            parse: Box::new(move |args, _kontext| (Ergebnis::Wert(f()), args)),
         }
     }
 
@@ -209,13 +232,18 @@ impl<'t, T, Error: 't> Argumente<'t, T, Error> {
     /// [convert](Argumente::convert)
     pub fn konvertiere<A: 't>(
         f: impl 't + Fn(A) -> T,
-        Argumente { konfigurationen, flag_kurzformen, parse }: Argumente<'t, A, Error>,
+        Argumente { konfigurationen, flag_kurzformen, einstellungen, parse }: Argumente<
+            't,
+            A,
+            Error,
+        >,
     ) -> Argumente<'t, T, Error> {
         Argumente {
             konfigurationen,
             flag_kurzformen,
-            parse: Box::new(move |args| {
-                let (ergebnis, nicht_verwendet) = parse(args);
+            einstellungen,
+            parse: Box::new(move |args, kontext| {
+                let (ergebnis, nicht_verwendet) = parse(args, kontext);
                 (ergebnis.konvertiere(&f), nicht_verwendet)
             }),
         }
@@ -242,3 +270,40 @@ impl<'t, T, Error: 't> Argumente<'t, T, Error> {
     impl_kombiniere_n! {kombiniere8-combine8(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H)}
     impl_kombiniere_n! {kombiniere9-combine9(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I)}
 }
+
+impl<'t, Error: 't> Argumente<'t, usize, Error> {
+    /// Parse keine Kommandozeilen-Argumente und erzeuge die Anzahl der bisher nicht verwendeten
+    /// Argumente, z.B. um auf zusätzliche Argumente zu reagieren.
+    ///
+    /// Die Argumente werden in der Reihenfolge des Kombinierens geparst,
+    /// daher sollte es als letztes Argument kombiniert werden.
+    /// Argumente nach dem Trenner werden nicht gezählt.
+    ///
+    /// ## English synonym
+    /// [count_remaining](Argumente::count_remaining)
+    pub fn anzahl_verbleibend() -> Argumente<'t, usize, Error> {
+        Argumente {
+            konfigurationen: Vec::new(),
+            flag_kurzformen: HashMap::new(),
+            einstellungen: ParseEinstellungen::default(),
+            parse: Box::new(|args, _kontext| {
+                let anzahl = args.iter().flatten().count();
+                (Ergebnis::Wert(anzahl), args)
+            }),
+        }
+    }
+
+    /// Parse no command line arguments and create the number of arguments not used so far,
+    /// e.g. to react to additional arguments.
+    ///
+    /// The arguments are parsed in the order they were combined,
+    /// so it should be combined as the last argument.
+    /// Arguments after the separator are not counted.
+    ///
+    /// ## Deutsches Synonym
+    /// [anzahl_verbleibend](Argumente::anzahl_verbleibend)
+    #[inline(always)]
+    pub fn count_remaining() -> Argumente<'t, usize, Error> {
+        Argumente::anzahl_verbleibend()
+    }
+}
@@ -0,0 +1,1334 @@
+//! Wert-Argumente mit mehreren Werten.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    ffi::OsString,
+    fmt::Display,
+};
+
+use itertools::Itertools;
+use nonempty::NonEmpty;
+
+use crate::{
+    argumente::{
+        nachsichtig::nachsichtiger_standard, namen_index::IndexKandidaten, wert::WertNamen,
+        Argumente, Arguments, ParseEinstellungen,
+    },
+    beschreibung::{Beschreibung, Description, Konfiguration},
+    ergebnis::{Ergebnis, Fehler, ParseError, ParseFehler},
+    sprache::{Language, Sprache},
+    unicode::{Compare, Normalisiert, Vergleich},
+};
+
+/// Wie werden leere Elemente einer Liste (z.B. `a,,b` oder `a,b,`) behandelt?
+///
+/// ## English synonym
+/// [EmptyElements]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LeereElemente {
+    /// Leere Elemente werden ignoriert: `a,,b,` wird zu `[a, b]`.
+    ///
+    /// ## English
+    /// Empty elements are ignored: `a,,b,` becomes `[a, b]`.
+    #[default]
+    Überspringen,
+
+    /// Leere Elemente erzeugen einen Fehler.
+    ///
+    /// ## English
+    /// Empty elements produce an error.
+    Fehler,
+}
+
+/// How are empty elements of a list (e.g. `a,,b` or `a,b,`) handled?
+///
+/// ## Deutsches Synonym
+/// [LeereElemente]
+pub type EmptyElements = LeereElemente;
+
+/// Wie wird ein mehrfach genanntes Wert-Argument behandelt?
+///
+/// ## English synonym
+/// [Multiple]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Mehrfach {
+    /// Nur der letzte Wert wird verwendet.
+    ///
+    /// ## English
+    /// Only the last value is used.
+    #[default]
+    Ersetze,
+
+    /// Alle Werte werden in der genannten Reihenfolge gesammelt.
+    ///
+    /// ## English
+    /// All values are collected in the given order.
+    Anhängen,
+
+    /// Mehrfaches Nennen erzeugt einen Fehler.
+    ///
+    /// ## English
+    /// Giving the argument multiple times produces an error.
+    Fehler,
+}
+
+/// How is a value-argument given multiple times handled?
+///
+/// ## Deutsches Synonym
+/// [Mehrfach]
+pub type Multiple = Mehrfach;
+
+/// Wie werden doppelte Elemente einer Menge (z.B. `a,b,a`) behandelt?
+///
+/// ## English synonym
+/// [Duplicates]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Duplikate {
+    /// Doppelte Elemente werden ignoriert: `a,b,a` wird zu `{a, b}`.
+    ///
+    /// ## English
+    /// Duplicate elements are ignored: `a,b,a` becomes `{a, b}`.
+    #[default]
+    Ignorieren,
+
+    /// Doppelte Elemente erzeugen einen Fehler.
+    ///
+    /// ## English
+    /// Duplicate elements produce an error.
+    Fehler,
+}
+
+/// How are duplicate elements of a set (e.g. `a,b,a`) handled?
+///
+/// ## Deutsches Synonym
+/// [Duplikate]
+pub type Duplicates = Duplikate;
+
+impl<'t, T: 't + Clone + Display> Argumente<'t, Vec<T>, String> {
+    /// Erzeuge ein Wert-Argument für eine durch `trenner` getrennte Liste.
+    ///
+    /// ## English synonym
+    /// [value_separated_list_with_language](Arguments::value_separated_list_with_language)
+    #[inline(always)]
+    pub fn wert_getrennte_liste_mit_sprache(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        trenner: char,
+        leere_elemente: LeereElemente,
+        parse_element: impl 't + Fn(&str) -> Result<T, String>,
+        sprache: Sprache,
+    ) -> Argumente<'t, Vec<T>, String> {
+        Argumente::wert_getrennte_liste(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            trenner,
+            leere_elemente,
+            sprache.leeres_element,
+            parse_element,
+        )
+    }
+
+    /// Create a value-argument for a list separated by `separator`.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_getrennte_liste_mit_sprache](Argumente::wert_getrennte_liste_mit_sprache)
+    #[inline(always)]
+    pub fn value_separated_list_with_language(
+        description: Description<'t, Vec<T>>,
+        separator: char,
+        empty_elements: EmptyElements,
+        parse_element: impl 't + Fn(&str) -> Result<T, String>,
+        language: Language,
+    ) -> Arguments<'t, Vec<T>, String> {
+        Argumente::wert_getrennte_liste_mit_sprache(
+            description,
+            separator,
+            empty_elements,
+            parse_element,
+            language,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument für eine durch `trenner` getrennte Liste.
+    ///
+    /// Der Wert wird an jedem `trenner` aufgeteilt und jedes Element mit `parse_element` geparst,
+    /// z.B. wird `--tags a,b,c` zu `[a, b, c]`.
+    /// Leere Elemente werden abhängig von `leere_elemente` ignoriert, oder erzeugen einen Fehler
+    /// mit Beschreibung `leeres_element`.
+    /// Wird das Argument mehrfach genannt, wird der letzte Wert verwendet.
+    ///
+    /// ## English synonym
+    /// [value_separated_list](Arguments::value_separated_list)
+    pub fn wert_getrennte_liste(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        trenner: char,
+        leere_elemente: LeereElemente,
+        leeres_element: &'t str,
+        parse_element: impl 't + Fn(&str) -> Result<T, String>,
+    ) -> Argumente<'t, Vec<T>, String> {
+        let wert_namen = WertNamen::neu(&beschreibung, wert_infix.into(), meta_var);
+        Argumente::wert_getrennte_liste_allgemein(
+            beschreibung,
+            wert_namen,
+            trenner,
+            leere_elemente,
+            leeres_element,
+            false,
+            parse_element,
+        )
+    }
+
+    /// Gemeinsame Implementierung getrennter Listen.
+    /// Mit `anhängen` werden die Elemente aller Vorkommen gesammelt,
+    /// ansonsten wird nur das letzte Vorkommen verwendet.
+    fn wert_getrennte_liste_allgemein(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        wert_namen: WertNamen<'t>,
+        trenner: char,
+        leere_elemente: LeereElemente,
+        leeres_element: &'t str,
+        anhängen: bool,
+        parse_element: impl 't + Fn(&str) -> Result<T, String>,
+    ) -> Argumente<'t, Vec<T>, String> {
+        let meta_var = wert_namen.meta_var;
+        let anzeige = |liste: &Vec<T>| liste.iter().join(&trenner.to_string());
+        let (beschreibung, standard) = beschreibung.als_string_beschreibung_allgemein(anzeige);
+        Argumente {
+            konfigurationen: vec![Konfiguration::Wert {
+                beschreibung,
+                wert_infix: wert_namen.wert_infix.clone(),
+                wert_infix_anzeige: None,
+                geheim: false,
+                meta_var,
+                mögliche_werte: None,
+                reihenfolge: 0,
+                versteckte_namen: Vec::new(),
+            }],
+            flag_kurzformen: HashMap::new(),
+            einstellungen: wert_namen.einstellungen(),
+            parse: Box::new(move |args, kontext| {
+                let mut ergebnis: Option<Vec<T>> = None;
+                let mut fehler = Vec::new();
+                let nicht_verwendet = wert_namen.suche(args, kontext.kandidaten, |arg| {
+                    match arg.map(OsString::into_string) {
+                        Some(Ok(string)) => {
+                            let mut liste = Vec::new();
+                            let mut liste_fehler = Vec::new();
+                            for element in string.split(trenner) {
+                                if element.is_empty() {
+                                    if leere_elemente == LeereElemente::Fehler {
+                                        liste_fehler.push(format!("{leeres_element}: {string}"))
+                                    }
+                                } else {
+                                    match parse_element(element) {
+                                        Ok(wert) => liste.push(wert),
+                                        Err(parse_fehler) => liste_fehler.push(parse_fehler),
+                                    }
+                                }
+                            }
+                            if !liste_fehler.is_empty() {
+                                fehler.extend(liste_fehler.into_iter().map(|parse_fehler| {
+                                    wert_namen.fehler_parse(ParseFehler::ParseFehler(parse_fehler))
+                                }))
+                            } else if let (true, Some(bisher)) = (anhängen, &mut ergebnis) {
+                                bisher.extend(liste)
+                            } else {
+                                ergebnis = Some(liste)
+                            }
+                        },
+                        Some(Err(os_string)) => fehler
+                            .push(wert_namen.fehler_parse(ParseFehler::InvaliderString(os_string))),
+                        None => fehler.push(wert_namen.fehler_kein_wert()),
+                    }
+                });
+                if let Some(fehler) = NonEmpty::from_vec(fehler) {
+                    let ergebnis = nachsichtiger_standard(
+                        Ergebnis::Fehler(fehler),
+                        standard.as_ref(),
+                        kontext.nachsichtig.as_deref_mut(),
+                    );
+                    (ergebnis, nicht_verwendet)
+                } else if let Some(liste) = ergebnis {
+                    (Ergebnis::Wert(liste), nicht_verwendet)
+                } else if let Some(liste) = &standard {
+                    (Ergebnis::Wert(liste.clone()), nicht_verwendet)
+                } else {
+                    (
+                        Ergebnis::Fehler(NonEmpty::singleton(wert_namen.fehler_fehlend())),
+                        nicht_verwendet,
+                    )
+                }
+            }),
+        }
+    }
+
+    /// Create a value-argument for a list separated by `separator`.
+    ///
+    /// The value is split at every `separator` and each element is parsed with `parse_element`,
+    /// e.g. `--tags a,b,c` becomes `[a, b, c]`.
+    /// Depending on `empty_elements`, empty elements are ignored, or produce an error
+    /// with description `empty_element`.
+    /// If the argument is given multiple times, the last value is used.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_getrennte_liste](Argumente::wert_getrennte_liste)
+    #[inline(always)]
+    pub fn value_separated_list(
+        description: Description<'t, Vec<T>>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        separator: char,
+        empty_elements: EmptyElements,
+        empty_element: &'t str,
+        parse_element: impl 't + Fn(&str) -> Result<T, String>,
+    ) -> Arguments<'t, Vec<T>, String> {
+        Argumente::wert_getrennte_liste(
+            description,
+            value_infix,
+            meta_var,
+            separator,
+            empty_elements,
+            empty_element,
+            parse_element,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument für eine durch `trenner` getrennte Liste,
+    /// das mehrfach genannt werden kann.
+    ///
+    /// ## English synonym
+    /// [value_separated_list_multiple_with_language](Arguments::value_separated_list_multiple_with_language)
+    #[inline(always)]
+    pub fn wert_getrennte_liste_mehrfach_mit_sprache(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        trenner: char,
+        leere_elemente: LeereElemente,
+        parse_element: impl 't + Fn(&str) -> Result<T, String>,
+        sprache: Sprache,
+    ) -> Argumente<'t, Vec<T>, String> {
+        Argumente::wert_getrennte_liste_mehrfach(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            trenner,
+            leere_elemente,
+            sprache.leeres_element,
+            parse_element,
+        )
+    }
+
+    /// Create a value-argument for a list separated by `separator`,
+    /// which may be given multiple times.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_getrennte_liste_mehrfach_mit_sprache](Argumente::wert_getrennte_liste_mehrfach_mit_sprache)
+    #[inline(always)]
+    pub fn value_separated_list_multiple_with_language(
+        description: Description<'t, Vec<T>>,
+        separator: char,
+        empty_elements: EmptyElements,
+        parse_element: impl 't + Fn(&str) -> Result<T, String>,
+        language: Language,
+    ) -> Arguments<'t, Vec<T>, String> {
+        Argumente::wert_getrennte_liste_mehrfach_mit_sprache(
+            description,
+            separator,
+            empty_elements,
+            parse_element,
+            language,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument für eine durch `trenner` getrennte Liste,
+    /// das mehrfach genannt werden kann.
+    ///
+    /// Wie bei [wert_getrennte_liste](Argumente::wert_getrennte_liste) wird jeder Wert
+    /// an jedem `trenner` aufgeteilt, allerdings werden die Elemente aller Vorkommen
+    /// in der genannten Reihenfolge gesammelt, z.B. wird `--tags a,b --tags c` zu `[a, b, c]`.
+    /// Leere Elemente werden abhängig von `leere_elemente` ignoriert, oder erzeugen einen Fehler
+    /// mit Beschreibung `leeres_element`.
+    ///
+    /// ## English synonym
+    /// [value_separated_list_multiple](Arguments::value_separated_list_multiple)
+    pub fn wert_getrennte_liste_mehrfach(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        trenner: char,
+        leere_elemente: LeereElemente,
+        leeres_element: &'t str,
+        parse_element: impl 't + Fn(&str) -> Result<T, String>,
+    ) -> Argumente<'t, Vec<T>, String> {
+        let wert_namen = WertNamen::neu(&beschreibung, wert_infix.into(), meta_var);
+        Argumente::wert_getrennte_liste_allgemein(
+            beschreibung,
+            wert_namen,
+            trenner,
+            leere_elemente,
+            leeres_element,
+            true,
+            parse_element,
+        )
+    }
+
+    /// Create a value-argument for a list separated by `separator`,
+    /// which may be given multiple times.
+    ///
+    /// Like [value_separated_list](Arguments::value_separated_list), every value is split
+    /// at every `separator`, but the elements of all occurrences are collected
+    /// in the given order, e.g. `--tags a,b --tags c` becomes `[a, b, c]`.
+    /// Depending on `empty_elements`, empty elements are ignored, or produce an error
+    /// with description `empty_element`.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_getrennte_liste_mehrfach](Argumente::wert_getrennte_liste_mehrfach)
+    #[inline(always)]
+    pub fn value_separated_list_multiple(
+        description: Description<'t, Vec<T>>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        separator: char,
+        empty_elements: EmptyElements,
+        empty_element: &'t str,
+        parse_element: impl 't + Fn(&str) -> Result<T, String>,
+    ) -> Arguments<'t, Vec<T>, String> {
+        Argumente::wert_getrennte_liste_mehrfach(
+            description,
+            value_infix,
+            meta_var,
+            separator,
+            empty_elements,
+            empty_element,
+            parse_element,
+        )
+    }
+}
+
+impl<'t, T: 't + Clone + Display + Ord> Argumente<'t, BTreeSet<T>, String> {
+    /// Erzeuge ein Wert-Argument für eine durch `trenner` getrennte Menge.
+    ///
+    /// ## English synonym
+    /// [value_set_with_language](Arguments::value_set_with_language)
+    #[inline(always)]
+    pub fn wert_menge_mit_sprache(
+        beschreibung: Beschreibung<'t, BTreeSet<T>>,
+        trenner: char,
+        duplikate: Duplikate,
+        parse_element: impl 't + Fn(&str) -> Result<T, String>,
+        sprache: Sprache,
+    ) -> Argumente<'t, BTreeSet<T>, String> {
+        Argumente::wert_menge(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            trenner,
+            duplikate,
+            sprache.doppeltes_element,
+            parse_element,
+        )
+    }
+
+    /// Create a value-argument for a set separated by `separator`.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_menge_mit_sprache](Argumente::wert_menge_mit_sprache)
+    #[inline(always)]
+    pub fn value_set_with_language(
+        description: Description<'t, BTreeSet<T>>,
+        separator: char,
+        duplicates: Duplicates,
+        parse_element: impl 't + Fn(&str) -> Result<T, String>,
+        language: Language,
+    ) -> Arguments<'t, BTreeSet<T>, String> {
+        Argumente::wert_menge_mit_sprache(
+            description,
+            separator,
+            duplicates,
+            parse_element,
+            language,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument für eine durch `trenner` getrennte Menge.
+    ///
+    /// Der Wert wird wie bei [wert_getrennte_liste](Argumente::wert_getrennte_liste)
+    /// an jedem `trenner` aufgeteilt, leere Elemente werden ignoriert,
+    /// z.B. wird `--features a,b,a` zu `{a, b}`.
+    /// Doppelte Elemente werden abhängig von `duplikate` ignoriert, oder erzeugen einen Fehler
+    /// mit Beschreibung `doppeltes_element`.
+    /// Wird das Argument mehrfach genannt, wird der letzte Wert verwendet.
+    ///
+    /// ## English synonym
+    /// [value_set](Arguments::value_set)
+    pub fn wert_menge(
+        beschreibung: Beschreibung<'t, BTreeSet<T>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        trenner: char,
+        duplikate: Duplikate,
+        doppeltes_element: &'t str,
+        parse_element: impl 't + Fn(&str) -> Result<T, String>,
+    ) -> Argumente<'t, BTreeSet<T>, String> {
+        let wert_namen = WertNamen::neu(&beschreibung, wert_infix.into(), meta_var);
+        let anzeige = |menge: &BTreeSet<T>| menge.iter().join(&trenner.to_string());
+        let (beschreibung, standard) = beschreibung.als_string_beschreibung_allgemein(anzeige);
+        Argumente {
+            konfigurationen: vec![Konfiguration::Wert {
+                beschreibung,
+                wert_infix: wert_namen.wert_infix.clone(),
+                wert_infix_anzeige: None,
+                geheim: false,
+                meta_var,
+                mögliche_werte: None,
+                reihenfolge: 0,
+                versteckte_namen: Vec::new(),
+            }],
+            flag_kurzformen: HashMap::new(),
+            einstellungen: wert_namen.einstellungen(),
+            parse: Box::new(move |args, kontext| {
+                let mut ergebnis: Option<BTreeSet<T>> = None;
+                let mut fehler = Vec::new();
+                let nicht_verwendet = wert_namen.suche(args, kontext.kandidaten, |arg| {
+                    match arg.map(OsString::into_string) {
+                        Some(Ok(string)) => {
+                            let mut menge = BTreeSet::new();
+                            let mut menge_fehler = Vec::new();
+                            for element in string.split(trenner).filter(|e| !e.is_empty()) {
+                                match parse_element(element) {
+                                    Ok(wert) => {
+                                        if !menge.insert(wert) && duplikate == Duplikate::Fehler {
+                                            menge_fehler
+                                                .push(format!("{doppeltes_element}: {element}"))
+                                        }
+                                    },
+                                    Err(parse_fehler) => menge_fehler.push(parse_fehler),
+                                }
+                            }
+                            if menge_fehler.is_empty() {
+                                ergebnis = Some(menge)
+                            } else {
+                                fehler.extend(menge_fehler.into_iter().map(|parse_fehler| {
+                                    wert_namen.fehler_parse(ParseFehler::ParseFehler(parse_fehler))
+                                }))
+                            }
+                        },
+                        Some(Err(os_string)) => fehler
+                            .push(wert_namen.fehler_parse(ParseFehler::InvaliderString(os_string))),
+                        None => fehler.push(wert_namen.fehler_kein_wert()),
+                    }
+                });
+                if let Some(fehler) = NonEmpty::from_vec(fehler) {
+                    let ergebnis = nachsichtiger_standard(
+                        Ergebnis::Fehler(fehler),
+                        standard.as_ref(),
+                        kontext.nachsichtig.as_deref_mut(),
+                    );
+                    (ergebnis, nicht_verwendet)
+                } else if let Some(menge) = ergebnis {
+                    (Ergebnis::Wert(menge), nicht_verwendet)
+                } else if let Some(menge) = &standard {
+                    (Ergebnis::Wert(menge.clone()), nicht_verwendet)
+                } else {
+                    (
+                        Ergebnis::Fehler(NonEmpty::singleton(wert_namen.fehler_fehlend())),
+                        nicht_verwendet,
+                    )
+                }
+            }),
+        }
+    }
+
+    /// Create a value-argument for a set separated by `separator`.
+    ///
+    /// The value is split at every `separator` like for
+    /// [value_separated_list](Arguments::value_separated_list), empty elements are ignored,
+    /// e.g. `--features a,b,a` becomes `{a, b}`.
+    /// Depending on `duplicates`, duplicate elements are ignored, or produce an error
+    /// with description `duplicate_element`.
+    /// If the argument is given multiple times, the last value is used.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_menge](Argumente::wert_menge)
+    #[inline(always)]
+    pub fn value_set(
+        description: Description<'t, BTreeSet<T>>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        separator: char,
+        duplicates: Duplicates,
+        duplicate_element: &'t str,
+        parse_element: impl 't + Fn(&str) -> Result<T, String>,
+    ) -> Arguments<'t, BTreeSet<T>, String> {
+        Argumente::wert_menge(
+            description,
+            value_infix,
+            meta_var,
+            separator,
+            duplicates,
+            duplicate_element,
+            parse_element,
+        )
+    }
+}
+
+impl<'t, T: 't + Clone, E> Argumente<'t, Vec<T>, E> {
+    /// Erzeuge ein Wert-Argument, das abhängig von `mehrfach` mehrfach genannt werden kann.
+    ///
+    /// ## English synonym
+    /// [value_multiple_with_language](Arguments::value_multiple_with_language)
+    #[inline(always)]
+    pub fn wert_mehrfach_mit_sprache(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        mehrfach: Mehrfach,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseFehler<E>>,
+        anzeige: impl 't + Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, Vec<T>, E> {
+        Argumente::wert_mehrfach(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            mehrfach,
+            sprache.mehrfach_genannt,
+            parse,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, which may be given multiple times depending on `multiple`.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mehrfach_mit_sprache](Argumente::wert_mehrfach_mit_sprache)
+    #[inline(always)]
+    pub fn value_multiple_with_language(
+        description: Description<'t, Vec<T>>,
+        multiple: Multiple,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl 't + Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, Vec<T>, E> {
+        Argumente::wert_mehrfach_mit_sprache(description, multiple, parse, display, language)
+    }
+
+    /// Erzeuge ein Wert-Argument, das abhängig von `mehrfach` mehrfach genannt werden kann.
+    ///
+    /// Mit [Mehrfach::Ersetze] wird nur der letzte Wert verwendet,
+    /// mit [Mehrfach::Anhängen] werden alle Werte in der genannten Reihenfolge zurückgegeben.
+    /// Mit [Mehrfach::Fehler] erzeugt jedes weitere Vorkommen einen
+    /// [Fehler::MehrfachGenannt](crate::Fehler::MehrfachGenannt) mit Beschreibung `mehrfach_genannt`.
+    /// Mit [parse_nachsichtig](Argumente::parse_nachsichtig) werden bei Fehlern
+    /// die gültigen Werte zurückgegeben und die Fehler gesammelt.
+    /// Wird das Argument nicht genannt, wird der Standard-Wert,
+    /// bzw. eine leere Liste zurückgegeben.
+    ///
+    /// ## English synonym
+    /// [value_multiple](Arguments::value_multiple)
+    pub fn wert_mehrfach(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        mehrfach: Mehrfach,
+        mehrfach_genannt: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseFehler<E>>,
+        anzeige: impl 't + Fn(&T) -> String,
+    ) -> Argumente<'t, Vec<T>, E> {
+        Argumente::wert_sammeln(
+            beschreibung,
+            wert_infix.into(),
+            meta_var,
+            move |werte| werte.iter().map(&anzeige).join(", "),
+            move |wert_namen, args, kandidaten| {
+                wert_namen.suche_werte(
+                    args,
+                    kandidaten,
+                    mehrfach,
+                    mehrfach_genannt,
+                    |wert_os_str| parse(wert_os_str.into_owned()),
+                )
+            },
+        )
+    }
+
+    /// Gemeinsame Implementierung für Wert-Argumente, die die Werte aller Vorkommen sammeln.
+    /// Die Werte und Fehler werden mit `sammle` bestimmt,
+    /// üblicherweise über [suche_werte](WertNamen::suche_werte).
+    /// Mit [parse_nachsichtig](Argumente::parse_nachsichtig) werden bei Fehlern
+    /// die gültigen Werte zurückgegeben und die Fehler gesammelt.
+    /// Ohne Werte wird der Standard-Wert, bzw. eine leere Liste zurückgegeben.
+    pub(crate) fn wert_sammeln(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        wert_infix: Vergleich<'t>,
+        meta_var: &'t str,
+        anzeige: impl Fn(&Vec<T>) -> String,
+        sammle: impl 't
+            + Fn(
+                &WertNamen<'t>,
+                Vec<Option<OsString>>,
+                Option<&IndexKandidaten<'_, 't>>,
+            ) -> (Vec<T>, Vec<Fehler<'t, E>>, Vec<Option<OsString>>),
+    ) -> Argumente<'t, Vec<T>, E> {
+        let wert_namen = WertNamen::neu(&beschreibung, wert_infix, meta_var);
+        let (beschreibung, standard) = beschreibung.als_string_beschreibung_allgemein(anzeige);
+        Argumente {
+            konfigurationen: vec![Konfiguration::Wert {
+                beschreibung,
+                wert_infix: wert_namen.wert_infix.clone(),
+                wert_infix_anzeige: None,
+                geheim: false,
+                meta_var,
+                mögliche_werte: None,
+                reihenfolge: 0,
+                versteckte_namen: Vec::new(),
+            }],
+            flag_kurzformen: HashMap::new(),
+            einstellungen: wert_namen.einstellungen(),
+            parse: Box::new(move |args, kontext| {
+                let (werte, fehler, nicht_verwendet) =
+                    sammle(&wert_namen, args, kontext.kandidaten);
+                let fehler = match (NonEmpty::from_vec(fehler), kontext.nachsichtig.as_deref_mut())
+                {
+                    (Some(fehler), Some(gesammelte_fehler)) => {
+                        // Nachsichtiges Parsen, behalte die gültigen Werte.
+                        gesammelte_fehler.extend(fehler);
+                        None
+                    },
+                    (fehler, _) => fehler,
+                };
+                if let Some(fehler) = fehler {
+                    (Ergebnis::Fehler(fehler), nicht_verwendet)
+                } else if werte.is_empty() {
+                    (Ergebnis::Wert(standard.clone().unwrap_or_default()), nicht_verwendet)
+                } else {
+                    (Ergebnis::Wert(werte), nicht_verwendet)
+                }
+            }),
+        }
+    }
+
+    /// Create a value-argument, which may be given multiple times depending on `multiple`.
+    ///
+    /// With [Multiple::Ersetze], only the last value is used,
+    /// with [Multiple::Anhängen], all values are returned in the given order.
+    /// With [Multiple::Fehler], every further occurrence produces an
+    /// [Error::MehrfachGenannt](crate::Error::MehrfachGenannt) with description `given_multiple_times`.
+    /// With [parse_lenient](Arguments::parse_lenient), the valid values are returned on errors
+    /// and the errors are collected.
+    /// If the argument is not given, the default value, or an empty list is returned.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mehrfach](Argumente::wert_mehrfach)
+    #[inline(always)]
+    pub fn value_multiple(
+        description: Description<'t, Vec<T>>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        multiple: Multiple,
+        given_multiple_times: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl 't + Fn(&T) -> String,
+    ) -> Arguments<'t, Vec<T>, E> {
+        Argumente::wert_mehrfach(
+            description,
+            value_infix,
+            meta_var,
+            multiple,
+            given_multiple_times,
+            parse,
+            display,
+        )
+    }
+}
+
+impl<'t, T: 't + Clone, E> Argumente<'t, Vec<T>, E> {
+    /// Erzeuge ein Wert-Argument, das höchstens `maximum` mal genannt werden kann.
+    ///
+    /// ## English synonym
+    /// [value_bounded_with_language](Arguments::value_bounded_with_language)
+    #[inline(always)]
+    pub fn wert_begrenzt_mit_sprache(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        maximum: usize,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseFehler<E>>,
+        anzeige: impl 't + Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, Vec<T>, E> {
+        Argumente::wert_begrenzt(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            maximum,
+            sprache.zu_viele_werte,
+            parse,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, which may be given at most `maximum` times.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_begrenzt_mit_sprache](Argumente::wert_begrenzt_mit_sprache)
+    #[inline(always)]
+    pub fn value_bounded_with_language(
+        description: Description<'t, Vec<T>>,
+        maximum: usize,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl 't + Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, Vec<T>, E> {
+        Argumente::wert_begrenzt_mit_sprache(description, maximum, parse, display, language)
+    }
+
+    /// Erzeuge ein Wert-Argument, das höchstens `maximum` mal genannt werden kann.
+    ///
+    /// Alle Werte werden in der genannten Reihenfolge zurückgegeben.
+    /// Jedes Vorkommen nach dem `maximum`-ten erzeugt einen
+    /// [Fehler::ZuVieleWerte](crate::Fehler::ZuVieleWerte) mit Beschreibung `zu_viele_werte`.
+    /// Mit [parse_nachsichtig](Argumente::parse_nachsichtig) werden bei Fehlern
+    /// die gültigen Werte zurückgegeben und die Fehler gesammelt.
+    /// Wird das Argument nicht genannt, wird der Standard-Wert,
+    /// bzw. eine leere Liste zurückgegeben.
+    ///
+    /// ## English synonym
+    /// [value_bounded](Arguments::value_bounded)
+    pub fn wert_begrenzt(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        maximum: usize,
+        zu_viele_werte: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseFehler<E>>,
+        anzeige: impl 't + Fn(&T) -> String,
+    ) -> Argumente<'t, Vec<T>, E> {
+        Argumente::wert_sammeln(
+            beschreibung,
+            wert_infix.into(),
+            meta_var,
+            move |werte| werte.iter().map(&anzeige).join(", "),
+            move |wert_namen, args, kandidaten| {
+                // Mit `Mehrfach::Anhängen` wird die Beschreibung für mehrfach genannte
+                // Argumente nicht verwendet.
+                let (mut werte, mut fehler, nicht_verwendet) = wert_namen.suche_werte(
+                    args,
+                    kandidaten,
+                    Mehrfach::Anhängen,
+                    "",
+                    |wert_os_str| parse(wert_os_str.into_owned()),
+                );
+                if werte.len() > maximum {
+                    fehler.extend(
+                        werte
+                            .drain(maximum..)
+                            .map(|_wert| wert_namen.fehler_zu_viele_werte(maximum, zu_viele_werte)),
+                    );
+                }
+                (werte, fehler, nicht_verwendet)
+            },
+        )
+    }
+
+    /// Create a value-argument, which may be given at most `maximum` times.
+    ///
+    /// All values are returned in the given order.
+    /// Every occurrence after the `maximum`-th produces an
+    /// [Error::ZuVieleWerte](crate::Error::ZuVieleWerte) with description `too_many_values`.
+    /// With [parse_lenient](Arguments::parse_lenient), the valid values are returned on errors
+    /// and the errors are collected.
+    /// If the argument is not given, the default value, or an empty list is returned.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_begrenzt](Argumente::wert_begrenzt)
+    #[inline(always)]
+    pub fn value_bounded(
+        description: Description<'t, Vec<T>>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        maximum: usize,
+        too_many_values: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl 't + Fn(&T) -> String,
+    ) -> Arguments<'t, Vec<T>, E> {
+        Argumente::wert_begrenzt(
+            description,
+            value_infix,
+            meta_var,
+            maximum,
+            too_many_values,
+            parse,
+            display,
+        )
+    }
+}
+
+/// Art eines Kommandozeilen-Arguments, bezogen auf ein Wert-Argument.
+enum Token {
+    /// Der Name des Wert-Arguments ohne Wert (`--name`).
+    Name,
+    /// Der Name des Wert-Arguments mit Wert (`--name=wert`).
+    NameMitWert(OsString),
+    /// Ein anderes Argument.
+    Anderes,
+}
+
+/// Bestimme die [Token]-Art eines Kommandozeilen-Arguments.
+fn token_art(wert_namen: &WertNamen<'_>, arg: &Option<OsString>) -> Token {
+    if arg.is_none() {
+        // Bereits von einem anderen Argument verwendet.
+        return Token::Anderes;
+    }
+    let mut gefunden = None;
+    let nicht_verwendet = wert_namen.suche(vec![arg.clone()], None, |wert| gefunden = wert);
+    match (gefunden, nicht_verwendet.as_slice()) {
+        (Some(wert), _) => Token::NameMitWert(wert),
+        // Der Name wurde verwendet, aber es folgt kein Argument als Wert.
+        (None, [None]) => Token::Name,
+        (None, _) => Token::Anderes,
+    }
+}
+
+impl<'t, T: 't + Clone, E: 't> Argumente<'t, Vec<T>, E> {
+    /// Erzeuge ein Wert-Argument, das mehrfach genannt werden kann
+    /// und bei dem jeder Wert einzeln mit `prüfung` überprüft wird.
+    ///
+    /// ## English synonym
+    /// [value_vec_validated_with_language](Arguments::value_vec_validated_with_language)
+    #[inline(always)]
+    pub fn wert_vec_validiert_mit_sprache(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        prüfung: impl 't + Fn(&T) -> Result<(), E>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseFehler<E>>,
+        anzeige: impl 't + Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, Vec<T>, E> {
+        Argumente::wert_vec_validiert(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            prüfung,
+            parse,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, which may be given multiple times
+    /// and where every value is validated separately using `validation`.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_vec_validiert_mit_sprache](Argumente::wert_vec_validiert_mit_sprache)
+    #[inline(always)]
+    pub fn value_vec_validated_with_language(
+        description: Description<'t, Vec<T>>,
+        validation: impl 't + Fn(&T) -> Result<(), E>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl 't + Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, Vec<T>, E> {
+        Argumente::wert_vec_validiert_mit_sprache(description, validation, parse, display, language)
+    }
+
+    /// Erzeuge ein Wert-Argument, das mehrfach genannt werden kann
+    /// und bei dem jeder Wert einzeln mit `prüfung` überprüft wird,
+    /// z.B. wird `--port 80 --port 443` zu `[80, 443]`.
+    ///
+    /// Jeder nicht parsbare oder ungültige Wert erzeugt einen eigenen Fehler,
+    /// es werden alle Fehler zurückgegeben.
+    /// Mit [parse_nachsichtig](Argumente::parse_nachsichtig) werden stattdessen
+    /// die gültigen Werte zurückgegeben und die Fehler gesammelt.
+    /// Wird das Argument nicht genannt, wird der Standard-Wert,
+    /// bzw. eine leere Liste zurückgegeben.
+    ///
+    /// ## English synonym
+    /// [value_vec_validated](Arguments::value_vec_validated)
+    pub fn wert_vec_validiert(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        prüfung: impl 't + Fn(&T) -> Result<(), E>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseFehler<E>>,
+        anzeige: impl 't + Fn(&T) -> String,
+    ) -> Argumente<'t, Vec<T>, E> {
+        // Mit `Mehrfach::Anhängen` wird die Beschreibung für mehrfach genannte
+        // Argumente nicht verwendet.
+        Argumente::wert_mehrfach(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            Mehrfach::Anhängen,
+            "",
+            move |wert_os_str| {
+                let wert = parse(wert_os_str)?;
+                prüfung(&wert).map_err(ParseFehler::ParseFehler)?;
+                Ok(wert)
+            },
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, which may be given multiple times
+    /// and where every value is validated separately using `validation`,
+    /// e.g. `--port 80 --port 443` becomes `[80, 443]`.
+    ///
+    /// Every value that can't be parsed or is invalid produces its own error,
+    /// all errors are returned.
+    /// With [parse_lenient](Arguments::parse_lenient), the valid values are returned instead
+    /// and the errors are collected.
+    /// If the argument is not given, the default value, or an empty list is returned.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_vec_validiert](Argumente::wert_vec_validiert)
+    #[inline(always)]
+    pub fn value_vec_validated(
+        description: Description<'t, Vec<T>>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        validation: impl 't + Fn(&T) -> Result<(), E>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl 't + Fn(&T) -> String,
+    ) -> Arguments<'t, Vec<T>, E> {
+        Argumente::wert_vec_validiert(
+            description,
+            value_infix,
+            meta_var,
+            validation,
+            parse,
+            display,
+        )
+    }
+}
+
+impl<'t, T: 't + Clone, E: 't + Clone> Argumente<'t, T, E> {
+    /// Erzeuge ein Wert-Argument, dessen Wert aus allen folgenden Argumenten
+    /// bis zum nächsten Flag-ähnlichen Argument besteht, verbunden mit Leerzeichen.
+    ///
+    /// ## English synonym
+    /// [value_joined_with_language](Arguments::value_joined_with_language)
+    #[inline(always)]
+    pub fn wert_verbunden_mit_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseFehler<E>>,
+        anzeige: impl Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_verbunden(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            parse,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, whose value consists of all following arguments
+    /// until the next flag-like argument, joined with spaces.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_verbunden_mit_sprache](Argumente::wert_verbunden_mit_sprache)
+    #[inline(always)]
+    pub fn value_joined_with_language(
+        description: Description<'t, T>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_verbunden_mit_sprache(description, parse, display, language)
+    }
+
+    /// Erzeuge ein Wert-Argument, dessen Wert aus allen folgenden Argumenten
+    /// bis zum nächsten Flag-ähnlichen Argument besteht, verbunden mit Leerzeichen,
+    /// z.B. wird `--nachricht hallo welt --verbose` zum Wert `hallo welt`.
+    /// Ein Wert nach dem `wert_infix` (`--nachricht=hallo welt`) ist der erste Teil des Wertes.
+    ///
+    /// Da die Namen anderer Argumente nicht bekannt sind, endet der Wert beim ersten Argument,
+    /// das mit `lang_präfix` oder `kurz_präfix` der Beschreibung beginnt.
+    /// Teile des Wertes, die mit einem Präfix beginnen (z.B. `-5`),
+    /// müssen daher im selben Argument angegeben werden (`--nachricht "hallo -5"`).
+    ///
+    /// ## English synonym
+    /// [value_joined](Arguments::value_joined)
+    pub fn wert_verbunden(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseFehler<E>>,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, E> {
+        let wert_infix = wert_infix.into();
+        let wert_namen = WertNamen::neu(&beschreibung, wert_infix.clone(), meta_var);
+        let Argumente { konfigurationen, flag_kurzformen, einstellungen, parse } =
+            Argumente::wert(beschreibung, wert_infix, meta_var, None, parse, anzeige);
+        let lang_präfix = wert_namen.lang_präfix.clone();
+        let kurz_präfix = wert_namen.kurz_präfix.clone();
+        let flag_ähnlich = move |arg: &OsString| {
+            arg.to_str().is_some_and(|string| {
+                let normalisiert = Normalisiert::neu(string);
+                // Ein leeres Präfix kennzeichnet kein Flag-ähnliches Argument.
+                let beginnt_mit = |präfix: &Vergleich<'_>| {
+                    !präfix.as_ref().is_empty() && präfix.strip_als_präfix(&normalisiert).is_some()
+                };
+                beginnt_mit(&lang_präfix) || beginnt_mit(&kurz_präfix)
+            })
+        };
+        let name = wert_namen.lang_präfix.string.as_ref().to_owned()
+            + wert_namen.lang.head.string.as_ref();
+        Argumente {
+            konfigurationen,
+            flag_kurzformen,
+            // Die verbundenen Argumente haben andere Positionen als die ursprünglichen,
+            // daher vergleicht das Wert-Argument alle Argumente ohne Index.
+            einstellungen: ParseEinstellungen { namen_einträge: Vec::new(), ..einstellungen },
+            parse: Box::new(move |args, kontext| {
+                let anzahl_args = args.len();
+                let mut verbundene_args = Vec::with_capacity(anzahl_args);
+                // Ursprüngliche Position jedes verbundenen Arguments,
+                // `None` für zusammengesetzte Werte.
+                let mut positionen = Vec::with_capacity(anzahl_args);
+                let mut args = args.into_iter().enumerate().peekable();
+                while let Some((position, arg)) = args.next() {
+                    let mut wert = match token_art(&wert_namen, &arg) {
+                        Token::Name => None,
+                        Token::NameMitWert(wert) => Some(wert),
+                        Token::Anderes => {
+                            verbundene_args.push(arg);
+                            positionen.push(Some(position));
+                            continue;
+                        },
+                    };
+                    while let Some((_position, Some(nächstes))) = args.peek() {
+                        if flag_ähnlich(nächstes) {
+                            break;
+                        }
+                        let teil = args.next().and_then(|(_position, teil)| teil);
+                        let teil = teil.expect("peek war Some(Some(_))");
+                        if let Some(wert) = &mut wert {
+                            wert.push(" ");
+                            wert.push(teil);
+                        } else {
+                            wert = Some(teil);
+                        }
+                    }
+                    verbundene_args.push(Some(OsString::from(&name)));
+                    positionen.push(Some(position));
+                    // Ohne Wert wird ein bereits verwendetes Argument angehängt,
+                    // damit ein Fehler für den fehlenden Wert erzeugt wird.
+                    verbundene_args.push(wert);
+                    positionen.push(None);
+                }
+                let (ergebnis, verbunden_nicht_verwendet) =
+                    parse(verbundene_args, &mut kontext.ohne_kandidaten());
+                // Übertrage nicht verwendete Argumente an ihre ursprüngliche Position.
+                let mut nicht_verwendet = vec![None; anzahl_args];
+                for (position, arg) in positionen.into_iter().zip(verbunden_nicht_verwendet) {
+                    if let Some(position) = position {
+                        nicht_verwendet[position] = arg;
+                    }
+                }
+                (ergebnis, nicht_verwendet)
+            }),
+        }
+    }
+
+    /// Create a value-argument, whose value consists of all following arguments
+    /// until the next flag-like argument, joined with spaces,
+    /// e.g. `--message hello world --verbose` produces the value `hello world`.
+    /// A value after the `value_infix` (`--message=hello world`) is the first part of the value.
+    ///
+    /// Since the names of other arguments are unknown, the value ends at the first argument
+    /// starting with `long_prefix` or `short_prefix` of the description.
+    /// Parts of the value starting with a prefix (e.g. `-5`)
+    /// therefore have to be given in the same argument (`--message "hello -5"`).
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_verbunden](Argumente::wert_verbunden)
+    #[inline(always)]
+    pub fn value_joined(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_verbunden(description, value_infix, meta_var, parse, display)
+    }
+}
+
+impl<'t, T: 't + Clone> Argumente<'t, Vec<T>, String> {
+    /// Erzeuge ein Wert-Argument, das genau `arität` Werte erwartet.
+    ///
+    /// ## English synonym
+    /// [value_with_arity_and_language](Arguments::value_with_arity_and_language)
+    #[inline(always)]
+    pub fn wert_mit_arität_und_sprache(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        arität: usize,
+        parse_element: impl 't + Fn(OsString) -> Result<T, ParseFehler<String>>,
+        anzeige: impl 't + Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, Vec<T>, String> {
+        Argumente::wert_mit_arität(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            arität,
+            sprache.erwartete_werte,
+            parse_element,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument expecting exactly `arity` values.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_arität_und_sprache](Argumente::wert_mit_arität_und_sprache)
+    #[inline(always)]
+    pub fn value_with_arity_and_language(
+        description: Description<'t, Vec<T>>,
+        arity: usize,
+        parse_element: impl 't + Fn(OsString) -> Result<T, ParseError<String>>,
+        display: impl 't + Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, Vec<T>, String> {
+        Argumente::wert_mit_arität_und_sprache(
+            description,
+            arity,
+            parse_element,
+            display,
+            language,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument, das genau `arität` Werte erwartet,
+    /// z.B. wird `--größe 800 600` zu `[800, 600]`.
+    ///
+    /// Nach dem Namen werden immer die folgenden `arität` Argumente als Werte verwendet,
+    /// auch wenn sie mit einem Präfix beginnen.
+    /// Ein Wert nach dem `wert_infix` (`--größe=800 600`) ist der erste Wert.
+    /// Folgen weniger Werte, wird ein Fehler mit Beschreibung `erwartete_werte` erzeugt.
+    /// Wird das Argument mehrfach genannt, werden die letzten Werte verwendet.
+    ///
+    /// ## English synonym
+    /// [value_with_arity](Arguments::value_with_arity)
+    pub fn wert_mit_arität(
+        beschreibung: Beschreibung<'t, Vec<T>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        arität: usize,
+        erwartete_werte: &'t str,
+        parse_element: impl 't + Fn(OsString) -> Result<T, ParseFehler<String>>,
+        anzeige: impl 't + Fn(&T) -> String,
+    ) -> Argumente<'t, Vec<T>, String> {
+        let wert_namen = WertNamen::neu(&beschreibung, wert_infix.into(), meta_var);
+        let (beschreibung, standard) = beschreibung
+            .als_string_beschreibung_allgemein(|werte| werte.iter().map(&anzeige).join(" "));
+        Argumente {
+            konfigurationen: vec![Konfiguration::Wert {
+                beschreibung,
+                wert_infix: wert_namen.wert_infix.clone(),
+                wert_infix_anzeige: None,
+                geheim: false,
+                meta_var,
+                mögliche_werte: None,
+                reihenfolge: 0,
+                versteckte_namen: Vec::new(),
+            }],
+            flag_kurzformen: HashMap::new(),
+            // Jedes Argument wird einzeln über `token_art` geprüft, ohne Index.
+            einstellungen: ParseEinstellungen {
+                namen_einträge: Vec::new(),
+                ..wert_namen.einstellungen()
+            },
+            parse: Box::new(move |args, kontext| {
+                let mut ergebnis = None;
+                let mut fehler = Vec::new();
+                let mut nicht_verwendet = Vec::with_capacity(args.len());
+                let mut args = args.into_iter();
+                while let Some(arg) = args.next() {
+                    let mut werte = match token_art(&wert_namen, &arg) {
+                        Token::Name => Vec::with_capacity(arität),
+                        Token::NameMitWert(wert) => vec![wert],
+                        Token::Anderes => {
+                            nicht_verwendet.push(arg);
+                            continue;
+                        },
+                    };
+                    nicht_verwendet.push(None);
+                    while werte.len() < arität {
+                        match args.next() {
+                            Some(Some(wert)) => {
+                                werte.push(wert);
+                                nicht_verwendet.push(None);
+                            },
+                            // Ein bereits verwendetes Argument beendet die Werte.
+                            Some(None) => {
+                                nicht_verwendet.push(None);
+                                break;
+                            },
+                            None => break,
+                        }
+                    }
+                    if werte.len() != arität {
+                        fehler.push(wert_namen.fehler_parse(ParseFehler::ParseFehler(format!(
+                            "{erwartete_werte}: {arität}"
+                        ))));
+                        continue;
+                    }
+                    let mut geparste_werte = Vec::with_capacity(arität);
+                    for wert in werte {
+                        match parse_element(wert) {
+                            Ok(wert) => geparste_werte.push(wert),
+                            Err(parse_fehler) => fehler.push(wert_namen.fehler_parse(parse_fehler)),
+                        }
+                    }
+                    ergebnis = Some(geparste_werte);
+                }
+                if let Some(fehler) = NonEmpty::from_vec(fehler) {
+                    let ergebnis = nachsichtiger_standard(
+                        Ergebnis::Fehler(fehler),
+                        standard.as_ref(),
+                        kontext.nachsichtig.as_deref_mut(),
+                    );
+                    (ergebnis, nicht_verwendet)
+                } else if let Some(werte) = ergebnis.or_else(|| standard.clone()) {
+                    (Ergebnis::Wert(werte), nicht_verwendet)
+                } else {
+                    (
+                        Ergebnis::Fehler(NonEmpty::singleton(wert_namen.fehler_fehlend())),
+                        nicht_verwendet,
+                    )
+                }
+            }),
+        }
+    }
+
+    /// Create a value-argument expecting exactly `arity` values,
+    /// e.g. `--size 800 600` becomes `[800, 600]`.
+    ///
+    /// After the name, the following `arity` arguments are always used as values,
+    /// even if they start with a prefix.
+    /// A value after the `value_infix` (`--size=800 600`) is the first value.
+    /// If fewer values follow, an error with description `expected_values` is created.
+    /// If the argument is given multiple times, the last values are used.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_arität](Argumente::wert_mit_arität)
+    #[inline(always)]
+    pub fn value_with_arity(
+        description: Description<'t, Vec<T>>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        arity: usize,
+        expected_values: &'t str,
+        parse_element: impl 't + Fn(OsString) -> Result<T, ParseError<String>>,
+        display: impl 't + Fn(&T) -> String,
+    ) -> Arguments<'t, Vec<T>, String> {
+        Argumente::wert_mit_arität(
+            description,
+            value_infix,
+            meta_var,
+            arity,
+            expected_values,
+            parse_element,
+            display,
+        )
+    }
+}
@@ -0,0 +1,88 @@
+//! Wert-Argumente, deren Wert zu einem regulären Ausdruck passen muss.
+
+use regex::Regex;
+
+use crate::{
+    argumente::{Argumente, Arguments},
+    beschreibung::{Beschreibung, Description},
+    sprache::{Language, Sprache},
+    unicode::{Compare, Vergleich},
+};
+
+impl<'t> Argumente<'t, String, String> {
+    /// Erzeuge ein Wert-Argument, dessen Wert zum regulären Ausdruck `muster` passen muss.
+    ///
+    /// ## English synonym
+    /// [value_with_regex_and_language](Arguments::value_with_regex_and_language)
+    #[inline(always)]
+    pub fn wert_mit_regex_und_sprache(
+        beschreibung: Beschreibung<'t, String>,
+        muster: &str,
+        sprache: Sprache,
+    ) -> Result<Argumente<'t, String, String>, regex::Error> {
+        Argumente::wert_mit_regex(beschreibung, sprache.wert_infix, sprache.meta_var, muster)
+    }
+
+    /// Create a value-argument, whose value has to match the regular expression `pattern`.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_regex_und_sprache](Argumente::wert_mit_regex_und_sprache)
+    #[inline(always)]
+    pub fn value_with_regex_and_language(
+        description: Description<'t, String>,
+        pattern: &str,
+        language: Language,
+    ) -> Result<Arguments<'t, String, String>, regex::Error> {
+        Argumente::wert_mit_regex_und_sprache(description, pattern, language)
+    }
+
+    /// Erzeuge ein Wert-Argument, dessen Wert zum regulären Ausdruck `muster` passen muss.
+    /// Der reguläre Ausdruck wird einmalig beim Erzeugen kompiliert,
+    /// ein ungültiges `muster` führt zu einem Fehler.
+    ///
+    /// Passt ein Wert nicht zum `muster` wird ein
+    /// [ParseFehler](crate::ParseFehler::ParseFehler) mit dem `muster` zurückgegeben.
+    ///
+    /// ## English synonym
+    /// [value_with_regex](Arguments::value_with_regex)
+    pub fn wert_mit_regex(
+        beschreibung: Beschreibung<'t, String>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        muster: &str,
+    ) -> Result<Argumente<'t, String, String>, regex::Error> {
+        let regex = Regex::new(muster)?;
+        Ok(Argumente::wert_string_display(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            None,
+            move |string| {
+                if regex.is_match(string) {
+                    Ok(string.to_owned())
+                } else {
+                    Err(format!("\"{string}\" ∉ /{}/", regex.as_str()))
+                }
+            },
+        ))
+    }
+
+    /// Create a value-argument, whose value has to match the regular expression `pattern`.
+    /// The regular expression is compiled once during creation,
+    /// an invalid `pattern` results in an error.
+    ///
+    /// If a value doesn't match the `pattern`, a
+    /// [ParseError](crate::ParseError::ParseFehler) mentioning the `pattern` is returned.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_regex](Argumente::wert_mit_regex)
+    #[inline(always)]
+    pub fn value_with_regex(
+        description: Description<'t, String>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        pattern: &str,
+    ) -> Result<Arguments<'t, String, String>, regex::Error> {
+        Argumente::wert_mit_regex(description, value_infix, meta_var, pattern)
+    }
+}
@@ -0,0 +1,123 @@
+//! Nachsichtiges Parsen, bei dem fehlerhafte Argumente durch einen Standard-Wert ersetzt werden.
+
+use std::ffi::OsString;
+
+use crate::{
+    argumente::{Argumente, Arguments, ParseZustand},
+    ergebnis::{Ergebnis, Error, Fehler},
+};
+
+impl<'t, T: 't + Clone, E: 't> Argumente<'t, T, E> {
+    /// Bei [parse_nachsichtig](Argumente::parse_nachsichtig) wird bei einem Fehler
+    /// `standard` als Wert verwendet, die Fehler werden gesammelt zurückgegeben.
+    /// Notwendig für Argumente ohne Standard-Wert in ihrer [Beschreibung](crate::Beschreibung),
+    /// sowie für kombinierte Argumente.
+    ///
+    /// Bei allen anderen parse-Methoden (z.B. [parse](Argumente::parse))
+    /// werden Fehler weiterhin wie gewohnt zurückgegeben.
+    ///
+    /// ## English synonym
+    /// [lenient](Arguments::lenient)
+    pub fn nachsichtig(self, standard: T) -> Argumente<'t, T, E> {
+        let Argumente { konfigurationen, flag_kurzformen, einstellungen, parse } = self;
+        Argumente {
+            konfigurationen,
+            flag_kurzformen,
+            einstellungen,
+            parse: Box::new(move |args, kontext| {
+                let (ergebnis, nicht_verwendet) = parse(args, kontext);
+                (
+                    nachsichtiger_standard(
+                        ergebnis,
+                        Some(&standard),
+                        kontext.nachsichtig.as_deref_mut(),
+                    ),
+                    nicht_verwendet,
+                )
+            }),
+        }
+    }
+
+    /// With [parse_lenient](Arguments::parse_lenient), `default` is used as value on an error,
+    /// the errors are collected and returned.
+    /// Required for arguments without default value in their [Description](crate::Description),
+    /// as well as for combined arguments.
+    ///
+    /// All other parse-methods (e.g. [parse](Arguments::parse)) return errors as usual.
+    ///
+    /// ## Deutsches Synonym
+    /// [nachsichtig](Argumente::nachsichtig)
+    #[inline(always)]
+    pub fn lenient(self, default: T) -> Arguments<'t, T, E> {
+        self.nachsichtig(default)
+    }
+}
+
+impl<'t, T, E> Argumente<'t, T, E> {
+    /// Parse die übergebenen Kommandozeilen-Argumente, wobei fehlerhafte Argumente
+    /// ihren Standard-Wert verwenden, z.B. für Werkzeuge, die trotz Fehlern alle Werte benötigen.
+    /// Neben dem bestmöglichen Wert werden alle Fehler und nicht verwendeten Argumente
+    /// zurückgegeben.
+    ///
+    /// Der Standard-Wert eines Arguments ist der Standard-Wert seiner
+    /// [Beschreibung](crate::Beschreibung), bzw. der Wert von [nachsichtig](Argumente::nachsichtig).
+    /// Die Argumente werden dafür nicht erneut geparst.
+    /// Hat ein fehlerhaftes Argument keinen Standard-Wert,
+    /// oder wird frühzeitig beendet (z.B. `--hilfe`), wird kein Wert ([None]) zurückgegeben.
+    ///
+    /// ## English synonym
+    /// [parse_lenient](Arguments::parse_lenient)
+    pub fn parse_nachsichtig(
+        &self,
+        args: impl Iterator<Item = OsString>,
+    ) -> (Option<T>, Vec<Fehler<'t, E>>, Vec<OsString>) {
+        let mut fehler = Vec::new();
+        let (ergebnis, nicht_verwendet) =
+            self.parse_mit_verwendeten(args, None, Some(&mut fehler), &mut ParseZustand::default());
+        let wert = match ergebnis {
+            Ergebnis::Wert(wert) => Some(wert),
+            Ergebnis::FrühesBeenden(_nachrichten) => None,
+            Ergebnis::Fehler(weitere_fehler) => {
+                fehler.extend(weitere_fehler);
+                None
+            },
+        };
+        (wert, fehler, nicht_verwendet)
+    }
+
+    /// Parse the given command line arguments, where erroneous arguments use their default value,
+    /// e.g. for tools requiring all values despite errors.
+    /// Besides the best-effort value, all errors and unused arguments are returned.
+    ///
+    /// The default value of an argument is the default value of its
+    /// [Description](crate::Description), or the value given to [lenient](Arguments::lenient).
+    /// The arguments are not parsed again for this.
+    /// If an erroneous argument has no default value,
+    /// or there is an early exit (e.g. `--help`), no value ([None]) is returned.
+    ///
+    /// ## Deutsches Synonym
+    /// [parse_nachsichtig](Argumente::parse_nachsichtig)
+    #[inline(always)]
+    pub fn parse_lenient(
+        &self,
+        args: impl Iterator<Item = OsString>,
+    ) -> (Option<T>, Vec<Error<'t, E>>, Vec<OsString>) {
+        self.parse_nachsichtig(args)
+    }
+}
+
+/// Verwende bei [nachsichtigem Parsen](Argumente::parse_nachsichtig) den `standard`
+/// anstelle eines Fehlers, die Fehler werden dabei gesammelt.
+pub(crate) fn nachsichtiger_standard<'t, T: Clone, E>(
+    ergebnis: Ergebnis<'t, T, E>,
+    standard: Option<&T>,
+    nachsichtig: Option<&mut Vec<Fehler<'t, E>>>,
+) -> Ergebnis<'t, T, E> {
+    match (ergebnis, standard, nachsichtig) {
+        (Ergebnis::Fehler(fehler), Some(standard), Some(gesammelte_fehler)) => {
+            gesammelte_fehler.extend(fehler);
+            Ergebnis::Wert(standard.clone())
+        },
+        (ergebnis, _standard, _nachsichtig) => ergebnis,
+    }
+}
@@ -0,0 +1,229 @@
+//! Index der Namen kombinierter [Argumente](crate::Argumente),
+//! damit nicht jedes Argument alle Kommandozeilen-Argumente vergleichen muss.
+
+use std::{collections::HashMap, ffi::OsString, rc::Rc};
+
+use crate::unicode::{Normalisiert, Vergleich};
+
+/// Präfixe, die nacheinander vor einem Namen entfernt werden,
+/// z.B. nur `--`, oder `--`, `kein` und `-` für die invertierte Form einer Flag.
+pub(crate) type PräfixKette<'t> = Vec<Vergleich<'t>>;
+
+/// Namen eines Arguments für den [NamenIndex].
+///
+/// Beim Parsen erhält das Argument über den [ParseKontext](crate::argumente::ParseKontext)
+/// die Positionen, an denen ein Name stehen könnte.
+/// Das Argument muss nur diese Positionen mit seinen Namen vergleichen.
+#[derive(Debug)]
+pub(crate) struct NamenEintrag<'t> {
+    präfixe: Vec<PräfixKette<'t>>,
+    namen: Vec<Vergleich<'t>>,
+}
+
+/// Gemeinsamer Eintrag, registriert in den
+/// [ParseEinstellungen](crate::argumente::ParseEinstellungen).
+/// Die Adresse identifiziert den Eintrag im [NamenIndex].
+pub(crate) type NamenIndexEintrag<'t> = Rc<NamenEintrag<'t>>;
+
+/// Positionen, an denen ein Name eines Arguments stehen könnte.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Kandidaten<'a> {
+    /// Aufsteigend sortiert.
+    positionen: &'a [usize],
+}
+
+impl Kandidaten<'_> {
+    /// Könnte an `position` ein Name des Arguments stehen?
+    pub(crate) fn möglich(&self, position: usize) -> bool {
+        self.positionen.binary_search(&position).is_ok()
+    }
+}
+
+/// Die beim aktuellen Parsen bestimmten [Kandidaten] aller Einträge eines [NamenIndex].
+#[derive(Debug)]
+pub(crate) struct IndexKandidaten<'a, 't> {
+    index: &'a NamenIndex<'t>,
+    anzahl_args: usize,
+    positionen: Vec<Vec<usize>>,
+}
+
+impl<'t> IndexKandidaten<'_, 't> {
+    /// Die [Kandidaten] für `eintrag`.
+    ///
+    /// Für nicht im [NamenIndex] enthaltene Einträge, oder falls die Anzahl der Argumente
+    /// nicht `anzahl_args` entspricht (z.B. weil sie vorher verändert wurden),
+    /// wird [None] zurückgegeben und jedes Argument muss verglichen werden.
+    pub(crate) fn für(
+        &self,
+        eintrag: &NamenIndexEintrag<'t>,
+        anzahl_args: usize,
+    ) -> Option<Kandidaten<'_>> {
+        if self.anzahl_args != anzahl_args {
+            return None;
+        }
+        let index = self.index.indizes.get(&Rc::as_ptr(eintrag))?;
+        Some(Kandidaten { positionen: &self.positionen[*index] })
+    }
+}
+
+impl<'t> NamenEintrag<'t> {
+    /// Erzeuge einen Eintrag für die `namen`, die nach einer der `präfixe` stehen.
+    pub(crate) fn neu(
+        präfixe: Vec<PräfixKette<'t>>,
+        namen: impl IntoIterator<Item = Vergleich<'t>>,
+    ) -> NamenIndexEintrag<'t> {
+        Rc::new(NamenEintrag { präfixe, namen: namen.into_iter().collect() })
+    }
+}
+
+/// Index der Namen aller [NamenEintrag], wird beim Kombinieren erzeugt.
+///
+/// Vor dem Parsen wird jedes Kommandozeilen-Argument einmal nachgeschlagen
+/// und die [Kandidaten] aller Einträge werden bestimmt.
+/// Der Index ist konservativ: Groß-/Kleinschreibung wird beim Nachschlagen immer ignoriert
+/// und Argumente mit Nicht-ASCII-Zeichen sind Kandidaten für alle Einträge,
+/// da sie nach Normalisierung zu jedem Namen passen könnten.
+/// Einträge mit Nicht-ASCII-Namen sind nicht Teil des Index, sie vergleichen weiterhin alle
+/// Argumente.
+#[derive(Debug)]
+pub(crate) struct NamenIndex<'t> {
+    einträge: Vec<NamenIndexEintrag<'t>>,
+    /// Index jedes Eintrags, über seine Adresse.
+    indizes: HashMap<*const NamenEintrag<'t>, usize>,
+    präfixe: Vec<PräfixKette<'t>>,
+    /// Namen in ASCII-Kleinbuchstaben, mit dem Index aller Einträge mit diesem Namen.
+    namen: HashMap<String, Vec<usize>>,
+    /// Aufsteigend sortierte Längen aller Namen in Bytes.
+    längen: Vec<usize>,
+}
+
+impl<'t> NamenIndex<'t> {
+    /// Erzeuge einen Index für alle `einträge` mit reinen ASCII-Namen.
+    pub(crate) fn neu(einträge: &[NamenIndexEintrag<'t>]) -> Option<Rc<NamenIndex<'t>>> {
+        let einträge: Vec<_> = einträge
+            .iter()
+            .filter(|eintrag| eintrag.namen.iter().all(|name| name.as_ref().is_ascii()))
+            .cloned()
+            .collect();
+        if einträge.is_empty() {
+            return None;
+        }
+        let mut präfixe = Vec::new();
+        let mut namen: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut längen = Vec::new();
+        for (index, eintrag) in einträge.iter().enumerate() {
+            for kette in &eintrag.präfixe {
+                if !präfixe.contains(kette) {
+                    präfixe.push(kette.clone());
+                }
+            }
+            for name in &eintrag.namen {
+                let name_str = name.as_ref();
+                let indizes = namen.entry(name_str.to_ascii_lowercase()).or_default();
+                if indizes.last() != Some(&index) {
+                    indizes.push(index);
+                }
+                längen.push(name_str.len());
+            }
+        }
+        längen.sort_unstable();
+        längen.dedup();
+        let indizes = einträge
+            .iter()
+            .enumerate()
+            .map(|(index, eintrag)| (Rc::as_ptr(eintrag), index))
+            .collect();
+        Some(Rc::new(NamenIndex { einträge, indizes, präfixe, namen, längen }))
+    }
+
+    /// Bestimme für alle Einträge die Positionen in `args`, an denen einer ihrer Namen
+    /// stehen könnte.
+    pub(crate) fn bestimme_kandidaten(&self, args: &[Option<OsString>]) -> IndexKandidaten<'_, 't> {
+        let mut positionen = vec![Vec::new(); self.einträge.len()];
+        for (position, arg) in args.iter().enumerate() {
+            // Nicht-Unicode Argumente werden nie als Name verwendet.
+            let Some(string) = arg.as_ref().and_then(|os_string| os_string.to_str()) else {
+                continue;
+            };
+            if !string.is_ascii() {
+                for positionen in positionen.iter_mut() {
+                    positionen.push(position);
+                }
+                continue;
+            }
+            let klein = string.to_ascii_lowercase();
+            for kette in &self.präfixe {
+                let Some(start) = entferne_präfixe(kette, string) else {
+                    continue;
+                };
+                for länge in self.längen.iter().take_while(|länge| start + *länge <= klein.len())
+                {
+                    let Some(indizes) = self.namen.get(&klein[start..start + länge]) else {
+                        continue;
+                    };
+                    for index in indizes {
+                        if positionen[*index].last() != Some(&position) {
+                            positionen[*index].push(position);
+                        }
+                    }
+                }
+            }
+        }
+        IndexKandidaten { index: self, anzahl_args: args.len(), positionen }
+    }
+}
+
+/// Entferne alle Präfixe der `kette` vom Anfang eines ASCII-Strings,
+/// wie es die Argumente beim Vergleichen machen.
+/// Zurückgegeben wird der Byte-Index des verbleibenden Strings.
+fn entferne_präfixe(kette: &[Vergleich<'_>], string: &str) -> Option<usize> {
+    let mut start = 0;
+    for präfix in kette {
+        // ASCII-Strings sind bereits normalisiert.
+        let normalisiert = Normalisiert::neu_borrowed_unchecked(&string[start..]);
+        let rest = präfix.strip_als_präfix(&normalisiert)?;
+        start = string.len() - rest.len();
+    }
+    Some(start)
+}
+
+/// Nachschlagen von Kurznamen, z.B. beim Aufteilen verschmolzener Kurzformen (`-abc`).
+///
+/// ASCII-Namen werden über ihre ASCII-Kleinbuchstaben gefunden und anschließend verglichen.
+/// Strings mit Nicht-ASCII-Zeichen werden mit allen Namen verglichen,
+/// Nicht-ASCII-Namen (z.B. ohne diakritische Zeichen verglichen) mit allen Strings.
+#[derive(Debug)]
+pub(crate) struct KurzIndex<'a, 't> {
+    namen: Vec<&'a Vergleich<'t>>,
+    ascii: HashMap<String, Vec<&'a Vergleich<'t>>>,
+    nicht_ascii: Vec<&'a Vergleich<'t>>,
+}
+
+impl<'a, 't> KurzIndex<'a, 't> {
+    /// Erzeuge einen Index für alle `namen`.
+    pub(crate) fn neu(namen: impl IntoIterator<Item = &'a Vergleich<'t>>) -> KurzIndex<'a, 't> {
+        let namen: Vec<_> = namen.into_iter().collect();
+        let mut ascii: HashMap<String, Vec<&'a Vergleich<'t>>> = HashMap::new();
+        let mut nicht_ascii = Vec::new();
+        for name in namen.iter().copied() {
+            if name.as_ref().is_ascii() {
+                ascii.entry(name.as_ref().to_ascii_lowercase()).or_default().push(name);
+            } else {
+                nicht_ascii.push(name);
+            }
+        }
+        KurzIndex { namen, ascii, nicht_ascii }
+    }
+
+    /// Passt `string` zu einem der Namen?
+    pub(crate) fn enthält(&self, string: &str) -> bool {
+        if string.is_ascii() {
+            self.ascii
+                .get(&string.to_ascii_lowercase())
+                .is_some_and(|namen| namen.iter().any(|name| name.passt(string)))
+                || self.nicht_ascii.iter().any(|name| name.passt(string))
+        } else {
+            self.namen.iter().any(|name| (*name).eq(string))
+        }
+    }
+}
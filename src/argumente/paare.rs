@@ -0,0 +1,260 @@
+//! Wiederholbare Wert-Argumente mit Schlüssel-Wert-Paaren.
+
+use std::{fmt::Display, str::FromStr};
+
+use itertools::Itertools;
+
+use crate::{
+    argumente::{liste::Mehrfach, Argumente, Arguments},
+    beschreibung::{Beschreibung, Description},
+    ergebnis::ParseFehler,
+    sprache::{Language, Sprache},
+    unicode::{Compare, Vergleich},
+};
+
+impl<'t> Argumente<'t, Vec<(String, String)>, String> {
+    /// Erzeuge ein wiederholbares Wert-Argument für Schlüssel-Wert-Paare.
+    ///
+    /// ## English synonym
+    /// [value_pairs_with_language](Arguments::value_pairs_with_language)
+    #[inline(always)]
+    pub fn wert_paare_mit_sprache(
+        beschreibung: Beschreibung<'t, Vec<(String, String)>>,
+        trenner: &'t str,
+        sprache: Sprache,
+    ) -> Argumente<'t, Vec<(String, String)>, String> {
+        Argumente::wert_paare(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            trenner,
+            sprache.fehlender_trenner,
+        )
+    }
+
+    /// Create a repeatable value-argument for key-value pairs.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_paare_mit_sprache](Argumente::wert_paare_mit_sprache)
+    #[inline(always)]
+    pub fn value_pairs_with_language(
+        description: Description<'t, Vec<(String, String)>>,
+        separator: &'t str,
+        language: Language,
+    ) -> Arguments<'t, Vec<(String, String)>, String> {
+        Argumente::wert_paare_mit_sprache(description, separator, language)
+    }
+
+    /// Erzeuge ein wiederholbares Wert-Argument für Schlüssel-Wert-Paare.
+    ///
+    /// Jedes Vorkommen wird am ersten `trenner` in Schlüssel und Wert aufgeteilt.
+    /// Die Paare werden in der Reihenfolge ihres Auftretens zurückgegeben,
+    /// doppelte Schlüssel bleiben erhalten.
+    /// Ohne `trenner` wird ein Fehler mit Beschreibung `fehlender_trenner` erzeugt.
+    /// Mit [parse_nachsichtig](Argumente::parse_nachsichtig) werden bei Fehlern
+    /// die gültigen Paare zurückgegeben und die Fehler gesammelt.
+    /// Wird das Argument nicht genannt, wird der Standard-Wert,
+    /// bzw. eine leere Liste zurückgegeben.
+    ///
+    /// ## English synonym
+    /// [value_pairs](Arguments::value_pairs)
+    pub fn wert_paare(
+        beschreibung: Beschreibung<'t, Vec<(String, String)>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        trenner: &'t str,
+        fehlender_trenner: &'t str,
+    ) -> Argumente<'t, Vec<(String, String)>, String> {
+        Argumente::paare_allgemein(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            trenner,
+            fehlender_trenner,
+            |paare: &Vec<(String, String)>| {
+                paare
+                    .iter()
+                    .map(|(schlüssel, wert)| format!("{schlüssel}{trenner}{wert}"))
+                    .join(", ")
+            },
+            |schlüssel, wert| Ok((schlüssel.to_owned(), wert.to_owned())),
+        )
+    }
+
+    /// Create a repeatable value-argument for key-value pairs.
+    ///
+    /// Each occurrence is split into key and value at the first `separator`.
+    /// The pairs are returned in the order they appear, duplicate keys are preserved.
+    /// Without `separator`, an error with description `missing_separator` is created.
+    /// With [parse_lenient](Arguments::parse_lenient), the valid pairs are returned on errors
+    /// and the errors are collected.
+    /// If the argument is not given, the default value, or an empty list is returned.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_paare](Argumente::wert_paare)
+    #[inline(always)]
+    pub fn value_pairs(
+        description: Description<'t, Vec<(String, String)>>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        separator: &'t str,
+        missing_separator: &'t str,
+    ) -> Arguments<'t, Vec<(String, String)>, String> {
+        Argumente::wert_paare(description, value_infix, meta_var, separator, missing_separator)
+    }
+}
+
+impl<'t, K, V> Argumente<'t, Vec<(K, V)>, String>
+where
+    K: 't + Clone + Display + FromStr,
+    K::Err: Display,
+    V: 't + Clone + Display + FromStr,
+    V::Err: Display,
+{
+    /// Erzeuge ein wiederholbares Wert-Argument für Schlüssel-Wert-Paare,
+    /// deren Schlüssel und Wert über [FromStr] geparst werden.
+    ///
+    /// ## English synonym
+    /// [value_typed_pairs_with_language](Arguments::value_typed_pairs_with_language)
+    #[inline(always)]
+    pub fn wert_typisierte_paare_mit_sprache(
+        beschreibung: Beschreibung<'t, Vec<(K, V)>>,
+        trenner: &'t str,
+        sprache: Sprache,
+    ) -> Argumente<'t, Vec<(K, V)>, String> {
+        Argumente::wert_typisierte_paare(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            trenner,
+            sprache.fehlender_trenner,
+            sprache.ungültiger_schlüssel,
+            sprache.ungültiger_wert,
+        )
+    }
+
+    /// Create a repeatable value-argument for key-value pairs,
+    /// whose key and value are parsed using [FromStr].
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_typisierte_paare_mit_sprache](Argumente::wert_typisierte_paare_mit_sprache)
+    #[inline(always)]
+    pub fn value_typed_pairs_with_language(
+        description: Description<'t, Vec<(K, V)>>,
+        separator: &'t str,
+        language: Language,
+    ) -> Arguments<'t, Vec<(K, V)>, String> {
+        Argumente::wert_typisierte_paare_mit_sprache(description, separator, language)
+    }
+
+    /// Erzeuge ein wiederholbares Wert-Argument für Schlüssel-Wert-Paare,
+    /// deren Schlüssel und Wert über [FromStr] geparst werden (z.B. `-D anzahl=5`).
+    ///
+    /// Die Aufteilung erfolgt wie bei [wert_paare](Argumente::wert_paare).
+    /// Kann der Schlüssel nicht geparst werden, wird ein Fehler mit Beschreibung
+    /// `ungültiger_schlüssel` erzeugt, für den Wert mit Beschreibung `ungültiger_wert`.
+    ///
+    /// ## English synonym
+    /// [value_typed_pairs](Arguments::value_typed_pairs)
+    pub fn wert_typisierte_paare(
+        beschreibung: Beschreibung<'t, Vec<(K, V)>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        trenner: &'t str,
+        fehlender_trenner: &'t str,
+        ungültiger_schlüssel: &'t str,
+        ungültiger_wert: &'t str,
+    ) -> Argumente<'t, Vec<(K, V)>, String> {
+        Argumente::paare_allgemein(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            trenner,
+            fehlender_trenner,
+            |paare: &Vec<(K, V)>| {
+                paare
+                    .iter()
+                    .map(|(schlüssel, wert)| format!("{schlüssel}{trenner}{wert}"))
+                    .join(", ")
+            },
+            move |schlüssel, wert| {
+                let schlüssel_geparst = schlüssel.parse().map_err(|fehler: K::Err| {
+                    format!("{ungültiger_schlüssel} \"{schlüssel}\": {fehler}")
+                })?;
+                let wert_geparst = wert
+                    .parse()
+                    .map_err(|fehler: V::Err| format!("{ungültiger_wert} \"{wert}\": {fehler}"))?;
+                Ok((schlüssel_geparst, wert_geparst))
+            },
+        )
+    }
+
+    /// Create a repeatable value-argument for key-value pairs,
+    /// whose key and value are parsed using [FromStr] (e.g. `-D count=5`).
+    ///
+    /// Splitting works like for [value_pairs](Arguments::value_pairs).
+    /// If the key can't be parsed, an error with description `invalid_key` is created,
+    /// for the value with description `invalid_value`.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_typisierte_paare](Argumente::wert_typisierte_paare)
+    #[inline(always)]
+    pub fn value_typed_pairs(
+        description: Description<'t, Vec<(K, V)>>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        separator: &'t str,
+        missing_separator: &'t str,
+        invalid_key: &'t str,
+        invalid_value: &'t str,
+    ) -> Arguments<'t, Vec<(K, V)>, String> {
+        Argumente::wert_typisierte_paare(
+            description,
+            value_infix,
+            meta_var,
+            separator,
+            missing_separator,
+            invalid_key,
+            invalid_value,
+        )
+    }
+}
+
+impl<'t, K: 't + Clone, V: 't + Clone> Argumente<'t, Vec<(K, V)>, String> {
+    /// Gemeinsame Implementierung für Schlüssel-Wert-Paare.
+    /// Jedes Vorkommen wird am ersten `trenner` aufgeteilt und mit `parse_paar` geparst.
+    fn paare_allgemein(
+        beschreibung: Beschreibung<'t, Vec<(K, V)>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        trenner: &'t str,
+        fehlender_trenner: &'t str,
+        anzeige: impl Fn(&Vec<(K, V)>) -> String,
+        parse_paar: impl 't + Fn(&str, &str) -> Result<(K, V), String>,
+    ) -> Argumente<'t, Vec<(K, V)>, String> {
+        Argumente::wert_sammeln(
+            beschreibung,
+            wert_infix.into(),
+            meta_var,
+            anzeige,
+            move |wert_namen, args, kandidaten| {
+                // Mit `Mehrfach::Anhängen` wird die Beschreibung für mehrfach genannte
+                // Argumente nicht verwendet.
+                wert_namen.suche_werte(args, kandidaten, Mehrfach::Anhängen, "", |wert_os_str| {
+                    let string = match wert_os_str.to_str() {
+                        Some(string) => string,
+                        None => return Err(ParseFehler::InvaliderString(wert_os_str.into_owned())),
+                    };
+                    match string.split_once(trenner) {
+                        Some((schlüssel, wert)) => {
+                            parse_paar(schlüssel, wert).map_err(ParseFehler::ParseFehler)
+                        },
+                        None => Err(ParseFehler::ParseFehler(format!(
+                            "{fehlender_trenner} \"{trenner}\": {string}"
+                        ))),
+                    }
+                })
+            },
+        )
+    }
+}
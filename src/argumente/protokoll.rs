@@ -0,0 +1,84 @@
+//! Parsen mit Protokollierung der verwendeten Argumente über [tracing].
+
+use std::ffi::OsString;
+
+use crate::{
+    argumente::{Argumente, ParseZustand},
+    ergebnis::{Ergebnis, Result},
+};
+
+/// Das `target` aller beim Parsen erzeugten [tracing]-Events.
+///
+/// ## English
+/// The `target` of all [tracing]-events created while parsing.
+pub const PROTOKOLL_TARGET: &str = "kommandozeilen_argumente";
+
+/// The `target` of all [tracing]-events created while parsing.
+///
+/// ## Deutsches Synonym
+/// [PROTOKOLL_TARGET]
+pub const LOG_TARGET: &str = PROTOKOLL_TARGET;
+
+impl<'t, T, E> Argumente<'t, T, E> {
+    /// Parse die übergebenen Kommandozeilen-Argumente wie [parse](Argumente::parse)
+    /// und protokolliere das Ergebnis über [tracing].
+    ///
+    /// Für jedes verwendete Argument wird ein `DEBUG`-Event mit den Feldern `index` und `argument`
+    /// erzeugt, wobei `index` sich auf die vorverarbeiteten Argumente bezieht
+    /// (z.B. nach dem Aufteilen verschmolzener Kurzformen).
+    /// Anschließend folgt ein `DEBUG`-Event mit den Feldern `ergebnis` und `nicht_verwendet`.
+    /// Alle Events verwenden [PROTOKOLL_TARGET] als `target`.
+    ///
+    /// ## English synonym
+    /// [parse_and_log](crate::Arguments::parse_and_log)
+    pub fn parse_und_protokolliere(
+        &self,
+        args: impl Iterator<Item = OsString>,
+    ) -> (Ergebnis<'t, T, E>, Vec<OsString>) {
+        let mut verwendet = Vec::new();
+        let (ergebnis, nicht_verwendet) = self.parse_mit_eingabeaufforderung(
+            args,
+            Some(&mut verwendet),
+            &mut ParseZustand::default(),
+        );
+        for (index, arg) in &verwendet {
+            tracing::debug!(
+                target: PROTOKOLL_TARGET,
+                index,
+                argument = %arg.to_string_lossy(),
+                "Argument verwendet"
+            );
+        }
+        let ergebnis_str = match &ergebnis {
+            Ergebnis::Wert(_wert) => "Wert",
+            Ergebnis::FrühesBeenden(_nachrichten) => "FrühesBeenden",
+            Ergebnis::Fehler(_fehler) => "Fehler",
+        };
+        tracing::debug!(
+            target: PROTOKOLL_TARGET,
+            ergebnis = ergebnis_str,
+            nicht_verwendet = ?nicht_verwendet,
+            "Parsen beendet"
+        );
+        (ergebnis, nicht_verwendet)
+    }
+
+    /// Parse the given command line arguments like [parse](crate::Arguments::parse)
+    /// and log the result using [tracing].
+    ///
+    /// For each used argument, a `DEBUG`-event with the fields `index` and `argument` is created,
+    /// where `index` refers to the preprocessed arguments
+    /// (e.g. after splitting merged short forms).
+    /// Afterwards, a `DEBUG`-event with the fields `ergebnis` and `nicht_verwendet` follows.
+    /// All events use [LOG_TARGET] as `target`.
+    ///
+    /// ## Deutsches Synonym
+    /// [parse_und_protokolliere](Argumente::parse_und_protokolliere)
+    #[inline(always)]
+    pub fn parse_and_log(
+        &self,
+        args: impl Iterator<Item = OsString>,
+    ) -> (Result<'t, T, E>, Vec<OsString>) {
+        self.parse_und_protokolliere(args)
+    }
+}
@@ -0,0 +1,153 @@
+//! Probelauf, der das Ergebnis des Parsens ohne Ausgabe und ohne Beenden des Programms beschreibt.
+
+use std::{ffi::OsString, fmt::Display};
+
+use crate::{
+    argumente::{Argumente, Aufzeichnung, ParseZustand},
+    ergebnis::Ergebnis,
+    sprache::{Language, Sprache},
+};
+
+/// Bericht eines [Probelaufs](Argumente::prüfe).
+///
+/// ## English synonym
+/// [CheckReport]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Prüfbericht<T> {
+    /// Der erzeugte Wert, sofern das Parsen erfolgreich war.
+    ///
+    /// ## English
+    /// The created value, if parsing was successful.
+    pub wert: Option<T>,
+
+    /// Alle (vorverarbeiteten) Argumente, die von einem Argument verwendet wurden.
+    ///
+    /// ## English
+    /// All (preprocessed) arguments used by an argument.
+    pub verwendet: Vec<OsString>,
+
+    /// Nachrichten eines frühen Beendens (z.B. `--version`), das nicht ausgeführt wurde.
+    ///
+    /// ## English
+    /// Messages of an early exit (e.g. `--version`), which was not executed.
+    pub frühes_beenden: Vec<String>,
+
+    /// Beschreibungen der nicht ausgeführten Aktionen
+    /// (z.B. von [wert_mit_aktion](Argumente::wert_mit_aktion)), jeweils Name und Wert.
+    ///
+    /// ## English
+    /// Descriptions of not executed actions
+    /// (e.g. from [value_with_action](crate::Arguments::value_with_action)), each name and value.
+    pub aktionen: Vec<String>,
+
+    /// Fehlermeldungen aller aufgetretenen Fehler.
+    ///
+    /// ## English
+    /// Error messages of all occurred errors.
+    pub fehler: Vec<String>,
+
+    /// Alle nicht verwendeten Argumente.
+    ///
+    /// ## English
+    /// All unused arguments.
+    pub nicht_verwendet: Vec<OsString>,
+}
+
+/// Report of a [dry run](crate::Arguments::check).
+///
+/// ## Deutsches Synonym
+/// [Prüfbericht]
+pub type CheckReport<T> = Prüfbericht<T>;
+
+impl<T> Prüfbericht<T> {
+    /// Wäre das Parsen ohne frühes Beenden, Fehler und nicht verwendete Argumente erfolgreich?
+    ///
+    /// ## English synonym
+    /// [is_valid](CheckReport::is_valid)
+    pub fn ist_gültig(&self) -> bool {
+        self.wert.is_some() && self.nicht_verwendet.is_empty()
+    }
+
+    /// Would parsing succeed without early exit, errors and unused arguments?
+    ///
+    /// ## Deutsches Synonym
+    /// [ist_gültig](Prüfbericht::ist_gültig)
+    #[inline(always)]
+    pub fn is_valid(&self) -> bool {
+        self.ist_gültig()
+    }
+}
+
+impl<T, E: Display> Argumente<'_, T, E> {
+    /// Parse die übergebenen Kommandozeilen-Argumente als Probelauf, z.B. für ein `--check`.
+    ///
+    /// Im Gegensatz zu [parse_vollständig](Argumente::parse_vollständig) wird nichts ausgegeben
+    /// und das Programm nicht beendet, stattdessen werden verwendete Argumente,
+    /// Nachrichten eines frühen Beendens und Fehlermeldungen in einem [Prüfbericht] gesammelt.
+    /// Aktionen (z.B. von [wert_mit_aktion](Argumente::wert_mit_aktion)) werden nicht ausgeführt,
+    /// sondern ebenfalls im [Prüfbericht] festgehalten.
+    /// Da ihr Ergebnis unbekannt ist, wird in diesem Fall kein Wert erzeugt.
+    /// Fehler werden auch bei einem frühen Beenden gemeldet.
+    ///
+    /// ## English synonym
+    /// [check](crate::Arguments::check)
+    pub fn prüfe(
+        &self,
+        args: impl Iterator<Item = OsString>,
+        sprache: Sprache,
+    ) -> Prüfbericht<T> {
+        let mut zustand = ParseZustand { probelauf: Some(Vec::new()), ..ParseZustand::default() };
+        let mut verwendet = Vec::new();
+        let (ergebnis, nicht_verwendet) =
+            self.parse_mit_verwendeten(args, Some(&mut verwendet), None, &mut zustand);
+        let mut bericht = Prüfbericht {
+            wert: None,
+            verwendet: verwendet.into_iter().map(|(_index, arg)| arg).collect(),
+            frühes_beenden: Vec::new(),
+            aktionen: Vec::new(),
+            fehler: Vec::new(),
+            nicht_verwendet,
+        };
+        for aufzeichnung in zustand.probelauf.into_iter().flatten() {
+            match aufzeichnung {
+                Aufzeichnung::Aktion(aktion) => bericht.aktionen.push(aktion),
+                Aufzeichnung::FrühesBeenden(nachricht) => {
+                    bericht.frühes_beenden.push(nachricht.into_owned())
+                },
+            }
+        }
+        match ergebnis {
+            Ergebnis::Wert(wert) => bericht.wert = Some(wert),
+            // Nachrichten und nicht ausgeführte Aktionen sind bereits aufgezeichnet.
+            Ergebnis::FrühesBeenden(_nachrichten) => {},
+            Ergebnis::Fehler(fehler) => {
+                bericht.fehler = fehler
+                    .iter()
+                    .map(|fehler| fehler.erstelle_fehlermeldung_mit_sprache(sprache))
+                    .collect()
+            },
+        }
+        bericht
+    }
+
+    /// Parse the given command line arguments as a dry run, e.g. for a `--check`.
+    ///
+    /// In contrast to [parse_complete](crate::Arguments::parse_complete), nothing is printed
+    /// and the program doesn't stop. Instead, used arguments,
+    /// messages of an early exit and error messages are collected in a [CheckReport].
+    /// Actions (e.g. from [value_with_action](crate::Arguments::value_with_action)) are not executed,
+    /// but recorded in the [CheckReport] as well.
+    /// Since their result is unknown, no value is created in this case.
+    /// Errors are reported even with an early exit.
+    ///
+    /// ## Deutsches Synonym
+    /// [prüfe](Argumente::prüfe)
+    #[inline(always)]
+    pub fn check(
+        &self,
+        args: impl Iterator<Item = OsString>,
+        language: Language,
+    ) -> CheckReport<T> {
+        self.prüfe(args, language)
+    }
+}
@@ -0,0 +1,51 @@
+//! Erfassen aller Argumente nach einem Trenner.
+
+use std::{collections::HashMap, ffi::OsString};
+
+use crate::{
+    argumente::{Argumente, Arguments, ParseEinstellungen},
+    ergebnis::Ergebnis,
+    unicode::{Compare, Vergleich},
+};
+
+impl<'t, E: 't> Argumente<'t, Vec<OsString>, E> {
+    /// Erzeuge ein Argument, das alle Argumente nach dem `trenner` (üblicherweise `--`)
+    /// unverändert erfasst, z.B. `["ls", "-la"]` für `run -- ls -la`.
+    ///
+    /// Der `trenner` wird wie bei [mit_trenner](Argumente::mit_trenner) verwendet,
+    /// die folgenden Argumente werden jedoch nicht als nicht verwendete Argumente zurückgegeben.
+    /// Wurde kein `trenner` angegeben, ist das Ergebnis ein leerer [Vec].
+    /// Das Argument erscheint nicht im Hilfe-Text.
+    ///
+    /// ## English synonym
+    /// [rest_after_separator](Arguments::rest_after_separator)
+    pub fn rest_nach_trenner(trenner: impl Into<Vergleich<'t>>) -> Argumente<'t, Vec<OsString>, E> {
+        Argumente {
+            konfigurationen: Vec::new(),
+            flag_kurzformen: HashMap::new(),
+            einstellungen: ParseEinstellungen {
+                trenner: Some(trenner.into()),
+                rest: true,
+                ..ParseEinstellungen::default()
+            },
+            parse: Box::new(|args, kontext| (Ergebnis::Wert(kontext.nach_trenner.to_vec()), args)),
+        }
+    }
+
+    /// Create an argument capturing all arguments after the `separator` (usually `--`) verbatim,
+    /// e.g. `["ls", "-la"]` for `run -- ls -la`.
+    ///
+    /// The `separator` is used as for [with_separator](Arguments::with_separator),
+    /// but the following arguments are not returned as unused arguments.
+    /// If no `separator` is given, the result is an empty [Vec].
+    /// The argument doesn't appear in the help text.
+    ///
+    /// ## Deutsches Synonym
+    /// [rest_nach_trenner](Argumente::rest_nach_trenner)
+    #[inline(always)]
+    pub fn rest_after_separator(
+        separator: impl Into<Compare<'t>>,
+    ) -> Arguments<'t, Vec<OsString>, E> {
+        Argumente::rest_nach_trenner(separator)
+    }
+}
@@ -0,0 +1,111 @@
+//! Ersetzen von Umgebungsvariablen (`@env:NAME`) in Werten.
+
+use std::{env, ffi::OsString};
+
+use crate::{
+    ergebnis::{ParseError, ParseFehler},
+    sprache::{Language, Sprache},
+};
+
+/// Präfix einer Referenz auf eine Umgebungsvariable innerhalb eines Wertes.
+const UMGEBUNG_PRÄFIX: &str = "@env:";
+
+/// Ersetze alle `@env:NAME`-Referenzen im Wert durch den Wert der Umgebungsvariable `NAME`.
+///
+/// Ein Name besteht aus ASCII-Buchstaben, Ziffern und `_`.
+/// Im Fehlerfall wird der Name der nicht gesetzten Umgebungsvariable zurückgegeben.
+fn ersetze_umgebungsvariablen(wert: OsString) -> Result<OsString, String> {
+    let string = match wert.to_str() {
+        Some(string) if string.contains(UMGEBUNG_PRÄFIX) => string,
+        _ => return Ok(wert),
+    };
+    let mut ergebnis = OsString::new();
+    let mut rest = string;
+    while let Some(index) = rest.find(UMGEBUNG_PRÄFIX) {
+        ergebnis.push(&rest[..index]);
+        let nach_präfix = &rest[index + UMGEBUNG_PRÄFIX.len()..];
+        let länge = nach_präfix
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(nach_präfix.len());
+        let (name, nach_name) = nach_präfix.split_at(länge);
+        if name.is_empty() {
+            ergebnis.push(UMGEBUNG_PRÄFIX);
+        } else if let Some(variable) = env::var_os(name) {
+            ergebnis.push(variable);
+        } else {
+            return Err(name.to_owned());
+        }
+        rest = nach_name;
+    }
+    ergebnis.push(rest);
+    Ok(ergebnis)
+}
+
+/// Erweitere eine `parse`-Funktion für Wert-Argumente,
+/// sodass `@env:NAME`-Referenzen vorher durch den Wert der Umgebungsvariable `NAME` ersetzt werden.
+///
+/// ## English synonym
+/// [expand_env_vars_with_language]
+#[inline(always)]
+pub fn erweitere_umgebungsvariablen_mit_sprache<T>(
+    parse: impl Fn(OsString) -> Result<T, ParseFehler<String>>,
+    sprache: Sprache,
+) -> impl Fn(OsString) -> Result<T, ParseFehler<String>> {
+    erweitere_umgebungsvariablen(parse, sprache.unbekannte_umgebungsvariable)
+}
+
+/// Extend a `parse`-function for value-arguments,
+/// so `@env:NAME` references are replaced by the value of the environment variable `NAME` first.
+///
+/// ## Deutsches Synonym
+/// [erweitere_umgebungsvariablen_mit_sprache]
+#[inline(always)]
+pub fn expand_env_vars_with_language<T>(
+    parse: impl Fn(OsString) -> Result<T, ParseError<String>>,
+    language: Language,
+) -> impl Fn(OsString) -> Result<T, ParseError<String>> {
+    erweitere_umgebungsvariablen_mit_sprache(parse, language)
+}
+
+/// Erweitere eine `parse`-Funktion für Wert-Argumente,
+/// sodass `@env:NAME`-Referenzen vorher durch den Wert der Umgebungsvariable `NAME` ersetzt werden.
+///
+/// Ein Name besteht aus ASCII-Buchstaben, Ziffern und `_`,
+/// z.B. wird `--url @env:BASE_URL/pfad` mit `BASE_URL=https://example.org`
+/// als `https://example.org/pfad` an `parse` übergeben.
+/// Ist eine referenzierte Umgebungsvariable nicht gesetzt, wird `parse` nicht aufgerufen,
+/// sondern ein Fehler mit der Beschreibung `unbekannte_umgebungsvariable` erzeugt.
+///
+/// ## English synonym
+/// [expand_env_vars]
+pub fn erweitere_umgebungsvariablen<T>(
+    parse: impl Fn(OsString) -> Result<T, ParseFehler<String>>,
+    unbekannte_umgebungsvariable: &str,
+) -> impl Fn(OsString) -> Result<T, ParseFehler<String>> {
+    let unbekannte_umgebungsvariable = unbekannte_umgebungsvariable.to_owned();
+    move |os_string| match ersetze_umgebungsvariablen(os_string) {
+        Ok(erweitert) => parse(erweitert),
+        Err(name) => {
+            Err(ParseFehler::ParseFehler(format!("{unbekannte_umgebungsvariable}: {name}")))
+        },
+    }
+}
+
+/// Extend a `parse`-function for value-arguments,
+/// so `@env:NAME` references are replaced by the value of the environment variable `NAME` first.
+///
+/// A name consists of ASCII letters, digits and `_`,
+/// e.g. `--url @env:BASE_URL/path` with `BASE_URL=https://example.org`
+/// is passed to `parse` as `https://example.org/path`.
+/// If a referenced environment variable is undefined, `parse` is not called;
+/// instead an error with the description `undefined_env_var` is created.
+///
+/// ## Deutsches Synonym
+/// [erweitere_umgebungsvariablen]
+#[inline(always)]
+pub fn expand_env_vars<T>(
+    parse: impl Fn(OsString) -> Result<T, ParseError<String>>,
+    undefined_env_var: &str,
+) -> impl Fn(OsString) -> Result<T, ParseError<String>> {
+    erweitere_umgebungsvariablen(parse, undefined_env_var)
+}
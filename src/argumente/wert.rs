@@ -1,16 +1,34 @@
 //! Wert-Argumente.
 
-use std::{collections::HashMap, ffi::OsString, fmt::Display, str::FromStr};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    ffi::{OsStr, OsString},
+    fmt::Display,
+    iter,
+    path::PathBuf,
+    str::FromStr,
+};
 
+use either::Either;
+use itertools::Itertools;
 use nonempty::NonEmpty;
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    argumente::{Argumente, Arguments},
-    beschreibung::{contains_prefix, contains_str, Beschreibung, Description, Konfiguration},
+    argumente::{
+        ist_bekannter_lang_name,
+        liste::{Mehrfach, Multiple},
+        nachsichtig::nachsichtiger_standard,
+        namen_index::{IndexKandidaten, NamenEintrag, NamenIndexEintrag},
+        Argumente, Arguments, Aufzeichnung, ParseEinstellungen, ParseZustand,
+    },
+    beschreibung::{
+        contains_prefix, contains_str, Beschreibung, Description, Konfiguration, MöglicheWerte,
+    },
     ergebnis::{Ergebnis, Fehler, Namen, ParseError, ParseFehler},
     sprache::{Language, Sprache},
-    unicode::{Compare, Normalisiert, Vergleich},
+    unicode::{parse_wahrheitswert, Compare, Normalisiert, Vergleich},
 };
 
 #[cfg(any(feature = "derive", all(doc, not(doctest))))]
@@ -283,6 +301,13 @@ impl<'t, T: 't + Clone, E> Argumente<'t, T, E> {
 
     /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
     ///
+    /// Ein Wert nach dem `wert_infix` (`--name=--`) oder im folgenden Argument (`--name --`)
+    /// wird unverändert an `parse` übergeben, auch wenn er mit einem Präfix beginnt.
+    /// Ein leerer Wert nach dem `wert_infix` (`--name=`) wird ebenfalls an `parse` übergeben,
+    /// fehlt dagegen ein Wert (`--name` als letztes Argument), wird [Fehler::FehlenderWert] erzeugt.
+    /// Wird [mit_trenner](Argumente::mit_trenner) mit `--` verwendet, werden die Argumente
+    /// dagegen bereits vorher abgeschnitten, so dass `--name --` [Fehler::FehlenderWert] erzeugt.
+    ///
     /// ## English synonym
     /// [value](Arguments::value)
     pub fn wert(
@@ -293,128 +318,25 @@ impl<'t, T: 't + Clone, E> Argumente<'t, T, E> {
         parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
         anzeige: impl Fn(&T) -> String,
     ) -> Argumente<'t, T, E> {
-        let name_lang_präfix = beschreibung.lang_präfix.clone();
-        let name_lang = beschreibung.lang.clone();
-        let name_kurz_präfix = beschreibung.kurz_präfix.clone();
-        let name_kurz = beschreibung.kurz.clone();
-        let (beschreibung, standard) = beschreibung.als_string_beschreibung_allgemein(&anzeige);
-        let wert_infix_vergleich = wert_infix.into();
-        Argumente {
-            konfigurationen: vec![Konfiguration::Wert {
-                beschreibung,
-                wert_infix: wert_infix_vergleich.clone(),
-                meta_var,
-                mögliche_werte: mögliche_werte
-                    .and_then(|werte| NonEmpty::from_vec(werte.iter().map(anzeige).collect())),
-            }],
-            flag_kurzformen: HashMap::new(),
-            parse: Box::new(move |args| {
-                let fehler_namen = || Namen {
-                    lang_präfix: name_lang_präfix.string.clone(),
-                    lang: name_lang.clone().map(|Vergleich { string, case: _ }| string),
-                    kurz_präfix: name_kurz_präfix.string.clone(),
-                    kurz: name_kurz
-                        .iter()
-                        .map(|Vergleich { string, case: _ }| string.clone())
-                        .collect(),
-                };
-                let fehler_kein_wert = || Fehler::FehlenderWert {
-                    namen: fehler_namen(),
-                    wert_infix: wert_infix_vergleich.string.clone(),
-                    meta_var,
-                };
-                let name_kurz_existiert = !name_kurz.is_empty();
-                let mut ergebnis = None;
-                let mut fehler = Vec::new();
-                let mut name_ohne_wert = false;
-                let mut nicht_verwendet = Vec::new();
-                let mut parse_auswerten = |arg: Option<OsString>| {
-                    if let Some(wert_os_str) = arg {
-                        match parse(wert_os_str) {
-                            Ok(wert) => ergebnis = Some(wert),
-                            Err(parse_fehler) => fehler.push(Fehler::Fehler {
-                                namen: fehler_namen(),
-                                wert_infix: wert_infix_vergleich.string.clone(),
-                                meta_var,
-                                fehler: parse_fehler,
-                            }),
-                        }
-                    } else {
-                        fehler.push(fehler_kein_wert())
-                    }
-                };
-                'args: for arg in args {
-                    if name_ohne_wert {
-                        parse_auswerten(arg);
-                        name_ohne_wert = false;
-                        nicht_verwendet.push(None);
-                        continue;
-                    } else if let Some(string) =
-                        arg.as_ref().and_then(|os_string| os_string.to_str())
-                    {
-                        let normalisiert = Normalisiert::neu(string);
-                        if let Some(lang) = name_lang_präfix.strip_als_präfix(&normalisiert) {
-                            let lang_normalisiert = Normalisiert::neu_borrowed_unchecked(lang);
-                            let suffixe = contains_prefix(&name_lang, &lang_normalisiert);
-                            for suffix in suffixe {
-                                let suffix_normalisiert =
-                                    Normalisiert::neu_borrowed_unchecked(suffix);
-                                if suffix.is_empty() {
-                                    name_ohne_wert = true;
-                                    nicht_verwendet.push(None);
-                                    continue 'args;
-                                } else if let Some(wert_graphemes) =
-                                    wert_infix_vergleich.strip_als_präfix(&suffix_normalisiert)
-                                {
-                                    parse_auswerten(Some(wert_graphemes.to_owned().into()));
-                                    nicht_verwendet.push(None);
-                                    continue 'args;
-                                }
-                            }
-                        } else if name_kurz_existiert {
-                            if let Some(kurz) = name_kurz_präfix.strip_als_präfix(&normalisiert) {
-                                let mut kurz_graphemes = kurz.graphemes(true);
-                                if kurz_graphemes
-                                    .next()
-                                    .map(|name| contains_str(&name_kurz, name))
-                                    .unwrap_or(false)
-                                {
-                                    let rest = kurz_graphemes.as_str();
-                                    let kurz_normalisiert =
-                                        Normalisiert::neu_borrowed_unchecked(rest);
-                                    let wert_str = if rest.is_empty() {
-                                        name_ohne_wert = true;
-                                        nicht_verwendet.push(None);
-                                        continue 'args;
-                                    } else {
-                                        wert_infix_vergleich
-                                            .strip_als_präfix(&kurz_normalisiert)
-                                            .unwrap_or(rest)
-                                    };
-                                    parse_auswerten(Some(wert_str.to_owned().into()));
-                                    nicht_verwendet.push(None);
-                                    continue 'args;
-                                }
-                            }
-                        }
-                    }
-                    nicht_verwendet.push(arg);
-                }
-                if let Some(fehler) = NonEmpty::from_vec(fehler) {
-                    (Ergebnis::Fehler(fehler), nicht_verwendet)
-                } else if let Some(wert) = ergebnis {
-                    (Ergebnis::Wert(wert), nicht_verwendet)
-                } else if let Some(wert) = &standard {
-                    (Ergebnis::Wert(wert.clone()), nicht_verwendet)
-                } else {
-                    (Ergebnis::Fehler(NonEmpty::singleton(fehler_kein_wert())), nicht_verwendet)
-                }
-            }),
-        }
+        Argumente::wert_os_str(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            mögliche_werte,
+            move |wert_os_str| parse(wert_os_str.into_owned()),
+            anzeige,
+        )
     }
 
     /// Create a Value-Argument, based on the given `parse`-function.
     ///
+    /// A value after the `value_infix` (`--name=--`) or in the following argument (`--name --`)
+    /// is passed to `parse` unchanged, even if it starts with a prefix.
+    /// An empty value after the `value_infix` (`--name=`) is passed to `parse` as well,
+    /// whereas a missing value (`--name` as the last argument) creates [Fehler::FehlenderWert].
+    /// However, if [with_separator](Arguments::with_separator) is used with `--`, the arguments
+    /// are cut off beforehand, so `--name --` creates [Fehler::FehlenderWert].
+    ///
     /// ## Deutsches Synonym
     /// [wert](Argumente::wert)
     #[inline(always)]
@@ -428,133 +350,2333 @@ impl<'t, T: 't + Clone, E> Argumente<'t, T, E> {
     ) -> Arguments<'t, T, E> {
         Argumente::wert(description, value_infix, meta_var, possible_values, parse, display)
     }
-}
 
-/// Trait für Typen mit einer festen Anzahl an Werten und Methode zum Parsen.
-/// Gedacht für Summentypen ohne extra Daten (nur Unit-Varianten).
-///
-/// Mit aktiviertem `derive`-Feature kann die Implementierung
-/// [automatisch erzeugt werden](derive@EnumArgument).
-///
-/// ## English
-/// Trait for types with a fixed number of values and a parse method.
-/// Intended for sum-types without extra data (only unit variants).
-///
-/// With activated `derive`-feature, the implementation can be
-/// [created automatically](derive@EnumArgument).
-pub trait EnumArgument: Sized {
-    /// Alle Varianten des Typs.
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
     ///
     /// ## English synonym
-    /// [variants](EnumArgument::variants)
-    fn varianten() -> Vec<Self>;
+    /// [value_os_str_with_language](Arguments::value_os_str_with_language)
+    #[inline(always)]
+    pub fn wert_os_str_mit_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        mögliche_werte: Option<NonEmpty<T>>,
+        parse: impl 't + Fn(Cow<'_, OsStr>) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_os_str(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            mögliche_werte,
+            parse,
+            anzeige,
+        )
+    }
 
-    /// All variants of the type.
+    /// Create a Value-Argument, based on the given `parse`-function.
     ///
     /// ## Deutsches Synonym
-    /// [varianten](EnumArgument::varianten)
+    /// [wert_os_str_mit_sprache](Argumente::wert_os_str_mit_sprache)
     #[inline(always)]
-    fn variants() -> Vec<Self> {
-        Self::varianten()
+    pub fn value_os_str_with_language(
+        description: Description<'t, T>,
+        possible_values: Option<NonEmpty<T>>,
+        parse: impl 't + Fn(Cow<'_, OsStr>) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_os_str_mit_sprache(description, possible_values, parse, display, language)
     }
 
-    /// Versuche einen Wert ausgehend vom übergebenen [OsString] zu erzeugen.
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
     ///
-    /// ## English
-    /// Try to parse a value from the given [OsString].
-    fn parse_enum(arg: OsString) -> Result<Self, ParseFehler<String>>;
-}
+    /// Im Gegensatz zu [wert](Argumente::wert) erhält `parse` den Wert als [Cow],
+    /// wodurch nur bei Bedarf eine Allokation notwendig ist.
+    /// Ein Wert im folgenden Argument (`--name wert`) wird als [Cow::Owned] übergeben.
+    /// Ein Wert nach dem `wert_infix` (`--name=wert`) oder direkt nach einem Kurznamen (`-nwert`)
+    /// wird als [Cow::Borrowed] übergeben und ist nur während des `parse`-Aufrufs gültig,
+    /// da er aus dem (normalisierten) Kommandozeilen-Argument ausgeliehen ist.
+    /// Wird der Wert länger benötigt, muss er mit [Cow::into_owned] kopiert werden.
+    ///
+    /// ## English synonym
+    /// [value_os_str](Arguments::value_os_str)
+    pub fn wert_os_str(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        mögliche_werte: Option<NonEmpty<T>>,
+        parse: impl 't + Fn(Cow<'_, OsStr>) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_os_str_mit_standard_fn(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            mögliche_werte.iter().flat_map(NonEmpty::iter),
+            Mehrfach::Ersetze,
+            "",
+            || Ok(None),
+            move |wert_os_str, _zustand| parse(wert_os_str),
+            anzeige,
+        )
+    }
 
-impl<'t, T: 't + Display + Clone + EnumArgument> Argumente<'t, T, String> {
-    /// Erzeuge ein Wert-Argument für ein [EnumArgument].
+    /// Create a Value-Argument, based on the given `parse`-function.
+    ///
+    /// In contrast to [value](Arguments::value), `parse` receives the value as a [Cow],
+    /// so an allocation is only necessary if required.
+    /// A value in the following argument (`--name value`) is passed as [Cow::Owned].
+    /// A value after the `value_infix` (`--name=value`) or directly after a short name
+    /// (`-nvalue`) is passed as [Cow::Borrowed] and only valid during the `parse`-call,
+    /// since it is borrowed from the (normalized) command line argument.
+    /// If the value is required for longer, it has to be copied using [Cow::into_owned].
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_os_str](Argumente::wert_os_str)
+    #[inline(always)]
+    pub fn value_os_str(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        possible_values: Option<NonEmpty<T>>,
+        parse: impl 't + Fn(Cow<'_, OsStr>) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_os_str(description, value_infix, meta_var, possible_values, parse, display)
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    ///
+    /// Im Gegensatz zu [wert_mit_sprache](Argumente::wert_mit_sprache) werden die
+    /// `mögliche_werte` nur ausgeliehen, wodurch z.B. die Varianten eines großen Enums
+    /// nicht in eine [NonEmpty] kopiert werden müssen.
     ///
     /// ## English synonym
-    /// [value_enum_display_with_language](Arguments::value_enum_display_with_language)
+    /// [value_with_borrowed_values_and_language](Arguments::value_with_borrowed_values_and_language)
     #[inline(always)]
-    pub fn wert_enum_display_mit_sprache(
+    pub fn wert_mit_geliehenen_werten_und_sprache(
         beschreibung: Beschreibung<'t, T>,
+        mögliche_werte: &[T],
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
         sprache: Sprache,
-    ) -> Argumente<'t, T, String> {
-        Argumente::wert_enum_display(beschreibung, sprache.wert_infix, sprache.meta_var)
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_mit_geliehenen_werten(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            mögliche_werte,
+            parse,
+            anzeige,
+        )
     }
 
-    /// Create a value-argument for an [EnumArgument].
+    /// Create a value-argument, based on the given `parse`-function.
+    ///
+    /// In contrast to [value_with_language](Arguments::value_with_language),
+    /// `possible_values` are only borrowed, so e.g. the variants of a big enum
+    /// don't have to be copied into a [NonEmpty].
     ///
     /// ## Deutsches Synonym
-    /// [wert_enum_display_mit_sprache](Argumente::wert_enum_display_mit_sprache)
+    /// [wert_mit_geliehenen_werten_und_sprache](Argumente::wert_mit_geliehenen_werten_und_sprache)
     #[inline(always)]
-    pub fn value_enum_display_with_language(
+    pub fn value_with_borrowed_values_and_language(
         description: Description<'t, T>,
+        possible_values: &[T],
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
         language: Language,
-    ) -> Arguments<'t, T, String> {
-        Argumente::wert_enum_display_mit_sprache(description, language)
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_geliehenen_werten_und_sprache(
+            description,
+            possible_values,
+            parse,
+            display,
+            language,
+        )
     }
 
-    /// Erzeuge ein Wert-Argument für ein [EnumArgument].
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    ///
+    /// Im Gegensatz zu [wert](Argumente::wert) werden die `mögliche_werte` nur ausgeliehen,
+    /// um ihre String-Darstellung für den Hilfe-Text zu erzeugen.
+    /// Ein leerer Slice entspricht `None` bei [wert](Argumente::wert).
+    /// Wie bei [wert](Argumente::wert) muss eine Überprüfung gegen die möglichen Werte
+    /// in der `parse`-Funktion erfolgen.
     ///
     /// ## English synonym
-    /// [value_enum_display](Arguments::value_enum_display)
-    #[inline(always)]
-    pub fn wert_enum_display(
+    /// [value_with_borrowed_values](Arguments::value_with_borrowed_values)
+    pub fn wert_mit_geliehenen_werten(
         beschreibung: Beschreibung<'t, T>,
         wert_infix: impl Into<Vergleich<'t>>,
         meta_var: &'t str,
-    ) -> Argumente<'t, T, String> {
-        Argumente::wert_enum(beschreibung, wert_infix, meta_var, T::to_string)
+        mögliche_werte: &[T],
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_os_str_mit_standard_fn(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            mögliche_werte,
+            Mehrfach::Ersetze,
+            "",
+            || Ok(None),
+            move |wert_os_str, _zustand| parse(wert_os_str.into_owned()),
+            anzeige,
+        )
     }
 
-    /// Create a value-argument for an [EnumArgument].
+    /// Create a value-argument, based on the given `parse`-function.
+    ///
+    /// In contrast to [value](Arguments::value), `possible_values` are only borrowed
+    /// to create their string representation for the help text.
+    /// An empty slice is equivalent to `None` for [value](Arguments::value).
+    /// Like with [value](Arguments::value), checking against the possible values
+    /// has to be done in the `parse`-function.
     ///
     /// ## Deutsches Synonym
-    /// [wert_enum_display](Argumente::wert_enum_display)
+    /// [wert_mit_geliehenen_werten](Argumente::wert_mit_geliehenen_werten)
     #[inline(always)]
-    pub fn value_enum_display(
+    pub fn value_with_borrowed_values(
         description: Description<'t, T>,
         value_infix: impl Into<Compare<'t>>,
         meta_var: &'t str,
-    ) -> Arguments<'t, T, String> {
-        Argumente::wert_enum_display(description, value_infix, meta_var)
+        possible_values: &[T],
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_geliehenen_werten(
+            description,
+            value_infix,
+            meta_var,
+            possible_values,
+            parse,
+            display,
+        )
     }
-}
 
-impl<'t, T: 't + Display + Clone + EnumArgument> Argumente<'t, T, String> {
-    /// Erzeuge ein Wert-Argument für ein [EnumArgument].
+    /// Gemeinsame Implementierung für Wert-Argumente.
+    /// Mehrfach genannte Werte werden abhängig von `mehrfach` behandelt
+    /// (siehe [suche_werte](WertNamen::suche_werte)).
+    /// Ist kein Standard-Wert in der [Beschreibung] gesetzt, wird `standard_fn` aufgerufen,
+    /// sofern das Argument nicht genannt wurde.
+    /// Die `mögliche_werte` werden nur ausgeliehen, um ihre String-Darstellung zu erzeugen.
+    /// `parse` erhält zusätzlich den [ParseZustand], z.B. um einen Probelauf zu erkennen.
+    #[allow(clippy::too_many_arguments)]
+    fn wert_os_str_mit_standard_fn<'w>(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        mögliche_werte: impl IntoIterator<Item = &'w T>,
+        mehrfach: Mehrfach,
+        mehrfach_genannt: &'t str,
+        standard_fn: impl 't + Fn() -> Result<Option<T>, E>,
+        parse: impl 't + Fn(Cow<'_, OsStr>, &mut ParseZustand<'t>) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, E>
+    where
+        T: 'w,
+    {
+        let wert_namen = WertNamen::neu(&beschreibung, wert_infix.into(), meta_var);
+        let (beschreibung, standard) = beschreibung.als_string_beschreibung_allgemein(&anzeige);
+        Argumente {
+            konfigurationen: vec![Konfiguration::Wert {
+                beschreibung,
+                wert_infix: wert_namen.wert_infix.clone(),
+                wert_infix_anzeige: None,
+                geheim: false,
+                meta_var,
+                mögliche_werte: NonEmpty::from_vec(
+                    mögliche_werte.into_iter().map(&anzeige).collect(),
+                )
+                .map(MöglicheWerte::Fest),
+                reihenfolge: 0,
+                versteckte_namen: Vec::new(),
+            }],
+            flag_kurzformen: HashMap::new(),
+            einstellungen: wert_namen.einstellungen(),
+            parse: Box::new(move |args, kontext| {
+                let (mut werte, fehler, nicht_verwendet) = wert_namen.suche_werte(
+                    args,
+                    kontext.kandidaten,
+                    mehrfach,
+                    mehrfach_genannt,
+                    |wert_os_str| parse(wert_os_str, kontext.zustand),
+                );
+                // Mit `Mehrfach::Anhängen` wird ebenfalls der letzte Wert verwendet.
+                let ergebnis = werte.pop();
+                if let Some(fehler) = NonEmpty::from_vec(fehler) {
+                    let ergebnis = nachsichtiger_standard(
+                        Ergebnis::Fehler(fehler),
+                        standard.as_ref(),
+                        kontext.nachsichtig.as_deref_mut(),
+                    );
+                    return (ergebnis, nicht_verwendet);
+                } else if let Some(wert) = ergebnis {
+                    return (Ergebnis::Wert(wert), nicht_verwendet);
+                }
+                // Der Standard-Wert wird nur bestimmt, wenn das Argument nicht genannt wurde.
+                let standard = match &standard {
+                    Some(wert) => Ok(Some(wert.clone())),
+                    None => standard_fn(),
+                };
+                match standard {
+                    Ok(Some(wert)) => (Ergebnis::Wert(wert), nicht_verwendet),
+                    Ok(None) => (
+                        Ergebnis::Fehler(NonEmpty::singleton(wert_namen.fehler_fehlend())),
+                        nicht_verwendet,
+                    ),
+                    Err(fehler) => (
+                        Ergebnis::Fehler(NonEmpty::singleton(
+                            wert_namen.fehler_parse(ParseFehler::ParseFehler(fehler)),
+                        )),
+                        nicht_verwendet,
+                    ),
+                }
+            }),
+        }
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Der Standard-Wert wird über `standard_fn` bestimmt.
     ///
     /// ## English synonym
-    /// [value_enum_with_language](Arguments::value_enum_with_language)
+    /// [value_with_default_fn_and_language](Arguments::value_with_default_fn_and_language)
     #[inline(always)]
-    pub fn wert_enum_mit_sprache(
+    pub fn wert_mit_standard_fn_und_sprache(
         beschreibung: Beschreibung<'t, T>,
+        mögliche_werte: Option<NonEmpty<T>>,
+        standard_fn: impl 't + Fn() -> Result<Option<T>, E>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
         anzeige: impl Fn(&T) -> String,
         sprache: Sprache,
-    ) -> Argumente<'t, T, String> {
-        Argumente::wert_enum(beschreibung, sprache.wert_infix, sprache.meta_var, anzeige)
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_mit_standard_fn(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            mögliche_werte,
+            standard_fn,
+            parse,
+            anzeige,
+        )
     }
 
-    /// Create a value-argument for an [EnumArgument].
+    /// Create a value-argument, based on the given `parse`-function.
+    /// The default value is determined by `default_fn`.
     ///
     /// ## Deutsches Synonym
-    /// [wert_enum_mit_sprache](Argumente::wert_enum_mit_sprache)
+    /// [wert_mit_standard_fn_und_sprache](Argumente::wert_mit_standard_fn_und_sprache)
     #[inline(always)]
-    pub fn value_enum_with_language(
+    pub fn value_with_default_fn_and_language(
         description: Description<'t, T>,
+        possible_values: Option<NonEmpty<T>>,
+        default_fn: impl 't + Fn() -> Result<Option<T>, E>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
         display: impl Fn(&T) -> String,
         language: Language,
-    ) -> Arguments<'t, T, String> {
-        Argumente::wert_enum_mit_sprache(description, display, language)
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_standard_fn_und_sprache(
+            description,
+            possible_values,
+            default_fn,
+            parse,
+            display,
+            language,
+        )
     }
 
-    /// Erzeuge ein Wert-Argument für ein [EnumArgument].
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Der Standard-Wert wird über `standard_fn` bestimmt,
+    /// z.B. durch Lesen einer Datei oder eine Abfrage des Systems.
+    ///
+    /// `standard_fn` wird nur aufgerufen, wenn das Argument nicht genannt wurde
+    /// und kein Standard-Wert in der [Beschreibung] gesetzt ist.
+    /// Bei `Ok(None)` wird ein Fehler für den fehlenden Wert erzeugt,
+    /// ein `Err` wird als [ParseFehler::ParseFehler] gemeldet.
     ///
     /// ## English synonym
-    /// [value_enum](Arguments::value_enum)
-    pub fn wert_enum(
+    /// [value_with_default_fn](Arguments::value_with_default_fn)
+    #[inline(always)]
+    pub fn wert_mit_standard_fn(
         beschreibung: Beschreibung<'t, T>,
         wert_infix: impl Into<Vergleich<'t>>,
         meta_var: &'t str,
+        mögliche_werte: Option<NonEmpty<T>>,
+        standard_fn: impl 't + Fn() -> Result<Option<T>, E>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
         anzeige: impl Fn(&T) -> String,
-    ) -> Argumente<'t, T, String> {
-        let mögliche_werte = NonEmpty::from_vec(T::varianten());
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_os_str_mit_standard_fn(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            mögliche_werte.iter().flat_map(NonEmpty::iter),
+            Mehrfach::Ersetze,
+            "",
+            standard_fn,
+            move |wert_os_str, _zustand| parse(wert_os_str.into_owned()),
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// The default value is determined by `default_fn`,
+    /// e.g. by reading a file or querying the system.
+    ///
+    /// `default_fn` is only called, if the argument is not given
+    /// and no default value is set in the [Description].
+    /// For `Ok(None)`, an error for the missing value is created,
+    /// an `Err` is reported as [ParseError::ParseFehler].
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_standard_fn](Argumente::wert_mit_standard_fn)
+    #[inline(always)]
+    pub fn value_with_default_fn(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        possible_values: Option<NonEmpty<T>>,
+        default_fn: impl 't + Fn() -> Result<Option<T>, E>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_standard_fn(
+            description,
+            value_infix,
+            meta_var,
+            possible_values,
+            default_fn,
+            parse,
+            display,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Ein mehrfach genanntes Argument wird abhängig von `mehrfach` behandelt.
+    ///
+    /// ## English synonym
+    /// [value_with_multiple_and_language](Arguments::value_with_multiple_and_language)
+    #[inline(always)]
+    pub fn wert_mit_mehrfach_und_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        mögliche_werte: Option<NonEmpty<T>>,
+        mehrfach: Mehrfach,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_mit_mehrfach(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            mögliche_werte,
+            mehrfach,
+            sprache.mehrfach_genannt,
+            parse,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// An argument given multiple times is handled depending on `multiple`.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_mehrfach_und_sprache](Argumente::wert_mit_mehrfach_und_sprache)
+    #[inline(always)]
+    pub fn value_with_multiple_and_language(
+        description: Description<'t, T>,
+        possible_values: Option<NonEmpty<T>>,
+        multiple: Multiple,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_mehrfach_und_sprache(
+            description,
+            possible_values,
+            multiple,
+            parse,
+            display,
+            language,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Ein mehrfach genanntes Argument wird abhängig von `mehrfach` behandelt.
+    ///
+    /// Mit [Mehrfach::Ersetze] wird, wie bei [wert](Argumente::wert), der letzte Wert verwendet.
+    /// Mit [Mehrfach::Fehler] erzeugt jedes weitere Vorkommen einen
+    /// [Fehler::MehrfachGenannt] mit Beschreibung `mehrfach_genannt`.
+    /// Da nur ein Wert zurückgegeben wird, verhält sich [Mehrfach::Anhängen] wie
+    /// [Mehrfach::Ersetze], zum Sammeln aller Werte gibt es
+    /// [wert_mehrfach](Argumente::wert_mehrfach).
+    ///
+    /// ## English synonym
+    /// [value_with_multiple](Arguments::value_with_multiple)
+    #[allow(clippy::too_many_arguments)]
+    pub fn wert_mit_mehrfach(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        mögliche_werte: Option<NonEmpty<T>>,
+        mehrfach: Mehrfach,
+        mehrfach_genannt: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_os_str_mit_standard_fn(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            mögliche_werte.iter().flat_map(NonEmpty::iter),
+            mehrfach,
+            mehrfach_genannt,
+            || Ok(None),
+            move |wert_os_str, _zustand| parse(wert_os_str.into_owned()),
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// An argument given multiple times is handled depending on `multiple`.
+    ///
+    /// With [Multiple::Ersetze], the last value is used, like for [value](Arguments::value).
+    /// With [Multiple::Fehler], every further occurrence produces an
+    /// [Error::MehrfachGenannt] with description `given_multiple_times`.
+    /// Since only one value is returned, [Multiple::Anhängen] behaves like
+    /// [Multiple::Ersetze], to collect all values there is
+    /// [value_multiple](Arguments::value_multiple).
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_mehrfach](Argumente::wert_mit_mehrfach)
+    #[allow(clippy::too_many_arguments)]
+    #[inline(always)]
+    pub fn value_with_multiple(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        possible_values: Option<NonEmpty<T>>,
+        multiple: Multiple,
+        given_multiple_times: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_mehrfach(
+            description,
+            value_infix,
+            meta_var,
+            possible_values,
+            multiple,
+            given_multiple_times,
+            parse,
+            display,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Erfolgreich geparste Werte werden anschließend mit `normalisiere` angepasst.
+    ///
+    /// ## English synonym
+    /// [value_with_normalization_and_language](Arguments::value_with_normalization_and_language)
+    #[inline(always)]
+    pub fn wert_mit_normalisierung_und_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        mögliche_werte: Option<NonEmpty<T>>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        normalisiere: impl 't + Fn(T) -> T,
+        anzeige: impl Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_mit_normalisierung(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            mögliche_werte,
+            parse,
+            normalisiere,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// Successfully parsed values are afterwards adjusted with `normalize`.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_normalisierung_und_sprache](Argumente::wert_mit_normalisierung_und_sprache)
+    #[inline(always)]
+    pub fn value_with_normalization_and_language(
+        description: Description<'t, T>,
+        possible_values: Option<NonEmpty<T>>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        normalize: impl 't + Fn(T) -> T,
+        display: impl Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_normalisierung_und_sprache(
+            description,
+            possible_values,
+            parse,
+            normalize,
+            display,
+            language,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Erfolgreich geparste Werte werden anschließend mit `normalisiere` angepasst,
+    /// z.B. um Synonyme auf einen kanonischen Wert abzubilden.
+    /// Der Standard-Wert wird nicht normalisiert.
+    ///
+    /// ## English synonym
+    /// [value_with_normalization](Arguments::value_with_normalization)
+    pub fn wert_mit_normalisierung(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        mögliche_werte: Option<NonEmpty<T>>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        normalisiere: impl 't + Fn(T) -> T,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            mögliche_werte,
+            move |os_string| parse(os_string).map(&normalisiere),
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// Successfully parsed values are afterwards adjusted with `normalize`,
+    /// e.g. to map synonyms to a canonical value.
+    /// The default value is not normalized.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_normalisierung](Argumente::wert_mit_normalisierung)
+    #[inline(always)]
+    pub fn value_with_normalization(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        possible_values: Option<NonEmpty<T>>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        normalize: impl 't + Fn(T) -> T,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_normalisierung(
+            description,
+            value_infix,
+            meta_var,
+            possible_values,
+            parse,
+            normalize,
+            display,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Die negierte Form (z.B. `--kein-output`) setzt das Argument auf den Standard-Wert zurück.
+    ///
+    /// ## English synonym
+    /// [value_negatable_with_language](Arguments::value_negatable_with_language)
+    #[inline(always)]
+    pub fn wert_negierbar_mit_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_negierbar(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            sprache.invertiere_präfix,
+            sprache.invertiere_infix,
+            parse,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// The negated form (e.g. `--no-output`) resets the argument to its default value.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_negierbar_mit_sprache](Argumente::wert_negierbar_mit_sprache)
+    #[inline(always)]
+    pub fn value_negatable_with_language(
+        description: Description<'t, T>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_negierbar_mit_sprache(description, parse, display, language)
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    ///
+    /// Die über `invertiere_präfix` und `invertiere_infix` negierte Form des Langnamen
+    /// (z.B. `--kein-output`) verwirft alle vorher genannten Werte.
+    /// Folgt kein weiterer Wert, wird der Standard-Wert verwendet,
+    /// bzw. ein Fehler für den fehlenden Wert erzeugt.
+    ///
+    /// ## English synonym
+    /// [value_negatable](Arguments::value_negatable)
+    pub fn wert_negierbar(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        invertiere_präfix: impl Into<Vergleich<'t>>,
+        invertiere_infix: impl Into<Vergleich<'t>>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, E> {
+        let wert_namen = WertNamen::neu(&beschreibung, wert_infix.into(), meta_var);
+        let invertiere_präfix = invertiere_präfix.into();
+        let invertiere_infix = invertiere_infix.into();
+        let (beschreibung, standard) = beschreibung.als_string_beschreibung_allgemein(&anzeige);
+        Argumente {
+            konfigurationen: vec![Konfiguration::Wert {
+                beschreibung,
+                wert_infix: wert_namen.wert_infix.clone(),
+                wert_infix_anzeige: None,
+                geheim: false,
+                meta_var,
+                mögliche_werte: None,
+                reihenfolge: 0,
+                versteckte_namen: Vec::new(),
+            }],
+            flag_kurzformen: HashMap::new(),
+            einstellungen: wert_namen.einstellungen(),
+            parse: Box::new(move |args, kontext| {
+                let mut ergebnis = None;
+                let mut fehler = Vec::new();
+                let nicht_verwendet = wert_namen.suche_negierbar(
+                    args,
+                    kontext.kandidaten,
+                    Some((&invertiere_präfix, &invertiere_infix, kontext.konfigurationen)),
+                    |vorkommen| match vorkommen {
+                        Vorkommen::Wert(Some(wert_os_str)) => match parse(wert_os_str.into_owned())
+                        {
+                            Ok(wert) => ergebnis = Some(wert),
+                            Err(parse_fehler) => fehler.push(wert_namen.fehler_parse(parse_fehler)),
+                        },
+                        Vorkommen::Wert(None) | Vorkommen::OhneWert => {
+                            fehler.push(wert_namen.fehler_kein_wert())
+                        },
+                        Vorkommen::Negiert => ergebnis = None,
+                    },
+                );
+                if let Some(fehler) = NonEmpty::from_vec(fehler) {
+                    let ergebnis = nachsichtiger_standard(
+                        Ergebnis::Fehler(fehler),
+                        standard.as_ref(),
+                        kontext.nachsichtig.as_deref_mut(),
+                    );
+                    (ergebnis, nicht_verwendet)
+                } else if let Some(wert) = ergebnis {
+                    (Ergebnis::Wert(wert), nicht_verwendet)
+                } else if let Some(wert) = &standard {
+                    (Ergebnis::Wert(wert.clone()), nicht_verwendet)
+                } else {
+                    (
+                        Ergebnis::Fehler(NonEmpty::singleton(wert_namen.fehler_fehlend())),
+                        nicht_verwendet,
+                    )
+                }
+            }),
+        }
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    ///
+    /// The negated form of the long name, using `invert_prefix` and `invert_infix`
+    /// (e.g. `--no-output`), discards all previously given values.
+    /// If no further value follows, the default value is used,
+    /// or an error for the missing value is created.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_negierbar](Argumente::wert_negierbar)
+    #[inline(always)]
+    pub fn value_negatable(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        invert_prefix: impl Into<Compare<'t>>,
+        invert_infix: impl Into<Compare<'t>>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_negierbar(
+            description,
+            value_infix,
+            meta_var,
+            invert_prefix,
+            invert_infix,
+            parse,
+            display,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument mit optionalem Wert.
+    ///
+    /// ## English synonym
+    /// [value_optional_with_default_and_language](Arguments::value_optional_with_default_and_language)
+    #[inline(always)]
+    pub fn wert_optional_mit_standard_und_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        vorhanden_ohne_wert: T,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_optional_mit_standard(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            vorhanden_ohne_wert,
+            parse,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument with an optional value.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_optional_mit_standard_und_sprache](Argumente::wert_optional_mit_standard_und_sprache)
+    #[inline(always)]
+    pub fn value_optional_with_default_and_language(
+        description: Description<'t, T>,
+        present_without_value: T,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_optional_mit_standard_und_sprache(
+            description,
+            present_without_value,
+            parse,
+            display,
+            language,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument mit optionalem Wert, ausgehend von der übergebenen `parse`-Funktion.
+    ///
+    /// Der Wert muss im selben Argument wie der Name angegeben werden
+    /// (`--jobs=4` oder `-j4`), das folgende Argument wird nie als Wert verwendet.
+    /// Wird der Name ohne Wert genannt (`-j`), ist das Ergebnis `vorhanden_ohne_wert`.
+    /// Ein leerer Wert nach dem `wert_infix` (`--jobs=`) wird dagegen an `parse` übergeben.
+    /// Wird das Argument nicht genannt, wird der Standard-Wert verwendet,
+    /// bzw. ein Fehler für den fehlenden Wert erzeugt.
+    /// Die Anzeige im Hilfe-Text kann über
+    /// [mit_wert_infix_anzeige](Argumente::mit_wert_infix_anzeige) angepasst werden.
+    ///
+    /// ## English synonym
+    /// [value_optional_with_default](Arguments::value_optional_with_default)
+    pub fn wert_optional_mit_standard(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        vorhanden_ohne_wert: T,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, E> {
+        let wert_namen = WertNamen::neu(&beschreibung, wert_infix.into(), meta_var);
+        let (beschreibung, standard) = beschreibung.als_string_beschreibung_allgemein(&anzeige);
+        Argumente {
+            konfigurationen: vec![Konfiguration::Wert {
+                beschreibung,
+                wert_infix: wert_namen.wert_infix.clone(),
+                wert_infix_anzeige: None,
+                geheim: false,
+                meta_var,
+                mögliche_werte: None,
+                reihenfolge: 0,
+                versteckte_namen: Vec::new(),
+            }],
+            flag_kurzformen: HashMap::new(),
+            einstellungen: wert_namen.einstellungen(),
+            parse: Box::new(move |args, kontext| {
+                let mut ergebnis = None;
+                let mut fehler = Vec::new();
+                let nicht_verwendet = wert_namen.suche_optional(
+                    args,
+                    kontext.kandidaten,
+                    |vorkommen| match vorkommen {
+                        Vorkommen::Wert(Some(wert_os_str)) => match parse(wert_os_str.into_owned())
+                        {
+                            Ok(wert) => ergebnis = Some(wert),
+                            Err(parse_fehler) => fehler.push(wert_namen.fehler_parse(parse_fehler)),
+                        },
+                        Vorkommen::OhneWert => ergebnis = Some(vorhanden_ohne_wert.clone()),
+                        Vorkommen::Wert(None) => fehler.push(wert_namen.fehler_kein_wert()),
+                        Vorkommen::Negiert => {},
+                    },
+                );
+                if let Some(fehler) = NonEmpty::from_vec(fehler) {
+                    let ergebnis = nachsichtiger_standard(
+                        Ergebnis::Fehler(fehler),
+                        standard.as_ref(),
+                        kontext.nachsichtig.as_deref_mut(),
+                    );
+                    (ergebnis, nicht_verwendet)
+                } else if let Some(wert) = ergebnis.or_else(|| standard.clone()) {
+                    (Ergebnis::Wert(wert), nicht_verwendet)
+                } else {
+                    (
+                        Ergebnis::Fehler(NonEmpty::singleton(wert_namen.fehler_fehlend())),
+                        nicht_verwendet,
+                    )
+                }
+            }),
+        }
+    }
+
+    /// Create a value-argument with an optional value, based on the given `parse`-function.
+    ///
+    /// The value has to be given in the same argument as the name
+    /// (`--jobs=4` or `-j4`), the following argument is never used as value.
+    /// If the name is given without a value (`-j`), the result is `present_without_value`.
+    /// In contrast, an empty value after the `value_infix` (`--jobs=`) is passed to `parse`.
+    /// If the argument is not given, the default value is used,
+    /// or an error for the missing value is created.
+    /// The display in the help text may be adjusted using
+    /// [with_value_infix_display](Arguments::with_value_infix_display).
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_optional_mit_standard](Argumente::wert_optional_mit_standard)
+    #[inline(always)]
+    pub fn value_optional_with_default(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        present_without_value: T,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_optional_mit_standard(
+            description,
+            value_infix,
+            meta_var,
+            present_without_value,
+            parse,
+            display,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Die im Hilfe-Text angezeigten möglichen Werte werden erst beim Erstellen
+    /// des Hilfe-Textes über `werte_fn` bestimmt.
+    ///
+    /// ## English synonym
+    /// [value_with_dynamic_values_and_language](Arguments::value_with_dynamic_values_and_language)
+    #[inline(always)]
+    pub fn wert_mit_dynamischen_werten_und_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        werte_fn: impl 't + Fn() -> Vec<String>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_mit_dynamischen_werten(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            werte_fn,
+            parse,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// The possible values shown in the help text are determined by `values_fn`
+    /// when the help text is created.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_dynamischen_werten_und_sprache](Argumente::wert_mit_dynamischen_werten_und_sprache)
+    #[inline(always)]
+    pub fn value_with_dynamic_values_and_language(
+        description: Description<'t, T>,
+        values_fn: impl 't + Fn() -> Vec<String>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_dynamischen_werten_und_sprache(
+            description,
+            values_fn,
+            parse,
+            display,
+            language,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Die im Hilfe-Text angezeigten möglichen Werte werden erst beim Erstellen
+    /// des Hilfe-Textes über `werte_fn` bestimmt.
+    ///
+    /// Die `parse`-Funktion wird dadurch nicht eingeschränkt,
+    /// eine Überprüfung gegen die aktuellen Werte muss dort selbst erfolgen.
+    ///
+    /// ## English synonym
+    /// [value_with_dynamic_values](Arguments::value_with_dynamic_values)
+    pub fn wert_mit_dynamischen_werten(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        werte_fn: impl 't + Fn() -> Vec<String>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, E> {
+        let mut argumente =
+            Argumente::wert(beschreibung, wert_infix, meta_var, None, parse, anzeige);
+        for konfiguration in argumente.konfigurationen.iter_mut() {
+            if let Konfiguration::Wert { mögliche_werte, .. } = konfiguration {
+                *mögliche_werte = Some(MöglicheWerte::Dynamisch(Box::new(werte_fn)));
+                break;
+            }
+        }
+        argumente
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// The possible values shown in the help text are determined by `values_fn`
+    /// when the help text is created.
+    ///
+    /// The `parse`-function is not restricted by this,
+    /// checking against the current values has to be done there.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_dynamischen_werten](Argumente::wert_mit_dynamischen_werten)
+    #[inline(always)]
+    pub fn value_with_dynamic_values(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        values_fn: impl 't + Fn() -> Vec<String>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_dynamischen_werten(
+            description,
+            value_infix,
+            meta_var,
+            values_fn,
+            parse,
+            display,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Ein Fehler beim Parsen bricht das Parsen nicht ab, sondern wird als [Err] zurückgegeben.
+    ///
+    /// ## English synonym
+    /// [value_result_with_language](Arguments::value_result_with_language)
+    #[inline(always)]
+    pub fn wert_ergebnis_mit_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        parse: impl 't + Fn(OsString) -> Result<T, String>,
+        anzeige: impl Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, Result<T, String>, E> {
+        Argumente::wert_ergebnis(beschreibung, sprache.wert_infix, sprache.meta_var, parse, anzeige)
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// A parse error does not abort parsing, but is returned as an [Err] instead.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_ergebnis_mit_sprache](Argumente::wert_ergebnis_mit_sprache)
+    #[inline(always)]
+    pub fn value_result_with_language(
+        description: Description<'t, T>,
+        parse: impl 't + Fn(OsString) -> Result<T, String>,
+        display: impl Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, Result<T, String>, E> {
+        Argumente::wert_ergebnis_mit_sprache(description, parse, display, language)
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Ein Fehler beim Parsen bricht das Parsen nicht ab, sondern wird als [Err] zurückgegeben.
+    /// Die Entscheidung, wie mit dem Fehler umgegangen wird, liegt damit bei der Anwendung.
+    ///
+    /// Ein fehlender Wert erzeugt weiterhin [Fehler::FehlenderWert].
+    ///
+    /// ## English synonym
+    /// [value_result](Arguments::value_result)
+    pub fn wert_ergebnis(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, String>,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, Result<T, String>, E> {
+        Argumente::wert(
+            beschreibung.konvertiere(Ok),
+            wert_infix,
+            meta_var,
+            None,
+            move |os_string| Ok(parse(os_string)),
+            move |ergebnis| match ergebnis {
+                Ok(wert) => anzeige(wert),
+                Err(fehler) => fehler.clone(),
+            },
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// A parse error does not abort parsing, but is returned as an [Err] instead.
+    /// This way, the application decides how to handle the error.
+    ///
+    /// A missing value still creates [Fehler::FehlenderWert].
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_ergebnis](Argumente::wert_ergebnis)
+    #[inline(always)]
+    pub fn value_result(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, String>,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, Result<T, String>, E> {
+        Argumente::wert_ergebnis(description, value_infix, meta_var, parse, display)
+    }
+}
+
+impl<'t, T: 't + Clone, E: 't> Argumente<'t, T, E> {
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Gibt `parse` [Either::Left] zurück, wird das Parsen mit der enthaltenen Nachricht
+    /// [frühzeitig beendet](Ergebnis::FrühesBeenden), z.B. für `--list-plugins all`.
+    ///
+    /// ## English synonym
+    /// [value_with_action_and_language](Arguments::value_with_action_and_language)
+    #[inline(always)]
+    pub fn wert_mit_aktion_und_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        parse: impl 't + Fn(OsString) -> Result<Either<String, T>, ParseError<E>>,
+        anzeige: impl 't + Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, E> {
+        Argumente::wert_mit_aktion(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            parse,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// If `parse` returns [Either::Left], parsing [exits early](Ergebnis::FrühesBeenden)
+    /// with the contained message, e.g. for `--list-plugins all`.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_aktion_und_sprache](Argumente::wert_mit_aktion_und_sprache)
+    #[inline(always)]
+    pub fn value_with_action_and_language(
+        description: Description<'t, T>,
+        parse: impl 't + Fn(OsString) -> Result<Either<String, T>, ParseError<E>>,
+        display: impl 't + Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_aktion_und_sprache(description, parse, display, language)
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Gibt `parse` [Either::Left] zurück, wird das Parsen mit der enthaltenen Nachricht
+    /// [frühzeitig beendet](Ergebnis::FrühesBeenden), z.B. für `--list-plugins all`.
+    /// Bei [Either::Right] wird der enthaltene Wert verwendet.
+    ///
+    /// Bei einem [Probelauf](Argumente::prüfe) wird `parse` nicht aufgerufen,
+    /// stattdessen wird die Aktion im [Prüfbericht](crate::Prüfbericht) festgehalten.
+    /// Fehler anderer Argumente werden dabei weiterhin gemeldet.
+    ///
+    /// ## English synonym
+    /// [value_with_action](Arguments::value_with_action)
+    pub fn wert_mit_aktion(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<Either<String, T>, ParseError<E>>,
+        anzeige: impl 't + Fn(&T) -> String,
+    ) -> Argumente<'t, T, E> {
+        let name =
+            format!("{}{}", beschreibung.lang_präfix.as_ref(), beschreibung.lang.head.as_ref());
+        let Argumente { konfigurationen, flag_kurzformen, einstellungen, parse } =
+            Argumente::wert_os_str_mit_standard_fn(
+                beschreibung.konvertiere(Either::Right),
+                wert_infix,
+                meta_var,
+                iter::empty(),
+                Mehrfach::Ersetze,
+                "",
+                || Ok(None),
+                move |wert_os_str, zustand| {
+                    // Bei einem Probelauf wird die Aktion nur beschrieben, nicht ausgeführt.
+                    // Das frühe Beenden hat dabei keinen Vorrang vor Fehlern anderer Argumente.
+                    if let Some(aufzeichnungen) = &mut zustand.probelauf {
+                        let aktion = format!("{name} {}", wert_os_str.to_string_lossy());
+                        aufzeichnungen.push(Aufzeichnung::Aktion(aktion.clone()));
+                        return Ok(Either::Left(aktion));
+                    }
+                    parse(wert_os_str.into_owned())
+                },
+                move |wert| match wert {
+                    Either::Left(nachricht) => nachricht.clone(),
+                    Either::Right(wert) => anzeige(wert),
+                },
+            );
+        Argumente {
+            konfigurationen,
+            flag_kurzformen,
+            einstellungen,
+            parse: Box::new(move |args, kontext| {
+                let (ergebnis, nicht_verwendet) = parse(args, kontext);
+                let ergebnis = match ergebnis {
+                    Ergebnis::Wert(Either::Left(nachricht)) => {
+                        Ergebnis::FrühesBeenden(NonEmpty::singleton(Cow::Owned(nachricht)))
+                    },
+                    Ergebnis::Wert(Either::Right(wert)) => Ergebnis::Wert(wert),
+                    Ergebnis::FrühesBeenden(nachrichten) => Ergebnis::FrühesBeenden(nachrichten),
+                    Ergebnis::Fehler(fehler) => Ergebnis::Fehler(fehler),
+                };
+                (ergebnis, nicht_verwendet)
+            }),
+        }
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// If `parse` returns [Either::Left], parsing [exits early](Ergebnis::FrühesBeenden)
+    /// with the contained message, e.g. for `--list-plugins all`.
+    /// For [Either::Right], the contained value is used.
+    ///
+    /// During a [dry run](crate::Arguments::check), `parse` is not called,
+    /// instead the action is recorded in the [CheckReport](crate::CheckReport).
+    /// Errors of other arguments are still reported.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_aktion](Argumente::wert_mit_aktion)
+    #[inline(always)]
+    pub fn value_with_action(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<Either<String, T>, ParseError<E>>,
+        display: impl 't + Fn(&T) -> String,
+    ) -> Arguments<'t, T, E> {
+        Argumente::wert_mit_aktion(description, value_infix, meta_var, parse, display)
+    }
+}
+
+/// Ein Vorkommen eines Wert-Arguments in den Kommandozeilen-Argumenten.
+pub(crate) enum Vorkommen<'a> {
+    /// Der zugehörige Wert, bzw. [None] falls das folgende Argument bereits verwendet wurde.
+    ///
+    /// Ein Wert nach dem `wert_infix` (`--name=wert`) wird nur für die Dauer
+    /// des Aufrufs ausgeliehen, ein Wert im folgenden Argument wird übergeben.
+    Wert(Option<Cow<'a, OsStr>>),
+    /// Der Name ohne Wert, falls das folgende Argument nicht als Wert verwendet wird
+    /// (siehe [suche_optional](WertNamen::suche_optional)).
+    OhneWert,
+    /// Die negierte Form des Langnamen, z.B. `--kein-output`.
+    Negiert,
+}
+
+/// Namen eines Wert-Arguments, zum Finden in den Kommandozeilen-Argumenten.
+#[derive(Debug, Clone)]
+pub(crate) struct WertNamen<'t> {
+    pub(crate) lang_präfix: Vergleich<'t>,
+    pub(crate) lang: NonEmpty<Vergleich<'t>>,
+    pub(crate) kurz_präfix: Vergleich<'t>,
+    pub(crate) kurz: Vec<Vergleich<'t>>,
+    pub(crate) wert_infix: Vergleich<'t>,
+    pub(crate) meta_var: &'t str,
+    pub(crate) namen_eintrag: NamenIndexEintrag<'t>,
+}
+
+impl<'t> WertNamen<'t> {
+    pub(crate) fn neu<T>(
+        beschreibung: &Beschreibung<'t, T>,
+        wert_infix: Vergleich<'t>,
+        meta_var: &'t str,
+    ) -> WertNamen<'t> {
+        let namen_eintrag = NamenEintrag::neu(
+            vec![vec![beschreibung.lang_präfix.clone()], vec![beschreibung.kurz_präfix.clone()]],
+            beschreibung.lang.iter().chain(&beschreibung.kurz).cloned(),
+        );
+        WertNamen {
+            lang_präfix: beschreibung.lang_präfix.clone(),
+            lang: beschreibung.lang.clone(),
+            kurz_präfix: beschreibung.kurz_präfix.clone(),
+            kurz: beschreibung.kurz.clone(),
+            wert_infix,
+            meta_var,
+            namen_eintrag,
+        }
+    }
+
+    /// Einstellungen mit den Namen für den [NamenIndex](crate::argumente::namen_index::NamenIndex).
+    pub(crate) fn einstellungen(&self) -> ParseEinstellungen<'t> {
+        ParseEinstellungen {
+            namen_einträge: vec![self.namen_eintrag.clone()],
+            ..ParseEinstellungen::default()
+        }
+    }
+
+    pub(crate) fn fehler_namen(&self) -> Namen<'t> {
+        Namen {
+            lang_präfix: self.lang_präfix.string.clone(),
+            lang: self.lang.clone().map(|Vergleich { string, .. }| string),
+            kurz_präfix: self.kurz_präfix.string.clone(),
+            kurz: self.kurz.iter().map(|Vergleich { string, .. }| string.clone()).collect(),
+        }
+    }
+
+    /// Der Name wurde genannt, es folgte aber kein Wert.
+    pub(crate) fn fehler_kein_wert<E>(&self) -> Fehler<'t, E> {
+        Fehler::FehlenderWert {
+            namen: self.fehler_namen(),
+            wert_infix: self.wert_infix.string.clone(),
+            meta_var: self.meta_var,
+            genannt: true,
+            fehlermeldung: None,
+        }
+    }
+
+    /// Das Argument wurde nicht genannt.
+    pub(crate) fn fehler_fehlend<E>(&self) -> Fehler<'t, E> {
+        Fehler::FehlenderWert {
+            namen: self.fehler_namen(),
+            wert_infix: self.wert_infix.string.clone(),
+            meta_var: self.meta_var,
+            genannt: false,
+            fehlermeldung: None,
+        }
+    }
+
+    pub(crate) fn fehler_zu_viele_werte<E>(
+        &self,
+        maximum: usize,
+        zu_viele_werte: &'t str,
+    ) -> Fehler<'t, E> {
+        Fehler::ZuVieleWerte {
+            namen: self.fehler_namen(),
+            wert_infix: self.wert_infix.string.clone(),
+            meta_var: self.meta_var,
+            maximum,
+            zu_viele_werte,
+        }
+    }
+
+    pub(crate) fn fehler_mehrfach_genannt<E>(&self, mehrfach_genannt: &'t str) -> Fehler<'t, E> {
+        Fehler::MehrfachGenannt {
+            namen: self.fehler_namen(),
+            wert_infix: self.wert_infix.string.clone(),
+            meta_var: self.meta_var,
+            mehrfach_genannt,
+        }
+    }
+
+    pub(crate) fn fehler_parse<E>(&self, fehler: ParseFehler<E>) -> Fehler<'t, E> {
+        Fehler::Fehler {
+            namen: self.fehler_namen(),
+            wert_infix: self.wert_infix.string.clone(),
+            meta_var: self.meta_var,
+            fehler,
+        }
+    }
+
+    /// Suche alle Vorkommen des Wert-Arguments.
+    ///
+    /// Für jedes Vorkommen wird `gefunden` mit dem zugehörigen Wert aufgerufen,
+    /// bzw. mit [None], falls das folgende Argument bereits verwendet wurde oder fehlt.
+    /// Ein leerer Wert nach dem `wert_infix` (`--name=`) wird als leerer String gemeldet.
+    /// Verwendete Argumente werden im Rückgabewert durch [None] ersetzt.
+    pub(crate) fn suche(
+        &self,
+        args: Vec<Option<OsString>>,
+        kandidaten: Option<&IndexKandidaten<'_, 't>>,
+        mut gefunden: impl FnMut(Option<OsString>),
+    ) -> Vec<Option<OsString>> {
+        self.suche_negierbar(args, kandidaten, None, |vorkommen| {
+            if let Vorkommen::Wert(arg) = vorkommen {
+                gefunden(arg.map(Cow::into_owned))
+            }
+        })
+    }
+
+    /// Suche alle Vorkommen des Wert-Arguments, wie [suche](WertNamen::suche),
+    /// und parse ihre Werte mit `parse`.
+    ///
+    /// Mit [Mehrfach::Ersetze] wird nur der letzte Wert behalten,
+    /// mit [Mehrfach::Anhängen] werden alle Werte in der genannten Reihenfolge gesammelt.
+    /// Mit [Mehrfach::Fehler] erzeugt jedes weitere Vorkommen einen
+    /// [Fehler::MehrfachGenannt] mit Beschreibung `mehrfach_genannt`.
+    /// Zurückgegeben werden die Werte, alle Fehler und die nicht verwendeten Argumente.
+    pub(crate) fn suche_werte<T, E>(
+        &self,
+        args: Vec<Option<OsString>>,
+        kandidaten: Option<&IndexKandidaten<'_, 't>>,
+        mehrfach: Mehrfach,
+        mehrfach_genannt: &'t str,
+        mut parse: impl FnMut(Cow<'_, OsStr>) -> Result<T, ParseFehler<E>>,
+    ) -> (Vec<T>, Vec<Fehler<'t, E>>, Vec<Option<OsString>>) {
+        let mut werte = Vec::new();
+        let mut fehler = Vec::new();
+        let mut genannt = false;
+        let nicht_verwendet = self.suche_negierbar(args, kandidaten, None, |vorkommen| {
+            let wert = match vorkommen {
+                Vorkommen::Wert(wert) => wert,
+                Vorkommen::OhneWert => None,
+                Vorkommen::Negiert => return,
+            };
+            if genannt && mehrfach == Mehrfach::Fehler {
+                fehler.push(self.fehler_mehrfach_genannt(mehrfach_genannt));
+                return;
+            }
+            genannt = true;
+            match wert.map(&mut parse) {
+                Some(Ok(wert)) => {
+                    if mehrfach == Mehrfach::Ersetze {
+                        werte.clear();
+                    }
+                    werte.push(wert)
+                },
+                Some(Err(parse_fehler)) => fehler.push(self.fehler_parse(parse_fehler)),
+                None => fehler.push(self.fehler_kein_wert()),
+            }
+        });
+        (werte, fehler, nicht_verwendet)
+    }
+
+    /// Suche alle Vorkommen des Wert-Arguments, wie [suche](WertNamen::suche).
+    ///
+    /// Zusätzlich wird die mit `invertiere` (Präfix und Infix) negierte Form
+    /// des Langnamen als [Vorkommen::Negiert] gemeldet,
+    /// sofern es sich nicht um einen exakten Langnamen eines anderen Arguments handelt.
+    pub(crate) fn suche_negierbar(
+        &self,
+        args: Vec<Option<OsString>>,
+        kandidaten: Option<&IndexKandidaten<'_, 't>>,
+        invertiere: Option<(&Vergleich<'t>, &Vergleich<'t>, &[Konfiguration<'t>])>,
+        gefunden: impl FnMut(Vorkommen<'_>),
+    ) -> Vec<Option<OsString>> {
+        self.suche_allgemein(args, kandidaten, invertiere, false, gefunden)
+    }
+
+    /// Suche alle Vorkommen des Wert-Arguments, wie [suche](WertNamen::suche).
+    ///
+    /// Ein Wert muss im selben Argument wie der Name angegeben werden,
+    /// für einen Namen ohne Wert wird [Vorkommen::OhneWert] gemeldet.
+    pub(crate) fn suche_optional(
+        &self,
+        args: Vec<Option<OsString>>,
+        kandidaten: Option<&IndexKandidaten<'_, 't>>,
+        gefunden: impl FnMut(Vorkommen<'_>),
+    ) -> Vec<Option<OsString>> {
+        self.suche_allgemein(args, kandidaten, None, true, gefunden)
+    }
+
+    /// Suche alle Vorkommen des Wert-Arguments, wie [suche_optional](WertNamen::suche_optional).
+    /// Zusätzlich wird die negierte Form des Langnamen als [Vorkommen::Negiert] gemeldet.
+    pub(crate) fn suche_optional_negierbar(
+        &self,
+        args: Vec<Option<OsString>>,
+        kandidaten: Option<&IndexKandidaten<'_, 't>>,
+        invertiere: (&Vergleich<'t>, &Vergleich<'t>, &[Konfiguration<'t>]),
+        gefunden: impl FnMut(Vorkommen<'_>),
+    ) -> Vec<Option<OsString>> {
+        self.suche_allgemein(args, kandidaten, Some(invertiere), true, gefunden)
+    }
+
+    /// Handelt es sich bei `string` um einen direkt an den Kurznamen geklebten Wert
+    /// (z.B. `-oWert`), also weder den Kurznamen selbst, noch einen mit `wert_infix`
+    /// angegebenen Wert (z.B. `-o=Wert`)?
+    pub(crate) fn ist_verklebter_kurzwert(&self, string: &str) -> bool {
+        let WertNamen { lang_präfix, kurz_präfix, kurz, wert_infix, .. } = self;
+        let normalisiert = Normalisiert::neu(string);
+        // Mit leerem `lang_präfix` kann ein Langname nicht am Präfix erkannt werden.
+        if kurz.is_empty()
+            || (!lang_präfix.as_ref().is_empty()
+                && lang_präfix.strip_als_präfix(&normalisiert).is_some())
+        {
+            return false;
+        }
+        let kurz_str = match kurz_präfix.strip_als_präfix(&normalisiert) {
+            Some(kurz_str) => kurz_str,
+            None => return false,
+        };
+        if contains_str(kurz, kurz_str) {
+            return false;
+        }
+        let kurz_str_normalisiert = Normalisiert::neu_borrowed_unchecked(kurz_str);
+        for name in kurz {
+            if let Some(rest) = name.strip_als_präfix(&kurz_str_normalisiert) {
+                let rest_normalisiert = Normalisiert::neu_borrowed_unchecked(rest);
+                if wert_infix.strip_als_präfix(&rest_normalisiert).is_some() {
+                    return false;
+                }
+            }
+        }
+        let mut kurz_graphemes = kurz_str.graphemes(true);
+        kurz_graphemes.next().map(|name| contains_str(kurz, name)).unwrap_or(false)
+            && !kurz_graphemes.as_str().is_empty()
+    }
+
+    fn suche_allgemein(
+        &self,
+        args: Vec<Option<OsString>>,
+        kandidaten: Option<&IndexKandidaten<'_, 't>>,
+        invertiere: Option<(&Vergleich<'t>, &Vergleich<'t>, &[Konfiguration<'t>])>,
+        optionaler_wert: bool,
+        mut gefunden: impl FnMut(Vorkommen<'_>),
+    ) -> Vec<Option<OsString>> {
+        let WertNamen {
+            lang_präfix,
+            lang,
+            kurz_präfix,
+            kurz,
+            wert_infix,
+            meta_var: _,
+            namen_eintrag,
+        } = self;
+        let kurz_existiert = !kurz.is_empty();
+        // Die negierte Form ist nicht Teil des Index, daher werden dann alle Argumente geprüft.
+        let kandidaten = kandidaten
+            .filter(|_| invertiere.is_none())
+            .and_then(|kandidaten| kandidaten.für(namen_eintrag, args.len()));
+        let mut name_ohne_wert = false;
+        let mut nicht_verwendet = Vec::new();
+        'args: for (position, arg) in args.into_iter().enumerate() {
+            if name_ohne_wert {
+                gefunden(Vorkommen::Wert(arg.map(Cow::Owned)));
+                name_ohne_wert = false;
+                nicht_verwendet.push(None);
+                continue;
+            } else if kandidaten.as_ref().is_some_and(|kandidaten| !kandidaten.möglich(position)) {
+                // Laut Index kann an dieser Position kein Name stehen.
+            } else if let Some(string) = arg.as_ref().and_then(|os_string| os_string.to_str()) {
+                let normalisiert = Normalisiert::neu(string);
+                if let Some(lang_str) = lang_präfix.strip_als_präfix(&normalisiert) {
+                    let lang_normalisiert = Normalisiert::neu_borrowed_unchecked(lang_str);
+                    let suffixe = contains_prefix(lang, &lang_normalisiert);
+                    for suffix in suffixe {
+                        let suffix_normalisiert = Normalisiert::neu_borrowed_unchecked(suffix);
+                        if suffix.is_empty() {
+                            if optionaler_wert {
+                                gefunden(Vorkommen::OhneWert);
+                            } else {
+                                name_ohne_wert = true;
+                            }
+                            nicht_verwendet.push(None);
+                            continue 'args;
+                        } else if let Some(wert_graphemes) =
+                            wert_infix.strip_als_präfix(&suffix_normalisiert)
+                        {
+                            gefunden(Vorkommen::Wert(Some(Cow::Borrowed(OsStr::new(
+                                wert_graphemes,
+                            )))));
+                            nicht_verwendet.push(None);
+                            continue 'args;
+                        }
+                    }
+                    if let Some((invertiere_präfix, invertiere_infix, konfigurationen)) = invertiere
+                    {
+                        if let Some(infix_name) =
+                            invertiere_präfix.strip_als_präfix(&lang_normalisiert)
+                        {
+                            let infix_name_normalisiert =
+                                Normalisiert::neu_borrowed_unchecked(infix_name);
+                            if let Some(negiert) =
+                                invertiere_infix.strip_als_präfix(&infix_name_normalisiert)
+                            {
+                                // Ein exakter Langname eines anderen Arguments
+                                // (z.B. `--kein-cache`) hat Vorrang.
+                                if contains_str(lang, negiert)
+                                    && !ist_bekannter_lang_name(konfigurationen, &normalisiert)
+                                {
+                                    gefunden(Vorkommen::Negiert);
+                                    nicht_verwendet.push(None);
+                                    continue 'args;
+                                }
+                            }
+                        }
+                    }
+                }
+                // Mit leerem `lang_präfix` (z.B. `start`) beginnt jedes Argument
+                // mit dem Präfix, daher werden anschließend die Kurznamen geprüft.
+                if kurz_existiert {
+                    if let Some(kurz_str) = kurz_präfix.strip_als_präfix(&normalisiert) {
+                        // Vergleiche zuerst den vollständigen Kurznamen,
+                        // damit auch Kurznamen aus mehreren Graphemes gefunden werden.
+                        if contains_str(kurz, kurz_str) {
+                            if optionaler_wert {
+                                gefunden(Vorkommen::OhneWert);
+                            } else {
+                                name_ohne_wert = true;
+                            }
+                            nicht_verwendet.push(None);
+                            continue 'args;
+                        }
+                        let kurz_str_normalisiert = Normalisiert::neu_borrowed_unchecked(kurz_str);
+                        for name in kurz {
+                            if let Some(rest) = name.strip_als_präfix(&kurz_str_normalisiert) {
+                                let rest_normalisiert = Normalisiert::neu_borrowed_unchecked(rest);
+                                if let Some(wert_str) =
+                                    wert_infix.strip_als_präfix(&rest_normalisiert)
+                                {
+                                    gefunden(Vorkommen::Wert(Some(Cow::Borrowed(OsStr::new(
+                                        wert_str,
+                                    )))));
+                                    nicht_verwendet.push(None);
+                                    continue 'args;
+                                }
+                            }
+                        }
+                        let mut kurz_graphemes = kurz_str.graphemes(true);
+                        if kurz_graphemes
+                            .next()
+                            .map(|name| contains_str(kurz, name))
+                            .unwrap_or(false)
+                        {
+                            let rest = kurz_graphemes.as_str();
+                            let kurz_normalisiert = Normalisiert::neu_borrowed_unchecked(rest);
+                            let wert_str = if rest.is_empty() {
+                                if optionaler_wert {
+                                    gefunden(Vorkommen::OhneWert);
+                                } else {
+                                    name_ohne_wert = true;
+                                }
+                                nicht_verwendet.push(None);
+                                continue 'args;
+                            } else {
+                                wert_infix.strip_als_präfix(&kurz_normalisiert).unwrap_or(rest)
+                            };
+                            gefunden(Vorkommen::Wert(Some(Cow::Borrowed(OsStr::new(wert_str)))));
+                            nicht_verwendet.push(None);
+                            continue 'args;
+                        }
+                    }
+                }
+            }
+            nicht_verwendet.push(arg);
+        }
+        if name_ohne_wert {
+            // Der Name war das letzte Argument, es folgt kein Wert mehr.
+            gefunden(Vorkommen::Wert(None));
+        }
+        nicht_verwendet
+    }
+}
+
+/// Quelle eines Wertes: Entweder ein explizit übergebener Wert,
+/// oder die Standard-Eingabe (üblicherweise über `-` angegeben).
+///
+/// ## English synonym
+/// [Source]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Quelle<T> {
+    /// Der Wert soll von der Standard-Eingabe gelesen werden.
+    ///
+    /// ## English
+    /// The value should be read from stdin.
+    Stdin,
+
+    /// Ein explizit übergebener Wert.
+    ///
+    /// ## English
+    /// An explicitly given value.
+    Wert(T),
+}
+
+/// Source of a value: Either an explicitly given value,
+/// or stdin (usually specified with `-`).
+///
+/// ## Deutsches Synonym
+/// [Quelle]
+pub type Source<T> = Quelle<T>;
+
+impl<'t, T: 't + Clone, E> Argumente<'t, Quelle<T>, E> {
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Der Wert `stdin` (üblicherweise `-`) wird nicht an `parse` übergeben,
+    /// sondern als [Quelle::Stdin] zurückgegeben.
+    ///
+    /// ## English synonym
+    /// [value_or_stdin_with_language](Arguments::value_or_stdin_with_language)
+    #[inline(always)]
+    pub fn wert_oder_stdin_mit_sprache(
+        beschreibung: Beschreibung<'t, Quelle<T>>,
+        stdin: &'t str,
+        mögliche_werte: Option<NonEmpty<T>>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, Quelle<T>, E> {
+        Argumente::wert_oder_stdin(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            stdin,
+            mögliche_werte,
+            parse,
+            anzeige,
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// The value `stdin` (usually `-`) is not passed to `parse`,
+    /// but returned as [Source::Stdin].
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_oder_stdin_mit_sprache](Argumente::wert_oder_stdin_mit_sprache)
+    #[inline(always)]
+    pub fn value_or_stdin_with_language(
+        description: Description<'t, Source<T>>,
+        stdin: &'t str,
+        possible_values: Option<NonEmpty<T>>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, Source<T>, E> {
+        Argumente::wert_oder_stdin_mit_sprache(
+            description,
+            stdin,
+            possible_values,
+            parse,
+            display,
+            language,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Der Wert `stdin` (üblicherweise `-`) wird nicht an `parse` übergeben,
+    /// sondern als [Quelle::Stdin] zurückgegeben.
+    ///
+    /// Das Lesen der Standard-Eingabe bleibt dem Aufrufer überlassen.
+    ///
+    /// ## English synonym
+    /// [value_or_stdin](Arguments::value_or_stdin)
+    pub fn wert_oder_stdin(
+        beschreibung: Beschreibung<'t, Quelle<T>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        stdin: &'t str,
+        mögliche_werte: Option<NonEmpty<T>>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, Quelle<T>, E> {
+        Argumente::wert(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            mögliche_werte.map(|werte| werte.map(Quelle::Wert)),
+            move |os_string| {
+                if os_string == stdin {
+                    Ok(Quelle::Stdin)
+                } else {
+                    parse(os_string).map(Quelle::Wert)
+                }
+            },
+            move |quelle| match quelle {
+                Quelle::Stdin => stdin.to_owned(),
+                Quelle::Wert(wert) => anzeige(wert),
+            },
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// The value `stdin` (usually `-`) is not passed to `parse`,
+    /// but returned as [Source::Stdin].
+    ///
+    /// Reading from stdin is left to the caller.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_oder_stdin](Argumente::wert_oder_stdin)
+    #[inline(always)]
+    pub fn value_or_stdin(
+        description: Description<'t, Source<T>>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        stdin: &'t str,
+        possible_values: Option<NonEmpty<T>>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<E>>,
+        display: impl Fn(&T) -> String,
+    ) -> Arguments<'t, Source<T>, E> {
+        Argumente::wert_oder_stdin(
+            description,
+            value_infix,
+            meta_var,
+            stdin,
+            possible_values,
+            parse,
+            display,
+        )
+    }
+}
+
+impl<'t, T: 't + Clone + Display + PartialEq> Argumente<'t, T, String> {
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Werte aus `verboten` werden mit einer Fehlermeldung abgelehnt.
+    ///
+    /// ## English synonym
+    /// [value_with_exclusion_and_language](Arguments::value_with_exclusion_and_language)
+    #[inline(always)]
+    pub fn wert_mit_ausschluss_und_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        mögliche_werte: Option<NonEmpty<T>>,
+        verboten: Vec<T>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<String>>,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, String> {
+        Argumente::wert_mit_ausschluss(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            mögliche_werte,
+            verboten,
+            sprache.wert_nicht_erlaubt,
+            parse,
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// Values from `forbidden` are rejected with an error message.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_ausschluss_und_sprache](Argumente::wert_mit_ausschluss_und_sprache)
+    #[inline(always)]
+    pub fn value_with_exclusion_and_language(
+        description: Description<'t, T>,
+        possible_values: Option<NonEmpty<T>>,
+        forbidden: Vec<T>,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<String>>,
+        language: Language,
+    ) -> Arguments<'t, T, String> {
+        Argumente::wert_mit_ausschluss_und_sprache(
+            description,
+            possible_values,
+            forbidden,
+            parse,
+            language,
+        )
+    }
+
+    /// Erzeuge ein Wert-Argument, ausgehend von der übergebenen `parse`-Funktion.
+    /// Werte aus `verboten` werden mit einer Fehlermeldung abgelehnt,
+    /// die mit `wert_nicht_erlaubt` beginnt und alle verbotenen Werte auflistet.
+    ///
+    /// ## English synonym
+    /// [value_with_exclusion](Arguments::value_with_exclusion)
+    pub fn wert_mit_ausschluss(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        mögliche_werte: Option<NonEmpty<T>>,
+        verboten: Vec<T>,
+        wert_nicht_erlaubt: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<String>>,
+    ) -> Argumente<'t, T, String> {
+        Argumente::wert(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            mögliche_werte,
+            move |os_string| {
+                let wert = parse(os_string)?;
+                if verboten.contains(&wert) {
+                    Err(ParseFehler::ParseFehler(format!(
+                        "{wert_nicht_erlaubt}: \"{wert}\" ∈ {{{}}}",
+                        verboten.iter().join(", ")
+                    )))
+                } else {
+                    Ok(wert)
+                }
+            },
+            ToString::to_string,
+        )
+    }
+
+    /// Create a value-argument, based on the given `parse`-function.
+    /// Values from `forbidden` are rejected with an error message,
+    /// starting with `value_not_allowed` and listing all forbidden values.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_mit_ausschluss](Argumente::wert_mit_ausschluss)
+    #[inline(always)]
+    pub fn value_with_exclusion(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        possible_values: Option<NonEmpty<T>>,
+        forbidden: Vec<T>,
+        value_not_allowed: &'t str,
+        parse: impl 't + Fn(OsString) -> Result<T, ParseError<String>>,
+    ) -> Arguments<'t, T, String> {
+        Argumente::wert_mit_ausschluss(
+            description,
+            value_infix,
+            meta_var,
+            possible_values,
+            forbidden,
+            value_not_allowed,
+            parse,
+        )
+    }
+}
+
+impl<'t> Argumente<'t, bool, String> {
+    /// Erzeuge ein Wert-Argument für einen Wahrheitswert,
+    /// mit den akzeptierten Schreibweisen aus der [Sprache].
+    ///
+    /// ## English synonym
+    /// [value_bool_with_language](Arguments::value_bool_with_language)
+    #[inline(always)]
+    pub fn wert_bool_mit_sprache(
+        beschreibung: Beschreibung<'t, bool>,
+        sprache: Sprache,
+    ) -> Argumente<'t, bool, String> {
+        Argumente::wert_bool(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            sprache.wahr,
+            sprache.falsch,
+        )
+    }
+
+    /// Create a value-argument for a boolean value,
+    /// with the accepted spellings from the [Language].
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_bool_mit_sprache](Argumente::wert_bool_mit_sprache)
+    #[inline(always)]
+    pub fn value_bool_with_language(
+        description: Description<'t, bool>,
+        language: Language,
+    ) -> Arguments<'t, bool, String> {
+        Argumente::wert_bool_mit_sprache(description, language)
+    }
+
+    /// Erzeuge ein Wert-Argument für einen Wahrheitswert.
+    /// Alle Schreibweisen aus `wahr` und `falsch` werden
+    /// ohne Beachtung von Groß-/Kleinschreibung akzeptiert (siehe [parse_wahrheitswert]),
+    /// z.B. `--farbe=JA` oder `--farbe Aus`.
+    /// Im Hilfe-Text wird jeweils die erste Schreibweise verwendet.
+    ///
+    /// ## English synonym
+    /// [value_bool](Arguments::value_bool)
+    pub fn wert_bool(
+        beschreibung: Beschreibung<'t, bool>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        wahr: &'t [&'t str],
+        falsch: &'t [&'t str],
+    ) -> Argumente<'t, bool, String> {
+        Argumente::wert(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            None,
+            move |os_string| parse_schreibweise(os_string, wahr, falsch),
+            move |wert| anzeige_schreibweise(*wert, wahr, falsch),
+        )
+    }
+
+    /// Create a value-argument for a boolean value.
+    /// All spellings from `true_spellings` and `false_spellings`
+    /// are accepted in a case-insensitive way (see [parse_bool](crate::unicode::parse_bool)),
+    /// e.g. `--color=YES` or `--color Off`.
+    /// The help text uses the respective first spelling.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_bool](Argumente::wert_bool)
+    #[inline(always)]
+    pub fn value_bool(
+        description: Description<'t, bool>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        true_spellings: &'t [&'t str],
+        false_spellings: &'t [&'t str],
+    ) -> Arguments<'t, bool, String> {
+        Argumente::wert_bool(description, value_infix, meta_var, true_spellings, false_spellings)
+    }
+}
+
+/// Parse einen Wahrheitswert in einer der Schreibweisen aus `wahr` oder `falsch`.
+fn parse_schreibweise(
+    os_string: OsString,
+    wahr: &[&str],
+    falsch: &[&str],
+) -> Result<bool, ParseFehler<String>> {
+    let string = os_string.into_string().map_err(ParseFehler::InvaliderString)?;
+    parse_wahrheitswert(&string, wahr, falsch).ok_or_else(|| {
+        ParseFehler::ParseFehler(format!(
+            "\"{string}\" ∉ {{{}}}",
+            wahr.iter().chain(falsch).join(", ")
+        ))
+    })
+}
+
+/// Anzeige eines Wahrheitswertes über die erste passende Schreibweise.
+fn anzeige_schreibweise(wert: bool, wahr: &[&str], falsch: &[&str]) -> String {
+    let schreibweisen = if wert { wahr } else { falsch };
+    schreibweisen.first().map_or_else(|| wert.to_string(), |s| (*s).to_owned())
+}
+
+impl<'t> Argumente<'t, Option<bool>, String> {
+    /// Erzeuge ein Flag-Argument mit optionalem Wahrheitswert,
+    /// mit den akzeptierten Schreibweisen und Präfixen aus der [Sprache].
+    ///
+    /// ## English synonym
+    /// [flag_with_optional_value_and_language](Arguments::flag_with_optional_value_and_language)
+    #[inline(always)]
+    pub fn flag_mit_optionalem_wert_und_sprache(
+        beschreibung: Beschreibung<'t, Option<bool>>,
+        sprache: Sprache,
+    ) -> Argumente<'t, Option<bool>, String> {
+        Argumente::flag_mit_optionalem_wert(
+            beschreibung,
+            sprache.invertiere_präfix,
+            sprache.invertiere_infix,
+            sprache.wert_infix,
+            sprache.meta_var,
+            sprache.wahr,
+            sprache.falsch,
+        )
+    }
+
+    /// Create a flag-argument with an optional boolean value,
+    /// with the accepted spellings and prefixes from the [Language].
+    ///
+    /// ## Deutsches Synonym
+    /// [flag_mit_optionalem_wert_und_sprache](Argumente::flag_mit_optionalem_wert_und_sprache)
+    #[inline(always)]
+    pub fn flag_with_optional_value_and_language(
+        description: Description<'t, Option<bool>>,
+        language: Language,
+    ) -> Arguments<'t, Option<bool>, String> {
+        Argumente::flag_mit_optionalem_wert_und_sprache(description, language)
+    }
+
+    /// Erzeuge ein Flag-Argument mit optionalem Wahrheitswert.
+    ///
+    /// Der Name ohne Wert (`--farbe`) ergibt `Some(true)`,
+    /// die invertierte Form (`--kein-farbe`) ergibt `Some(false)`.
+    /// Ein Wert muss im selben Argument wie der Name angegeben werden (`--farbe=nein`),
+    /// dabei werden alle Schreibweisen aus `wahr` und `falsch` akzeptiert
+    /// (siehe [parse_wahrheitswert]).
+    /// Wird das Argument nicht genannt, wird der Standard-Wert verwendet,
+    /// bzw. ein Fehler für den fehlenden Wert erzeugt.
+    ///
+    /// ## English synonym
+    /// [flag_with_optional_value](Arguments::flag_with_optional_value)
+    pub fn flag_mit_optionalem_wert(
+        beschreibung: Beschreibung<'t, Option<bool>>,
+        invertiere_präfix: impl Into<Vergleich<'t>>,
+        invertiere_infix: impl Into<Vergleich<'t>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        wahr: &'t [&'t str],
+        falsch: &'t [&'t str],
+    ) -> Argumente<'t, Option<bool>, String> {
+        let wert_namen = WertNamen::neu(&beschreibung, wert_infix.into(), meta_var);
+        let invertiere_präfix = invertiere_präfix.into();
+        let invertiere_infix = invertiere_infix.into();
+        let (beschreibung, standard) =
+            beschreibung.als_string_beschreibung_allgemein(|wert| match wert {
+                Some(wert) => anzeige_schreibweise(*wert, wahr, falsch),
+                None => "None".to_owned(),
+            });
+        Argumente {
+            konfigurationen: vec![Konfiguration::Wert {
+                beschreibung,
+                wert_infix: wert_namen.wert_infix.clone(),
+                wert_infix_anzeige: None,
+                geheim: false,
+                meta_var,
+                mögliche_werte: None,
+                reihenfolge: 0,
+                versteckte_namen: Vec::new(),
+            }],
+            flag_kurzformen: HashMap::new(),
+            einstellungen: wert_namen.einstellungen(),
+            parse: Box::new(move |args, kontext| {
+                let mut ergebnis = None;
+                let mut fehler = Vec::new();
+                let nicht_verwendet = wert_namen.suche_optional_negierbar(
+                    args,
+                    kontext.kandidaten,
+                    (&invertiere_präfix, &invertiere_infix, kontext.konfigurationen),
+                    |vorkommen| match vorkommen {
+                        Vorkommen::Wert(Some(wert_os_str)) => {
+                            match parse_schreibweise(wert_os_str.into_owned(), wahr, falsch) {
+                                Ok(wert) => ergebnis = Some(Some(wert)),
+                                Err(parse_fehler) => {
+                                    fehler.push(wert_namen.fehler_parse(parse_fehler))
+                                },
+                            }
+                        },
+                        Vorkommen::OhneWert => ergebnis = Some(Some(true)),
+                        Vorkommen::Negiert => ergebnis = Some(Some(false)),
+                        Vorkommen::Wert(None) => fehler.push(wert_namen.fehler_kein_wert()),
+                    },
+                );
+                if let Some(fehler) = NonEmpty::from_vec(fehler) {
+                    let ergebnis = nachsichtiger_standard(
+                        Ergebnis::Fehler(fehler),
+                        standard.as_ref(),
+                        kontext.nachsichtig.as_deref_mut(),
+                    );
+                    (ergebnis, nicht_verwendet)
+                } else if let Some(wert) = ergebnis.or(standard) {
+                    (Ergebnis::Wert(wert), nicht_verwendet)
+                } else {
+                    (
+                        Ergebnis::Fehler(NonEmpty::singleton(wert_namen.fehler_fehlend())),
+                        nicht_verwendet,
+                    )
+                }
+            }),
+        }
+    }
+
+    /// Create a flag-argument with an optional boolean value.
+    ///
+    /// The name without a value (`--color`) results in `Some(true)`,
+    /// the inverted form (`--no-color`) results in `Some(false)`.
+    /// A value has to be given in the same argument as the name (`--color=no`),
+    /// all spellings from `true_spellings` and `false_spellings` are accepted
+    /// (see [parse_bool](crate::unicode::parse_bool)).
+    /// If the argument is not given, the default value is used,
+    /// or an error for the missing value is created.
+    ///
+    /// ## Deutsches Synonym
+    /// [flag_mit_optionalem_wert](Argumente::flag_mit_optionalem_wert)
+    #[inline(always)]
+    pub fn flag_with_optional_value(
+        description: Description<'t, Option<bool>>,
+        invert_prefix: impl Into<Compare<'t>>,
+        invert_infix: impl Into<Compare<'t>>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        true_spellings: &'t [&'t str],
+        false_spellings: &'t [&'t str],
+    ) -> Arguments<'t, Option<bool>, String> {
+        Argumente::flag_mit_optionalem_wert(
+            description,
+            invert_prefix,
+            invert_infix,
+            value_infix,
+            meta_var,
+            true_spellings,
+            false_spellings,
+        )
+    }
+}
+
+impl<'t, E> Argumente<'t, PathBuf, E> {
+    /// Erzeuge ein Wert-Argument für einen Pfad relativ zum Verzeichnis `basis`,
+    /// mit der Meta-Variable aus der [Sprache].
+    ///
+    /// ## English synonym
+    /// [value_path_relative_to_with_language](Arguments::value_path_relative_to_with_language)
+    #[inline(always)]
+    pub fn wert_pfad_relativ_zu_mit_sprache(
+        beschreibung: Beschreibung<'t, PathBuf>,
+        basis: PathBuf,
+        sprache: Sprache,
+    ) -> Argumente<'t, PathBuf, E> {
+        Argumente::wert_pfad_relativ_zu(beschreibung, sprache.wert_infix, sprache.meta_var, basis)
+    }
+
+    /// Create a value-argument for a path relative to the directory `base`,
+    /// with the meta-variable from the [Language].
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_pfad_relativ_zu_mit_sprache](Argumente::wert_pfad_relativ_zu_mit_sprache)
+    #[inline(always)]
+    pub fn value_path_relative_to_with_language(
+        description: Description<'t, PathBuf>,
+        base: PathBuf,
+        language: Language,
+    ) -> Arguments<'t, PathBuf, E> {
+        Argumente::wert_pfad_relativ_zu_mit_sprache(description, base, language)
+    }
+
+    /// Erzeuge ein Wert-Argument für einen Pfad relativ zum Verzeichnis `basis`,
+    /// z.B. dem Verzeichnis einer Konfigurations-Datei.
+    ///
+    /// Ein relativer Pfad wird an `basis` angehängt, ein absoluter Pfad bleibt unverändert.
+    /// Der Standard-Wert wird unverändert übernommen.
+    ///
+    /// ## English synonym
+    /// [value_path_relative_to](Arguments::value_path_relative_to)
+    pub fn wert_pfad_relativ_zu(
+        beschreibung: Beschreibung<'t, PathBuf>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        basis: PathBuf,
+    ) -> Argumente<'t, PathBuf, E> {
+        Argumente::wert(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            None,
+            move |os_string| Ok(basis.join(os_string)),
+            |pfad| pfad.display().to_string(),
+        )
+    }
+
+    /// Create a value-argument for a path relative to the directory `base`,
+    /// e.g. the directory of a configuration file.
+    ///
+    /// A relative path is appended to `base`, an absolute path remains unchanged.
+    /// The default value is used unchanged.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_pfad_relativ_zu](Argumente::wert_pfad_relativ_zu)
+    #[inline(always)]
+    pub fn value_path_relative_to(
+        description: Description<'t, PathBuf>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        base: PathBuf,
+    ) -> Arguments<'t, PathBuf, E> {
+        Argumente::wert_pfad_relativ_zu(description, value_infix, meta_var, base)
+    }
+}
+
+/// Trait für Typen mit einer festen Anzahl an Werten und Methode zum Parsen.
+/// Gedacht für Summentypen ohne extra Daten (nur Unit-Varianten).
+///
+/// Mit aktiviertem `derive`-Feature kann die Implementierung
+/// [automatisch erzeugt werden](derive@EnumArgument).
+///
+/// ## English
+/// Trait for types with a fixed number of values and a parse method.
+/// Intended for sum-types without extra data (only unit variants).
+///
+/// With activated `derive`-feature, the implementation can be
+/// [created automatically](derive@EnumArgument).
+pub trait EnumArgument: Sized {
+    /// Alle Varianten des Typs.
+    ///
+    /// ## English synonym
+    /// [variants](EnumArgument::variants)
+    fn varianten() -> Vec<Self>;
+
+    /// All variants of the type.
+    ///
+    /// ## Deutsches Synonym
+    /// [varianten](EnumArgument::varianten)
+    #[inline(always)]
+    fn variants() -> Vec<Self> {
+        Self::varianten()
+    }
+
+    /// Versuche einen Wert ausgehend vom übergebenen [OsString] zu erzeugen.
+    ///
+    /// ## English
+    /// Try to parse a value from the given [OsString].
+    fn parse_enum(arg: OsString) -> Result<Self, ParseFehler<String>>;
+}
+
+impl<'t, T: 't + Display + Clone + EnumArgument> Argumente<'t, T, String> {
+    /// Erzeuge ein Wert-Argument für ein [EnumArgument].
+    ///
+    /// ## English synonym
+    /// [value_enum_display_with_language](Arguments::value_enum_display_with_language)
+    #[inline(always)]
+    pub fn wert_enum_display_mit_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, String> {
+        Argumente::wert_enum_display(beschreibung, sprache.wert_infix, sprache.meta_var)
+    }
+
+    /// Create a value-argument for an [EnumArgument].
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_enum_display_mit_sprache](Argumente::wert_enum_display_mit_sprache)
+    #[inline(always)]
+    pub fn value_enum_display_with_language(
+        description: Description<'t, T>,
+        language: Language,
+    ) -> Arguments<'t, T, String> {
+        Argumente::wert_enum_display_mit_sprache(description, language)
+    }
+
+    /// Erzeuge ein Wert-Argument für ein [EnumArgument].
+    ///
+    /// ## English synonym
+    /// [value_enum_display](Arguments::value_enum_display)
+    #[inline(always)]
+    pub fn wert_enum_display(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+    ) -> Argumente<'t, T, String> {
+        Argumente::wert_enum(beschreibung, wert_infix, meta_var, T::to_string)
+    }
+
+    /// Create a value-argument for an [EnumArgument].
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_enum_display](Argumente::wert_enum_display)
+    #[inline(always)]
+    pub fn value_enum_display(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+    ) -> Arguments<'t, T, String> {
+        Argumente::wert_enum_display(description, value_infix, meta_var)
+    }
+}
+
+impl<'t, T: 't + Display + Clone + EnumArgument> Argumente<'t, T, String> {
+    /// Erzeuge ein Wert-Argument für ein [EnumArgument].
+    ///
+    /// ## English synonym
+    /// [value_enum_with_language](Arguments::value_enum_with_language)
+    #[inline(always)]
+    pub fn wert_enum_mit_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        anzeige: impl Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, String> {
+        Argumente::wert_enum(beschreibung, sprache.wert_infix, sprache.meta_var, anzeige)
+    }
+
+    /// Create a value-argument for an [EnumArgument].
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_enum_mit_sprache](Argumente::wert_enum_mit_sprache)
+    #[inline(always)]
+    pub fn value_enum_with_language(
+        description: Description<'t, T>,
+        display: impl Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, T, String> {
+        Argumente::wert_enum_mit_sprache(description, display, language)
+    }
+
+    /// Erzeuge ein Wert-Argument für ein [EnumArgument].
+    ///
+    /// ## English synonym
+    /// [value_enum](Arguments::value_enum)
+    pub fn wert_enum(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        anzeige: impl Fn(&T) -> String,
+    ) -> Argumente<'t, T, String> {
+        let mögliche_werte = NonEmpty::from_vec(T::varianten());
         Argumente::wert(beschreibung, wert_infix, meta_var, mögliche_werte, T::parse_enum, anzeige)
     }
 
@@ -573,6 +2695,108 @@ impl<'t, T: 't + Display + Clone + EnumArgument> Argumente<'t, T, String> {
     }
 }
 
+impl<'t, T: 't + Clone + EnumArgument> Argumente<'t, T, String> {
+    /// Erzeuge ein Wert-Argument für ein [EnumArgument],
+    /// dessen Varianten über `namen` angegeben werden.
+    ///
+    /// ## English synonym
+    /// [value_enum_with_names_and_language](Arguments::value_enum_with_names_and_language)
+    #[inline(always)]
+    pub fn wert_enum_mit_namen_und_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        namen: impl Fn(&T) -> String,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, String> {
+        Argumente::wert_enum_mit_namen(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            namen,
+            sprache.wert_nicht_erlaubt,
+        )
+    }
+
+    /// Create a value-argument for an [EnumArgument],
+    /// whose variants are given using `names`.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_enum_mit_namen_und_sprache](Argumente::wert_enum_mit_namen_und_sprache)
+    #[inline(always)]
+    pub fn value_enum_with_names_and_language(
+        description: Description<'t, T>,
+        names: impl Fn(&T) -> String,
+        language: Language,
+    ) -> Arguments<'t, T, String> {
+        Argumente::wert_enum_mit_namen_und_sprache(description, names, language)
+    }
+
+    /// Erzeuge ein Wert-Argument für ein [EnumArgument],
+    /// dessen Varianten über `namen` angegeben werden.
+    ///
+    /// Im Gegensatz zu [wert_enum](Argumente::wert_enum) wird [EnumArgument::parse_enum]
+    /// nicht verwendet, sowohl Parsen als auch Hilfe-Text verwenden `namen`.
+    /// Unbekannte Namen erzeugen einen Fehler, der mit `wert_nicht_erlaubt` beginnt
+    /// und alle erlaubten Namen auflistet.
+    ///
+    /// ## English synonym
+    /// [value_enum_with_names](Arguments::value_enum_with_names)
+    pub fn wert_enum_mit_namen(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        namen: impl Fn(&T) -> String,
+        wert_nicht_erlaubt: &'t str,
+    ) -> Argumente<'t, T, String> {
+        let varianten = T::varianten();
+        let erlaubt = varianten.iter().map(&namen).join(", ");
+        let vergleiche: Vec<(Vergleich<'t>, T)> = varianten
+            .iter()
+            .map(|variante| (Vergleich::from(namen(variante)), variante.clone()))
+            .collect();
+        Argumente::wert(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            NonEmpty::from_vec(varianten),
+            move |os_string| {
+                let string = os_string.into_string().map_err(ParseFehler::InvaliderString)?;
+                vergleiche
+                    .iter()
+                    .find_map(|(vergleich, variante)| {
+                        vergleich.eq(&string).then(|| variante.clone())
+                    })
+                    .ok_or_else(|| {
+                        ParseFehler::ParseFehler(format!(
+                            "{wert_nicht_erlaubt}: \"{string}\" ∉ {{{erlaubt}}}"
+                        ))
+                    })
+            },
+            namen,
+        )
+    }
+
+    /// Create a value-argument for an [EnumArgument],
+    /// whose variants are given using `names`.
+    ///
+    /// In contrast to [value_enum](Arguments::value_enum), [EnumArgument::parse_enum]
+    /// is not used, both parsing and help text use `names`.
+    /// Unknown names produce an error starting with `value_not_allowed`,
+    /// listing all allowed names.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_enum_mit_namen](Argumente::wert_enum_mit_namen)
+    #[inline(always)]
+    pub fn value_enum_with_names(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        names: impl Fn(&T) -> String,
+        value_not_allowed: &'t str,
+    ) -> Arguments<'t, T, String> {
+        Argumente::wert_enum_mit_namen(description, value_infix, meta_var, names, value_not_allowed)
+    }
+}
+
 impl<'t, T> Argumente<'t, T, String>
 where
     T: 't + Display + Clone + FromStr,
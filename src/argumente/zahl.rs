@@ -0,0 +1,186 @@
+//! Wert-Argumente für Zahlen mit konfigurierbarem Dezimal- und Gruppierungs-Trennzeichen.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    argumente::{Argumente, Arguments},
+    beschreibung::{Beschreibung, Description},
+    ergebnis::ParseFehler,
+    sprache::{Language, Sprache},
+    unicode::{Compare, Vergleich},
+};
+
+/// Trennzeichen beim Angeben einer Zahl.
+///
+/// ## English synonym
+/// [NumberFormat]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Zahlenformat {
+    /// Trennzeichen zwischen ganzzahligem und gebrochenem Teil.
+    ///
+    /// ## English
+    /// Separator between the integer and fractional part.
+    pub dezimal: char,
+
+    /// Optionales Trennzeichen zur Gruppierung der Ziffern des ganzzahligen Teils
+    /// in Gruppen aus drei Ziffern, wird beim Parsen entfernt.
+    ///
+    /// ## English
+    /// Optional separator to group the digits of the integer part in groups of three,
+    /// removed while parsing.
+    pub gruppierung: Option<char>,
+}
+
+/// Separators when writing a number.
+///
+/// ## Deutsches Synonym
+/// [Zahlenformat]
+pub type NumberFormat = Zahlenformat;
+
+impl Zahlenformat {
+    /// Format der [FromStr]-Implementierung: `.` als Dezimal-Trennzeichen, keine Gruppierung.
+    pub const STANDARD: Zahlenformat = Zahlenformat { dezimal: '.', gruppierung: None };
+
+    /// Format of the [FromStr] implementation: `.` as decimal separator, no grouping.
+    pub const DEFAULT: NumberFormat = Zahlenformat::STANDARD;
+
+    /// Deutsches Format: `,` als Dezimal-Trennzeichen, `.` zur Gruppierung (z.B. `1.000,5`).
+    pub const DEUTSCH: Zahlenformat = Zahlenformat { dezimal: ',', gruppierung: Some('.') };
+
+    /// German format: `,` as decimal separator, `.` for grouping (e.g. `1.000,5`).
+    pub const GERMAN: NumberFormat = Zahlenformat::DEUTSCH;
+
+    /// Entferne Gruppierungs-Trennzeichen und ersetze das Dezimal-Trennzeichen durch `.`.
+    ///
+    /// Gruppierungs-Trennzeichen sind nur im ganzzahligen Teil zwischen Gruppen aus drei Ziffern
+    /// erlaubt (z.B. `1.000.000`), ansonsten wird ein Fehler zurückgegeben.
+    /// Dadurch wird z.B. `1.5` mit [Zahlenformat::DEUTSCH] nicht stillschweigend zu `15`.
+    fn normalisiere(&self, string: &str) -> Result<String, String> {
+        let Zahlenformat { dezimal, gruppierung } = *self;
+        if let Some(gruppierung) = gruppierung {
+            let (ganzzahlig, gebrochen) = match string.split_once(dezimal) {
+                Some((ganzzahlig, gebrochen)) => (ganzzahlig, Some(gebrochen)),
+                None => (string, None),
+            };
+            let ohne_vorzeichen = ganzzahlig.trim_start_matches(['+', '-']);
+            let mut gruppen = ohne_vorzeichen.split(gruppierung);
+            let erste_gruppe = gruppen.next().unwrap_or_default().chars().count();
+            let gültig = !gebrochen.is_some_and(|gebrochen| gebrochen.contains(gruppierung))
+                && (!ohne_vorzeichen.contains(gruppierung)
+                    || ((1..=3).contains(&erste_gruppe)
+                        && gruppen.all(|gruppe| gruppe.chars().count() == 3)));
+            if !gültig {
+                return Err(format!("Ungültige Gruppierung mit '{gruppierung}': {string}"));
+            }
+        }
+        Ok(string
+            .chars()
+            .filter(|c| Some(*c) != gruppierung)
+            .map(|c| if c == dezimal { '.' } else { c })
+            .collect())
+    }
+
+    /// Ersetze das Dezimal-Trennzeichen `.` der [Display]-Implementierung.
+    fn formatiere(&self, string: &str) -> String {
+        let Zahlenformat { dezimal, gruppierung: _ } = *self;
+        string.chars().map(|c| if c == '.' { dezimal } else { c }).collect()
+    }
+}
+
+impl Default for Zahlenformat {
+    fn default() -> Self {
+        Zahlenformat::STANDARD
+    }
+}
+
+impl<'t, T> Argumente<'t, T, String>
+where
+    T: 't + Display + Clone + FromStr,
+    T::Err: Display,
+{
+    /// Erzeuge ein Wert-Argument für eine Zahl im übergebenen [Zahlenformat].
+    ///
+    /// ## English synonym
+    /// [value_number_with_locale_and_language](Arguments::value_number_with_locale_and_language)
+    #[inline(always)]
+    pub fn wert_zahl_mit_locale_und_sprache(
+        beschreibung: Beschreibung<'t, T>,
+        zahlenformat: Zahlenformat,
+        sprache: Sprache,
+    ) -> Argumente<'t, T, String> {
+        Argumente::wert_zahl_mit_locale(
+            beschreibung,
+            sprache.wert_infix,
+            sprache.meta_var,
+            zahlenformat,
+        )
+    }
+
+    /// Create a value-argument for a number in the given [NumberFormat].
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_zahl_mit_locale_und_sprache](Argumente::wert_zahl_mit_locale_und_sprache)
+    #[inline(always)]
+    pub fn value_number_with_locale_and_language(
+        description: Description<'t, T>,
+        number_format: NumberFormat,
+        language: Language,
+    ) -> Arguments<'t, T, String> {
+        Argumente::wert_zahl_mit_locale_und_sprache(description, number_format, language)
+    }
+
+    /// Erzeuge ein Wert-Argument für eine Zahl im übergebenen [Zahlenformat].
+    ///
+    /// Vor dem Parsen über die [FromStr]-Implementierung werden Gruppierungs-Trennzeichen
+    /// entfernt und das Dezimal-Trennzeichen durch `.` ersetzt,
+    /// z.B. wird `1.000,5` mit [Zahlenformat::DEUTSCH] zu `1000.5`.
+    /// Gruppierungs-Trennzeichen außerhalb von Gruppen aus drei Ziffern im ganzzahligen Teil
+    /// (z.B. `1.5`) führen zu einem Fehler.
+    /// Im Hilfe-Text wird der Standard-Wert mit dem Dezimal-Trennzeichen angezeigt.
+    ///
+    /// ## English synonym
+    /// [value_number_with_locale](Arguments::value_number_with_locale)
+    pub fn wert_zahl_mit_locale(
+        beschreibung: Beschreibung<'t, T>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        zahlenformat: Zahlenformat,
+    ) -> Argumente<'t, T, String> {
+        Argumente::wert(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            None,
+            move |os_string| {
+                let string = os_string.into_string().map_err(ParseFehler::InvaliderString)?;
+                zahlenformat
+                    .normalisiere(&string)
+                    .map_err(ParseFehler::ParseFehler)?
+                    .parse()
+                    .map_err(|fehler: T::Err| ParseFehler::ParseFehler(fehler.to_string()))
+            },
+            move |wert| zahlenformat.formatiere(&wert.to_string()),
+        )
+    }
+
+    /// Create a value-argument for a number in the given [NumberFormat].
+    ///
+    /// Before parsing with the [FromStr] implementation, grouping separators are removed
+    /// and the decimal separator is replaced by `.`,
+    /// e.g. `1.000,5` becomes `1000.5` with [NumberFormat::GERMAN].
+    /// Grouping separators outside of groups of three digits in the integer part
+    /// (e.g. `1.5`) result in an error.
+    /// The help text shows the default value with the decimal separator.
+    ///
+    /// ## Deutsches Synonym
+    /// [wert_zahl_mit_locale](Argumente::wert_zahl_mit_locale)
+    #[inline(always)]
+    pub fn value_number_with_locale(
+        description: Description<'t, T>,
+        value_infix: impl Into<Compare<'t>>,
+        meta_var: &'t str,
+        number_format: NumberFormat,
+    ) -> Arguments<'t, T, String> {
+        Argumente::wert_zahl_mit_locale(description, value_infix, meta_var, number_format)
+    }
+}
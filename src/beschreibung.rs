@@ -1,8 +1,14 @@
 //! Beschreibung eines Arguments.
 
-use std::{convert::AsRef, fmt::Display};
+use std::{
+    borrow::Cow,
+    convert::AsRef,
+    fmt::{self, Debug, Display, Formatter},
+};
 
+use itertools::Itertools;
 use nonempty::NonEmpty;
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
     sprache::{Language, Sprache},
@@ -16,9 +22,13 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct Beschreibung<'t, T> {
     /// Präfix vor dem LangNamen.
+    /// Ein leeres Präfix erlaubt Argumente als einfache Wörter (z.B. `start`),
+    /// die nur bei exakt übereinstimmendem Namen erkannt werden.
     ///
     /// ## English
     /// Prefix before the long name.
+    /// An empty prefix allows arguments as bare words (e.g. `start`),
+    /// which are only matched if the name is exactly equal.
     pub lang_präfix: Vergleich<'t>,
 
     /// Voller Name, wird nach `lang_präfix` angegeben.
@@ -28,22 +38,26 @@ pub struct Beschreibung<'t, T> {
     pub lang: NonEmpty<Vergleich<'t>>,
 
     /// Präfix vor dem KurzNamen.
+    /// Bei leerem Präfix werden KurzNamen nicht miteinander verschmolzen.
     ///
     /// ## English
     /// Prefix before the short name.
+    /// With an empty prefix, short names are not merged.
     pub kurz_präfix: Vergleich<'t>,
 
     /// Kurzer Name, wird nach `kurz_präfix` angegeben.
     /// Bei Flag-Argumenten können KurzNamen mit identischen `kurz_präfix` zusammen angegeben werden,
     /// zum Beispiel "-fgh".
     /// Kurznamen länger als ein [Grapheme](unicode_segmentation::UnicodeSegmentation::graphemes)
-    /// werden nicht unterstützt.
+    /// werden nur vollständig erkannt und können nicht mit anderen Kurznamen verschmolzen werden
+    /// (siehe [neu_mit_mehrteiligen_kurznamen](Beschreibung::neu_mit_mehrteiligen_kurznamen)).
     ///
     /// ## English
     /// Short name, given after `short_präfix`.
     /// Flag arguments with identical `kurz_präfix` may be given at once, e.g. "-fgh".
     /// Short names longer than a [Grapheme](unicode_segmentation::UnicodeSegmentation::graphemes)
-    /// are not supported.
+    /// are only matched whole and can't be merged with other short names
+    /// (see [new_with_multi_grapheme_short_names](Description::new_with_multi_grapheme_short_names)).
     pub kurz: Vec<Vergleich<'t>>,
 
     /// Im automatischen Hilfetext angezeigte Beschreibung.
@@ -260,6 +274,9 @@ impl<'t, S: AsRef<str>> KurzNamen<'t> for &'t Vec<S> {
 impl<'t, T> Beschreibung<'t, T> {
     /// Erzeuge eine neue [Beschreibung].
     ///
+    /// Mit aktivierten `debug_assertions` wird eine panic ausgelöst,
+    /// wenn ein Kurzname nicht aus genau einem Grapheme besteht oder doppelt vorkommt.
+    ///
     /// ## English synonym
     /// [new](Description::new)
     pub fn neu(
@@ -270,11 +287,22 @@ impl<'t, T> Beschreibung<'t, T> {
         hilfe: Option<&'t str>,
         standard: Option<T>,
     ) -> Beschreibung<'t, T> {
+        let kurz = kurz.kurz_namen();
+        debug_assert!(
+            kurz.iter().all(|name| name.string.as_ref().graphemes(true).exactly_one().is_ok()),
+            "Kurznamen müssen aus genau einem Grapheme bestehen: {:?}",
+            kurz.iter().map(|name| name.string.as_ref()).collect::<Vec<_>>()
+        );
+        debug_assert!(
+            kurz.iter().map(|name| name.string.as_ref()).all_unique(),
+            "Doppelte Kurznamen: {:?}",
+            kurz.iter().map(|name| name.string.as_ref()).collect::<Vec<_>>()
+        );
         Beschreibung {
             lang_präfix: lang_präfix.into(),
             lang: lang.lang_namen(),
             kurz_präfix: kurz_präfix.into(),
-            kurz: kurz.kurz_namen(),
+            kurz,
             hilfe,
             standard,
         }
@@ -282,6 +310,9 @@ impl<'t, T> Beschreibung<'t, T> {
 
     /// Create a new [Description].
     ///
+    /// With active `debug_assertions`, this panics
+    /// if a short name doesn't consist of exactly one grapheme, or is duplicated.
+    ///
     /// ## Deutsches Synonym
     /// [neu](Beschreibung::neu)
     #[inline(always)]
@@ -296,6 +327,68 @@ impl<'t, T> Beschreibung<'t, T> {
         Beschreibung::neu(long_prefix, long, short_prefix, short, help, default)
     }
 
+    /// Erzeuge eine neue [Beschreibung], deren Kurznamen aus mehreren Graphemes bestehen dürfen.
+    ///
+    /// Solche Kurznamen werden nur vollständig erkannt, z.B. `+rw` für den Kurznamen `rw`.
+    /// Ein Wert muss entweder als nächstes Argument, oder nach dem `wert_infix` angegeben werden.
+    ///
+    /// Mit aktivierten `debug_assertions` wird eine panic ausgelöst,
+    /// wenn ein Kurzname doppelt vorkommt.
+    ///
+    /// ## English synonym
+    /// [new_with_multi_grapheme_short_names](Description::new_with_multi_grapheme_short_names)
+    pub fn neu_mit_mehrteiligen_kurznamen(
+        lang_präfix: impl Into<Vergleich<'t>>,
+        lang: impl LangNamen<'t>,
+        kurz_präfix: impl Into<Vergleich<'t>>,
+        kurz: impl KurzNamen<'t>,
+        hilfe: Option<&'t str>,
+        standard: Option<T>,
+    ) -> Beschreibung<'t, T> {
+        let kurz = kurz.kurz_namen();
+        debug_assert!(
+            kurz.iter().map(|name| name.string.as_ref()).all_unique(),
+            "Doppelte Kurznamen: {:?}",
+            kurz.iter().map(|name| name.string.as_ref()).collect::<Vec<_>>()
+        );
+        Beschreibung {
+            lang_präfix: lang_präfix.into(),
+            lang: lang.lang_namen(),
+            kurz_präfix: kurz_präfix.into(),
+            kurz,
+            hilfe,
+            standard,
+        }
+    }
+
+    /// Create a new [Description], whose short names may consist of multiple graphemes.
+    ///
+    /// Such short names are only matched whole, e.g. `+rw` for the short name `rw`.
+    /// A value must be given either as the next argument, or after the `value_infix`.
+    ///
+    /// With active `debug_assertions`, this panics if a short name is duplicated.
+    ///
+    /// ## Deutsches Synonym
+    /// [neu_mit_mehrteiligen_kurznamen](Beschreibung::neu_mit_mehrteiligen_kurznamen)
+    #[inline(always)]
+    pub fn new_with_multi_grapheme_short_names(
+        long_prefix: Compare<'t>,
+        long: impl LangNamen<'t>,
+        short_prefix: Compare<'t>,
+        short: impl KurzNamen<'t>,
+        help: Option<&'t str>,
+        default: Option<T>,
+    ) -> Description<'t, T> {
+        Beschreibung::neu_mit_mehrteiligen_kurznamen(
+            long_prefix,
+            long,
+            short_prefix,
+            short,
+            help,
+            default,
+        )
+    }
+
     /// Erzeuge eine neue [Beschreibung].
     ///
     /// ## English synonym
@@ -351,6 +444,38 @@ pub enum Konfiguration<'t> {
         /// Prefix and following infix to invert the flag argument.
         /// The value is [None] if it is a flag causing an early exit.
         invertiere_präfix_infix: Option<(Vergleich<'t>, Vergleich<'t>)>,
+
+        /// Präfixe zum Aktivieren und Deaktivieren des Flag-Arguments (z.B. `+x`/`-x`),
+        /// werden anstelle von `lang_präfix` und `kurz_präfix` verwendet.
+        ///
+        /// ## English
+        /// Prefixes to enable and disable the flag argument (e.g. `+x`/`-x`),
+        /// used instead of `lang_präfix` and `kurz_präfix`.
+        ein_aus_präfix: Option<(Vergleich<'t>, Vergleich<'t>)>,
+
+        /// Markierung einer veralteten Flag im Hilfe-Text (z.B. `Veraltet`).
+        /// Der Wert ist [None], wenn die Flag nicht veraltet ist.
+        ///
+        /// ## English
+        /// Marker of a deprecated flag in the help-text (e.g. `Deprecated`).
+        /// The value is [None], if the flag is not deprecated.
+        veraltet: Option<&'t str>,
+
+        /// Sortier-Schlüssel für die Reihenfolge im Hilfe-Text, kleinere Werte werden zuerst angezeigt.
+        /// Argumente mit gleichem Schlüssel behalten ihre ursprüngliche Reihenfolge.
+        ///
+        /// ## English
+        /// Sort key for the order in the help-text, smaller values are shown first.
+        /// Arguments with the same key keep their original order.
+        reihenfolge: i32,
+
+        /// Namen (Lang- oder Kurznamen) der `beschreibung`, die im Hilfe-Text nicht angezeigt werden.
+        /// Sie werden beim Parsen weiterhin erkannt.
+        ///
+        /// ## English
+        /// Names (long or short names) of the `beschreibung` not shown in the help-text.
+        /// They are still matched when parsing.
+        versteckte_namen: Vec<String>,
     },
 
     /// Es handelt sich um ein Wert-Argument.
@@ -370,6 +495,22 @@ pub enum Konfiguration<'t> {
         /// Infix to give a value in the same argument as the name.
         wert_infix: Vergleich<'t>,
 
+        /// Im Hilfe-Text anstelle des `wert_infix` angezeigter String.
+        /// Der Wert ist [None], wenn der `wert_infix` angezeigt wird.
+        ///
+        /// ## English
+        /// String shown instead of the `value_infix` in the help-text.
+        /// The value is [None], if the `value_infix` is shown.
+        wert_infix_anzeige: Option<&'t str>,
+
+        /// Ist der Wert geheim (z.B. ein Passwort), wird er im rekonstruierten Aufruf
+        /// und als Standard-Wert als `***` angezeigt.
+        ///
+        /// ## English
+        /// If the value is secret (e.g. a password), it is shown as `***`
+        /// in the reconstructed invocation and as default value.
+        geheim: bool,
+
         /// Meta-Variable im Hilfe-Text.
         ///
         /// ## English
@@ -380,7 +521,23 @@ pub enum Konfiguration<'t> {
         ///
         /// ## English
         /// String-representation of the allowed values.
-        mögliche_werte: Option<NonEmpty<String>>,
+        mögliche_werte: Option<MöglicheWerte<'t>>,
+
+        /// Sortier-Schlüssel für die Reihenfolge im Hilfe-Text, kleinere Werte werden zuerst angezeigt.
+        /// Argumente mit gleichem Schlüssel behalten ihre ursprüngliche Reihenfolge.
+        ///
+        /// ## English
+        /// Sort key for the order in the help-text, smaller values are shown first.
+        /// Arguments with the same key keep their original order.
+        reihenfolge: i32,
+
+        /// Namen (Lang- oder Kurznamen) der `beschreibung`, die im Hilfe-Text nicht angezeigt werden.
+        /// Sie werden beim Parsen weiterhin erkannt.
+        ///
+        /// ## English
+        /// Names (long or short names) of the `beschreibung` not shown in the help-text.
+        /// They are still matched when parsing.
+        versteckte_namen: Vec<String>,
     },
 }
 
@@ -389,3 +546,62 @@ pub enum Konfiguration<'t> {
 /// ## Deutsches Synonym
 /// [Konfiguration]
 pub type Configuration<'t> = Konfiguration<'t>;
+
+/// String-Darstellung der erlaubten Werte eines Wert-Arguments.
+///
+/// ## English synonym
+/// [PossibleValues]
+pub enum MöglicheWerte<'t> {
+    /// Beim Erstellen des Arguments festgelegte Werte.
+    ///
+    /// ## English
+    /// Values fixed when creating the argument.
+    Fest(NonEmpty<String>),
+
+    /// Erst beim Erstellen des Hilfe-Textes bestimmte Werte.
+    ///
+    /// ## English
+    /// Values determined when creating the help text.
+    Dynamisch(Box<dyn 't + Fn() -> Vec<String>>),
+}
+
+/// String-representation of the allowed values of a value argument.
+///
+/// ## Deutsches Synonym
+/// [MöglicheWerte]
+pub type PossibleValues<'t> = MöglicheWerte<'t>;
+
+impl Debug for MöglicheWerte<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MöglicheWerte::Fest(werte) => f.debug_tuple("Fest").field(werte).finish(),
+            MöglicheWerte::Dynamisch(_werte_fn) => {
+                f.debug_tuple("Dynamisch").field(&"<function>").finish()
+            },
+        }
+    }
+}
+
+impl MöglicheWerte<'_> {
+    /// Bestimme die aktuellen Werte.
+    /// Für [MöglicheWerte::Dynamisch] wird dazu die gespeicherte Funktion aufgerufen.
+    ///
+    /// ## English synonym
+    /// [values](PossibleValues::values)
+    pub fn werte(&self) -> Option<Cow<'_, NonEmpty<String>>> {
+        match self {
+            MöglicheWerte::Fest(werte) => Some(Cow::Borrowed(werte)),
+            MöglicheWerte::Dynamisch(werte_fn) => NonEmpty::from_vec(werte_fn()).map(Cow::Owned),
+        }
+    }
+
+    /// Determine the current values.
+    /// For [PossibleValues::Dynamisch] the stored function is called.
+    ///
+    /// ## Deutsches Synonym
+    /// [werte](MöglicheWerte::werte)
+    #[inline(always)]
+    pub fn values(&self) -> Option<Cow<'_, NonEmpty<String>>> {
+        self.werte()
+    }
+}
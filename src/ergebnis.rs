@@ -60,8 +60,62 @@ impl<'t, T, E> Ergebnis<'t, T, E> {
     pub fn convert<S>(self, f: impl FnOnce(T) -> S) -> Ergebnis<'t, S, E> {
         self.konvertiere(f)
     }
+
+    /// Konvertiere den Fehler-Typ aller Fehler mit der spezifizierten Funktion.
+    ///
+    /// ## English synonym
+    /// [convert_error](Result::convert_error)
+    pub fn konvertiere_fehler<F>(self, f: impl Fn(E) -> F) -> Ergebnis<'t, T, F> {
+        match self {
+            Ergebnis::Wert(t) => Ergebnis::Wert(t),
+            Ergebnis::FrühesBeenden(nachrichten) => Ergebnis::FrühesBeenden(nachrichten),
+            Ergebnis::Fehler(fehler) => {
+                Ergebnis::Fehler(fehler.map(|fehler| fehler.konvertiere(&f)))
+            },
+        }
+    }
+
+    /// Convert the error type of all errors using the specified function.
+    ///
+    /// ## Deutsches Synonym
+    /// [konvertiere_fehler](Ergebnis::konvertiere_fehler)
+    #[inline(always)]
+    pub fn convert_error<F>(self, f: impl Fn(E) -> F) -> Ergebnis<'t, T, F> {
+        self.konvertiere_fehler(f)
+    }
 }
 
+/// Grund, warum beim Parsen kein Wert erzeugt wurde.
+///
+/// ## English synonym
+/// [ParseAbort]
+#[derive(Debug)]
+pub enum ParseAbbruch<'t, E> {
+    /// Frühes Beenden durch zeigen der Nachrichten gewünscht.
+    /// Die Nachrichten wurden nicht ausgegeben.
+    ///
+    /// ## English
+    /// Request an early exit, showing the given messages.
+    /// The messages were not printed.
+    FrühesBeenden(NonEmpty<Cow<'t, str>>),
+    /// Fehler beim Parsen der Kommandozeilen-Argumente.
+    ///
+    /// ## English
+    /// Error while parsing command line arguments.
+    Fehler(NonEmpty<Fehler<'t, E>>),
+    /// Nicht alle Kommandozeilen-Argumente wurden verwendet.
+    ///
+    /// ## English
+    /// Not all command line arguments were used.
+    NichtVerwendet(NonEmpty<OsString>),
+}
+
+/// Reason, why no value was created while parsing.
+///
+/// ## Deutsches Synonym
+/// [ParseAbbruch]
+pub type ParseAbort<'t, E> = ParseAbbruch<'t, E>;
+
 /// Alle Namen eines Arguments.
 ///
 /// ## English synonym
@@ -127,6 +181,12 @@ pub enum Fehler<'t, E> {
         /// ## English
         /// Infix following the prefix to invert the flag argument.
         invertiere_infix: Normalisiert<'t>,
+
+        /// Angepasste Fehlermeldung, siehe [fehlermeldung_fehlend](crate::Argumente::fehlermeldung_fehlend).
+        ///
+        /// ## English
+        /// Custom error message, see [missing_error_message](crate::Arguments::missing_error_message).
+        fehlermeldung: Option<&'t str>,
     },
     /// Ein benötigtes Wert-Argument wurde nicht genannt.
     ///
@@ -150,6 +210,20 @@ pub enum Fehler<'t, E> {
         /// ## English
         /// Used Meta-variable of the value.
         meta_var: &'t str,
+
+        /// Der Name wurde genannt, es folgte aber kein Wert (z.B. `--name` als letztes Argument).
+        ///
+        /// ## English
+        /// The name was given, but no value followed (e.g. `--name` as the last argument).
+        genannt: bool,
+
+        /// Angepasste Fehlermeldung, siehe [fehlermeldung_fehlend](crate::Argumente::fehlermeldung_fehlend).
+        /// Wird nur verwendet, wenn das Argument nicht genannt wurde.
+        ///
+        /// ## English
+        /// Custom error message, see [missing_error_message](crate::Arguments::missing_error_message).
+        /// Only used if the argument was not given.
+        fehlermeldung: Option<&'t str>,
     },
     /// Fehler beim Parsen des genannten Wertes.
     ///
@@ -180,6 +254,99 @@ pub enum Fehler<'t, E> {
         /// Reported error from parsing.
         fehler: ParseFehler<E>,
     },
+    /// Ein Wert-Argument wurde öfter als erlaubt genannt.
+    ///
+    /// ## English
+    /// A value argument was given more often than allowed.
+    ZuVieleWerte {
+        /// Alle Namen des Wert-Arguments.
+        ///
+        /// ## English
+        /// All names of the value argument.
+        namen: Namen<'t>,
+
+        /// Infix um einen Wert im selben Argument wie den Namen anzugeben.
+        ///
+        /// ## English
+        /// Infix to give a value in the same argument as the name.
+        wert_infix: Normalisiert<'t>,
+
+        /// Verwendete Meta-Variable für den Wert.
+        ///
+        /// ## English
+        /// Used Meta-variable of the value.
+        meta_var: &'t str,
+
+        /// Maximale Anzahl an Werten.
+        ///
+        /// ## English
+        /// Maximum number of values.
+        maximum: usize,
+
+        /// Beschreibung des Fehlers in der Fehlermeldung.
+        ///
+        /// ## English
+        /// Description of the error in the error message.
+        zu_viele_werte: &'t str,
+    },
+    /// Ein Wert-Argument, das nur einmal genannt werden darf, wurde mehrfach genannt.
+    ///
+    /// ## English
+    /// A value argument, which may only be given once, was given multiple times.
+    MehrfachGenannt {
+        /// Alle Namen des Wert-Arguments.
+        ///
+        /// ## English
+        /// All names of the value argument.
+        namen: Namen<'t>,
+
+        /// Infix um einen Wert im selben Argument wie den Namen anzugeben.
+        ///
+        /// ## English
+        /// Infix to give a value in the same argument as the name.
+        wert_infix: Normalisiert<'t>,
+
+        /// Verwendete Meta-Variable für den Wert.
+        ///
+        /// ## English
+        /// Used Meta-variable of the value.
+        meta_var: &'t str,
+
+        /// Beschreibung des Fehlers in der Fehlermeldung.
+        ///
+        /// ## English
+        /// Description of the error in the error message.
+        mehrfach_genannt: &'t str,
+    },
+    /// Eine Flag wurde sowohl aktiviert, als auch deaktiviert.
+    ///
+    /// ## English
+    /// A flag was both enabled and disabled.
+    WidersprüchlicheFlags {
+        /// Alle Namen des Flag-Arguments.
+        ///
+        /// ## English
+        /// All names of the flag argument.
+        namen: Namen<'t>,
+
+        /// Präfix zum invertieren des Flag-Arguments.
+        ///
+        /// ## English
+        /// Prefix to invert the flag argument.
+        invertiere_präfix: Normalisiert<'t>,
+
+        /// Auf Präfix folgendes Infix zum invertieren des Flag-Arguments.
+        ///
+        /// ## English
+        /// Infix following the prefix to invert the flag argument.
+        invertiere_infix: Normalisiert<'t>,
+
+        /// Beschreibung des Fehlers in der Fehlermeldung.
+        ///
+        /// ## English
+        /// Description of the error in the error message.
+        widersprüchliche_flags: &'t str,
+    },
 }
 
 /// Possible errors when parsing command line arguments.
@@ -188,6 +355,76 @@ pub enum Fehler<'t, E> {
 /// [Fehler]
 pub type Error<'t, E> = Fehler<'t, E>;
 
+impl<'t, E> Fehler<'t, E> {
+    /// Alle Namen des Arguments, das den Fehler erzeugt hat.
+    ///
+    /// ## English synonym
+    /// [names](Error::names)
+    pub fn namen(&self) -> &Namen<'t> {
+        match self {
+            Fehler::FehlendeFlag { namen, .. }
+            | Fehler::FehlenderWert { namen, .. }
+            | Fehler::Fehler { namen, .. }
+            | Fehler::ZuVieleWerte { namen, .. }
+            | Fehler::MehrfachGenannt { namen, .. }
+            | Fehler::WidersprüchlicheFlags { namen, .. } => namen,
+        }
+    }
+
+    /// All names of the argument that produced the error.
+    ///
+    /// ## Deutsches Synonym
+    /// [namen](Fehler::namen)
+    #[inline(always)]
+    pub fn names(&self) -> &Names<'t> {
+        self.namen()
+    }
+
+    /// Konvertiere den Fehler-Typ mit der spezifizierten Funktion.
+    ///
+    /// ## English synonym
+    /// [convert](Error::convert)
+    pub fn konvertiere<F>(self, f: impl FnOnce(E) -> F) -> Fehler<'t, F> {
+        match self {
+            Fehler::FehlendeFlag { namen, invertiere_präfix, invertiere_infix, fehlermeldung } => {
+                Fehler::FehlendeFlag { namen, invertiere_präfix, invertiere_infix, fehlermeldung }
+            },
+            Fehler::FehlenderWert { namen, wert_infix, meta_var, genannt, fehlermeldung } => {
+                Fehler::FehlenderWert { namen, wert_infix, meta_var, genannt, fehlermeldung }
+            },
+            Fehler::Fehler { namen, wert_infix, meta_var, fehler } => {
+                Fehler::Fehler { namen, wert_infix, meta_var, fehler: fehler.konvertiere(f) }
+            },
+            Fehler::ZuVieleWerte { namen, wert_infix, meta_var, maximum, zu_viele_werte } => {
+                Fehler::ZuVieleWerte { namen, wert_infix, meta_var, maximum, zu_viele_werte }
+            },
+            Fehler::MehrfachGenannt { namen, wert_infix, meta_var, mehrfach_genannt } => {
+                Fehler::MehrfachGenannt { namen, wert_infix, meta_var, mehrfach_genannt }
+            },
+            Fehler::WidersprüchlicheFlags {
+                namen,
+                invertiere_präfix,
+                invertiere_infix,
+                widersprüchliche_flags,
+            } => Fehler::WidersprüchlicheFlags {
+                namen,
+                invertiere_präfix,
+                invertiere_infix,
+                widersprüchliche_flags,
+            },
+        }
+    }
+
+    /// Convert the error type using the specified function.
+    ///
+    /// ## Deutsches Synonym
+    /// [konvertiere](Fehler::konvertiere)
+    #[inline(always)]
+    pub fn convert<F>(self, f: impl FnOnce(E) -> F) -> Error<'t, F> {
+        self.konvertiere(f)
+    }
+}
+
 pub(crate) fn namen_regex_hinzufügen<S: AsRef<str>>(string: &mut String, head: &S, tail: &[S]) {
     if !tail.is_empty() {
         string.push('(')
@@ -231,6 +468,28 @@ pub enum ParseFehler<E> {
 /// [ParseFehler]
 pub type ParseError<E> = ParseFehler<E>;
 
+impl<E> ParseFehler<E> {
+    /// Konvertiere den Fehler-Typ mit der spezifizierten Funktion.
+    ///
+    /// ## English synonym
+    /// [convert](ParseError::convert)
+    pub fn konvertiere<F>(self, f: impl FnOnce(E) -> F) -> ParseFehler<F> {
+        match self {
+            ParseFehler::InvaliderString(os_string) => ParseFehler::InvaliderString(os_string),
+            ParseFehler::ParseFehler(fehler) => ParseFehler::ParseFehler(f(fehler)),
+        }
+    }
+
+    /// Convert the error type using the specified function.
+    ///
+    /// ## Deutsches Synonym
+    /// [konvertiere](ParseFehler::konvertiere)
+    #[inline(always)]
+    pub fn convert<F>(self, f: impl FnOnce(E) -> F) -> ParseError<F> {
+        self.konvertiere(f)
+    }
+}
+
 impl<E: Display> Fehler<'_, E> {
     /// Zeige den Fehler in Menschen-lesbarer Form an.
     ///
@@ -296,10 +555,15 @@ impl<E: Display> Fehler<'_, E> {
             fehlermeldung.push_str(lang_präfix.as_ref());
             match flag_oder_wert {
                 Either::Left((invertiere_präfix, invertiere_infix)) => {
-                    fehlermeldung.push('[');
-                    fehlermeldung.push_str(invertiere_präfix.as_ref());
-                    fehlermeldung.push_str(invertiere_infix.as_ref());
-                    fehlermeldung.push(']');
+                    // Flags mit Präfixen zum Aktivieren/Deaktivieren haben keine Invertierung.
+                    if !invertiere_präfix.as_ref().is_empty()
+                        || !invertiere_infix.as_ref().is_empty()
+                    {
+                        fehlermeldung.push('[');
+                        fehlermeldung.push_str(invertiere_präfix.as_ref());
+                        fehlermeldung.push_str(invertiere_infix.as_ref());
+                        fehlermeldung.push(']');
+                    }
                     namen_regex_hinzufügen(&mut fehlermeldung, &lang.head, &lang.tail);
                 },
                 Either::Right((wert_infix, meta_var)) => {
@@ -324,12 +588,21 @@ impl<E: Display> Fehler<'_, E> {
             fehlermeldung
         }
         match self {
-            Fehler::FehlendeFlag { namen, invertiere_präfix, invertiere_infix } => fehlermeldung(
+            Fehler::FehlendeFlag { fehlermeldung: Some(angepasst), .. }
+            | Fehler::FehlenderWert { fehlermeldung: Some(angepasst), .. } => {
+                (*angepasst).to_owned()
+            },
+            Fehler::FehlendeFlag {
+                namen,
+                invertiere_präfix,
+                invertiere_infix,
+                fehlermeldung: None,
+            } => fehlermeldung(
                 fehlende_flag,
                 namen,
                 Either::Left((invertiere_präfix, invertiere_infix)),
             ),
-            Fehler::FehlenderWert { namen, wert_infix, meta_var } => {
+            Fehler::FehlenderWert { namen, wert_infix, meta_var, fehlermeldung: None, .. } => {
                 fehlermeldung(fehlender_wert, namen, Either::Right((wert_infix, meta_var)))
             },
             Fehler::Fehler { namen, wert_infix, meta_var, fehler } => {
@@ -345,6 +618,26 @@ impl<E: Display> Fehler<'_, E> {
                 fehlermeldung.push_str(&fehler_anzeige);
                 fehlermeldung
             },
+            Fehler::ZuVieleWerte { namen, wert_infix, meta_var, maximum, zu_viele_werte } => {
+                fehlermeldung(
+                    &format!("{zu_viele_werte} (> {maximum})"),
+                    namen,
+                    Either::Right((wert_infix, meta_var)),
+                )
+            },
+            Fehler::MehrfachGenannt { namen, wert_infix, meta_var, mehrfach_genannt } => {
+                fehlermeldung(mehrfach_genannt, namen, Either::Right((wert_infix, meta_var)))
+            },
+            Fehler::WidersprüchlicheFlags {
+                namen,
+                invertiere_präfix,
+                invertiere_infix,
+                widersprüchliche_flags,
+            } => fehlermeldung(
+                widersprüchliche_flags,
+                namen,
+                Either::Left((invertiere_präfix, invertiere_infix)),
+            ),
         }
     }
 
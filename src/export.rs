@@ -0,0 +1,209 @@
+//! Export der Konfiguration in eine neutrale, nur lesbare Beschreibung.
+//!
+//! Die Struktur orientiert sich an der Introspektion von `clap` (Namen, Hilfe, benötigt),
+//! ohne von `clap` abzuhängen.
+
+use crate::{
+    argumente::Argumente,
+    beschreibung::{Beschreibung, Konfiguration, MöglicheWerte},
+};
+
+/// Neutrale Beschreibung eines Programms und seiner Kommandozeilen-Argumente.
+///
+/// ## English synonym
+/// [Command]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Befehl {
+    /// Name des Programms.
+    ///
+    /// ## English
+    /// Name of the program.
+    pub name: String,
+
+    /// Beschreibung des Programms.
+    ///
+    /// ## English
+    /// Description of the program.
+    pub über: Option<String>,
+
+    /// Version des Programms.
+    ///
+    /// ## English
+    /// Version of the program.
+    pub version: Option<String>,
+
+    /// Alle Kommandozeilen-Argumente in der Reihenfolge ihrer Konfiguration.
+    ///
+    /// ## English
+    /// All command line arguments in the order of their configuration.
+    pub argumente: Vec<Argument>,
+}
+
+/// Neutral description of a program and its command line arguments.
+///
+/// ## Deutsches Synonym
+/// [Befehl]
+pub type Command = Befehl;
+
+/// Neutrale Beschreibung eines Kommandozeilen-Arguments.
+///
+/// ## English synonym
+/// [Arg]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Argument {
+    /// Eindeutiger Bezeichner, der erste LangName.
+    ///
+    /// ## English
+    /// Unique identifier, the first long name.
+    pub id: String,
+
+    /// Präfix vor den LangNamen.
+    ///
+    /// ## English
+    /// Prefix before the long names.
+    pub lang_präfix: String,
+
+    /// Alle LangNamen.
+    ///
+    /// ## English
+    /// All long names.
+    pub lang: Vec<String>,
+
+    /// Präfix vor den KurzNamen.
+    ///
+    /// ## English
+    /// Prefix before the short names.
+    pub kurz_präfix: String,
+
+    /// Alle KurzNamen.
+    ///
+    /// ## English
+    /// All short names.
+    pub kurz: Vec<String>,
+
+    /// Im Hilfe-Text angezeigte Beschreibung.
+    ///
+    /// ## English
+    /// Description shown in the help text.
+    pub hilfe: Option<String>,
+
+    /// Muss das Argument angegeben werden?
+    ///
+    /// ## English
+    /// Is the argument required?
+    pub benötigt: bool,
+
+    /// Meta-Variable, falls das Argument einen Wert erwartet.
+    ///
+    /// ## English
+    /// Meta-variable, if the argument takes a value.
+    pub meta_var: Option<String>,
+
+    /// String-Darstellung der erlaubten Werte.
+    ///
+    /// ## English
+    /// String-representation of the allowed values.
+    pub mögliche_werte: Vec<String>,
+
+    /// String-Darstellung des Standard-Wertes.
+    ///
+    /// ## English
+    /// String-representation of the default value.
+    pub standard: Option<String>,
+}
+
+/// Neutral description of a command line argument.
+///
+/// ## Deutsches Synonym
+/// [Argument]
+pub type Arg = Argument;
+
+impl Argument {
+    fn neu(
+        beschreibung: &Beschreibung<'_, String>,
+        benötigt: bool,
+        meta_var: Option<String>,
+        mögliche_werte: Vec<String>,
+    ) -> Argument {
+        let Beschreibung { lang_präfix, lang, kurz_präfix, kurz, hilfe, standard } = beschreibung;
+        Argument {
+            id: lang.head.as_ref().to_owned(),
+            lang_präfix: lang_präfix.as_ref().to_owned(),
+            lang: lang.iter().map(|name| name.as_ref().to_owned()).collect(),
+            kurz_präfix: kurz_präfix.as_ref().to_owned(),
+            kurz: kurz.iter().map(|name| name.as_ref().to_owned()).collect(),
+            hilfe: hilfe.map(str::to_owned),
+            benötigt,
+            meta_var,
+            mögliche_werte,
+            standard: standard.clone(),
+        }
+    }
+}
+
+impl<'t> From<&Konfiguration<'t>> for Argument {
+    fn from(konfiguration: &Konfiguration<'t>) -> Self {
+        match konfiguration {
+            Konfiguration::Flag {
+                beschreibung, invertiere_präfix_infix, ein_aus_präfix, ..
+            } => {
+                // Flags ohne Invertierung führen zu frühem Beenden und sind nie benötigt.
+                let benötigt = beschreibung.standard.is_none()
+                    && (invertiere_präfix_infix.is_some() || ein_aus_präfix.is_some());
+                let mut argument = Argument::neu(beschreibung, benötigt, None, Vec::new());
+                if let Some((ein_präfix, _aus_präfix)) = ein_aus_präfix {
+                    argument.lang_präfix = ein_präfix.as_ref().to_owned();
+                    argument.kurz_präfix = ein_präfix.as_ref().to_owned();
+                }
+                argument
+            },
+            Konfiguration::Wert { beschreibung, meta_var, mögliche_werte, .. } => {
+                let mögliche_werte = mögliche_werte
+                    .as_ref()
+                    .and_then(MöglicheWerte::werte)
+                    .map(|werte| werte.iter().cloned().collect())
+                    .unwrap_or_default();
+                Argument::neu(
+                    beschreibung,
+                    beschreibung.standard.is_none(),
+                    Some((*meta_var).to_owned()),
+                    mögliche_werte,
+                )
+            },
+        }
+    }
+}
+
+impl<T, E> Argumente<'_, T, E> {
+    /// Exportiere die Konfiguration aller Argumente in eine neutrale Beschreibung.
+    ///
+    /// ## English synonym
+    /// [export](Argumente::export)
+    pub fn exportiere(
+        &self,
+        programm_name: &str,
+        programm_beschreibung: Option<&str>,
+        version: Option<&str>,
+    ) -> Befehl {
+        Befehl {
+            name: programm_name.to_owned(),
+            über: programm_beschreibung.map(str::to_owned),
+            version: version.map(str::to_owned),
+            argumente: self.konfigurationen().map(Argument::from).collect(),
+        }
+    }
+
+    /// Export the configuration of all arguments into a neutral description.
+    ///
+    /// ## Deutsches Synonym
+    /// [exportiere](Argumente::exportiere)
+    #[inline(always)]
+    pub fn export(
+        &self,
+        program_name: &str,
+        program_description: Option<&str>,
+        version: Option<&str>,
+    ) -> Command {
+        self.exportiere(program_name, program_description, version)
+    }
+}
@@ -34,9 +34,15 @@
 // Verwende doc_cfg für bessere Dokumentation von feature-gated derive Macros.
 #![cfg_attr(all(doc, not(doctest)), feature(doc_cfg))]
 
+#[doc(no_inline)]
+pub use either::Either;
 #[doc(no_inline)]
 pub use nonempty::NonEmpty;
 
+// Nur in den Tests zu Fehlermeldungen des derive-Macros verwendet.
+#[cfg(test)]
+use trybuild as _;
+
 #[macro_export]
 /// Crate Name spezifiziert in Cargo.toml.
 ///
@@ -62,6 +68,9 @@ macro_rules! crate_version {
 pub mod argumente;
 pub mod beschreibung;
 pub mod ergebnis;
+#[cfg(any(feature = "export", all(doc, not(doctest))))]
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "export")))]
+pub mod export;
 pub mod parse;
 pub mod sprache;
 pub mod unicode;
@@ -69,10 +78,37 @@ pub mod unicode;
 #[doc(inline)]
 #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "derive")))]
 pub use self::{
-    argumente::{wert::EnumArgument, Argumente, Arguments},
-    beschreibung::{Beschreibung, Configuration, Description, Konfiguration},
-    ergebnis::{Ergebnis, Error, Fehler, ParseError, ParseFehler, Result},
+    argumente::{
+        befehlszeile::{
+            split_command_line, zerlege_befehlszeile, OffenesAnführungszeichen, UnterminatedQuote,
+        },
+        frühes_beenden::{HelpLayout, HilfeLayout},
+        gemeinsam::{
+            parse_gemeinsam, parse_together, GemeinsamerParser, PreprocessingPart,
+            Vorverarbeitungsteil,
+        },
+        liste::{Duplicates, Duplikate, EmptyElements, LeereElemente, Mehrfach, Multiple},
+        prüfen::{CheckReport, Prüfbericht},
+        reconstruct_invocation, rekonstruiere_aufruf,
+        umgebung::{
+            erweitere_umgebungsvariablen, erweitere_umgebungsvariablen_mit_sprache,
+            expand_env_vars, expand_env_vars_with_language,
+        },
+        wert::{EnumArgument, Quelle, Source},
+        zahl::{NumberFormat, Zahlenformat},
+        AlleinstehenderKurzPräfix, Argumente, Arguments, LoneShortPrefix,
+    },
+    beschreibung::{
+        Beschreibung, Configuration, Description, Konfiguration, MöglicheWerte, PossibleValues,
+    },
+    ergebnis::{
+        Ergebnis, Error, Fehler, ParseAbbruch, ParseAbort, ParseError, ParseFehler, Result,
+    },
     parse::{Parse, ParseArgument},
     sprache::{Language, Sprache},
-    unicode::{Case, Compare, Normalisiert, Normalized, Vergleich},
+    unicode::{Case, Compare, Diacritics, Diakritika, Normalisiert, Normalized, Vergleich},
 };
+
+#[cfg(feature = "tracing")]
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "tracing")))]
+pub use self::argumente::protokoll::{LOG_TARGET, PROTOKOLL_TARGET};
@@ -1,13 +1,18 @@
 //! Trait für Typen, die aus Kommandozeilen-Argumenten geparst werden können.
 
-use std::{collections::HashMap, ffi::OsString, fmt::Display, num::NonZeroI32, str::FromStr};
+use std::{
+    collections::HashMap, ffi::OsString, fmt::Display, num::NonZeroI32, path::PathBuf, str::FromStr,
+};
 
 use nonempty::NonEmpty;
 
 use crate::{
-    argumente::{wert::EnumArgument, Argumente, Arguments},
+    argumente::{
+        nachsichtig::nachsichtiger_standard, wert::EnumArgument, Argumente, Arguments,
+        ParseEinstellungen,
+    },
     beschreibung::{Beschreibung, Description, Konfiguration},
-    ergebnis::{Ergebnis, Error, Fehler, ParseFehler},
+    ergebnis::{Ergebnis, Error, Fehler, ParseAbbruch, ParseAbort, ParseFehler},
     sprache::{Language, Sprache},
     unicode::Vergleich,
 };
@@ -40,12 +45,76 @@ pub trait ParseArgument: Sized {
         meta_var: &'t str,
     ) -> Argumente<'t, Self, String>;
 
+    /// Erstelle ein [Argumente] mit den konfigurierten Eigenschaften,
+    /// wobei weitere Einstellungen aus der `sprache` übernommen werden können,
+    /// z.B. die Schreibweisen für `Option<bool>`.
+    /// Ohne explizite Implementierung wird [argumente](ParseArgument::argumente) verwendet.
+    ///
+    /// ## English
+    /// Create an [Arguments] with the configured properties,
+    /// where further settings may be taken from the `language`,
+    /// e.g. the spellings for `Option<bool>`.
+    /// Without an explicit implementation, [argumente](ParseArgument::argumente) is used.
+    #[inline(always)]
+    fn argumente_allgemein<'t>(
+        beschreibung: Beschreibung<'t, Self>,
+        invertiere_präfix: impl Into<Vergleich<'t>>,
+        invertiere_infix: impl Into<Vergleich<'t>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        _sprache: Sprache,
+    ) -> Argumente<'t, Self, String> {
+        Self::argumente(beschreibung, invertiere_präfix, invertiere_infix, wert_infix, meta_var)
+    }
+
     /// Sollen Argumente dieses Typs normalerweise einen Standard-Wert haben?
     ///
     /// ## English
     /// Should arguments of this type have a default value if left unspecified?
     fn standard() -> Option<Self>;
 
+    /// Meta-Variable für Argumente dieses Typs in der gewünschten `sprache`,
+    /// sofern keine explizit angegeben wurde.
+    /// Bei [None] wird die allgemeine Meta-Variable der `sprache` verwendet.
+    ///
+    /// ## English
+    /// Meta-variable for arguments of this type in the requested `language`,
+    /// if none was specified explicitly.
+    /// With [None], the general meta-variable of the `language` is used.
+    #[inline(always)]
+    fn meta_var_standard(_sprache: Sprache) -> Option<&'static str> {
+        None
+    }
+
+    /// Erstelle ein [Argumente] für einen optionalen Wert dieses Typs, verwendet für `Option<Self>`.
+    /// Ohne explizite Implementierung wird ein Wert-Argument erzeugt,
+    /// das ohne Angabe [None] ergibt.
+    /// Für [bool] wird stattdessen ein Flag-Argument mit optionalem Wert erzeugt
+    /// (siehe [flag_mit_optionalem_wert](Argumente::flag_mit_optionalem_wert)),
+    /// das die Schreibweisen `wahr` und `falsch` der `sprache` akzeptiert.
+    ///
+    /// ## English
+    /// Create an [Arguments] for an optional value of this type, used for `Option<Self>`.
+    /// Without an explicit implementation a value-argument is created,
+    /// resulting in [None] if left unspecified.
+    /// For [bool], a flag-argument with an optional value is created instead
+    /// (see [flag_with_optional_value](Arguments::flag_with_optional_value)),
+    /// accepting the spellings `true` and `false` of the `language`.
+    #[inline(always)]
+    fn argumente_optional<'t>(
+        beschreibung: Beschreibung<'t, Option<Self>>,
+        invertiere_präfix: impl Into<Vergleich<'t>>,
+        invertiere_infix: impl Into<Vergleich<'t>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        _sprache: Sprache,
+    ) -> Argumente<'t, Option<Self>, String>
+    where
+        Self: 'static + Clone + Display,
+    {
+        option_argumente(beschreibung, invertiere_präfix, invertiere_infix, wert_infix, meta_var)
+    }
+
     /// Erstelle ein [Argumente] für die übergebene [Beschreibung].
     ///
     /// ## English synonym
@@ -55,12 +124,13 @@ pub trait ParseArgument: Sized {
         beschreibung: Beschreibung<'t, Self>,
         sprache: Sprache,
     ) -> Argumente<'t, Self, String> {
-        Self::argumente(
+        Self::argumente_allgemein(
             beschreibung,
             sprache.invertiere_präfix,
             sprache.invertiere_infix,
             sprache.wert_infix,
             sprache.meta_var,
+            sprache,
         )
     }
 
@@ -109,6 +179,26 @@ impl ParseArgument for bool {
     fn standard() -> Option<Self> {
         Some(false)
     }
+
+    fn argumente_optional<'t>(
+        beschreibung: Beschreibung<'t, Option<Self>>,
+        invertiere_präfix: impl Into<Vergleich<'t>>,
+        invertiere_infix: impl Into<Vergleich<'t>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        sprache: Sprache,
+    ) -> Argumente<'t, Option<Self>, String> {
+        let Sprache { wahr, falsch, .. } = sprache;
+        Argumente::flag_mit_optionalem_wert(
+            beschreibung,
+            invertiere_präfix,
+            invertiere_infix,
+            wert_infix,
+            meta_var,
+            wahr,
+            falsch,
+        )
+    }
 }
 
 impl ParseArgument for String {
@@ -134,7 +224,7 @@ impl ParseArgument for String {
 }
 
 macro_rules! impl_parse_argument {
-    ($($type:ty),*$(,)?) => {$(
+    ($meta_var_standard:ident: $($type:ty),*$(,)?) => {$(
         impl ParseArgument for $type {
             fn argumente<'t>(
                 beschreibung: Beschreibung<'t,Self>,
@@ -157,12 +247,147 @@ macro_rules! impl_parse_argument {
             fn standard() -> Option<Self> {
                 None
             }
+
+            fn meta_var_standard(sprache: Sprache) -> Option<&'static str> {
+                Some(sprache.$meta_var_standard)
+            }
         }
     )*};
 }
-impl_parse_argument! {i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64}
+impl_parse_argument! {meta_var_anzahl: i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize}
+impl_parse_argument! {meta_var_zahl: f32, f64}
+
+impl ParseArgument for PathBuf {
+    fn argumente<'t>(
+        beschreibung: Beschreibung<'t, Self>,
+        _invertiere_präfix: impl Into<Vergleich<'t>>,
+        _invertiere_infix: impl Into<Vergleich<'t>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+    ) -> Argumente<'t, Self, String> {
+        Argumente::wert(
+            beschreibung,
+            wert_infix,
+            meta_var,
+            None,
+            |os_str| Ok(PathBuf::from(os_str)),
+            |pfad| pfad.display().to_string(),
+        )
+    }
+
+    fn standard() -> Option<Self> {
+        None
+    }
+
+    fn meta_var_standard(sprache: Sprache) -> Option<&'static str> {
+        Some(sprache.meta_var_pfad)
+    }
+}
+
+/// Standard-Implementierung von [ParseArgument::argumente_optional].
+fn option_argumente<'t, T: 'static + ParseArgument + Clone + Display>(
+    beschreibung: Beschreibung<'t, Option<T>>,
+    invertiere_präfix: impl Into<Vergleich<'t>>,
+    invertiere_infix: impl Into<Vergleich<'t>>,
+    wert_infix: impl Into<Vergleich<'t>>,
+    meta_var: &'t str,
+) -> Argumente<'t, Option<T>, String> {
+    let name_lang_präfix = beschreibung.lang_präfix.clone();
+    let name_lang = beschreibung.lang.clone();
+    let name_kurz_präfix = beschreibung.kurz_präfix.clone();
+    let name_kurz = beschreibung.kurz.clone();
+    let wert_infix_vergleich = wert_infix.into();
+    let Argumente { parse, .. } = T::argumente(
+        Beschreibung::neu(
+            name_lang_präfix,
+            name_lang.clone(),
+            name_kurz_präfix,
+            name_kurz.clone(),
+            None::<&str>,
+            None,
+        ),
+        invertiere_präfix,
+        invertiere_infix,
+        wert_infix_vergleich.clone(),
+        meta_var,
+    );
+    let (beschreibung_string, option_standard) =
+        beschreibung.als_string_beschreibung_allgemein(|opt| {
+            if let Some(t) = opt {
+                t.to_string()
+            } else {
+                "None".to_owned()
+            }
+        });
+    type F<'s, T> =
+        Box<dyn 's + Fn(NonEmpty<Fehler<'_, String>>) -> Ergebnis<'_, Option<T>, String>>;
+    let nachsichtig_standard = option_standard.clone();
+    let verwende_standard: F<'t, T> = if let Some(standard) = option_standard {
+        Box::new(move |fehler_sammlung| {
+            let mut fehler_iter = fehler_sammlung.into_iter().filter_map(|fehler| match fehler {
+                Fehler::FehlenderWert { namen, wert_infix, meta_var, genannt, fehlermeldung } => {
+                    let passender_lang_name = namen.lang.iter().eq(name_lang
+                        .iter()
+                        .map(|Vergleich { string, case: _, diakritika: _ }| string));
+                    let passender_kurz_name = namen.kurz.iter().eq(name_kurz
+                        .iter()
+                        .map(|Vergleich { string, case: _, diakritika: _ }| string));
+                    if passender_lang_name && passender_kurz_name {
+                        None
+                    } else {
+                        Some(Fehler::FehlenderWert {
+                            namen,
+                            wert_infix,
+                            meta_var,
+                            genannt,
+                            fehlermeldung,
+                        })
+                    }
+                },
+                fehler => Some(fehler),
+            });
+            if let Some(head) = fehler_iter.next() {
+                let tail = fehler_iter.collect();
+                Ergebnis::Fehler(NonEmpty { head, tail })
+            } else {
+                Ergebnis::Wert(standard.clone())
+            }
+        })
+    } else {
+        Box::new(|e| Ergebnis::Fehler(e))
+    };
+    Argumente {
+        konfigurationen: vec![Konfiguration::Wert {
+            beschreibung: beschreibung_string,
+            meta_var,
+            wert_infix: wert_infix_vergleich,
+            wert_infix_anzeige: None,
+            geheim: false,
+            mögliche_werte: None,
+            reihenfolge: 0,
+            versteckte_namen: Vec::new(),
+        }],
+        flag_kurzformen: HashMap::new(),
+        einstellungen: ParseEinstellungen::default(),
+        parse: Box::new(move |args, kontext| {
+            let (ergebnis, nicht_verwendet) = parse(args, kontext);
+            let option_ergebnis = match ergebnis {
+                Ergebnis::Wert(wert) => Ergebnis::Wert(Some(wert)),
+                Ergebnis::FrühesBeenden(nachrichten) => Ergebnis::FrühesBeenden(nachrichten),
+                Ergebnis::Fehler(fehler_sammlung) => verwende_standard(fehler_sammlung),
+            };
+            let option_ergebnis = nachsichtiger_standard(
+                option_ergebnis,
+                nachsichtig_standard.as_ref(),
+                kontext.nachsichtig.as_deref_mut(),
+            );
+            (option_ergebnis, nicht_verwendet)
+        }),
+    }
+}
 
 impl<T: 'static + ParseArgument + Clone + Display> ParseArgument for Option<T> {
+    #[inline(always)]
     fn argumente<'t>(
         beschreibung: Beschreibung<'t, Self>,
         invertiere_präfix: impl Into<Vergleich<'t>>,
@@ -170,88 +395,42 @@ impl<T: 'static + ParseArgument + Clone + Display> ParseArgument for Option<T> {
         wert_infix: impl Into<Vergleich<'t>>,
         meta_var: &'t str,
     ) -> Argumente<'t, Self, String> {
-        let name_lang_präfix = beschreibung.lang_präfix.clone();
-        let name_lang = beschreibung.lang.clone();
-        let name_kurz_präfix = beschreibung.kurz_präfix.clone();
-        let name_kurz = beschreibung.kurz.clone();
-        let wert_infix_vergleich = wert_infix.into();
-        let Argumente { parse, .. } = T::argumente(
-            Beschreibung::neu(
-                name_lang_präfix,
-                name_lang.clone(),
-                name_kurz_präfix,
-                name_kurz.clone(),
-                None::<&str>,
-                None,
-            ),
+        T::argumente_optional(
+            beschreibung,
             invertiere_präfix,
             invertiere_infix,
-            wert_infix_vergleich.clone(),
+            wert_infix,
             meta_var,
-        );
-        let (beschreibung_string, option_standard) = beschreibung
-            .als_string_beschreibung_allgemein(|opt| {
-                if let Some(t) = opt {
-                    t.to_string()
-                } else {
-                    "None".to_owned()
-                }
-            });
-        type F<'s, T> =
-            Box<dyn 's + Fn(NonEmpty<Fehler<'_, String>>) -> Ergebnis<'_, Option<T>, String>>;
-        let verwende_standard: F<'t, T> =
-            if let Some(standard) = option_standard {
-                Box::new(move |fehler_sammlung| {
-                    let mut fehler_iter =
-                        fehler_sammlung.into_iter().filter_map(|fehler| match fehler {
-                            Fehler::FehlenderWert { namen, wert_infix, meta_var } => {
-                                let passender_lang_name = namen.lang.iter().eq(name_lang
-                                    .iter()
-                                    .map(|Vergleich { string, case: _ }| string));
-                                let passender_kurz_name = namen.kurz.iter().eq(name_kurz
-                                    .iter()
-                                    .map(|Vergleich { string, case: _ }| string));
-                                if passender_lang_name && passender_kurz_name {
-                                    None
-                                } else {
-                                    Some(Fehler::FehlenderWert { namen, wert_infix, meta_var })
-                                }
-                            },
-                            fehler => Some(fehler),
-                        });
-                    if let Some(head) = fehler_iter.next() {
-                        let tail = fehler_iter.collect();
-                        Ergebnis::Fehler(NonEmpty { head, tail })
-                    } else {
-                        Ergebnis::Wert(standard.clone())
-                    }
-                })
-            } else {
-                Box::new(|e| Ergebnis::Fehler(e))
-            };
-        Argumente {
-            konfigurationen: vec![Konfiguration::Wert {
-                beschreibung: beschreibung_string,
-                meta_var,
-                wert_infix: wert_infix_vergleich,
-                mögliche_werte: None,
-            }],
-            flag_kurzformen: HashMap::new(),
-            parse: Box::new(move |args| {
-                let (ergebnis, nicht_verwendet) = parse(args);
-                let option_ergebnis = match ergebnis {
-                    Ergebnis::Wert(wert) => Ergebnis::Wert(Some(wert)),
-                    Ergebnis::FrühesBeenden(nachrichten) => Ergebnis::FrühesBeenden(nachrichten),
-                    Ergebnis::Fehler(fehler_sammlung) => verwende_standard(fehler_sammlung),
-                };
-                (option_ergebnis, nicht_verwendet)
-            }),
-        }
+            Sprache::ENGLISH,
+        )
+    }
+
+    #[inline(always)]
+    fn argumente_allgemein<'t>(
+        beschreibung: Beschreibung<'t, Self>,
+        invertiere_präfix: impl Into<Vergleich<'t>>,
+        invertiere_infix: impl Into<Vergleich<'t>>,
+        wert_infix: impl Into<Vergleich<'t>>,
+        meta_var: &'t str,
+        sprache: Sprache,
+    ) -> Argumente<'t, Self, String> {
+        T::argumente_optional(
+            beschreibung,
+            invertiere_präfix,
+            invertiere_infix,
+            wert_infix,
+            meta_var,
+            sprache,
+        )
     }
 
     fn standard() -> Option<Self> {
         Some(None)
     }
+
+    fn meta_var_standard(sprache: Sprache) -> Option<&'static str> {
+        T::meta_var_standard(sprache)
+    }
 }
 
 impl<T: 'static + EnumArgument + Display + Clone> ParseArgument for T {
@@ -291,6 +470,27 @@ pub trait Parse: Sized {
     /// Create a description, how command line arguments should be parsed.
     fn kommandozeilen_argumente<'t>() -> Argumente<'t, Self, Self::Fehler>;
 
+    /// Erzeuge eine Beschreibung, wie Kommandozeilen-Argumente geparst werden sollen.
+    /// Die Werte von `standards` (z.B. aus einer Konfigurations-Datei) werden
+    /// als Standard-Werte der einzelnen Felder verwendet.
+    ///
+    /// Die Standard-Implementierung ignoriert `standards`,
+    /// die [automatisch erzeugte Implementierung](derive@Parse) verwendet alle Felder.
+    ///
+    /// ## English
+    /// Create a description, how command line arguments should be parsed.
+    /// The values of `standards` (e.g. from a config file) are used
+    /// as default values for the individual fields.
+    ///
+    /// The default implementation ignores `standards`,
+    /// the [automatically created implementation](derive@Parse) uses all fields.
+    #[inline(always)]
+    fn kommandozeilen_argumente_mit_standards<'t>(
+        _standards: Self,
+    ) -> Argumente<'t, Self, Self::Fehler> {
+        Self::kommandozeilen_argumente()
+    }
+
     /// Parse die übergebenen Kommandozeilen-Argumente und versuche den gewünschten Typ zu erzeugen.
     ///
     /// ## English
@@ -306,6 +506,112 @@ pub trait Parse: Sized {
         Self::kommandozeilen_argumente().parse(args)
     }
 
+    /// Parse die übergebenen Strings als Kommandozeilen-Argumente
+    /// und versuche den gewünschten Typ zu erzeugen, z.B. aus einem `Vec<String>` in Tests.
+    ///
+    /// ## English synonym
+    /// [parse_from_strings](Parse::parse_from_strings)
+    #[inline(always)]
+    fn parse_aus_strings<'t, S: AsRef<str>>(
+        args: impl IntoIterator<Item = S>,
+    ) -> (Ergebnis<'t, Self, Self::Fehler>, Vec<OsString>)
+    where
+        Self: 't,
+        Self::Fehler: 't,
+    {
+        Self::kommandozeilen_argumente().parse_aus_strings(args)
+    }
+
+    /// Parse the given strings as command line arguments to create the requested type,
+    /// e.g. from a `Vec<String>` in tests.
+    ///
+    /// ## Deutsches Synonym
+    /// [parse_aus_strings](Parse::parse_aus_strings)
+    #[inline(always)]
+    fn parse_from_strings<'t, S: AsRef<str>>(
+        args: impl IntoIterator<Item = S>,
+    ) -> (Ergebnis<'t, Self, Self::Fehler>, Vec<OsString>)
+    where
+        Self: 't,
+        Self::Fehler: 't,
+    {
+        Self::parse_aus_strings(args)
+    }
+
+    /// Parse die übergebenen Kommandozeilen-Argumente wie
+    /// [parse_als_result](Argumente::parse_als_result) und versuche den gewünschten Typ zu erzeugen.
+    /// Es wird nichts ausgegeben und das Programm nicht beendet,
+    /// daher wird für [Fehler](Parse::Fehler) kein [Display](std::fmt::Display) benötigt.
+    ///
+    /// ## English synonym
+    /// [try_parse](Parse::try_parse)
+    #[inline(always)]
+    #[allow(clippy::result_large_err)]
+    fn versuche_parse<'t>(
+        args: impl Iterator<Item = OsString>,
+    ) -> Result<Self, ParseAbbruch<'t, Self::Fehler>>
+    where
+        Self: 't,
+        Self::Fehler: 't,
+    {
+        Self::kommandozeilen_argumente().parse_als_result(args)
+    }
+
+    /// Parse the given command line arguments like
+    /// [parse_as_result](Argumente::parse_as_result) and try to create the requested type.
+    /// Nothing is printed and the program doesn't stop,
+    /// so [Fehler](Parse::Fehler) doesn't require [Display](std::fmt::Display).
+    ///
+    /// ## Deutsches Synonym
+    /// [versuche_parse](Parse::versuche_parse)
+    #[inline(always)]
+    #[allow(clippy::result_large_err)]
+    fn try_parse<'t>(
+        args: impl Iterator<Item = OsString>,
+    ) -> Result<Self, ParseAbort<'t, Self::Fehler>>
+    where
+        Self: 't,
+        Self::Fehler: 't,
+    {
+        Self::versuche_parse(args)
+    }
+
+    /// Parse die übergebenen Kommandozeilen-Argumente [nachsichtig](Argumente::parse_nachsichtig).
+    /// Fehlerhafte Felder erhalten ihren Standard-Wert, z.B. über `standard: <wert>`
+    /// oder [kommandozeilen_argumente_mit_standards](Parse::kommandozeilen_argumente_mit_standards).
+    /// Hat ein fehlerhaftes Feld keinen Standard-Wert, wird kein Wert ([None]) zurückgegeben.
+    ///
+    /// ## English synonym
+    /// [parse_lenient](Parse::parse_lenient)
+    #[inline(always)]
+    fn parse_nachsichtig<'t>(
+        args: impl Iterator<Item = OsString>,
+    ) -> (Option<Self>, Vec<Fehler<'t, Self::Fehler>>, Vec<OsString>)
+    where
+        Self: 't,
+        Self::Fehler: 't,
+    {
+        Self::kommandozeilen_argumente().parse_nachsichtig(args)
+    }
+
+    /// Parse the given command line arguments [leniently](Argumente::parse_lenient).
+    /// Erroneous fields get their default value, e.g. from `default: <value>`
+    /// or [kommandozeilen_argumente_mit_standards](Parse::kommandozeilen_argumente_mit_standards).
+    /// If an erroneous field has no default value, no value ([None]) is returned.
+    ///
+    /// ## Deutsches Synonym
+    /// [parse_nachsichtig](Parse::parse_nachsichtig)
+    #[inline(always)]
+    fn parse_lenient<'t>(
+        args: impl Iterator<Item = OsString>,
+    ) -> (Option<Self>, Vec<Error<'t, Self::Fehler>>, Vec<OsString>)
+    where
+        Self: 't,
+        Self::Fehler: 't,
+    {
+        Self::parse_nachsichtig(args)
+    }
+
     /// Parse [args_os](std::env::args_os) und versuche den gewünschten Typ zu erzeugen.
     ///
     /// ## English synonym
@@ -673,3 +979,27 @@ pub trait Parse: Sized {
         Self::kommandozeilen_argumente().parse_with_error_message_from_env(error_code)
     }
 }
+
+impl<'t, T: Parse> Argumente<'t, T, T::Fehler> {
+    /// Erzeuge die [Argumente] eines [Parse]-Typs, wobei die Werte von `standards`
+    /// (z.B. aus einer Konfigurations-Datei) als Standard-Werte der einzelnen Felder verwendet werden.
+    /// Über Kommandozeilen-Argumente können diese überschrieben werden.
+    ///
+    /// ## English synonym
+    /// [with_defaults](Arguments::with_defaults)
+    #[inline(always)]
+    pub fn mit_standards(standards: T) -> Argumente<'t, T, T::Fehler> {
+        T::kommandozeilen_argumente_mit_standards(standards)
+    }
+
+    /// Create the [Arguments] of a [Parse] type, using the values of `defaults`
+    /// (e.g. from a config file) as default values of the individual fields.
+    /// They may be overwritten using command line arguments.
+    ///
+    /// ## Deutsches Synonym
+    /// [mit_standards](Argumente::mit_standards)
+    #[inline(always)]
+    pub fn with_defaults(defaults: T) -> Arguments<'t, T, T::Fehler> {
+        Argumente::mit_standards(defaults)
+    }
+}
@@ -1,5 +1,7 @@
 //! Alle Strings, die zum erstellen von Hilfe-Text und Fehlermeldung notwendig sind.
 
+use std::env;
+
 /// Alle Strings, die zum erstellen von Hilfe-Text und Fehlermeldung notwendig sind.
 ///
 /// ## English synonym
@@ -42,6 +44,30 @@ pub struct Sprache {
     /// Default-value for the meta-variable in the help text.
     pub meta_var: &'static str,
 
+    /// Standard-Wert für die Meta-Variable einer Dauer im Hilfe-Text
+    ///
+    /// ## English
+    /// Default-value for the meta-variable of a duration in the help text.
+    pub meta_var_dauer: &'static str,
+
+    /// Standard-Wert für die Meta-Variable einer Ganzzahl im Hilfe-Text
+    ///
+    /// ## English
+    /// Default-value for the meta-variable of an integer in the help text.
+    pub meta_var_anzahl: &'static str,
+
+    /// Standard-Wert für die Meta-Variable einer Gleitkommazahl im Hilfe-Text
+    ///
+    /// ## English
+    /// Default-value for the meta-variable of a floating point number in the help text.
+    pub meta_var_zahl: &'static str,
+
+    /// Standard-Wert für die Meta-Variable eines Pfades im Hilfe-Text
+    ///
+    /// ## English
+    /// Default-value for the meta-variable of a path in the help text.
+    pub meta_var_pfad: &'static str,
+
     /// Meta-Beschreibung für Optionen im Hilfe-Text.
     ///
     /// ## English
@@ -60,6 +86,12 @@ pub struct Sprache {
     /// Description for possible values in the help text.
     pub erlaubte_werte: &'static str,
 
+    /// Überschrift des Abschnitts mit Exit-Codes im Hilfe-Text.
+    ///
+    /// ## English
+    /// Heading of the section with exit codes in the help text.
+    pub exit_codes: &'static str,
+
     /// Beschreibung einer fehlenden Flag in einer Fehlermeldung.
     ///
     /// ## English
@@ -84,6 +116,106 @@ pub struct Sprache {
     /// Description for an invalid String in an error message.
     pub invalider_string: &'static str,
 
+    /// Beschreibung eines fehlenden Trennzeichens zwischen Schlüssel und Wert
+    /// in einer Fehlermeldung.
+    ///
+    /// ## English
+    /// Description for a missing separator between key and value in an error message.
+    pub fehlender_trenner: &'static str,
+
+    /// Beschreibung eines nicht parsebaren Schlüssels eines Schlüssel-Wert-Paares
+    /// in einer Fehlermeldung.
+    ///
+    /// ## English
+    /// Description for a key of a key-value pair that could not be parsed in an error message.
+    pub ungültiger_schlüssel: &'static str,
+
+    /// Beschreibung eines nicht parsebaren Wertes eines Schlüssel-Wert-Paares
+    /// in einer Fehlermeldung.
+    ///
+    /// ## English
+    /// Description for a value of a key-value pair that could not be parsed in an error message.
+    pub ungültiger_wert: &'static str,
+
+    /// Beschreibung eines explizit verbotenen Wertes in einer Fehlermeldung.
+    ///
+    /// ## English
+    /// Description for an explicitly forbidden value in an error message.
+    pub wert_nicht_erlaubt: &'static str,
+
+    /// Beschreibung eines leeren Elements einer Liste in einer Fehlermeldung.
+    ///
+    /// ## English
+    /// Description for an empty element of a list in an error message.
+    pub leeres_element: &'static str,
+
+    /// Beschreibung eines doppelten Elements einer Menge in einer Fehlermeldung.
+    ///
+    /// ## English
+    /// Description for a duplicate element of a set in an error message.
+    pub doppeltes_element: &'static str,
+
+    /// Beschreibung eines unerlaubt mehrfach genannten Arguments in einer Fehlermeldung.
+    ///
+    /// ## English
+    /// Description for an argument given multiple times, although not allowed,
+    /// in an error message.
+    pub mehrfach_genannt: &'static str,
+
+    /// Beschreibung einer nicht gesetzten Umgebungsvariable in einer Fehlermeldung.
+    ///
+    /// ## English
+    /// Description for an undefined environment variable in an error message.
+    pub unbekannte_umgebungsvariable: &'static str,
+
+    /// Beschreibung für zu viele Werte eines Arguments in einer Fehlermeldung.
+    ///
+    /// ## English
+    /// Description for too many values of an argument in an error message.
+    pub zu_viele_werte: &'static str,
+
+    /// Beschreibung einer gleichzeitig aktivierten und deaktivierten Flag in einer Fehlermeldung.
+    ///
+    /// ## English
+    /// Description for a flag enabled and disabled at the same time in an error message.
+    pub widersprüchliche_flags: &'static str,
+
+    /// Markierung einer veralteten Flag im Hilfe-Text.
+    ///
+    /// ## English
+    /// Marker of a deprecated flag in the help text.
+    pub veraltet: &'static str,
+
+    /// Beschreibung einer falschen Anzahl an Werten eines Arguments in einer Fehlermeldung.
+    ///
+    /// ## English
+    /// Description for a wrong number of values of an argument in an error message.
+    pub erwartete_werte: &'static str,
+
+    /// Akzeptierte Schreibweisen für den Wahrheitswert `true`,
+    /// Groß-/Kleinschreibung wird nicht beachtet.
+    /// Die erste Schreibweise wird im Hilfe-Text verwendet.
+    ///
+    /// ## English
+    /// Accepted spellings for the boolean value `true`, compared case-insensitively.
+    /// The first spelling is used in the help text.
+    pub wahr: &'static [&'static str],
+
+    /// Akzeptierte Schreibweisen für den Wahrheitswert `false`,
+    /// Groß-/Kleinschreibung wird nicht beachtet.
+    /// Die erste Schreibweise wird im Hilfe-Text verwendet.
+    ///
+    /// ## English
+    /// Accepted spellings for the boolean value `false`, compared case-insensitively.
+    /// The first spelling is used in the help text.
+    pub falsch: &'static [&'static str],
+
+    /// Beschreibung für den rekonstruierten Aufruf in einer Fehlermeldung.
+    ///
+    /// ## English
+    /// Description for the reconstructed invocation in an error message.
+    pub aufruf: &'static str,
+
     /// Beschreibung für ein nicht verwendetes Argument in einer Fehlermeldung.
     ///
     /// ## English
@@ -142,13 +274,33 @@ impl Sprache {
         invertiere_infix: "-",
         wert_infix: "=",
         meta_var: "WERT",
+        meta_var_dauer: "DAUER",
+        meta_var_anzahl: "ANZAHL",
+        meta_var_zahl: "ZAHL",
+        meta_var_pfad: "PFAD",
         optionen: "OPTIONEN",
         standard: "Standard",
         erlaubte_werte: "Erlaubte Werte",
+        exit_codes: "EXIT-CODES",
         fehlende_flag: "Fehlende Flag",
         fehlender_wert: "Fehlender Wert",
         parse_fehler: "Parse-Fehler",
         invalider_string: "Invalider String",
+        fehlender_trenner: "Fehlendes Trennzeichen",
+        ungültiger_schlüssel: "Ungültiger Schlüssel",
+        ungültiger_wert: "Ungültiger Wert",
+        wert_nicht_erlaubt: "Wert nicht erlaubt",
+        leeres_element: "Leeres Element",
+        doppeltes_element: "Doppeltes Element",
+        mehrfach_genannt: "Mehrfach genannt",
+        unbekannte_umgebungsvariable: "Unbekannte Umgebungsvariable",
+        zu_viele_werte: "Zu viele Werte",
+        widersprüchliche_flags: "Widersprüchliche Flags",
+        veraltet: "Veraltet",
+        erwartete_werte: "Erwartete Anzahl an Werten",
+        wahr: &["wahr", "ja", "an", "ein", "1"],
+        falsch: &["falsch", "nein", "aus", "0"],
+        aufruf: "Aufruf",
         argument_nicht_verwendet: "Nicht alle Argumente verwendet",
         hilfe_beschreibung: "Zeige diesen Text an.",
         hilfe_lang: "hilfe",
@@ -166,13 +318,33 @@ impl Sprache {
         invertiere_infix: "-",
         wert_infix: "=",
         meta_var: "VALUE",
+        meta_var_dauer: "DURATION",
+        meta_var_anzahl: "COUNT",
+        meta_var_zahl: "NUMBER",
+        meta_var_pfad: "PATH",
         optionen: "OPTIONS",
         standard: "Default",
         erlaubte_werte: "Possible values",
+        exit_codes: "EXIT CODES",
         fehlende_flag: "Missing Flag",
         fehlender_wert: "Missing Value",
         parse_fehler: "Parse Error",
         invalider_string: "Invalid String",
+        fehlender_trenner: "Missing separator",
+        ungültiger_schlüssel: "Invalid key",
+        ungültiger_wert: "Invalid value",
+        wert_nicht_erlaubt: "Value not allowed",
+        leeres_element: "Empty element",
+        doppeltes_element: "Duplicate element",
+        mehrfach_genannt: "Given multiple times",
+        unbekannte_umgebungsvariable: "Undefined environment variable",
+        zu_viele_werte: "Too many values",
+        widersprüchliche_flags: "Contradictory flags",
+        veraltet: "Deprecated",
+        erwartete_werte: "Expected number of values",
+        wahr: &["true", "yes", "on", "1"],
+        falsch: &["false", "no", "off", "0"],
+        aufruf: "Invocation",
         argument_nicht_verwendet: "Unused argument(s)",
         hilfe_beschreibung: "Show this text.",
         hilfe_lang: "hilfe",
@@ -181,4 +353,81 @@ impl Sprache {
         version_lang: "version",
         version_kurz: "v",
     };
+
+    /// Bestimme die Sprache anhand der Umgebungsvariablen `LC_ALL`, `LC_MESSAGES` und `LANG`,
+    /// wobei die erste gesetzte, nicht-leere Variable verwendet wird.
+    ///
+    /// Für eine deutsche Locale (z.B. `de_DE.UTF-8`) wird [Sprache::DEUTSCH] zurückgegeben,
+    /// ansonsten [Sprache::ENGLISH].
+    ///
+    /// ## English synonym
+    /// [from_environment](Language::from_environment)
+    pub fn aus_umgebung() -> Sprache {
+        let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(env::var_os)
+            .find(|wert| !wert.is_empty());
+        let sprach_code = locale.as_ref().and_then(|wert| wert.to_str()).and_then(|locale| {
+            locale.split(['_', '.', '@', '-']).next().map(str::to_ascii_lowercase)
+        });
+        match sprach_code.as_deref() {
+            Some("de") => Sprache::DEUTSCH,
+            _ => Sprache::ENGLISH,
+        }
+    }
+
+    /// Determine the language based on the environment variables `LC_ALL`, `LC_MESSAGES`
+    /// and `LANG`, using the first set, non-empty variable.
+    ///
+    /// A german locale (e.g. `de_DE.UTF-8`) returns [Sprache::DEUTSCH],
+    /// otherwise [Sprache::ENGLISH].
+    ///
+    /// ## Deutsches Synonym
+    /// [aus_umgebung](Sprache::aus_umgebung)
+    #[inline(always)]
+    pub fn from_environment() -> Language {
+        Sprache::aus_umgebung()
+    }
+
+    /// Übernehme alle Strings, die auf der Kommandozeile eingegeben werden, aus `namen`.
+    /// Das sind Präfixe und Infixe, die Namen von Hilfe- und Version-Flag,
+    /// sowie die akzeptierten Schreibweisen für Wahrheitswerte.
+    /// Beschreibungen im Hilfe-Text und Fehlermeldungen bleiben unverändert.
+    ///
+    /// Damit werden z.B. englische Namen mit deutschen Fehlermeldungen kombiniert:
+    /// `Sprache::DEUTSCH.mit_namen_von(Sprache::ENGLISH)`.
+    ///
+    /// ## English synonym
+    /// [with_names_from](Language::with_names_from)
+    pub const fn mit_namen_von(self, namen: Sprache) -> Sprache {
+        Sprache {
+            lang_präfix: namen.lang_präfix,
+            kurz_präfix: namen.kurz_präfix,
+            invertiere_präfix: namen.invertiere_präfix,
+            invertiere_infix: namen.invertiere_infix,
+            wert_infix: namen.wert_infix,
+            wahr: namen.wahr,
+            falsch: namen.falsch,
+            hilfe_lang: namen.hilfe_lang,
+            hilfe_kurz: namen.hilfe_kurz,
+            version_lang: namen.version_lang,
+            version_kurz: namen.version_kurz,
+            ..self
+        }
+    }
+
+    /// Take all strings entered on the command line from `names`.
+    /// These are prefixes and infixes, the names of the help and version flags,
+    /// as well as the accepted spellings for boolean values.
+    /// Descriptions in the help text and error messages remain unchanged.
+    ///
+    /// This allows e.g. to combine english names with german error messages:
+    /// `Language::DEUTSCH.with_names_from(Language::ENGLISH)`.
+    ///
+    /// ## Deutsches Synonym
+    /// [mit_namen_von](Sprache::mit_namen_von)
+    #[inline(always)]
+    pub const fn with_names_from(self, names: Language) -> Language {
+        self.mit_namen_von(names)
+    }
 }
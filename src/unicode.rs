@@ -2,7 +2,9 @@
 
 use std::{borrow::Cow, convert::AsRef};
 
-use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+use unicode_normalization::{
+    char::is_combining_mark, is_nfc_quick, IsNormalized, UnicodeNormalization,
+};
 use unicode_segmentation::UnicodeSegmentation;
 
 /// Ein normalisierter Unicode String.
@@ -54,6 +56,10 @@ impl<'t> Normalisiert<'t> {
     #[inline(always)]
     pub fn neu(s: impl Into<Cow<'t, str>>) -> Normalisiert<'t> {
         let cow = s.into();
+        if cow.is_ascii() {
+            // ASCII-Strings sind bereits normalisiert.
+            return Normalisiert(cow);
+        }
         let normalisiert = match is_nfc_quick(cow.chars()) {
             IsNormalized::Yes if !cow.chars().eq(cow.cjk_compat_variants()) => cow,
             _ => Cow::Owned(cow.cjk_compat_variants().nfc().collect()),
@@ -96,6 +102,93 @@ impl<'t> Normalisiert<'t> {
     }
 }
 
+/// Normalisiere einen Unicode-String, wie es der Parser für alle Argumente macht
+/// (siehe [Normalisiert::neu]).
+///
+/// Nützlich um eigene Vorverarbeitung oder Vergleiche
+/// (z.B. in einer eigenen `frühes_beenden`-Funktion) konsistent zum Parser durchzuführen.
+///
+/// ## Beispiel
+/// ```
+/// use kommandozeilen_argumente::unicode::normalisiere;
+///
+/// // "e" mit kombinierendem Akut wird zu "é".
+/// assert_eq!(normalisiere("cafe\u{301}"), "café");
+/// ```
+///
+/// ## English synonym
+/// [normalize]
+pub fn normalisiere(s: &str) -> String {
+    Normalisiert::neu(s).as_ref().to_owned()
+}
+
+/// Normalize a unicode string, as the parser does for all arguments
+/// (see [Normalized::new]).
+///
+/// Useful to perform custom pre-processing or comparisons
+/// (e.g. in a custom `early_exit` function) consistent with the parser.
+///
+/// ## Example
+/// ```
+/// use kommandozeilen_argumente::unicode::normalize;
+///
+/// // "e" with combining acute accent becomes "é".
+/// assert_eq!(normalize("cafe\u{301}"), "café");
+/// ```
+///
+/// ## Deutsches Synonym
+/// [normalisiere]
+#[inline(always)]
+pub fn normalize(s: &str) -> String {
+    normalisiere(s)
+}
+
+/// Parse einen Wahrheitswert, wobei alle Schreibweisen aus `wahr` und `falsch`
+/// nach Unicode Normalisierung und ohne Beachtung von Groß-/Kleinschreibung akzeptiert werden.
+/// Für andere Strings wird [None] zurückgegeben.
+///
+/// ## Beispiel
+/// ```
+/// use kommandozeilen_argumente::unicode::parse_wahrheitswert;
+///
+/// assert_eq!(parse_wahrheitswert("JA", &["ja", "1"], &["nein", "0"]), Some(true));
+/// assert_eq!(parse_wahrheitswert("Nein", &["ja", "1"], &["nein", "0"]), Some(false));
+/// assert_eq!(parse_wahrheitswert("vielleicht", &["ja", "1"], &["nein", "0"]), None);
+/// ```
+///
+/// ## English synonym
+/// [parse_bool]
+pub fn parse_wahrheitswert(s: &str, wahr: &[&str], falsch: &[&str]) -> Option<bool> {
+    let passt = |schreibweise: &&str| Vergleich::from((*schreibweise, Case::Insensitive)).passt(s);
+    if wahr.iter().any(passt) {
+        Some(true)
+    } else if falsch.iter().any(passt) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Parse a boolean value, accepting all spellings from `true_spellings` and `false_spellings`
+/// after unicode normalization and in a case-insensitive way.
+/// For other strings, [None] is returned.
+///
+/// ## Example
+/// ```
+/// use kommandozeilen_argumente::unicode::parse_bool;
+///
+/// assert_eq!(parse_bool("True", &["true", "on"], &["false", "off"]), Some(true));
+/// assert_eq!(parse_bool("OFF", &["true", "on"], &["false", "off"]), Some(false));
+/// assert_eq!(parse_bool("maybe", &["true", "on"], &["false", "off"]), None);
+/// ```
+///
+/// ## Deutsches Synonym
+/// [parse_wahrheitswert]
+#[inline(always)]
+pub fn parse_bool(s: &str, true_spellings: &[&str], false_spellings: &[&str]) -> Option<bool> {
+    parse_wahrheitswert(s, true_spellings, false_spellings)
+}
+
 /// Wird Groß-/Kleinschreibung beachtet?
 ///
 /// ## English
@@ -131,8 +224,40 @@ impl From<Case> for bool {
     }
 }
 
+/// Werden diakritische Zeichen (z.B. Akzente) beachtet?
+///
+/// ## English synonym
+/// [Diacritics]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Diakritika {
+    /// Beachte diakritische Zeichen: `"café" != "cafe"`
+    ///
+    /// ## English
+    /// Compare respecting diacritics: `"café" != "cafe"`
+    #[default]
+    Beachten,
+
+    /// Ignoriere diakritische Zeichen: `"café" == "cafe"`
+    ///
+    /// ## English
+    /// Compare ignoring diacritics: `"café" == "cafe"`
+    Ignorieren,
+}
+
+/// Are diacritics (e.g. accents) respected?
+///
+/// ## Deutsches Synonym
+/// [Diakritika]
+pub type Diacritics = Diakritika;
+
+/// Entferne alle diakritischen Zeichen (combining marks) nach
+/// [kanonischer Zerlegung](UnicodeNormalization::nfd).
+pub(crate) fn entferne_diakritika(s: &str) -> String {
+    s.nfd().filter(|c| !is_combining_mark(*c)).nfc().collect()
+}
+
 /// Normalisierter Unicode-String, sowie ob dieser unter berücksichtigen von
-/// Groß-/Kleinschreibung verglichen werden soll.
+/// Groß-/Kleinschreibung und diakritischen Zeichen verglichen werden soll.
 ///
 /// ## English synonym
 /// [Compare]
@@ -150,6 +275,34 @@ pub struct Vergleich<'t> {
     /// ## English
     /// Is the comparison case-(in)sensitive?
     pub case: Case,
+
+    /// Sollen diakritische Zeichen beim Vergleich beachtet werden.
+    ///
+    /// ## English
+    /// Are diacritics respected when comparing?
+    pub diakritika: Diakritika,
+}
+
+impl<'t> Vergleich<'t> {
+    /// Erzeuge einen [Vergleich], der diakritische Zeichen beachtet.
+    /// Diese können über [ohne_diakritika](Vergleich::ohne_diakritika) ignoriert werden.
+    ///
+    /// ## English synonym
+    /// [new](Compare::new)
+    #[inline(always)]
+    pub fn neu(string: Normalisiert<'t>, case: Case) -> Vergleich<'t> {
+        Vergleich { string, case, diakritika: Diakritika::Beachten }
+    }
+
+    /// Create a [Compare], respecting diacritics.
+    /// They may be ignored using [ignore_diacritics](Compare::ignore_diacritics).
+    ///
+    /// ## Deutsches Synonym
+    /// [neu](Vergleich::neu)
+    #[inline(always)]
+    pub fn new(string: Normalized<'t>, case: Case) -> Compare<'t> {
+        Vergleich::neu(string, case)
+    }
 }
 
 macro_rules! impl_vergleich_from {
@@ -157,14 +310,14 @@ macro_rules! impl_vergleich_from {
         #[allow(single_use_lifetimes)]
         impl<'t> From<$type> for Vergleich<'t> {
             fn from(input: $type) -> Self {
-                Vergleich { string: Normalisiert::neu(input), case: Case::Sensitive }
+                Vergleich::neu(Normalisiert::neu(input), Case::Sensitive)
             }
         }
 
         #[allow(single_use_lifetimes)]
         impl<'t> From<($type, Case)> for Vergleich<'t> {
             fn from((s, case): ($type, Case)) -> Self {
-                Vergleich { string: Normalisiert::neu(s), case }
+                Vergleich::neu(Normalisiert::neu(s), case)
             }
         }
     };
@@ -175,13 +328,13 @@ impl_vergleich_from! {&'t str}
 
 impl<'t> From<Normalisiert<'t>> for Vergleich<'t> {
     fn from(input: Normalisiert<'t>) -> Self {
-        Vergleich { string: input, case: Case::Sensitive }
+        Vergleich::neu(input, Case::Sensitive)
     }
 }
 
 impl<'t> From<(Normalisiert<'t>, Case)> for Vergleich<'t> {
     fn from((string, case): (Normalisiert<'t>, Case)) -> Self {
-        Vergleich { string, case }
+        Vergleich::neu(string, case)
     }
 }
 
@@ -206,13 +359,81 @@ impl Vergleich<'_> {
     /// Check whether two Strings are identical after unicode normalization,
     /// optionally in a [case-insensitive way](unicase::eq).
     pub fn eq(&self, gesucht: &str) -> bool {
-        let Vergleich { string, case } = self;
-        string.eq(gesucht, *case)
+        let Vergleich { string, case, diakritika } = self;
+        if let Some(gleich) = ascii_eq(string.as_ref(), gesucht, *case) {
+            return gleich;
+        }
+        match diakritika {
+            Diakritika::Beachten => string.eq(gesucht, *case),
+            Diakritika::Ignorieren => Normalisiert::neu(entferne_diakritika(string.as_ref()))
+                .eq(&entferne_diakritika(gesucht), *case),
+        }
+    }
+
+    /// Überprüfe, ob `gesucht` mit der selben Semantik wie beim Parsen passt,
+    /// d.h. nach Unicode Normalisierung, abhängig von [Case] und [Diakritika].
+    ///
+    /// ## Beispiel
+    /// ```
+    /// use kommandozeilen_argumente::unicode::{Case, Vergleich};
+    ///
+    /// let vergleich = Vergleich::from(("Hilfe", Case::Insensitive));
+    /// assert!(vergleich.passt("hilfe"));
+    /// assert!(!vergleich.passt("hilf"));
+    /// assert!(Vergleich::from("cafe").ohne_diakritika().passt("café"));
+    /// ```
+    ///
+    /// ## English synonym
+    /// [matches](Compare::matches)
+    #[inline(always)]
+    pub fn passt(&self, gesucht: &str) -> bool {
+        self.eq(gesucht)
+    }
+
+    /// Check if `searched` matches with the same semantics as during parsing,
+    /// i.e. after unicode normalization, depending on [Case] and [Diacritics].
+    ///
+    /// ## Example
+    /// ```
+    /// use kommandozeilen_argumente::unicode::{Case, Compare};
+    ///
+    /// let compare = Compare::from(("Help", Case::Insensitive));
+    /// assert!(compare.matches("help"));
+    /// assert!(!compare.matches("hel"));
+    /// ```
+    ///
+    /// ## Deutsches Synonym
+    /// [passt](Vergleich::passt)
+    #[inline(always)]
+    pub fn matches(&self, searched: &str) -> bool {
+        self.passt(searched)
+    }
+
+    /// Ignoriere diakritische Zeichen (z.B. Akzente) beim Vergleich,
+    /// so dass z.B. `"cafe"` mit `"café"` übereinstimmt.
+    ///
+    /// ## English synonym
+    /// [ignore_diacritics](Compare::ignore_diacritics)
+    pub fn ohne_diakritika(self) -> Self {
+        Vergleich { diakritika: Diakritika::Ignorieren, ..self }
+    }
+
+    /// Ignore diacritics (e.g. accents) when comparing,
+    /// so e.g. `"cafe"` matches `"café"`.
+    ///
+    /// ## Deutsches Synonym
+    /// [ohne_diakritika](Vergleich::ohne_diakritika)
+    #[inline(always)]
+    pub fn ignore_diacritics(self) -> Self {
+        self.ohne_diakritika()
     }
 
     /// Versuche einen String vom Anfang des anderen Strings zu entfernen.
     pub(crate) fn strip_als_präfix<'t>(&self, string: &'t Normalisiert<'t>) -> Option<&'t str> {
         let string_str = string.as_ref();
+        if let Some(rest) = self.strip_als_ascii_präfix(string_str) {
+            return rest;
+        }
         let string_länge = string_str.len();
         let mut graphemes_indices = string_str.grapheme_indices(true);
         let mut präfixe = vec![(string_str, string_länge)];
@@ -225,4 +446,42 @@ impl Vergleich<'_> {
             .find(|(präfix, _ix)| self.eq(präfix))
             .map(|(_präfix, ix)| &string_str[*ix..string_länge])
     }
+
+    /// Schneller Pfad von [strip_als_präfix](Vergleich::strip_als_präfix) für ASCII-Strings,
+    /// der für jedes Argument von jedem Argument-Namen aufgerufen wird.
+    ///
+    /// Normalisierung, Groß-/Kleinschreibung und diakritische Zeichen verändern die Länge
+    /// eines ASCII-Strings nicht, daher kommt nur der Präfix mit der selben Länge in Frage.
+    /// Folgt darauf ein anderes Zeichen (z.B. ein kombinierendes Zeichen),
+    /// ist das Ergebnis unbekannt und [None] wird zurückgegeben.
+    fn strip_als_ascii_präfix<'s>(&self, string_str: &'s str) -> Option<Option<&'s str>> {
+        let präfix = self.string.as_ref();
+        if !präfix.is_ascii() {
+            return None;
+        }
+        let bytes = string_str.as_bytes();
+        let länge = präfix.len();
+        match bytes.get(..länge) {
+            Some(kandidat) if kandidat.is_ascii() => {},
+            None if string_str.is_ascii() => return Some(None),
+            _ => return None,
+        }
+        match bytes.get(länge) {
+            Some(nächstes) if !nächstes.is_ascii() => return None,
+            // `\r\n` ist ein einzelnes Grapheme.
+            Some(b'\n') if länge > 0 && bytes[länge - 1] == b'\r' => return None,
+            _ => {},
+        }
+        let passt = ascii_eq(präfix, &string_str[..länge], self.case).unwrap_or(false);
+        Some(passt.then(|| &string_str[länge..]))
+    }
+}
+
+/// Vergleiche zwei ASCII-Strings, für die Normalisierung und diakritische Zeichen
+/// keine Rolle spielen. Ist einer der Strings kein ASCII-String, wird [None] zurückgegeben.
+fn ascii_eq(a: &str, b: &str, case: Case) -> Option<bool> {
+    (a.is_ascii() && b.is_ascii()).then(|| match case {
+        Case::Sensitive => a == b,
+        Case::Insensitive => a.eq_ignore_ascii_case(b),
+    })
 }
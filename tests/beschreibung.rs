@@ -0,0 +1,90 @@
+//! Tests für die Beschreibung eines Arguments.
+
+use std::ffi::OsString;
+
+use void::Void;
+
+use kommandozeilen_argumente::{kombiniere, Argumente, Beschreibung, Ergebnis, Sprache};
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Kurznamen müssen aus genau einem Grapheme bestehen")]
+fn kurzname_mehrere_graphemes() {
+    let _: Beschreibung<'_, bool> =
+        Beschreibung::neu_mit_sprache("flag", "fl", None, None, Sprache::DEUTSCH);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Doppelte Kurznamen")]
+fn kurzname_doppelt() {
+    let _: Beschreibung<'_, bool> =
+        Beschreibung::neu_mit_sprache("flag", vec!["x", "x"], None, None, Sprache::DEUTSCH);
+}
+
+#[test]
+fn kurzname_grapheme_cluster() {
+    let beschreibung: Beschreibung<'_, bool> =
+        Beschreibung::neu_mit_sprache("flag", vec!["x", "e\u{301}"], None, None, Sprache::DEUTSCH);
+    assert_eq!(beschreibung.kurz.len(), 2);
+}
+
+fn kurz_flag(beschreibung: Beschreibung<'static, bool>) -> Argumente<'static, bool, Void> {
+    Argumente::flag_bool_mit_sprache(beschreibung, Sprache::DEUTSCH)
+}
+
+#[test]
+fn mehrteiliger_kurzname_flag() {
+    let kombiniert = || {
+        let lesen_schreiben = kurz_flag(Beschreibung::neu_mit_mehrteiligen_kurznamen(
+            "--",
+            "lesen-schreiben",
+            "+",
+            "rw",
+            None,
+            Some(false),
+        ));
+        let lesen = kurz_flag(Beschreibung::neu("--", "lesen", "+", "r", None, Some(false)));
+        let schreiben =
+            kurz_flag(Beschreibung::neu("--", "schreiben", "+", "w", None, Some(false)));
+        kombiniere!(|rw, r, w| (rw, r, w), lesen_schreiben, lesen, schreiben)
+    };
+    let fälle: [(&[&str], _); 3] = [
+        (&["+rw"], (true, false, false)),
+        (&["+wr"], (false, true, true)),
+        (&["+r", "+rw"], (true, true, false)),
+    ];
+    for (args, erwartet) in fälle {
+        match kombiniert().parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
+
+#[test]
+fn mehrteiliger_kurzname_wert() {
+    let wert = || {
+        Argumente::<i32, ()>::wert_from_str(
+            Beschreibung::neu_mit_mehrteiligen_kurznamen("--", "nummer", "+", "nr", None, None),
+            "=",
+            "NUMMER",
+            None,
+            ToString::to_string,
+            |_| (),
+        )
+    };
+    let fälle: [(&[&str], i32); 2] = [(&["+nr", "5"], 5), (&["+nr=7"], 7)];
+    for (args, erwartet) in fälle {
+        match wert().parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
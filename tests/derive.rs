@@ -3,10 +3,14 @@
 use std::{
     ffi::OsString,
     fmt::{self, Debug, Display, Formatter},
-    iter, process,
+    iter,
+    path::PathBuf,
+    process,
 };
 
-use kommandozeilen_argumente::{Argumente, EnumArgument, Ergebnis, Parse, ParseArgument};
+use kommandozeilen_argumente::{
+    Argumente, EnumArgument, Ergebnis, NonEmpty, Parse, ParseAbbruch, ParseArgument, ParseFehler,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, EnumArgument)]
 #[kommandozeilen_argumente(case: insensitive)]
@@ -29,6 +33,18 @@ fn arg_enum_derive() {
     assert_eq!(parse_res, Ok(Bla::Meh));
 }
 
+#[test]
+fn arg_enum_derive_unbekannte_variante() {
+    let os_string: OsString = "xyz".to_owned().into();
+    let parse_res = Bla::parse_enum(os_string);
+    assert_eq!(
+        parse_res,
+        Err(ParseFehler::ParseFehler(
+            "Unbekannte Variante \"xyz\", erlaubt sind: Meh, Muh".to_owned()
+        ))
+    );
+}
+
 #[derive(Debug, PartialEq, Eq, Parse)]
 #[kommandozeilen_argumente(sprache: deutsch, version, hilfe)]
 struct Test {
@@ -56,13 +72,33 @@ const DUMMY: kommandozeilen_argumente::Sprache = kommandozeilen_argumente::Sprac
     invertiere_infix: "*",
     wert_infix: "+",
     meta_var: "dummy",
+    meta_var_dauer: "dummy",
+    meta_var_anzahl: "dummy",
+    meta_var_zahl: "dummy",
+    meta_var_pfad: "dummy",
     optionen: "dummy",
     standard: "dummy",
     erlaubte_werte: "dummy",
+    exit_codes: "dummy",
     fehlende_flag: "dummy",
     fehlender_wert: "dummy",
     parse_fehler: "dummy",
     invalider_string: "dummy",
+    fehlender_trenner: "dummy",
+    ungültiger_schlüssel: "dummy",
+    ungültiger_wert: "dummy",
+    wert_nicht_erlaubt: "dummy",
+    leeres_element: "dummy",
+    doppeltes_element: "dummy",
+    mehrfach_genannt: "dummy",
+    unbekannte_umgebungsvariable: "dummy",
+    zu_viele_werte: "dummy",
+    widersprüchliche_flags: "dummy",
+    veraltet: "dummy",
+    erwartete_werte: "dummy",
+    wahr: &["dummy"],
+    falsch: &["dummy"],
+    aufruf: "dummy",
     argument_nicht_verwendet: "dummy",
     hilfe_beschreibung: "dummy",
     hilfe_lang: "dummy",
@@ -235,3 +271,621 @@ fn verschmelze_kurzformen() {
         },
     }
 }
+
+#[test]
+fn derive_hilfe_text() {
+    let arg = Test::kommandozeilen_argumente();
+    let nachrichten = arg
+        .frühes_beenden_nachrichten(iter::once(OsString::from("--hilfe")))
+        .expect("Frühes Beenden erwartet.");
+    assert_eq!(nachrichten.len(), 1, "{:?}", nachrichten);
+    let hilfe_text = &nachrichten.head;
+    let titel = format!("{} {}\n", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+    assert!(hilfe_text.starts_with(&titel), "{hilfe_text}");
+    let erwartet = [
+        "  --bla(=| )WERT                             bla [Erlaubte Werte: Meh, Muh]",
+        "  --alternativ(=| )WERT | -(p|q|r)[=| ]WERT  opt [Standard: None]",
+        "  --from_str(=| )VAR                         from_str [Standard: 42]",
+        "  --[kein]-flag                              flag [Standard: true]",
+        "  --version             | -v                 Zeige die aktuelle Version an.",
+        "  --hilfe               | -h                 Zeige diesen Text an.",
+    ];
+    assert!(hilfe_text.ends_with(&format!("OPTIONEN:\n{}\n", erwartet.join("\n"))), "{hilfe_text}");
+    assert!(arg.frühes_beenden_nachrichten(iter::once(OsString::from("--bla=meh"))).is_none());
+}
+
+#[derive(Debug, PartialEq, Parse)]
+#[kommandozeilen_argumente(sprache: deutsch, hilfe)]
+struct MetaVarStandard {
+    anzahl: u8,
+    faktor: Option<f64>,
+    pfad: PathBuf,
+    name: String,
+    #[kommandozeilen_argumente(meta_var: N)]
+    explizit: usize,
+}
+
+#[test]
+fn derive_meta_var_standard() {
+    let nachrichten = MetaVarStandard::kommandozeilen_argumente()
+        .frühes_beenden_nachrichten(iter::once(OsString::from("--hilfe")))
+        .expect("Frühes Beenden erwartet.");
+    let hilfe_text = &nachrichten.head;
+    for erwartet in [
+        "--anzahl(=| )ANZAHL",
+        "--faktor(=| )ZAHL",
+        "--pfad(=| )PFAD",
+        "--name(=| )WERT",
+        "--explizit(=| )N",
+    ] {
+        assert!(hilfe_text.contains(erwartet), "{erwartet}\n{hilfe_text}");
+    }
+}
+
+#[derive(Debug, Parse)]
+#[kommandozeilen_argumente(help)]
+struct MetaVarStandardEnglish {
+    count: u8,
+    factor: Option<f64>,
+    path: PathBuf,
+    name: String,
+}
+
+#[test]
+fn derive_meta_var_standard_english() {
+    let nachrichten = MetaVarStandardEnglish::kommandozeilen_argumente()
+        .frühes_beenden_nachrichten(iter::once(OsString::from("--hilfe")))
+        .expect("Frühes Beenden erwartet.");
+    let hilfe_text = &nachrichten.head;
+    for erwartet in
+        ["--count(=| )COUNT", "--factor(=| )NUMBER", "--path(=| )PATH", "--name(=| )VALUE"]
+    {
+        assert!(hilfe_text.contains(erwartet), "{erwartet}\n{hilfe_text}");
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+#[kommandozeilen_argumente(invertiere_präfix: no, invertiere_infix: "")]
+struct LeererInfix {
+    flag: bool,
+}
+
+#[test]
+fn derive_leerer_invertiere_infix() {
+    for (arg, erwartet) in [("--flag", true), ("--noflag", false)] {
+        match LeererInfix::parse(iter::once(OsString::from(arg))) {
+            (Ergebnis::Wert(LeererInfix { flag }), nicht_verwendet) => {
+                assert_eq!(flag, erwartet, "Argument: {arg}");
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {arg}: {:?}", res),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+#[kommandozeilen_argumente(sprache: deutsch, hilfe)]
+struct Bereich {
+    /// Anfang und
+    #[kommandozeilen_argumente(aus: range, trenner: "-")]
+    start: u32,
+    /// Ende des Bereichs.
+    #[kommandozeilen_argumente(aus: range)]
+    end: u32,
+    #[kommandozeilen_argumente(standard: false)]
+    flag: bool,
+}
+
+#[test]
+fn derive_aus_gemeinsamem_wert() {
+    match Bereich::parse(["--range", "1-10"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(bereich), nicht_verwendet) => {
+            assert_eq!(bereich, Bereich { start: 1, end: 10, flag: false });
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match Bereich::parse(iter::once(OsString::from("--range=1"))) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+            let fehlermeldung = fehler.head.fehlermeldung();
+            assert!(fehlermeldung.ends_with("Fehlendes Trennzeichen \"-\": 1"), "{fehlermeldung}");
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let nachrichten = Bereich::kommandozeilen_argumente()
+        .frühes_beenden_nachrichten(iter::once(OsString::from("--hilfe")))
+        .expect("Frühes Beenden erwartet.");
+    assert!(
+        nachrichten.head.lines().any(|zeile| zeile.starts_with("  --range(=| )WERT ")
+            && zeile.ends_with(" Anfang und Ende des Bereichs.")),
+        "{}",
+        nachrichten.head
+    );
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, EnumArgument)]
+#[kommandozeilen_argumente(umbenennen_alle: kebab-case, anzeige)]
+enum Aktion {
+    Create,
+    DeleteAll,
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+#[kommandozeilen_argumente(sprache: deutsch, hilfe)]
+struct Umbenannt {
+    aktion: Aktion,
+}
+
+#[test]
+fn derive_enum_umbenennen_alle() {
+    assert_eq!(Aktion::DeleteAll.to_string(), "delete-all");
+    assert_eq!(Aktion::parse_enum(OsString::from("delete-all")), Ok(Aktion::DeleteAll));
+    assert!(Aktion::parse_enum(OsString::from("DeleteAll")).is_err());
+    let arg = Umbenannt::kommandozeilen_argumente();
+    match arg.parse(["--aktion", "delete-all"].map(OsString::from).into_iter()) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, Umbenannt { aktion: Aktion::DeleteAll });
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let nachrichten = Umbenannt::kommandozeilen_argumente()
+        .frühes_beenden_nachrichten(iter::once(OsString::from("--hilfe")))
+        .expect("Frühes Beenden erwartet.");
+    let hilfe_text = &nachrichten.head;
+    assert!(hilfe_text.contains("create, delete-all"), "{hilfe_text}");
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, EnumArgument)]
+#[kommandozeilen_argumente(umbenennen_alle: snake_case)]
+enum Farbe {
+    HellRot,
+    Blau,
+}
+
+// Ohne `anzeige` wird keine Display-Implementierung erzeugt.
+impl Display for Farbe {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Farbe::HellRot => "hellrot",
+            Farbe::Blau => "blau",
+        })
+    }
+}
+
+#[test]
+fn derive_enum_umbenennen_alle_eigene_anzeige() {
+    assert_eq!(Farbe::HellRot.to_string(), "hellrot");
+    assert_eq!(Farbe::parse_enum(OsString::from("hell_rot")), Ok(Farbe::HellRot));
+    assert!(Farbe::parse_enum(OsString::from("hellrot")).is_err());
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, EnumArgument)]
+enum Stufe {
+    #[kommandozeilen_argumente(reihenfolge: 2)]
+    Hoch,
+    Niedrig,
+    #[kommandozeilen_argumente(order: -1)]
+    Aus,
+}
+
+impl Display for Stufe {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+#[kommandozeilen_argumente(sprache: deutsch, hilfe)]
+struct Sortiert {
+    stufe: Stufe,
+}
+
+#[test]
+fn derive_enum_reihenfolge() {
+    assert_eq!(Stufe::varianten(), vec![Stufe::Aus, Stufe::Niedrig, Stufe::Hoch]);
+    let nachrichten = Sortiert::kommandozeilen_argumente()
+        .frühes_beenden_nachrichten(iter::once(OsString::from("--hilfe")))
+        .expect("Frühes Beenden erwartet.");
+    let hilfe_text = &nachrichten.head;
+    assert!(hilfe_text.contains("Aus, Niedrig, Hoch"), "{hilfe_text}");
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, EnumArgument)]
+#[kommandozeilen_argumente(umbenennen_alle: kebab-case, display)]
+enum Modus {
+    #[kommandozeilen_argumente(kurz: "r")]
+    Read,
+    #[kommandozeilen_argumente(short: "w")]
+    Write,
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+struct MitModus {
+    modus: Modus,
+}
+
+#[test]
+fn derive_enum_kurz() {
+    assert_eq!(Modus::parse_enum(OsString::from("w")), Ok(Modus::Write));
+    assert_eq!(Modus::parse_enum(OsString::from("write")), Ok(Modus::Write));
+    for modus in ["r", "read"] {
+        match MitModus::parse_aus_strings(["--modus", modus]) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, MitModus { modus: Modus::Read });
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis: {:?}", res),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+struct OptionalesFlag {
+    feature: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+#[kommandozeilen_argumente(sprache: deutsch)]
+struct OptionalesFlagDeutsch {
+    farbe: Option<bool>,
+}
+
+#[test]
+fn derive_option_bool() {
+    let fälle: [(&[&str], Option<bool>); 5] = [
+        (&["--feature"], Some(true)),
+        (&["--no-feature"], Some(false)),
+        (&["--feature=false"], Some(false)),
+        (&["--feature=yes"], Some(true)),
+        (&[], None),
+    ];
+    for (args, erwartet) in fälle {
+        match OptionalesFlag::parse_aus_strings(args) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, OptionalesFlag { feature: erwartet }, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+    // Das folgende Argument wird nie als Wert verwendet.
+    match OptionalesFlag::parse_aus_strings(["--feature", "false"]) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, OptionalesFlag { feature: Some(true) });
+            assert_eq!(nicht_verwendet, vec![OsString::from("false")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    // Mit deutscher Sprache werden deren Schreibweisen akzeptiert.
+    let fälle: [(&[&str], Option<bool>); 4] = [
+        (&["--farbe=ja"], Some(true)),
+        (&["--farbe=nein"], Some(false)),
+        (&["--kein-farbe"], Some(false)),
+        (&[], None),
+    ];
+    for (args, erwartet) in fälle {
+        match OptionalesFlagDeutsch::parse_aus_strings(args) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(
+                    wert,
+                    OptionalesFlagDeutsch { farbe: erwartet },
+                    "Argumente: {:?}",
+                    args
+                );
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
+
+#[test]
+fn derive_parse_aus_strings() {
+    let args: Vec<String> = vec!["--aktion".to_owned(), "create".to_owned()];
+    match Umbenannt::parse_aus_strings(&args) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, Umbenannt { aktion: Aktion::Create });
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match Umbenannt::parse_aus_strings(["--aktion=delete-all", "übrig"]) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, Umbenannt { aktion: Aktion::DeleteAll });
+            assert_eq!(nicht_verwendet, vec![OsString::from("übrig")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+struct Markierungen {
+    #[kommandozeilen_argumente(mehrfach, kurz)]
+    tags: Vec<String>,
+    #[kommandozeilen_argumente(multiple)]
+    zahlen: Vec<u8>,
+}
+
+#[test]
+fn derive_mehrfach() {
+    let args = ["--tags", "a", "-t=b", "--zahlen", "1", "--tags=c"];
+    match Markierungen::parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            let tags = vec!["a".to_owned(), "b".to_owned(), "c".to_owned()];
+            assert_eq!(wert, Markierungen { tags, zahlen: vec![1] });
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match Markierungen::parse(iter::empty()) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, Markierungen { tags: Vec::new(), zahlen: Vec::new() });
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match Markierungen::parse(["--zahlen", "x"].iter().map(OsString::from)) {
+        (Ergebnis::Fehler(_fehler), _nicht_verwendet) => {},
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+#[kommandozeilen_argumente(standard_werte)]
+struct Verschachtelt {
+    #[kommandozeilen_argumente(standard: 3)]
+    tiefe: u8,
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+#[kommandozeilen_argumente(default_values)]
+struct MitStandardWerten {
+    flag: bool,
+    optional: Option<String>,
+    #[kommandozeilen_argumente(standard: 42)]
+    zahl: i32,
+    #[kommandozeilen_argumente(mehrfach)]
+    tags: Vec<String>,
+    #[kommandozeilen_argumente(glätten)]
+    verschachtelt: Verschachtelt,
+}
+
+#[test]
+fn derive_standard_werte() {
+    let standard = MitStandardWerten::standard_werte();
+    assert_eq!(
+        standard,
+        MitStandardWerten {
+            flag: false,
+            optional: None,
+            zahl: 42,
+            tags: Vec::new(),
+            verschachtelt: Verschachtelt { tiefe: 3 },
+        }
+    );
+    match MitStandardWerten::parse(iter::empty()) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, standard);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+#[kommandozeilen_argumente(lang_präfix: "opt-", case(lang_präfix: insensitive))]
+struct CasePräfix {
+    #[kommandozeilen_argumente(standard: 0)]
+    name: u8,
+    #[kommandozeilen_argumente(kurz, case(lang: insensitive, kurz: insensitive), standard: 0)]
+    beliebig: u8,
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+#[kommandozeilen_argumente(case: insensitive)]
+struct CaseStruct {
+    #[kommandozeilen_argumente(standard: 0)]
+    name: u8,
+    #[kommandozeilen_argumente(kurz, case(lang: sensitive, kurz: sensitive), standard: 0)]
+    genau: u8,
+}
+
+#[test]
+fn derive_case_pro_bestandteil() {
+    let args = ["OPT-name", "1", "Opt-BELIEBIG=2"];
+    match CasePräfix::parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, CasePräfix { name: 1, beliebig: 2 });
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match CasePräfix::parse(["opt-Name", "1", "-B", "3"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, CasePräfix { name: 0, beliebig: 3 });
+            assert_eq!(nicht_verwendet, vec![OsString::from("opt-Name"), OsString::from("1")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match CaseStruct::parse(["--NAME", "1", "--genau", "2"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, CaseStruct { name: 1, genau: 2 });
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match CaseStruct::parse(["--GENAU", "1", "-G", "2"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, CaseStruct { name: 0, genau: 0 });
+            let erwartet: Vec<_> = ["--GENAU", "1", "-G", "2"].iter().map(OsString::from).collect();
+            assert_eq!(nicht_verwendet, erwartet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+#[kommandozeilen_argumente(lang_präfix: "", kurz_präfix: "-")]
+struct OhneLangPräfix {
+    #[kommandozeilen_argumente(kurz)]
+    start: bool,
+    #[kommandozeilen_argumente(standard: 0)]
+    anzahl: u8,
+}
+
+#[derive(Debug, PartialEq, Eq, Parse)]
+#[kommandozeilen_argumente(lang_präfix: "-")]
+struct GoStil {
+    #[kommandozeilen_argumente(lang: "dry-run")]
+    probelauf: bool,
+}
+
+#[test]
+fn derive_präfix_im_namen() {
+    match OhneLangPräfix::parse(["start", "anzahl=3"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, OhneLangPräfix { start: true, anzahl: 3 });
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match GoStil::parse(["-dry-run"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, GoStil { probelauf: true });
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Parse)]
+struct Intervall {
+    #[kommandozeilen_argumente(aus: bereich, trenner: "-")]
+    von: u8,
+    #[kommandozeilen_argumente(aus: bereich)]
+    bis: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Parse)]
+#[kommandozeilen_argumente(hilfe, version)]
+struct Konfiguration {
+    ausführlich: bool,
+    #[kommandozeilen_argumente(standard: 1)]
+    threads: u8,
+    name: String,
+    #[kommandozeilen_argumente(mehrfach)]
+    tags: Vec<String>,
+    #[kommandozeilen_argumente(glätten)]
+    bereich: Intervall,
+}
+
+#[test]
+fn derive_mit_standards() {
+    let aus_datei = || Konfiguration {
+        ausführlich: true,
+        threads: 4,
+        name: "datei".to_owned(),
+        tags: vec!["a".to_owned()],
+        bereich: Intervall { von: 1, bis: 5 },
+    };
+    // Ohne Standard-Werte sind `name` und `bereich` benötigt.
+    match Konfiguration::parse(iter::empty()) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => assert_eq!(fehler.len(), 2),
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let argumente = Argumente::mit_standards(aus_datei());
+    match argumente.parse(iter::empty()) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, aus_datei());
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let nachrichten = argumente
+        .frühes_beenden_nachrichten(iter::once(OsString::from("--hilfe")))
+        .expect("Frühes Beenden erwartet.");
+    let hilfe_text = &nachrichten.head;
+    assert!(hilfe_text.contains("[Standard: 1-5]"), "{hilfe_text}");
+    assert!(!hilfe_text.contains("[Standard: ]"), "{hilfe_text}");
+    let args = ["--no-ausführlich", "--name", "cli", "--bereich=2-3", "--tags", "b"];
+    match argumente.parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            let erwartet = Konfiguration {
+                ausführlich: false,
+                name: "cli".to_owned(),
+                tags: vec!["b".to_owned()],
+                bereich: Intervall { von: 2, bis: 3 },
+                ..aus_datei()
+            };
+            assert_eq!(wert, erwartet);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn derive_parse_nachsichtig() {
+    let aus_datei = Konfiguration {
+        ausführlich: false,
+        threads: 4,
+        name: "datei".to_owned(),
+        tags: Vec::new(),
+        bereich: Intervall { von: 1, bis: 5 },
+    };
+    let args = ["--threads", "viele", "--name", "cli", "--bereich=2-x", "--unbekannt"];
+    let (wert, fehler, nicht_verwendet) =
+        Konfiguration::kommandozeilen_argumente_mit_standards(aus_datei.clone())
+            .parse_nachsichtig(args.iter().map(OsString::from));
+    // Fehlerhafte Felder verwenden ihren Standard-Wert, die übrigen den geparsten Wert.
+    assert_eq!(wert, Some(Konfiguration { name: "cli".to_owned(), ..aus_datei }));
+    let namen: Vec<_> = fehler.iter().map(|fehler| fehler.namen().lang.head.as_ref()).collect();
+    assert_eq!(namen, vec!["threads", "bereich"]);
+    assert_eq!(nicht_verwendet, vec![OsString::from("--unbekannt")]);
+    // Ohne Standard-Werte erhalten nur Felder mit `standard` ihren Standard-Wert.
+    let args = ["--threads", "viele", "--name", "cli", "--bereich=2-3"];
+    let (wert, fehler, nicht_verwendet) =
+        Konfiguration::parse_nachsichtig(args.iter().map(OsString::from));
+    let erwartet = Konfiguration {
+        ausführlich: false,
+        threads: 1,
+        name: "cli".to_owned(),
+        tags: Vec::new(),
+        bereich: Intervall { von: 2, bis: 3 },
+    };
+    assert_eq!(wert, Some(erwartet));
+    assert_eq!(fehler.len(), 1, "{:?}", fehler);
+    assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+    // Ein fehlerhaftes Feld ohne Standard-Wert verhindert einen Wert.
+    let args = ["--name", "cli", "--bereich=2-x"];
+    let (wert, fehler, _nicht_verwendet) =
+        Konfiguration::parse_nachsichtig(args.iter().map(OsString::from));
+    assert_eq!(wert, None);
+    assert_eq!(fehler.len(), 1, "{:?}", fehler);
+}
+
+#[test]
+fn derive_versuche_parse() {
+    let args = |args: &[&str]| args.iter().map(OsString::from).collect::<Vec<_>>();
+    match Bereich::versuche_parse(args(&["--range", "1-10", "--flag"]).into_iter()) {
+        Ok(bereich) => assert_eq!(bereich, Bereich { start: 1, end: 10, flag: true }),
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match Bereich::versuche_parse(args(&["--hilfe"]).into_iter()) {
+        Err(ParseAbbruch::FrühesBeenden(nachrichten)) => {
+            assert!(nachrichten.head.contains("--range"), "{}", nachrichten.head)
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match Bereich::versuche_parse(args(&["--range", "1-10", "--unbekannt"]).into_iter()) {
+        Err(ParseAbbruch::NichtVerwendet(nicht_verwendet)) => {
+            assert_eq!(nicht_verwendet, NonEmpty::singleton(OsString::from("--unbekannt")))
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match Bereich::versuche_parse(args(&[]).into_iter()) {
+        Err(ParseAbbruch::Fehler(fehler)) => assert_eq!(fehler.len(), 1, "{:?}", fehler),
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
@@ -0,0 +1,7 @@
+//! Tests für Fehlermeldungen des derive-Macros zur Compile-Zeit.
+
+#[test]
+fn derive_fehler() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/derive_fehler/*.rs");
+}
@@ -0,0 +1,10 @@
+use kommandozeilen_argumente::Parse;
+
+#[derive(Parse)]
+#[kommandozeilen_argumente(sprache: deutsch)]
+struct Args {
+    #[kommandozeilen_argumente(lang: "--ausgabe")]
+    ausgabe: bool,
+}
+
+fn main() {}
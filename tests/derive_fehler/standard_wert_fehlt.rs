@@ -0,0 +1,10 @@
+use kommandozeilen_argumente::Parse;
+
+#[derive(Parse)]
+#[kommandozeilen_argumente(sprache: deutsch, standard_werte)]
+struct Args {
+    flag: bool,
+    zahl: u8,
+}
+
+fn main() {}
@@ -0,0 +1,84 @@
+//! Tests für Wert-Argumente mit Eingabeaufforderung.
+
+use std::{cell::Cell, ffi::OsString};
+
+use kommandozeilen_argumente::{Argumente, Beschreibung, Ergebnis, ParseFehler, Sprache};
+
+/// Wird von cargo beim Ausführen der Tests gesetzt,
+/// dadurch muss die Umgebung des Prozesses nicht verändert werden.
+const UMGEBUNGSVARIABLE: &str = "CARGO_PKG_NAME";
+
+fn parse_passwort(args: &[&str]) -> (Ergebnis<'static, String, String>, Vec<OsString>) {
+    let arg: Argumente<'_, String, String> = Argumente::wert_mit_eingabeaufforderung_und_sprache(
+        Beschreibung::neu_mit_sprache("passwort", None::<&str>, None, None, Sprache::DEUTSCH),
+        Some(UMGEBUNGSVARIABLE),
+        "Passwort: ",
+        |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+        String::clone,
+        Sprache::DEUTSCH,
+    );
+    arg.parse(args.iter().map(OsString::from))
+}
+
+#[test]
+fn vorrang_kommandozeile_umgebungsvariable() {
+    for (args, erwartet) in [
+        (&["--passwort", "geheim"][..], "geheim"),
+        (&["--passwort=geheim"], "geheim"),
+        (&[], env!("CARGO_PKG_NAME")),
+    ] {
+        match parse_passwort(args) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
+
+#[test]
+fn eingabeaufforderung_nach_parsen() {
+    let aufrufe = Cell::new(0);
+    let passwort: Argumente<'_, String, String> = Argumente::wert_mit_eingabeaufforderung_fn(
+        Beschreibung::neu_mit_sprache("passwort", None::<&str>, None, None, Sprache::DEUTSCH),
+        Sprache::DEUTSCH.wert_infix,
+        Sprache::DEUTSCH.meta_var,
+        None,
+        || {
+            aufrufe.set(aufrufe.get() + 1);
+            Some(OsString::from("eingabe"))
+        },
+        |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+        String::clone,
+    );
+    let zahl: Argumente<'_, u8, String> = Argumente::wert_from_str_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("zahl", None::<&str>, None, Some(0), Sprache::DEUTSCH),
+        None,
+        Sprache::DEUTSCH,
+    );
+    let argumente = Argumente::kombiniere2(|passwort, zahl| (passwort, zahl), passwort, zahl)
+        .hilfe_mit_sprache("programm", None, None, Sprache::DEUTSCH);
+    // Frühes Beenden, Fehler anderer Argumente und ein Probelauf fragen nicht nach.
+    assert!(argumente.frühes_beenden_nachrichten(["--hilfe"].iter().map(OsString::from)).is_some());
+    assert!(matches!(
+        argumente.parse(["--hilfe"].iter().map(OsString::from)).0,
+        Ergebnis::FrühesBeenden(_)
+    ));
+    assert!(matches!(
+        argumente.parse(["--zahl=abc"].iter().map(OsString::from)).0,
+        Ergebnis::Fehler(_)
+    ));
+    let bericht = argumente.prüfe(Vec::new().into_iter(), Sprache::DEUTSCH);
+    assert_eq!(bericht.fehler, vec!["Fehlender Wert: --passwort( |=)WERT".to_owned()]);
+    assert_eq!(aufrufe.get(), 0);
+    // Ansonsten wird der fehlende Wert abgefragt.
+    match argumente.parse(["--zahl", "3"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, ("eingabe".to_owned(), 3));
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    assert_eq!(aufrufe.get(), 1);
+}
@@ -0,0 +1,80 @@
+//! Tests zum Export der Konfiguration.
+
+use void::Void;
+
+use kommandozeilen_argumente::{
+    export::{Argument, Befehl},
+    kombiniere, Argumente, Beschreibung, Sprache,
+};
+
+#[test]
+fn exportiere_konfiguration() {
+    let flag: Argumente<'_, bool, Void> = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache("flag", "f", Some("Eine Flag."), None, Sprache::DEUTSCH),
+        Sprache::DEUTSCH,
+    );
+    let wert: Argumente<'_, String, Void> = Argumente::wert_display_mit_sprache(
+        Beschreibung::neu_mit_sprache(
+            "wert",
+            None::<&str>,
+            None,
+            Some("standard".to_owned()),
+            Sprache::DEUTSCH,
+        ),
+        None,
+        |os_string| Ok(os_string.to_string_lossy().into_owned()),
+        Sprache::DEUTSCH,
+    );
+    let argumente = kombiniere!(|flag, wert| (flag, wert), flag, wert).hilfe_mit_sprache(
+        "programm",
+        None,
+        None,
+        Sprache::DEUTSCH,
+    );
+    let befehl = argumente.exportiere("programm", Some("Beschreibung."), Some("1.0"));
+    let Befehl { name, über, version, argumente } = befehl;
+    assert_eq!(name, "programm");
+    assert_eq!(über.as_deref(), Some("Beschreibung."));
+    assert_eq!(version.as_deref(), Some("1.0"));
+    assert_eq!(
+        argumente,
+        vec![
+            Argument {
+                id: "flag".to_owned(),
+                lang_präfix: "--".to_owned(),
+                lang: vec!["flag".to_owned()],
+                kurz_präfix: "-".to_owned(),
+                kurz: vec!["f".to_owned()],
+                hilfe: Some("Eine Flag.".to_owned()),
+                benötigt: true,
+                meta_var: None,
+                mögliche_werte: Vec::new(),
+                standard: None,
+            },
+            Argument {
+                id: "wert".to_owned(),
+                lang_präfix: "--".to_owned(),
+                lang: vec!["wert".to_owned()],
+                kurz_präfix: "-".to_owned(),
+                kurz: Vec::new(),
+                hilfe: None,
+                benötigt: false,
+                meta_var: Some("WERT".to_owned()),
+                mögliche_werte: Vec::new(),
+                standard: Some("standard".to_owned()),
+            },
+            Argument {
+                id: "hilfe".to_owned(),
+                lang_präfix: "--".to_owned(),
+                lang: vec!["hilfe".to_owned()],
+                kurz_präfix: "-".to_owned(),
+                kurz: vec!["h".to_owned()],
+                hilfe: Some("Zeige diesen Text an.".to_owned()),
+                benötigt: false,
+                meta_var: None,
+                mögliche_werte: Vec::new(),
+                standard: None,
+            },
+        ]
+    );
+}
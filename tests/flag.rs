@@ -0,0 +1,288 @@
+//! Tests für Flag-Argumente.
+
+use std::{env, ffi::OsString, iter, num::NonZeroI32, process};
+
+use void::Void;
+
+use kommandozeilen_argumente::{kombiniere, Argumente, Beschreibung, Ergebnis, Sprache};
+
+fn ein_aus_flag(standard: Option<bool>) -> Argumente<'static, bool, Void> {
+    Argumente::flag_bool_ein_aus(
+        Beschreibung::neu_mit_sprache(
+            "verbose",
+            "x",
+            Some("Ausführlich."),
+            standard,
+            Sprache::DEUTSCH,
+        ),
+        "+",
+        "-",
+    )
+}
+
+#[test]
+fn plus_minus_flag() {
+    let fälle: [(&[&str], bool); 5] = [
+        (&["+x"], true),
+        (&["-x"], false),
+        (&["+verbose"], true),
+        (&["-verbose"], false),
+        (&["+x", "-x"], false),
+    ];
+    for (args, erwartet) in fälle {
+        match ein_aus_flag(None).parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+    match ein_aus_flag(Some(false)).parse(["--verbose", "+y"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert!(!wert);
+            assert_eq!(nicht_verwendet, vec![OsString::from("--verbose"), OsString::from("+y")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match ein_aus_flag(None).parse(std::iter::empty()) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+            assert_eq!(fehler.head.fehlermeldung(), "Fehlende Flag: (+|-)verbose | (+|-)x");
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn plus_minus_flag_hilfe() {
+    let hilfe_text = ein_aus_flag(Some(false)).hilfe_text("programm", None, None);
+    assert!(hilfe_text.contains("(+|-)verbose | (+|-)x"), "{hilfe_text}");
+}
+
+#[test]
+fn leerer_invertiere_infix() {
+    let flag = |standard| -> Argumente<'static, bool, Void> {
+        Argumente::flag_bool(
+            Beschreibung::neu_mit_sprache("flag", None::<&str>, None, standard, Sprache::ENGLISH),
+            "no",
+            "",
+        )
+    };
+    for (arg, erwartet) in [("--flag", true), ("--noflag", false)] {
+        match flag(None).parse(iter::once(OsString::from(arg))) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argument: {arg}");
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {arg}: {:?}", res),
+        }
+    }
+    match flag(None).parse(iter::once(OsString::from("--no-flag"))) {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            assert_eq!(fehler.head.fehlermeldung(), "Fehlende Flag: --[no]flag");
+            assert_eq!(nicht_verwendet, vec![OsString::from("--no-flag")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let nachrichten = flag(Some(false))
+        .hilfe_mit_sprache("programm", None, None, Sprache::DEUTSCH)
+        .frühes_beenden_nachrichten(iter::once(OsString::from("--hilfe")))
+        .expect("Frühes Beenden erwartet.");
+    assert!(nachrichten.head.contains("  --[no]flag "), "{}", nachrichten.head);
+}
+
+#[test]
+fn impliziert() {
+    let flag = |name: &'static str| -> Argumente<'static, bool, Void> {
+        Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache(name, None::<&str>, None, Some(false), Sprache::DEUTSCH),
+            Sprache::DEUTSCH,
+        )
+    };
+    let (alle, a, b, c) = (flag("alle"), flag("a"), flag("b"), flag("c"));
+    let argumente = kombiniere!(|alle, a, b, c| (alle, a, b, c), alle, a, b, c)
+        .impliziert("alle", ["a", "b", "c"]);
+    let fälle: [(&[&str], _); 4] = [
+        (&[], (false, false, false, false)),
+        (&["--b"], (false, false, true, false)),
+        (&["--alle"], (true, true, true, true)),
+        (&["--alle", "--kein-b"], (true, true, false, true)),
+    ];
+    for (args, erwartet) in fälle {
+        match argumente.parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+    // Implizite Argumente verschieben die Position der übrigen Argumente nicht,
+    // auch nicht innerhalb weiterer kombinierter Argumente.
+    let d = flag("d");
+    let verschachtelt = kombiniere!(|argumente, d| (argumente, d), argumente, d);
+    match verschachtelt.parse(["--alle", "foo", "--d"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, ((true, true, true, true), true));
+            assert_eq!(nicht_verwendet, vec!["foo"]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let bericht =
+        verschachtelt.prüfe(["--alle", "foo"].iter().map(OsString::from), Sprache::DEUTSCH);
+    assert_eq!(bericht.verwendet, vec![OsString::from("--alle")]);
+    assert_eq!(bericht.nicht_verwendet, vec![OsString::from("foo")]);
+}
+
+#[test]
+fn zähler_mit_gegenstück() {
+    let zähler: Argumente<'static, i64, Void> = Argumente::flag_zähler_mit_gegenstück(
+        Beschreibung::neu_mit_sprache("verbose", "v", None, None, Sprache::DEUTSCH),
+        Beschreibung::neu_mit_sprache("quiet", "q", None, None, Sprache::DEUTSCH),
+    );
+    for (args, erwartet) in
+        [(&["-vvq"][..], 1), (&[], 0), (&["-qq", "--verbose"], -1), (&["-v", "--quiet", "-vvv"], 3)]
+    {
+        match zähler.parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "{:?}", args);
+                assert!(nicht_verwendet.is_empty(), "{:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis: {:?}", res),
+        }
+    }
+}
+
+#[test]
+fn widersprüchliche_flags() {
+    let flag: Argumente<'static, bool, Void> = Argumente::flag_bool_streng_mit_sprache(
+        Beschreibung::neu_mit_sprache("verbose", "v", None, Some(false), Sprache::DEUTSCH),
+        Sprache::DEUTSCH,
+    );
+    match flag.parse(["--verbose", "--kein-verbose"].iter().map(OsString::from)) {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            assert!(nicht_verwendet.is_empty(), "{:?}", nicht_verwendet);
+            let fehlermeldung = fehler.head.fehlermeldung();
+            assert!(
+                fehlermeldung.starts_with("Widersprüchliche Flags: --[kein-]verbose"),
+                "{}",
+                fehlermeldung
+            );
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    for (args, erwartet) in
+        [(&["--kein-verbose", "--kein-verbose"][..], false), (&["-v", "--verbose"], true)]
+    {
+        match flag.parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "{:?}", args);
+                assert!(nicht_verwendet.is_empty(), "{:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis: {:?}", res),
+        }
+    }
+}
+
+#[test]
+fn veraltete_flag() {
+    // Der Test startet sich selbst erneut, um die Warnung über `stderr` zu prüfen.
+    const KIND_PROZESS: &str = "KOMMANDOZEILEN_ARGUMENTE_VERALTETE_FLAG";
+    let argumente = || {
+        let verbose: Argumente<'static, bool, Void> = Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache("verbose", "v", None, Some(false), Sprache::DEUTSCH),
+            Sprache::DEUTSCH,
+        );
+        let alt: Argumente<'static, (), Void> = Argumente::veraltete_flag_mit_sprache(
+            Beschreibung::neu_mit_sprache(
+                "alt",
+                "a",
+                Some("Ohne Funktion."),
+                None,
+                Sprache::DEUTSCH,
+            ),
+            Some("--alt ist veraltet."),
+            Sprache::DEUTSCH,
+        );
+        kombiniere!(|verbose, ()| verbose, verbose, alt)
+    };
+    let args = || ["-va", "--alt"].iter().map(OsString::from);
+    match env::var(KIND_PROZESS).as_deref() {
+        Ok("parse") => {
+            match argumente().parse(args()) {
+                (Ergebnis::Wert(true), nicht_verwendet) if nicht_verwendet.is_empty() => {},
+                res => panic!("Unerwartetes Ergebnis: {:?}", res),
+            }
+            return;
+        },
+        Ok("vollständig") => {
+            let fehler_code = NonZeroI32::new(1).expect("1 != 0");
+            assert!(argumente().parse_vollständig_mit_sprache(
+                args(),
+                fehler_code,
+                Sprache::DEUTSCH
+            ));
+            return;
+        },
+        Ok("leise") => {
+            let fehler_code = NonZeroI32::new(1).expect("1 != 0");
+            assert!(argumente().leise().parse_vollständig_mit_sprache(
+                args(),
+                fehler_code,
+                Sprache::DEUTSCH
+            ));
+            return;
+        },
+        _ => {},
+    }
+    let hilfe_text = argumente().hilfe_text("programm", None, None);
+    assert!(
+        hilfe_text.contains("[Veraltet] Ohne Funktion."),
+        "Unerwarteter Hilfe-Text: {}",
+        hilfe_text
+    );
+    // Die Warnung wird nur von `parse_vollständig` ausgegeben, sofern es nicht `leise` ist.
+    for (modus, anzahl) in [("parse", 0), ("vollständig", 1), ("leise", 0)] {
+        let ausgabe = process::Command::new(env::current_exe().expect("Pfad der Test-Datei."))
+            .args(["veraltete_flag", "--exact", "--nocapture"])
+            .env(KIND_PROZESS, modus)
+            .output()
+            .expect("Test-Prozess starten.");
+        assert!(ausgabe.status.success(), "{:?}", ausgabe);
+        let stderr = String::from_utf8_lossy(&ausgabe.stderr);
+        assert_eq!(
+            stderr.matches("--alt ist veraltet.").count(),
+            anzahl,
+            "Unerwartete Ausgabe für {}: {}",
+            modus,
+            stderr
+        );
+    }
+}
+
+#[test]
+fn exakter_name_mit_invertiere_präfix() {
+    let flag = |name: &'static str| -> Argumente<'static, bool, Void> {
+        Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache(name, None::<&str>, None, Some(false), Sprache::DEUTSCH),
+            Sprache::DEUTSCH,
+        )
+    };
+    let (cache, kein_cache) = (flag("cache"), flag("kein-cache"));
+    let argumente = kombiniere!(|cache, kein_cache| (cache, kein_cache), cache, kein_cache);
+    let fälle: [(&[&str], (bool, bool)); 4] = [
+        (&["--cache"], (true, false)),
+        (&["--kein-cache"], (false, true)),
+        (&["--cache", "--kein-cache"], (true, true)),
+        (&["--kein-cache", "--kein-kein-cache"], (false, false)),
+    ];
+    for (args, erwartet) in fälle {
+        match argumente.parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
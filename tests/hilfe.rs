@@ -1,10 +1,21 @@
 //! Tests zur automatisch erzeugen Hilfe.
 
-use std::{ffi::OsString, iter, process};
+use std::{
+    env,
+    ffi::OsString,
+    fs, iter,
+    num::NonZeroI32,
+    path::PathBuf,
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use void::Void;
 
-use kommandozeilen_argumente::{Argumente, Beschreibung, Ergebnis, Sprache};
+use kommandozeilen_argumente::{
+    Argumente, Beschreibung, Ergebnis, HilfeLayout, NonEmpty, ParseAbbruch, ParseFehler, Sprache,
+    Zahlenformat,
+};
 
 #[test]
 fn hilfe_test() {
@@ -38,3 +49,615 @@ fn hilfe_test() {
         },
     }
 }
+
+/// Temporäres Verzeichnis, das beim Drop (auch nach einem panic) entfernt wird.
+struct TemporäresVerzeichnis(PathBuf);
+
+impl TemporäresVerzeichnis {
+    fn neu(name: &str) -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|dauer| dauer.as_nanos())
+            .unwrap_or_default();
+        let pfad = env::temp_dir().join(format!("{name}_{}_{nanos}", process::id()));
+        fs::create_dir(&pfad).expect("Temporäres Verzeichnis erstellen.");
+        TemporäresVerzeichnis(pfad)
+    }
+}
+
+impl Drop for TemporäresVerzeichnis {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn dynamische_werte() {
+    let temporäres_verzeichnis = TemporäresVerzeichnis::neu("kommandozeilen_argumente_dynamisch");
+    let verzeichnis = &temporäres_verzeichnis.0;
+    for datei in ["eins.txt", "zwei.txt"] {
+        fs::write(verzeichnis.join(datei), "").expect("Temporäre Datei erstellen.");
+    }
+    let verzeichnis_clone = verzeichnis.clone();
+    let arg: Argumente<String, Void> = Argumente::wert_mit_dynamischen_werten_und_sprache(
+        Beschreibung::neu_mit_sprache(
+            "datei",
+            None::<&str>,
+            Some("Eine Datei."),
+            None,
+            Sprache::DEUTSCH,
+        ),
+        move || {
+            let mut dateien: Vec<String> = fs::read_dir(&verzeichnis_clone)
+                .into_iter()
+                .flatten()
+                .filter_map(|eintrag| eintrag.ok()?.file_name().into_string().ok())
+                .collect();
+            dateien.sort();
+            dateien
+        },
+        |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+        String::clone,
+        Sprache::DEUTSCH,
+    );
+    let hilfe_text = arg.hilfe_text("programm", None, None);
+    assert!(
+        hilfe_text.contains("[Erlaubte Werte: eins.txt, zwei.txt]"),
+        "Unerwarteter Hilfe-Text: {}",
+        hilfe_text
+    );
+    fs::write(verzeichnis.join("drei.txt"), "").expect("Temporäre Datei erstellen.");
+    let hilfe_text = arg.hilfe_text("programm", None, None);
+    assert!(
+        hilfe_text.contains("[Erlaubte Werte: drei.txt, eins.txt, zwei.txt]"),
+        "Unerwarteter Hilfe-Text: {}",
+        hilfe_text
+    );
+}
+
+#[test]
+fn parse_als_result() {
+    let arg: Argumente<bool, Void> = Argumente::hilfe(
+        Argumente::flag_bool_deutsch(Beschreibung::neu_mit_sprache(
+            "test",
+            None::<&str>,
+            None,
+            None,
+            Sprache::DEUTSCH,
+        )),
+        "programm",
+        None,
+        None,
+    );
+    let args = |args: &[&str]| args.iter().map(OsString::from).collect::<Vec<_>>();
+    assert!(matches!(arg.parse_als_result(args(&["--test"]).into_iter()), Ok(true)));
+    assert!(matches!(
+        arg.parse_als_result(args(&["--hilfe"]).into_iter()),
+        Err(ParseAbbruch::FrühesBeenden(_))
+    ));
+    assert!(matches!(arg.parse_als_result(args(&[]).into_iter()), Err(ParseAbbruch::Fehler(_))));
+    match arg.parse_als_result(args(&["--kein-test", "--unbekannt"]).into_iter()) {
+        Err(ParseAbbruch::NichtVerwendet(nicht_verwendet)) => {
+            assert_eq!(nicht_verwendet.into_iter().collect::<Vec<_>>(), vec!["--unbekannt"])
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn max_namen_breite() {
+    let flag = |name: &'static str, hilfe: &'static str| -> Argumente<'static, bool, Void> {
+        Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache(name, None::<&str>, Some(hilfe), None, Sprache::DEUTSCH),
+            Sprache::DEUTSCH,
+        )
+    };
+    let kurz = flag("kurz", "Kurz.");
+    let lang = flag("ein-sehr-langer-name-mit-ümläuten", "Lang.");
+    let arg = kommandozeilen_argumente::kombiniere!(|kurz, lang| (kurz, lang), kurz, lang)
+        .mit_max_namen_breite(20);
+    let hilfe_text = arg.hilfe_text("programm", None, None);
+    let erwartet = "OPTIONEN:
+  --[kein]-kurz         Kurz.
+  --[kein]-ein-sehr-la
+  nger-name-mit-ümläut
+  en
+                        Lang.
+";
+    assert!(hilfe_text.ends_with(erwartet), "Unerwarteter Hilfe-Text: {}", hilfe_text);
+}
+
+#[test]
+fn kurze_nutzung_bei_fehler() {
+    // Der Test startet sich selbst erneut, um die Ausgabe vor `process::exit` zu prüfen.
+    const KIND_PROZESS: &str = "KOMMANDOZEILEN_ARGUMENTE_KURZE_NUTZUNG";
+    let fehler_code = NonZeroI32::new(3).expect("3 != 0");
+    if env::var_os(KIND_PROZESS).is_some() {
+        let arg: Argumente<bool, Void> = Argumente::flag_bool_deutsch(
+            Beschreibung::neu_mit_sprache("test", None::<&str>, None, None, Sprache::DEUTSCH),
+        );
+        let _ = arg.parse_vollständig_mit_kurzer_nutzung(
+            iter::empty(),
+            fehler_code,
+            "programm",
+            Sprache::DEUTSCH,
+        );
+        unreachable!("Parsen ohne Argumente erfolgreich.");
+    }
+    let exe = env::current_exe().expect("Pfad der Test-Datei.");
+    let ausgabe = process::Command::new(&exe)
+        .args(["kurze_nutzung_bei_fehler", "--exact", "--nocapture"])
+        .env(KIND_PROZESS, "1")
+        .output()
+        .expect("Test-Prozess starten.");
+    assert_eq!(ausgabe.status.code(), Some(fehler_code.get()));
+    let stderr = String::from_utf8_lossy(&ausgabe.stderr);
+    let exe_name = exe.file_name().and_then(|name| name.to_str()).expect("Name der Test-Datei.");
+    let kurze_nutzung = format!("{exe_name} [OPTIONEN]\n");
+    assert!(stderr.starts_with(&kurze_nutzung), "Unerwartete Ausgabe: {}", stderr);
+    assert!(stderr.contains("Fehlende Flag"), "Unerwartete Ausgabe: {}", stderr);
+}
+
+#[test]
+fn wert_infix_anzeige() {
+    let wert = |name: &'static str, kurz: &'static str| -> Argumente<'static, String, String> {
+        Argumente::wert_string_mit_sprache(
+            Beschreibung::neu_mit_sprache(name, kurz, None, None, Sprache::DEUTSCH),
+            None,
+            |string| Ok(string.to_owned()),
+            String::clone,
+            Sprache::DEUTSCH,
+        )
+    };
+    let angepasst = wert("angepasst", "a").mit_wert_infix_anzeige("=");
+    let normal = wert("normal", "n");
+    let arg = kommandozeilen_argumente::kombiniere!(
+        |angepasst, normal| (angepasst, normal),
+        angepasst,
+        normal
+    );
+    let hilfe_text = arg.hilfe_text("programm", None, None);
+    let erwartet =
+        "OPTIONEN:\n  --angepasst=WERT  | -a=WERT      \n  --normal(=| )WERT | -n[=| ]WERT  \n";
+    assert!(hilfe_text.ends_with(erwartet), "Unerwarteter Hilfe-Text: {}", hilfe_text);
+    let args = ["--angepasst", "eins", "--normal=zwei"];
+    match arg.parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert((angepasst, normal)), nicht_verwendet) => {
+            assert_eq!(angepasst, "eins");
+            assert_eq!(normal, "zwei");
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn standard_mit_anzeige() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Dauer(u64);
+    impl std::fmt::Display for Dauer {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Dauer {{ sekunden: {} }}", self.0)
+        }
+    }
+    let dauer: Argumente<'_, Dauer, String> = Argumente::wert_mit_sprache(
+        Beschreibung::neu_mit_sprache(
+            "dauer",
+            None::<&str>,
+            None,
+            Some(Dauer(30)),
+            Sprache::DEUTSCH,
+        ),
+        Some(NonEmpty { head: Dauer(30), tail: vec![Dauer(60)] }),
+        |os_string| {
+            let string = os_string.into_string().map_err(ParseFehler::InvaliderString)?;
+            let sekunden = string.strip_suffix('s').unwrap_or(&string);
+            sekunden
+                .parse()
+                .map(Dauer)
+                .map_err(|fehler| ParseFehler::ParseFehler(format!("{fehler}")))
+        },
+        |Dauer(sekunden)| format!("{sekunden}s"),
+        Sprache::DEUTSCH,
+    );
+    let hilfe_text = dauer.hilfe_text("programm", None, None);
+    assert!(
+        hilfe_text.contains("[Erlaubte Werte: 30s, 60s | Standard: 30s]"),
+        "Unerwarteter Hilfe-Text: {}",
+        hilfe_text
+    );
+    assert!(!hilfe_text.contains("Dauer {"), "Unerwarteter Hilfe-Text: {}", hilfe_text);
+
+    let zahl: Argumente<'_, f64, String> = Argumente::wert_zahl_mit_locale_und_sprache(
+        Beschreibung::neu_mit_sprache("zahl", None::<&str>, None, Some(0.5), Sprache::DEUTSCH),
+        Zahlenformat::DEUTSCH,
+        Sprache::DEUTSCH,
+    );
+    let hilfe_text = zahl.hilfe_text("programm", None, None);
+    assert!(hilfe_text.contains("[Standard: 0,5]"), "Unerwarteter Hilfe-Text: {}", hilfe_text);
+}
+
+#[test]
+fn version_text() {
+    let arg: Argumente<bool, String> = Argumente::flag_bool_deutsch(Beschreibung::neu_mit_sprache(
+        "flag",
+        None::<&str>,
+        Some("Eine Flag."),
+        Some(false),
+        Sprache::DEUTSCH,
+    ));
+    let version_text = arg.version_text("programm", "0.1.0");
+    assert_eq!(version_text, "programm 0.1.0");
+    let hilfe_text = arg.hilfe_text("programm", None, Some("0.1.0"));
+    assert!(hilfe_text.starts_with(&version_text), "Unerwarteter Hilfe-Text: {}", hilfe_text);
+    let arg = arg.version_deutsch("programm", "0.1.0");
+    match arg.parse(["--version"].iter().map(OsString::from)) {
+        (Ergebnis::FrühesBeenden(nachrichten), nicht_verwendet)
+            if nachrichten.len() == 1
+                && nachrichten.head == version_text
+                && nicht_verwendet.is_empty() => {},
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn leise_version() {
+    // Der Test startet sich selbst erneut, um die Ausgabe vor `process::exit` zu prüfen.
+    const KIND_PROZESS: &str = "KOMMANDOZEILEN_ARGUMENTE_LEISE_VERSION";
+    let fehler_code = NonZeroI32::new(3).expect("3 != 0");
+    if let Some(modus) = env::var_os(KIND_PROZESS) {
+        let arg: Argumente<bool, Void> =
+            Argumente::flag_bool_deutsch(Beschreibung::neu_mit_sprache(
+                "test",
+                None::<&str>,
+                None,
+                Some(false),
+                Sprache::DEUTSCH,
+            ))
+            .version_deutsch("programm", "0.1.0");
+        let args = ["--version"].iter().map(OsString::from);
+        let _ = match modus.to_str() {
+            Some("leise") => arg.parse_vollständig_leise(args, fehler_code, Sprache::DEUTSCH),
+            Some("einstellung") => {
+                arg.leise().parse_vollständig_mit_sprache(args, fehler_code, Sprache::DEUTSCH)
+            },
+            _ => arg.parse_vollständig_mit_sprache(args, fehler_code, Sprache::DEUTSCH),
+        };
+        unreachable!("Kein frühes Beenden mit --version.");
+    }
+    let exe = env::current_exe().expect("Pfad der Test-Datei.");
+    for (modus, version_erwartet) in [("normal", true), ("leise", false), ("einstellung", false)] {
+        let ausgabe = process::Command::new(&exe)
+            .args(["leise_version", "--exact", "--nocapture"])
+            .env(KIND_PROZESS, modus)
+            .output()
+            .expect("Test-Prozess starten.");
+        assert_eq!(ausgabe.status.code(), Some(0), "{:?}", ausgabe);
+        let stdout = String::from_utf8_lossy(&ausgabe.stdout);
+        assert_eq!(
+            stdout.contains("programm 0.1.0"),
+            version_erwartet,
+            "Unerwartete Ausgabe für {}: {}",
+            modus,
+            stdout
+        );
+    }
+}
+
+#[test]
+fn hilfe_anmerkungen() {
+    let fälle: [(Option<&str>, bool, Option<u8>, &str); 8] = [
+        (None, false, None, "  --zahl(=| )WERT  \n"),
+        (Some("Hilfe."), false, None, "  --zahl(=| )WERT  Hilfe.\n"),
+        (None, true, None, "  --zahl(=| )WERT  [Erlaubte Werte: 1, 2]\n"),
+        (None, false, Some(1), "  --zahl(=| )WERT  [Standard: 1]\n"),
+        (None, true, Some(1), "  --zahl(=| )WERT  [Erlaubte Werte: 1, 2 | Standard: 1]\n"),
+        (Some("Hilfe."), true, None, "  --zahl(=| )WERT  Hilfe. [Erlaubte Werte: 1, 2]\n"),
+        (Some("Hilfe."), false, Some(1), "  --zahl(=| )WERT  Hilfe. [Standard: 1]\n"),
+        (
+            Some("Hilfe."),
+            true,
+            Some(1),
+            "  --zahl(=| )WERT  Hilfe. [Erlaubte Werte: 1, 2 | Standard: 1]\n",
+        ),
+    ];
+    for (hilfe, mit_werten, standard, erwartet) in fälle {
+        let arg: Argumente<'_, u8, String> = Argumente::wert_from_str_display_mit_sprache(
+            Beschreibung::neu_mit_sprache("zahl", None::<&str>, hilfe, standard, Sprache::DEUTSCH),
+            mit_werten.then(|| NonEmpty { head: 1, tail: vec![2] }),
+            Sprache::DEUTSCH,
+        );
+        let hilfe_text = arg.hilfe_text("programm", None, None);
+        assert!(hilfe_text.ends_with(erwartet), "Unerwarteter Hilfe-Text: {}", hilfe_text);
+    }
+}
+
+#[test]
+fn hilfe_layout_tabelle() {
+    let verbose: Argumente<'_, bool, String> = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache(
+            "verbose",
+            "v",
+            Some("Mehr Ausgaben."),
+            None,
+            Sprache::DEUTSCH,
+        ),
+        Sprache::DEUTSCH,
+    );
+    let anzahl: Argumente<'_, u8, String> = Argumente::wert_from_str_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("anzahl", "n", Some("Anzahl."), Some(1), Sprache::DEUTSCH),
+        None,
+        Sprache::DEUTSCH,
+    );
+    let name: Argumente<'_, String, String> = Argumente::wert_from_str_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("name", None::<&str>, Some("Name."), None, Sprache::DEUTSCH),
+        None,
+        Sprache::DEUTSCH,
+    );
+    let arg = kommandozeilen_argumente::kombiniere!(
+        |verbose, anzahl, name| (verbose, anzahl, name),
+        verbose,
+        anzahl,
+        name
+    )
+    .mit_hilfe_layout(HilfeLayout::Tabelle);
+    let hilfe_text = arg.hilfe_text("programm", None, None);
+    let erwartet = "
+OPTIONEN:
+           -v  --[kein]-verbose   Mehr Ausgaben.
+  -n[=| ]WERT  --anzahl(=| )WERT  Anzahl. [Standard: 1]
+               --name(=| )WERT    Name.
+";
+    assert!(hilfe_text.ends_with(erwartet), "Unerwarteter Hilfe-Text: {}", hilfe_text);
+}
+
+#[test]
+fn hilfe_text_angepasst() {
+    let argumente: Argumente<'_, (bool, String), String> = Argumente::kombiniere2(
+        |verbose, name| (verbose, name),
+        Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache(
+                "verbose",
+                "v",
+                Some("Zeige zusätzliche Ausgaben während der Ausführung an."),
+                Some(false),
+                Sprache::DEUTSCH,
+            ),
+            Sprache::DEUTSCH,
+        ),
+        Argumente::wert_from_str_display_mit_sprache(
+            Beschreibung::neu_mit_sprache(
+                "name",
+                None::<&str>,
+                Some("Name."),
+                None,
+                Sprache::DEUTSCH,
+            ),
+            None,
+            Sprache::DEUTSCH,
+        ),
+    );
+    env::set_var("COLUMNS", "50");
+    let hilfe_text = argumente.hilfe_text_angepasst("programm", None, None, Sprache::DEUTSCH);
+    let erwartet = "  --[kein]-verbose | -v  Zeige zusätzliche
+                         Ausgaben während der
+                         Ausführung an. [Standard:
+                         false]
+  --name(=| )WERT        Name.
+";
+    assert_eq!(hilfe_text.split_once("OPTIONEN:\n").map(|(_, optionen)| optionen), Some(erwartet));
+    // Ohne gültige Breite wird nicht umgebrochen.
+    env::set_var("COLUMNS", "keine Zahl");
+    let hilfe_text = argumente.hilfe_text_angepasst("programm", None, None, Sprache::DEUTSCH);
+    assert!(
+        hilfe_text.contains(
+            "  --[kein]-verbose | -v  Zeige zusätzliche Ausgaben während der Ausführung an. \
+             [Standard: false]\n"
+        ),
+        "{hilfe_text}"
+    );
+    env::remove_var("COLUMNS");
+}
+
+#[test]
+fn reihenfolge() {
+    let flag = |name: &'static str| -> Argumente<'static, bool, Void> {
+        Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache(name, None::<&str>, None, None, Sprache::DEUTSCH),
+            Sprache::DEUTSCH,
+        )
+    };
+    let (a, b, c) = (flag("a").reihenfolge(1), flag("b"), flag("c").reihenfolge(-1));
+    let arg = kommandozeilen_argumente::kombiniere!(|a, b, c| (a, b, c), a, b, c)
+        .version_deutsch("programm", "0.1.0");
+    let hilfe_text = arg.hilfe_text("programm", None, None);
+    let namen: Vec<_> = hilfe_text
+        .split_once("OPTIONEN:\n")
+        .map(|(_nutzung, optionen)| optionen.lines().map(str::trim_end).collect())
+        .unwrap_or_default();
+    assert_eq!(
+        namen,
+        [
+            "  --[kein]-c",
+            "  --[kein]-b",
+            "  --[kein]-a",
+            "  --version  | -v  Zeige die aktuelle Version an."
+        ],
+        "Unerwarteter Hilfe-Text: {}",
+        hilfe_text
+    );
+}
+
+#[test]
+fn reihenfolge_mit_hilfe_und_version() {
+    let flag = |name: &'static str| -> Argumente<'static, bool, Void> {
+        Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache(name, None::<&str>, None, None, Sprache::DEUTSCH),
+            Sprache::DEUTSCH,
+        )
+    };
+    // Hilfe und Version werden vor dem letzten Argument hinzugefügt.
+    let a = flag("a").reihenfolge(2).hilfe_und_version("programm", None, "0.1.0");
+    let b = flag("b").reihenfolge(1);
+    let arg = kommandozeilen_argumente::kombiniere!(|a, b| (a, b), a, b);
+    let nachrichten = arg
+        .frühes_beenden_nachrichten(iter::once(OsString::from("--hilfe")))
+        .expect("Frühes Beenden erwartet.");
+    let namen: Vec<_> = nachrichten
+        .head
+        .split_once("OPTIONEN:\n")
+        .map(|(_nutzung, optionen)| optionen.lines().map(str::trim_end).collect())
+        .unwrap_or_default();
+    assert_eq!(
+        namen,
+        [
+            "  --[kein]-a",
+            "  --version  | -v  Zeige die aktuelle Version an.",
+            "  --hilfe    | -h  Zeige diesen Text an."
+        ],
+        "Unerwarteter Hilfe-Text: {}",
+        nachrichten.head
+    );
+    // Im Hilfe-Text aller Argumente stehen Hilfe und Version ebenfalls zuletzt.
+    let hilfe_text = arg.hilfe_text("programm", None, None);
+    let namen: Vec<_> = hilfe_text
+        .split_once("OPTIONEN:\n")
+        .map(|(_nutzung, optionen)| optionen.lines().map(str::trim_end).collect())
+        .unwrap_or_default();
+    assert_eq!(
+        namen,
+        [
+            "  --[kein]-b",
+            "  --[kein]-a",
+            "  --version  | -v  Zeige die aktuelle Version an.",
+            "  --hilfe    | -h  Zeige diesen Text an."
+        ],
+        "Unerwarteter Hilfe-Text: {}",
+        hilfe_text
+    );
+}
+
+#[test]
+fn versteckte_aliase() {
+    let farbe: Argumente<'_, bool, Void> = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache(
+            NonEmpty { head: "farbe", tail: vec!["color"] },
+            vec!["f", "c"],
+            Some("Farbige Ausgabe."),
+            None,
+            Sprache::DEUTSCH,
+        ),
+        Sprache::DEUTSCH,
+    )
+    .alias_versteckt("color")
+    .alias_versteckt("c");
+    let hilfe_text = farbe.hilfe_text("programm", None, None);
+    let namen: Vec<_> = hilfe_text
+        .split_once("OPTIONEN:\n")
+        .map(|(_nutzung, optionen)| optionen.lines().map(str::trim_end).collect())
+        .unwrap_or_default();
+    assert_eq!(namen, ["  --[kein]-farbe | -f  Farbige Ausgabe."], "{}", hilfe_text);
+    for args in [&["--color"][..], &["-c"], &["--farbe"]] {
+        match farbe.parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert!(wert, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
+
+#[test]
+fn exit_codes() {
+    let flag: Argumente<'_, bool, Void> = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache("a", None::<&str>, None, None, Sprache::DEUTSCH),
+        Sprache::DEUTSCH,
+    );
+    let ohne_exit_codes = flag.hilfe_text("programm", None, None);
+    assert!(!ohne_exit_codes.contains("EXIT-CODES"), "{}", ohne_exit_codes);
+    let arg = flag.mit_exit_codes(&[(0, "Erfolg"), (2, "Ungültige Argumente"), (130, "Abbruch")]);
+    let hilfe_text = arg.hilfe_text("programm", None, None);
+    let exit_codes: Vec<_> = hilfe_text
+        .split_once("\nEXIT-CODES:\n")
+        .map(|(_optionen, exit_codes)| exit_codes.lines().collect())
+        .unwrap_or_default();
+    assert_eq!(
+        exit_codes,
+        ["  0    Erfolg", "  2    Ungültige Argumente", "  130  Abbruch"],
+        "Unerwarteter Hilfe-Text: {}",
+        hilfe_text
+    );
+}
+
+#[test]
+fn hilfe_einstellungen_reihenfolge() {
+    const EXIT_CODES: &[(i32, &str)] = &[(0, "Erfolg")];
+    let flag = || -> Argumente<'static, bool, Void> {
+        Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache("a", None::<&str>, None, None, Sprache::DEUTSCH),
+            Sprache::DEUTSCH,
+        )
+    };
+    let hilfe_nachricht = |arg: &Argumente<'static, bool, Void>| -> String {
+        arg.frühes_beenden_nachrichten(iter::once(OsString::from("--hilfe")))
+            .expect("Frühes Beenden erwartet.")
+            .head
+            .into_owned()
+    };
+    // Vor der Hilfe gesetzte Einstellungen werden im Hilfe-Text berücksichtigt.
+    let vorher = flag()
+        .mit_exit_codes(EXIT_CODES)
+        .mit_hilfe_layout(HilfeLayout::Tabelle)
+        .hilfe("programm", None, None);
+    let nachricht = hilfe_nachricht(&vorher);
+    assert!(nachricht.contains("\nEXIT-CODES:\n  0  Erfolg"), "{}", nachricht);
+    assert!(nachricht.contains("\n  -h  --hilfe"), "{}", nachricht);
+    // Der Hilfe-Text wird beim Hinzufügen der Hilfe erstellt,
+    // danach gesetzte Einstellungen haben keinen Einfluss mehr auf ihn.
+    let nachher = flag()
+        .hilfe("programm", None, None)
+        .mit_exit_codes(EXIT_CODES)
+        .mit_hilfe_layout(HilfeLayout::Tabelle);
+    let nachricht = hilfe_nachricht(&nachher);
+    assert!(!nachricht.contains("EXIT-CODES"), "{}", nachricht);
+    assert!(nachricht.contains("\n  --hilfe"), "{}", nachricht);
+    // Der direkt erzeugte Hilfe-Text verwendet dagegen immer die aktuellen Einstellungen.
+    let hilfe_text = nachher.hilfe_text("programm", None, None);
+    assert!(hilfe_text.contains("\nEXIT-CODES:\n  0  Erfolg"), "{}", hilfe_text);
+}
+
+#[test]
+fn nachrichten_trenner() {
+    // Der Test startet sich selbst erneut, um die Ausgabe vor `process::exit` zu prüfen.
+    const KIND_PROZESS: &str = "KOMMANDOZEILEN_ARGUMENTE_NACHRICHTEN_TRENNER";
+    if env::var_os(KIND_PROZESS).is_some() {
+        let arg: Argumente<bool, Void> = Argumente::flag_bool_deutsch(
+            Beschreibung::neu_mit_sprache("test", None::<&str>, None, None, Sprache::DEUTSCH),
+        )
+        .mit_nachrichten_trenner("\n----\n")
+        .hilfe_und_version("programm", None, "0.1.0");
+        let _ = arg.parse_vollständig_mit_sprache(
+            ["--hilfe", "--version"].iter().map(OsString::from),
+            NonZeroI32::new(1).expect("1 != 0"),
+            Sprache::DEUTSCH,
+        );
+        unreachable!("Kein frühes Beenden.");
+    }
+    let ausgabe = process::Command::new(env::current_exe().expect("Pfad der Test-Datei."))
+        .args(["nachrichten_trenner", "--exact", "--nocapture"])
+        .env(KIND_PROZESS, "1")
+        .output()
+        .expect("Test-Prozess starten.");
+    assert_eq!(ausgabe.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&ausgabe.stdout);
+    let nachrichten: Vec<_> = stdout.split("\n----\n").collect();
+    assert_eq!(nachrichten.len(), 2, "Unerwartete Ausgabe: {}", stdout);
+    assert_eq!(
+        nachrichten.iter().filter(|nachricht| nachricht.contains("OPTIONEN:")).count(),
+        1,
+        "Unerwartete Ausgabe: {}",
+        stdout
+    );
+    assert!(nachrichten.iter().any(|nachricht| nachricht.contains("0.1.0")), "{}", stdout);
+}
@@ -0,0 +1,577 @@
+//! Tests zum Kombinieren mehrerer Kommandozeilen-Argumente.
+
+use std::{cell::Cell, ffi::OsString, iter};
+
+use void::Void;
+
+use kommandozeilen_argumente::{
+    kombiniere, parse_gemeinsam, rekonstruiere_aufruf, zerlege_befehlszeile, Argumente,
+    Beschreibung, Ergebnis, GemeinsamerParser, OffenesAnführungszeichen, ParseFehler, Sprache,
+};
+
+fn flag(name: &'static str) -> Argumente<'static, bool, Void> {
+    Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache(name, None::<&str>, None, Some(false), Sprache::DEUTSCH),
+        Sprache::DEUTSCH,
+    )
+}
+
+#[test]
+fn nicht_verwendet_genau_einmal() {
+    let a = flag("a");
+    let b = flag("b");
+    let c = flag("c");
+    let argumente = kombiniere!(|a, b, c| (a, b, c), a, b, c);
+    let args = ["--unbekannt", "--b", "wert", "--unbekannt", "--a"];
+    match argumente.parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, (true, true, false));
+            assert_eq!(nicht_verwendet, vec!["--unbekannt", "wert", "--unbekannt"]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+fn kurz_flag(name: &'static str, kurz: &'static str) -> Argumente<'static, bool, Void> {
+    Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache(name, kurz, None, Some(false), Sprache::DEUTSCH),
+        Sprache::DEUTSCH,
+    )
+}
+
+#[test]
+fn ohne_verschmolzene_kurzformen() {
+    let kombiniert = || {
+        let a = kurz_flag("aa", "a");
+        let b = kurz_flag("bb", "b");
+        kombiniere!(|a, b| (a, b), a, b)
+    };
+    let args = ["-ab"];
+    match kombiniert().parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, (true, true));
+            assert!(nicht_verwendet.is_empty(), "{:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match kombiniert().ohne_verschmolzene_kurzformen().parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, (false, false));
+            assert_eq!(nicht_verwendet, vec!["-ab"]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn vorverarbeitung_alias() {
+    let a = kurz_flag("aa", "a");
+    let b = kurz_flag("bb", "b");
+    let c = kurz_flag("cc", "c");
+    let kombiniert = kombiniere!(|a, b, c| (a, b, c), a, b, c).mit_vorverarbeitung(|args| {
+        args.into_iter()
+            .flat_map(|arg| match arg.to_str() {
+                Some("alle") => vec![OsString::from("--aa"), OsString::from("-bc")],
+                _ => vec![arg],
+            })
+            .collect()
+    });
+    let args = ["alle"];
+    assert_eq!(
+        kombiniert.vorverarbeitete_args(args.iter().map(OsString::from)),
+        vec!["--aa", "-b", "-c"]
+    );
+    match kombiniert.parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, (true, true, true));
+            assert!(nicht_verwendet.is_empty(), "{:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn sehr_lange_verschmolzene_kurzformen() {
+    let a = kurz_flag("aa", "a");
+    let b = kurz_flag("bb", "b");
+    let argumente = kombiniere!(|a, b| (a, b), a, b);
+    let unbekannt = format!("-{}x", "a".repeat(200_000));
+    let vorverarbeitet = argumente.vorverarbeitete_args(iter::once(OsString::from(&unbekannt)));
+    assert_eq!(vorverarbeitet, vec![OsString::from(unbekannt)]);
+    let bekannt = format!("-{}", "ab".repeat(10_000));
+    let vorverarbeitet = argumente.vorverarbeitete_args(iter::once(OsString::from(bekannt)));
+    assert_eq!(vorverarbeitet.len(), 20_000);
+    assert!(vorverarbeitet.chunks(2).all(|paar| paar == ["-a", "-b"]));
+}
+
+#[test]
+fn vorverarbeiteter_aufruf() {
+    let a = kurz_flag("aa", "a");
+    let b = kurz_flag("bb", "b");
+    let argumente = kombiniere!(|a, b| (a, b), a, b)
+        .mit_trenner("--")
+        .zeige_aufruf_bei_fehler_mit_sprache(Sprache::DEUTSCH);
+    let args = ["-ab", "hallo welt", "", "it's", "--", "-ab"];
+    let vorverarbeitet = argumente.vorverarbeitete_args(args.iter().map(OsString::from));
+    assert_eq!(vorverarbeitet, vec!["-a", "-b", "hallo welt", "", "it's"]);
+    assert_eq!(rekonstruiere_aufruf(&vorverarbeitet), "-a -b 'hallo welt' '' 'it'\\''s'");
+}
+
+#[test]
+fn debug_zusammenfassung() {
+    let a = kurz_flag("aa", "a");
+    let b = flag("b");
+    let argumente =
+        kombiniere!(|a, b| (a, b), a, b).mit_trenner("--").mit_vorverarbeitung(|args| args);
+    let debug = format!("{:?}", argumente);
+    assert!(
+        debug.contains("konfigurationen: [--aa | -a: Flag, --b: Flag]"),
+        "Unerwartete Debug-Ausgabe: {}",
+        debug
+    );
+    assert!(debug.contains("flag_kurzformen: [\"-[a]\"]"), "Unerwartete Debug-Ausgabe: {}", debug);
+    assert!(debug.contains("parse: \"<function>\""), "Unerwartete Debug-Ausgabe: {}", debug);
+    assert!(debug.contains("trenner: Some(\"--\")"), "Unerwartete Debug-Ausgabe: {}", debug);
+    assert!(debug.contains("vorverarbeitung: 1"), "Unerwartete Debug-Ausgabe: {}", debug);
+    assert!(!debug.contains("Vergleich"), "Unerwartete Debug-Ausgabe: {}", debug);
+    assert!(!debug.contains("Normalisiert"), "Unerwartete Debug-Ausgabe: {}", debug);
+}
+
+#[test]
+fn gemeinsam() {
+    let a = flag("a");
+    let zahl: Argumente<'static, u8, String> = Argumente::wert_string_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("zahl", None::<&str>, None, Some(0), Sprache::DEUTSCH),
+        None,
+        |s| s.parse().map_err(|fehler| format!("{fehler}")),
+        Sprache::DEUTSCH,
+    );
+    let parser: [&dyn GemeinsamerParser; 2] = [&a, &zahl];
+    let args = ["--zahl", "42", "--unbekannt", "--a"];
+    let (ergebnisse, nicht_verwendet) = parse_gemeinsam(args.iter().map(OsString::from), &parser);
+    assert_eq!(nicht_verwendet, vec!["--unbekannt"]);
+    let mut ergebnisse = ergebnisse.into_iter();
+    let ergebnis_a =
+        ergebnisse.next().expect("Ergebnis für a").downcast::<Ergebnis<'static, bool, Void>>();
+    match ergebnis_a.as_deref() {
+        Ok(Ergebnis::Wert(true)) => {},
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let ergebnis_zahl =
+        ergebnisse.next().expect("Ergebnis für zahl").downcast::<Ergebnis<'static, u8, String>>();
+    match ergebnis_zahl.as_deref() {
+        Ok(Ergebnis::Wert(42)) => {},
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    assert!(ergebnisse.next().is_none());
+}
+
+#[test]
+fn gemeinsam_vorverarbeitung() {
+    let a = kurz_flag("aa", "a");
+    let b = kurz_flag("bb", "b").mit_vorverarbeitung(|args| {
+        args.into_iter()
+            .map(|arg| if arg == "beide" { OsString::from("-ab") } else { arg })
+            .collect()
+    });
+    let c = kurz_flag("cc", "c").mit_trenner("--");
+    let parser: [&dyn GemeinsamerParser; 3] = [&a, &b, &c];
+    let args = ["beide", "--unbekannt", "--", "-c"];
+    let (ergebnisse, nicht_verwendet) = parse_gemeinsam(args.iter().map(OsString::from), &parser);
+    assert_eq!(nicht_verwendet, vec!["--unbekannt", "-c"]);
+    let werte: Vec<_> = ergebnisse
+        .into_iter()
+        .map(|ergebnis| match ergebnis.downcast::<Ergebnis<'static, bool, Void>>().as_deref() {
+            Ok(Ergebnis::Wert(wert)) => *wert,
+            res => panic!("Unerwartetes Ergebnis: {:?}", res),
+        })
+        .collect();
+    assert_eq!(werte, vec![true, true, false]);
+}
+
+#[test]
+fn geheimer_wert() {
+    let a: Argumente<'static, bool, String> = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache("aa", "a", None, Some(false), Sprache::DEUTSCH),
+        Sprache::DEUTSCH,
+    );
+    let passwort: Argumente<'static, String, String> = Argumente::wert_string_display_mit_sprache(
+        Beschreibung::neu_mit_sprache(
+            "passwort",
+            "p",
+            None,
+            Some("streng-geheim".to_owned()),
+            Sprache::DEUTSCH,
+        ),
+        None,
+        |s| Ok(s.to_owned()),
+        Sprache::DEUTSCH,
+    )
+    .geheim();
+    let argumente = kombiniere!(|a, passwort| (a, passwort), a, passwort);
+    let args = ["-a", "--passwort", "geheim 1", "--passwort=geheim2", "-pgeheim3", "-p", "geheim4"];
+    assert_eq!(
+        argumente.rekonstruierter_aufruf(args.iter().map(OsString::from)),
+        "-a --passwort '***' '--passwort=***' '-p***' -p '***'"
+    );
+    let debug = format!("{:?}", argumente.konfigurationen().collect::<Vec<_>>());
+    assert!(!debug.contains("streng-geheim"), "Unerwartete Debug-Ausgabe: {}", debug);
+    assert!(debug.contains("***"), "Unerwartete Debug-Ausgabe: {}", debug);
+}
+
+#[test]
+fn frühes_beenden_vor_fehlern() {
+    let a: Argumente<'static, bool, String> = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache("a", None::<&str>, None, Some(false), Sprache::DEUTSCH),
+        Sprache::DEUTSCH,
+    )
+    .frühes_beenden(
+        Beschreibung::neu_mit_sprache("hilfe", None::<&str>, None, None, Sprache::DEUTSCH),
+        "Hilfe",
+    );
+    let zahl: Argumente<'static, u8, String> = Argumente::wert_string_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("zahl", None::<&str>, None, None, Sprache::DEUTSCH),
+        None,
+        |s| s.parse().map_err(|fehler| format!("{fehler}")),
+        Sprache::DEUTSCH,
+    );
+    let argumente = kombiniere!(|a, zahl| (a, zahl), a, zahl);
+    match argumente.parse(["--zahl=abc", "--hilfe"].iter().map(OsString::from)) {
+        (Ergebnis::FrühesBeenden(nachrichten), nicht_verwendet) => {
+            assert_eq!(nachrichten.into_iter().collect::<Vec<_>>(), vec!["Hilfe"]);
+            assert!(nicht_verwendet.is_empty(), "{:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn nachsichtig() {
+    let zahl = |name: &'static str, standard: Option<u8>| -> Argumente<'static, u8, String> {
+        Argumente::wert_string_display_mit_sprache(
+            Beschreibung::neu_mit_sprache(name, None::<&str>, None, standard, Sprache::DEUTSCH),
+            None,
+            |s| s.parse().map_err(|fehler| format!("{fehler}")),
+            Sprache::DEUTSCH,
+        )
+    };
+    let a = zahl("a", None).nachsichtig(0);
+    let b = zahl("b", None).nachsichtig(0);
+    // Ohne `nachsichtig` wird der Standard-Wert der Beschreibung verwendet.
+    let c = zahl("c", Some(7));
+    let argumente = kombiniere!(|a, b, c| (a, b, c), a, b, c);
+    let args = ["--a=3", "--b=abc", "--c=x", "übrig"];
+    let (wert, fehler, nicht_verwendet) =
+        argumente.parse_nachsichtig(args.iter().map(OsString::from));
+    assert_eq!(wert, Some((3, 0, 7)));
+    let namen: Vec<_> = fehler.iter().map(|fehler| fehler.namen().lang.head.as_ref()).collect();
+    assert_eq!(namen, vec!["b", "c"]);
+    assert_eq!(nicht_verwendet, vec![OsString::from("übrig")]);
+    // Ohne parse_nachsichtig werden Fehler weiterhin zurückgegeben.
+    match argumente.parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            assert_eq!(fehler.len(), 2);
+            assert_eq!(nicht_verwendet, vec![OsString::from("übrig")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    // Ohne Standard-Wert für ein fehlerhaftes Argument gibt es keinen Wert.
+    let a = zahl("a", None).nachsichtig(0);
+    let d = zahl("d", None);
+    let argumente = kombiniere!(|a, d| (a, d), a, d);
+    let (wert, fehler, nicht_verwendet) =
+        argumente.parse_nachsichtig(["--a=3"].iter().map(OsString::from));
+    assert_eq!(wert, None);
+    assert_eq!(fehler.len(), 1, "{:?}", fehler);
+    assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+}
+
+#[test]
+fn nachsichtig_ohne_erneutes_parsen() {
+    let aufrufe = Cell::new(0);
+    let e: Argumente<'_, u8, String> = Argumente::wert_mit_standard_fn_und_sprache(
+        Beschreibung::neu_mit_sprache("e", None::<&str>, None, None, Sprache::DEUTSCH),
+        None,
+        || {
+            aufrufe.set(aufrufe.get() + 1);
+            Ok(Some(5))
+        },
+        |os_string| {
+            os_string
+                .to_string_lossy()
+                .parse()
+                .map_err(|fehler| ParseFehler::ParseFehler(format!("{fehler}")))
+        },
+        u8::to_string,
+        Sprache::DEUTSCH,
+    );
+    let e = e.nachsichtig(0);
+    let f = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache("f", None::<&str>, None, Some(false), Sprache::DEUTSCH),
+        Sprache::DEUTSCH,
+    );
+    let argumente = kombiniere!(|e, f| (e, f), e, f);
+    // Der Standard-Wert wird nicht durch erneutes Parsen bestimmt.
+    let (wert, fehler, _nicht_verwendet) =
+        argumente.parse_nachsichtig(["--e=x"].iter().map(OsString::from));
+    assert_eq!(wert, Some((0, false)));
+    assert_eq!(fehler.len(), 1, "{:?}", fehler);
+    assert_eq!(aufrufe.get(), 0);
+}
+
+#[test]
+fn erkannte_tokens() {
+    let a: Argumente<'_, bool, String> = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache("aa", "a", None, Some(false), Sprache::DEUTSCH),
+        Sprache::DEUTSCH,
+    );
+    let anzahl: Argumente<'_, u32, String> = Argumente::wert_from_str_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("anzahl", "n", None, Some(1), Sprache::DEUTSCH),
+        None,
+        Sprache::DEUTSCH,
+    );
+    let plus = Argumente::flag_bool_ein_aus(
+        Beschreibung::neu_mit_sprache("x", None::<&str>, None, Some(false), Sprache::DEUTSCH),
+        "+",
+        "-",
+    );
+    let argumente = kombiniere!(|a, anzahl, plus| (a, anzahl, plus), a, anzahl, plus);
+    assert_eq!(
+        argumente.erkannte_tokens(),
+        vec!["--aa", "--kein-aa", "-a", "--anzahl", "-n", "+x", "-x"]
+    );
+}
+
+#[test]
+fn leere_präfixe() {
+    let verb = |name: &'static str, kurz: &'static str| -> Argumente<'static, bool, Void> {
+        Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu("", name, "-", kurz, None, Some(false)),
+            Sprache::DEUTSCH,
+        )
+    };
+    let name: Argumente<'static, String, Void> = Argumente::wert_string_mit_sprache(
+        Beschreibung::neu("", "name", "-", "n", None, Some("standard".to_owned())),
+        None,
+        |string| Ok(string.to_owned()),
+        String::clone,
+        Sprache::DEUTSCH,
+    );
+    let (start, stop) = (verb("start", "s"), verb("stop", "t"));
+    let argumente = kombiniere!(|start, stop, name| (start, stop, name), start, stop, name);
+    let fälle: [(&[&str], _, &[&str]); 7] = [
+        (&["start"], (true, false, "standard"), &[]),
+        (&["stop", "name", "x"], (false, true, "x"), &[]),
+        (&["-s", "name=x"], (true, false, "x"), &[]),
+        (&["-st", "-n", "y"], (true, true, "y"), &[]),
+        (&["-ny", "kein-start"], (false, false, "y"), &[]),
+        (&["starter", "nameless"], (false, false, "standard"), &["starter", "nameless"]),
+        (&["foo", "-"], (false, false, "standard"), &["foo", "-"]),
+    ];
+    for (args, (start, stop, name), erwartet_nicht_verwendet) in fälle {
+        match argumente.parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, (start, stop, name.to_owned()), "Argumente: {:?}", args);
+                assert_eq!(nicht_verwendet, erwartet_nicht_verwendet, "Argumente: {:?}", args);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+    // Ohne `kurz_präfix` werden Wörter aus Kurznamen nicht aufgeteilt.
+    let a = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu("", "aa", "", "a", None, Some(false)),
+        Sprache::DEUTSCH,
+    );
+    let b = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu("", "bb", "", "b", None, Some(false)),
+        Sprache::DEUTSCH,
+    );
+    let argumente: Argumente<'_, _, Void> = kombiniere!(|a, b| (a, b), a, b);
+    match argumente.parse(["a", "ab"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, (true, false));
+            assert_eq!(nicht_verwendet, vec!["ab"]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn anzahl_verbleibend() {
+    let a = flag("a");
+    let verbleibend = Argumente::anzahl_verbleibend();
+    let argumente = kombiniere!(|a, verbleibend| (a, verbleibend), a, verbleibend);
+    let fälle: [(&[&str], (bool, usize)); 3] =
+        [(&["--a"], (true, 0)), (&["x", "--a", "--b"], (true, 2)), (&["x", "y", "z"], (false, 3))];
+    for (args, erwartet) in fälle {
+        match argumente.parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert_eq!(nicht_verwendet.len(), erwartet.1, "Argumente: {:?}", args);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
+
+#[test]
+fn namen_index() {
+    let kombiniert = || {
+        let a = kurz_flag("aa", "a");
+        let b = kurz_flag("Bb", "b");
+        let name: Argumente<'static, String, Void> = Argumente::wert_string_mit_sprache(
+            Beschreibung::neu_mit_sprache(
+                "name",
+                "n",
+                None,
+                Some("-".to_owned()),
+                Sprache::DEUTSCH,
+            ),
+            None,
+            |string| Ok(string.to_owned()),
+            String::clone,
+            Sprache::DEUTSCH,
+        );
+        let café = Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache(
+                "café",
+                None::<&str>,
+                None,
+                Some(false),
+                Sprache::DEUTSCH,
+            ),
+            Sprache::DEUTSCH,
+        );
+        let nachricht = Argumente::wert_verbunden(
+            Beschreibung::neu_mit_sprache("nachricht", None::<&str>, None, None, Sprache::DEUTSCH),
+            "=",
+            "TEXT",
+            |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+            |string: &String| string.clone(),
+        );
+        kombiniere!(
+            |a, b, name, café, nachricht| (a, b, name, café, nachricht),
+            a,
+            b,
+            name,
+            café,
+            nachricht
+        )
+        .frühes_beenden(
+            Beschreibung::neu_mit_sprache("version", "v", None, None, Sprache::DEUTSCH),
+            "0.1",
+        )
+    };
+    let fälle: [&[&str]; 8] = [
+        &["--aa", "--kein-bb", "--name", "x", "--café", "--nachricht", "a", "b"],
+        &["-ab", "-nwert", "x", "--nachricht=a", "b", "--NAME=y"],
+        &["--nachricht", "a", "b", "--nachricht=c"],
+        &["--BB", "--cafe\u{301}", "-n", "--aa", "--unbekannt"],
+        &["--kein-aa", "--nachricht", "hallo", "--aaa", "-v"],
+        &["--name", "--name", "--name=", "-a", "--nachricht"],
+        &["--nachricht", "x"],
+        &[],
+    ];
+    for args in fälle {
+        let mit_index = kombiniert().parse(args.iter().map(OsString::from));
+        let ohne_index = kombiniert().ohne_namen_index().parse(args.iter().map(OsString::from));
+        assert_eq!(format!("{mit_index:?}"), format!("{ohne_index:?}"), "Argumente: {:?}", args);
+    }
+    let args = ["x", "-ab", "--name=--aa", "--nachricht", "a", "b"];
+    match kombiniert().parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, (true, true, "--aa".to_owned(), false, "a b".to_owned()));
+            assert_eq!(nicht_verwendet, vec!["x"]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn namen_index_impliziert() {
+    let kombiniert = || {
+        let (alle, a, b) = (kurz_flag("alle", "x"), kurz_flag("aa", "a"), kurz_flag("bb", "b"));
+        let innen =
+            kombiniere!(|alle, a, b| (alle, a, b), alle, a, b).impliziert("alle", ["aa", "bb"]);
+        let c = kurz_flag("cc", "c");
+        kombiniere!(|innen, c| (innen, c), innen, c)
+    };
+    let fälle: [&[&str]; 4] =
+        [&["--alle", "-c"], &["-x", "--kein-bb", "y"], &["-c", "z", "--alle", "--aa"], &[]];
+    let argumente = kombiniert();
+    for args in fälle {
+        // Wiederholtes Parsen derselben Argumente verwendet jeweils neue Kandidaten.
+        for _ in 0..2 {
+            let mit_index = argumente.parse(args.iter().map(OsString::from));
+            let ohne_index = kombiniert().ohne_namen_index().parse(args.iter().map(OsString::from));
+            assert_eq!(
+                format!("{mit_index:?}"),
+                format!("{ohne_index:?}"),
+                "Argumente: {:?}",
+                args
+            );
+        }
+    }
+    match argumente.parse(["-x", "--kein-bb", "y", "-c"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, ((true, true, false), true));
+            assert_eq!(nicht_verwendet, vec!["y"]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn befehlszeile() {
+    assert_eq!(
+        zerlege_befehlszeile(r#"  a "b c" 'd "e"' f\ g "h\"i" '' j\\k "#),
+        Ok(vec![
+            "a".into(),
+            "b c".into(),
+            "d \"e\"".into(),
+            "f g".into(),
+            "h\"i".into(),
+            "".into(),
+            "j\\k".into()
+        ])
+    );
+    assert_eq!(
+        zerlege_befehlszeile(r#"a "unterminated"#),
+        Err(OffenesAnführungszeichen { anführungszeichen: '"', position: 2 })
+    );
+    assert_eq!(
+        zerlege_befehlszeile(r#"a 'b "c" d"#),
+        Err(OffenesAnführungszeichen { anführungszeichen: '\'', position: 2 })
+    );
+    assert_eq!(
+        zerlege_befehlszeile(r#"a "b\""#),
+        Err(OffenesAnführungszeichen { anführungszeichen: '"', position: 2 })
+    );
+    let a = flag("a");
+    let name: Argumente<'static, String, Void> = Argumente::wert_string_mit_sprache(
+        Beschreibung::neu_mit_sprache("name", None::<&str>, None, None, Sprache::DEUTSCH),
+        None,
+        |string| Ok(string.to_owned()),
+        String::clone,
+        Sprache::DEUTSCH,
+    );
+    let argumente = kombiniere!(|a, name| (a, name), a, name);
+    let fälle = [
+        (r#"--name "Max Mustermann" --a"#, (true, "Max Mustermann")),
+        ("--name='mit Leerzeichen'", (false, "mit Leerzeichen")),
+        (r"--a --name Max\ Mustermann", (true, "Max Mustermann")),
+    ];
+    for (zeile, (a, name)) in fälle {
+        match argumente.parse_befehlszeile(zeile) {
+            Ok((Ergebnis::Wert(wert), nicht_verwendet)) => {
+                assert_eq!(wert, (a, name.to_owned()), "Befehlszeile: {}", zeile);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {}: {:?}", zeile, res),
+        }
+    }
+    match argumente.parse_befehlszeile("--a --name 'Max") {
+        Err(OffenesAnführungszeichen { anführungszeichen: '\'', position: 11 }) => {},
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
@@ -0,0 +1,51 @@
+//! Tests für Wert-Argumente mit regulärem Ausdruck.
+
+use std::ffi::OsString;
+
+use kommandozeilen_argumente::{Argumente, Beschreibung, Ergebnis, Fehler, ParseFehler, Sprache};
+
+const MUSTER: &str = "^[a-z][a-z0-9_]*$";
+
+fn name() -> Argumente<'static, String, String> {
+    Argumente::wert_mit_regex_und_sprache(
+        Beschreibung::neu_mit_sprache("name", None::<&str>, None, None, Sprache::DEUTSCH),
+        MUSTER,
+        Sprache::DEUTSCH,
+    )
+    .expect("Gültiger regulärer Ausdruck")
+}
+
+#[test]
+fn passender_wert() {
+    match name().parse(["--name", "abc_12"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, "abc_12");
+            assert!(nicht_verwendet.is_empty(), "{:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn unpassender_wert() {
+    match name().parse(["--name=1abc"].iter().map(OsString::from)) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => match fehler.head {
+            Fehler::Fehler { fehler: ParseFehler::ParseFehler(nachricht), .. } => {
+                assert!(nachricht.contains(MUSTER), "{}", nachricht);
+                assert!(nachricht.contains("1abc"), "{}", nachricht);
+            },
+            fehler => panic!("Unerwarteter Fehler: {:?}", fehler),
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn ungültiges_muster() {
+    let ergebnis: Result<Argumente<'_, String, String>, _> = Argumente::wert_mit_regex_und_sprache(
+        Beschreibung::neu_mit_sprache("name", None::<&str>, None, None, Sprache::DEUTSCH),
+        "[a-z",
+        Sprache::DEUTSCH,
+    );
+    assert!(ergebnis.is_err());
+}
@@ -0,0 +1,94 @@
+//! Tests für das Protokollieren der verwendeten Argumente.
+
+use std::{
+    ffi::OsString,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Event, Metadata, Subscriber,
+};
+
+use kommandozeilen_argumente::{
+    kombiniere, Argumente, Beschreibung, Ergebnis, Sprache, PROTOKOLL_TARGET,
+};
+
+/// Sammelt die Felder aller Events als `name=wert`-Strings.
+#[derive(Debug, Clone, Default)]
+struct Sammler(Arc<Mutex<Vec<Vec<String>>>>);
+
+struct Felder(Vec<String>);
+
+impl Visit for Felder {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.push(format!("{}={:?}", field.name(), value))
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push(format!("{}={}", field.name(), value))
+    }
+}
+
+impl Subscriber for Sammler {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == PROTOKOLL_TARGET
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut felder = Felder(Vec::new());
+        event.record(&mut felder);
+        self.0.lock().expect("Mutex vergiftet").push(felder.0);
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+fn argumente() -> Argumente<'static, (bool, String), String> {
+    let verbose = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache("verbose", "v", None, Some(false), Sprache::DEUTSCH),
+        Sprache::DEUTSCH,
+    );
+    let name = Argumente::wert_from_str_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("name", None::<&str>, None, None, Sprache::DEUTSCH),
+        None,
+        Sprache::DEUTSCH,
+    );
+    kombiniere!(|verbose, name| (verbose, name), verbose, name)
+}
+
+#[test]
+fn events_für_verwendete_argumente() {
+    let sammler = Sammler::default();
+    let args = ["-v", "übrig", "--name", "hallo"];
+    let (ergebnis, nicht_verwendet) = tracing::subscriber::with_default(sammler.clone(), || {
+        argumente().parse_und_protokolliere(args.iter().map(OsString::from))
+    });
+    match ergebnis {
+        Ergebnis::Wert(wert) => assert_eq!(wert, (true, "hallo".to_owned())),
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    assert_eq!(nicht_verwendet, vec![OsString::from("übrig")]);
+    let events = sammler.0.lock().expect("Mutex vergiftet").clone();
+    assert_eq!(
+        events,
+        vec![
+            vec!["message=Argument verwendet", "index=0", "argument=-v"],
+            vec!["message=Argument verwendet", "index=2", "argument=--name"],
+            vec!["message=Argument verwendet", "index=3", "argument=hallo"],
+            vec!["message=Parsen beendet", "ergebnis=Wert", "nicht_verwendet=[\"übrig\"]"],
+        ]
+    );
+}
@@ -0,0 +1,128 @@
+//! Tests für den Probelauf.
+
+use std::{cell::Cell, ffi::OsString};
+
+use kommandozeilen_argumente::{
+    Argumente, Beschreibung, Either, Ergebnis, ParseFehler, Prüfbericht, Sprache,
+};
+
+#[test]
+fn probelauf() {
+    let aufrufe = Cell::new(0);
+    let argumente: Argumente<'_, String, String> = Argumente::wert_mit_aktion_und_sprache(
+        Beschreibung::neu_mit_sprache("plugin", None::<&str>, None, None, Sprache::DEUTSCH),
+        |os_string| {
+            aufrufe.set(aufrufe.get() + 1);
+            let string = os_string.into_string().map_err(ParseFehler::InvaliderString)?;
+            if string == "liste" {
+                Ok(Either::Left("eins, zwei".to_owned()))
+            } else {
+                Ok(Either::Right(string))
+            }
+        },
+        String::clone,
+        Sprache::DEUTSCH,
+    )
+    .version_mit_sprache("programm", "0.1.0", Sprache::DEUTSCH);
+    let prüfe = |args: &[&str]| argumente.prüfe(args.iter().map(OsString::from), Sprache::DEUTSCH);
+    let bericht = prüfe(&["--version"]);
+    assert_eq!(
+        bericht,
+        Prüfbericht {
+            wert: None,
+            verwendet: vec![OsString::from("--version")],
+            frühes_beenden: vec!["programm 0.1.0".to_owned()],
+            aktionen: Vec::new(),
+            // Fehler werden trotz frühem Beenden gemeldet.
+            fehler: vec!["Fehlender Wert: --plugin( |=)WERT".to_owned()],
+            nicht_verwendet: Vec::new(),
+        }
+    );
+    assert!(!bericht.ist_gültig());
+    // Aktionen werden nicht ausgeführt, sondern nur festgehalten.
+    let bericht = prüfe(&["--plugin", "liste"]);
+    assert_eq!(bericht.aktionen, vec!["--plugin liste".to_owned()]);
+    assert!(bericht.frühes_beenden.is_empty(), "{:?}", bericht.frühes_beenden);
+    assert_eq!(bericht.verwendet, vec![OsString::from("--plugin"), OsString::from("liste")]);
+    let bericht = prüfe(&["--plugin=abc"]);
+    assert_eq!(bericht.aktionen, vec!["--plugin abc".to_owned()]);
+    assert_eq!(bericht.wert, None);
+    assert_eq!(aufrufe.get(), 0);
+    let bericht = prüfe(&["übrig"]);
+    assert_eq!(bericht.fehler, vec!["Fehlender Wert: --plugin( |=)WERT".to_owned()]);
+    assert!(bericht.aktionen.is_empty(), "{:?}", bericht.aktionen);
+    assert_eq!(bericht.nicht_verwendet, vec![OsString::from("übrig")]);
+    // Außerhalb eines Probelaufs wird die Aktion weiterhin ausgeführt.
+    match argumente.parse(["--plugin=abc"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, "abc");
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    assert_eq!(aufrufe.get(), 1);
+}
+
+#[test]
+fn probelauf_gültig() {
+    let argumente: Argumente<'_, (bool, String), String> = Argumente::kombiniere2(
+        |verbose, plugin| (verbose, plugin),
+        Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache("verbose", "v", None, Some(false), Sprache::DEUTSCH),
+            Sprache::DEUTSCH,
+        ),
+        Argumente::wert_mit_aktion_und_sprache(
+            Beschreibung::neu_mit_sprache(
+                "plugin",
+                None::<&str>,
+                None,
+                Some("keins".to_owned()),
+                Sprache::DEUTSCH,
+            ),
+            |os_string| {
+                os_string.into_string().map(Either::Right).map_err(ParseFehler::InvaliderString)
+            },
+            String::clone,
+            Sprache::DEUTSCH,
+        ),
+    );
+    let bericht = argumente.prüfe(["-v"].iter().map(OsString::from), Sprache::DEUTSCH);
+    assert_eq!(bericht.wert, Some((true, "keins".to_owned())));
+    assert!(bericht.aktionen.is_empty(), "{:?}", bericht.aktionen);
+    assert!(bericht.ist_gültig());
+}
+
+#[test]
+fn probelauf_aktion_mit_fehler() {
+    let plugin: Argumente<'_, String, String> = Argumente::wert_mit_aktion_und_sprache(
+        Beschreibung::neu_mit_sprache(
+            "liste",
+            None::<&str>,
+            None,
+            Some(String::new()),
+            Sprache::DEUTSCH,
+        ),
+        |os_string| Ok(Either::Left(os_string.to_string_lossy().into_owned())),
+        String::clone,
+        Sprache::DEUTSCH,
+    );
+    let zahl: Argumente<'_, u8, String> = Argumente::wert_from_str_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("zahl", None::<&str>, None, Some(0), Sprache::DEUTSCH),
+        None,
+        Sprache::DEUTSCH,
+    );
+    let argumente = Argumente::kombiniere2(|plugin, zahl| (plugin, zahl), plugin, zahl)
+        .version_mit_sprache("programm", "0.1.0", Sprache::DEUTSCH);
+    let prüfe = |args: &[&str]| argumente.prüfe(args.iter().map(OsString::from), Sprache::DEUTSCH);
+    // Die aufgezeichnete Aktion verdeckt nicht die Fehler anderer Argumente.
+    let bericht = prüfe(&["--liste", "x", "--zahl=abc"]);
+    assert_eq!(bericht.aktionen, vec!["--liste x".to_owned()]);
+    assert_eq!(bericht.fehler.len(), 1, "{:?}", bericht.fehler);
+    assert!(bericht.frühes_beenden.is_empty(), "{:?}", bericht.frühes_beenden);
+    assert_eq!(bericht.wert, None);
+    assert_eq!(bericht.fehler, prüfe(&["--liste=x", "--zahl=abc"]).fehler);
+    // Ebenso ein frühes Beenden.
+    let bericht = prüfe(&["--version", "--zahl=abc"]);
+    assert_eq!(bericht.frühes_beenden, vec!["programm 0.1.0".to_owned()]);
+    assert_eq!(bericht.fehler.len(), 1, "{:?}", bericht.fehler);
+}
@@ -0,0 +1,81 @@
+//! Tests für die Auswahl einer Sprache.
+
+use std::{env, ffi::OsString, iter};
+
+use kommandozeilen_argumente::{Argumente, Beschreibung, Ergebnis, Sprache};
+use void::Void;
+
+#[test]
+fn aus_umgebung() {
+    let fälle = [
+        ((None, None, Some("de_DE.UTF-8")), "Aufruf"),
+        ((None, None, Some("en_US.UTF-8")), "Invocation"),
+        ((None, Some("de_AT"), Some("en_US.UTF-8")), "Aufruf"),
+        ((Some("C"), Some("de_AT"), Some("de_DE")), "Invocation"),
+        ((Some(""), None, Some("de")), "Aufruf"),
+        ((None, None, Some("fr_FR.UTF-8")), "Invocation"),
+        ((None, None, None), "Invocation"),
+    ];
+    for ((lc_all, lc_messages, lang), erwartet) in fälle {
+        for (variable, wert) in [("LC_ALL", lc_all), ("LC_MESSAGES", lc_messages), ("LANG", lang)] {
+            match wert {
+                Some(wert) => env::set_var(variable, wert),
+                None => env::remove_var(variable),
+            }
+        }
+        let sprache = Sprache::aus_umgebung();
+        assert!(
+            sprache.aufruf == erwartet,
+            "{:?}: {}",
+            (lc_all, lc_messages, lang),
+            sprache.aufruf
+        );
+    }
+}
+
+#[test]
+fn englische_namen_deutsche_fehlermeldungen() {
+    const SPRACHE: Sprache = Sprache::DEUTSCH.mit_namen_von(Sprache::ENGLISH);
+    let argumente = || -> Argumente<'static, bool, Void> {
+        Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache(
+                "verbose",
+                None::<&str>,
+                Some("Mehr Ausgaben."),
+                None,
+                SPRACHE,
+            ),
+            SPRACHE,
+        )
+        .version_mit_sprache("programm", "0.1.0", SPRACHE)
+        .hilfe_mit_sprache("programm", None, None, SPRACHE)
+    };
+    match argumente().parse(iter::once(OsString::from("--no-verbose"))) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert!(!wert);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match argumente().parse(iter::empty()) {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            assert_eq!(
+                fehler.head.erstelle_fehlermeldung_mit_sprache(SPRACHE),
+                "Fehlende Flag: --[no-]verbose"
+            );
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let nachrichten = argumente()
+        .frühes_beenden_nachrichten(iter::once(OsString::from("--version")))
+        .expect("Frühes Beenden erwartet.");
+    assert_eq!(nachrichten.head, "programm 0.1.0");
+    let nachrichten = argumente()
+        .frühes_beenden_nachrichten(iter::once(OsString::from("--hilfe")))
+        .expect("Frühes Beenden erwartet.");
+    let hilfe = nachrichten.head;
+    assert!(hilfe.contains("  --[no]-verbose       Mehr Ausgaben."), "{}", hilfe);
+    assert!(hilfe.contains("Zeige die aktuelle Version an."), "{}", hilfe);
+    assert!(hilfe.contains("OPTIONEN"), "{}", hilfe);
+}
@@ -0,0 +1,216 @@
+//! Tests für alleinstehende Präfixe (`-` und `--`).
+
+use std::ffi::OsString;
+
+use void::Void;
+
+use kommandozeilen_argumente::{
+    kombiniere, AlleinstehenderKurzPräfix, Argumente, Beschreibung, Ergebnis, Fehler, ParseFehler,
+    Sprache,
+};
+
+fn flag() -> Argumente<'static, bool, Void> {
+    Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache("flag", "f", None, Some(false), Sprache::DEUTSCH),
+        Sprache::DEUTSCH,
+    )
+}
+
+#[test]
+fn alleinstehende_präfixe_nicht_verwendet() {
+    let args = ["-", "--flag", "--"];
+    match flag().parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert!(wert);
+            assert_eq!(nicht_verwendet, vec![OsString::from("-"), OsString::from("--")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn doppelstrich_als_trenner() {
+    let args = ["-", "--", "--flag", "-", "--"];
+    match flag().mit_trenner("--").parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert!(!wert);
+            let erwartet: Vec<_> = ["-", "--flag", "-", "--"].iter().map(OsString::from).collect();
+            assert_eq!(nicht_verwendet, erwartet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn doppelstrich_als_wert_mit_trenner() {
+    let name = || -> Argumente<'static, String, String> {
+        Argumente::wert_from_str_display_mit_sprache(
+            Beschreibung::neu_mit_sprache("name", None::<&str>, None, None, Sprache::DEUTSCH),
+            None,
+            Sprache::DEUTSCH,
+        )
+        .mit_trenner("--")
+    };
+    // Mit Infix wird `--` als Wert verwendet.
+    match name().parse(["--name=--", "--", "rest"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, "--");
+            assert_eq!(nicht_verwendet, vec![OsString::from("rest")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    // Als eigenes Argument beendet `--` die zu parsenden Argumente, der Wert fehlt.
+    match name().parse(["--name", "--", "rest"].iter().map(OsString::from)) {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            assert_eq!(fehler.len(), 1, "Fehler: {:?}", fehler);
+            assert!(
+                matches!(fehler.head, Fehler::FehlenderWert { .. }),
+                "Fehler: {:?}",
+                fehler.head
+            );
+            assert_eq!(nicht_verwendet, vec![OsString::from("rest")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn alleinstehender_kurz_präfix() {
+    let argumente = |verhalten: Option<AlleinstehenderKurzPräfix>| {
+        let flag = Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache("flag", "f", None, Some(false), Sprache::DEUTSCH),
+            Sprache::DEUTSCH,
+        );
+        let name = Argumente::wert_from_str_display_mit_sprache(
+            Beschreibung::neu_mit_sprache(
+                "name",
+                None::<&str>,
+                None,
+                Some("standard".to_owned()),
+                Sprache::DEUTSCH,
+            ),
+            None,
+            Sprache::DEUTSCH,
+        );
+        let argumente: Argumente<'_, _, String> =
+            kombiniere!(|flag, name| (flag, name), flag, name);
+        match verhalten {
+            Some(verhalten) => argumente.mit_alleinstehendem_kurz_präfix(verhalten),
+            None => argumente,
+        }
+    };
+    let args = ["--name", "-", "--flag", "-"];
+    // Standardmäßig wird `-` nach einem Namen als Wert verwendet.
+    for verhalten in [None, Some(AlleinstehenderKurzPräfix::Stdin)] {
+        match argumente(verhalten).parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert((flag, name)), nicht_verwendet) => {
+                assert!(flag);
+                assert_eq!(name, "-");
+                assert_eq!(nicht_verwendet, vec![OsString::from("-")]);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", verhalten, res),
+        }
+    }
+    // Als nicht verwendetes Argument fehlt der Wert.
+    match argumente(Some(AlleinstehenderKurzPräfix::NichtVerwendet))
+        .parse(args.iter().map(OsString::from))
+    {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            assert_eq!(fehler.len(), 1, "Fehler: {:?}", fehler);
+            assert!(
+                matches!(fehler.head, Fehler::FehlenderWert { .. }),
+                "Fehler: {:?}",
+                fehler.head
+            );
+            assert_eq!(nicht_verwendet, vec![OsString::from("-"), OsString::from("-")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match argumente(Some(AlleinstehenderKurzPräfix::NichtVerwendet))
+        .parse(["-", "--flag"].iter().map(OsString::from))
+    {
+        (Ergebnis::Wert((flag, name)), nicht_verwendet) => {
+            assert!(flag);
+            assert_eq!(name, "standard");
+            assert_eq!(nicht_verwendet, vec![OsString::from("-")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    // Als Wert erzeugt `-` einen Fehler, ansonsten wird er nicht verwendet.
+    match argumente(Some(AlleinstehenderKurzPräfix::Fehler)).parse(args.iter().map(OsString::from))
+    {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            assert_eq!(fehler.len(), 1, "Fehler: {:?}", fehler);
+            match &fehler.head {
+                Fehler::Fehler { namen, fehler: ParseFehler::InvaliderString(wert), .. } => {
+                    assert_eq!(namen.lang.head.as_ref(), "name");
+                    assert_eq!(wert, "-");
+                },
+                fehler => panic!("Unerwarteter Fehler: {:?}", fehler),
+            }
+            assert_eq!(nicht_verwendet, vec![OsString::from("-")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match argumente(Some(AlleinstehenderKurzPräfix::Fehler))
+        .parse(["-", "--flag"].iter().map(OsString::from))
+    {
+        (Ergebnis::Wert((flag, name)), nicht_verwendet) => {
+            assert!(flag);
+            assert_eq!(name, "standard");
+            assert_eq!(nicht_verwendet, vec![OsString::from("-")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    // Nach einem Wert im selben Argument ist `-` kein Wert.
+    match argumente(Some(AlleinstehenderKurzPräfix::Fehler))
+        .parse(["--name=x", "-"].iter().map(OsString::from))
+    {
+        (Ergebnis::Wert((flag, name)), nicht_verwendet) => {
+            assert!(!flag);
+            assert_eq!(name, "x");
+            assert_eq!(nicht_verwendet, vec![OsString::from("-")]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn vollständig_geparst() {
+    for (args, erwartet) in [(&["--flag"][..], true), (&["--flag", "-"], false)] {
+        match flag().parse_vollständig_geparst(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), vollständig) => {
+                assert!(wert);
+                assert_eq!(vollständig, erwartet, "Argumente: {:?}", args);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
+
+#[test]
+fn rest_nach_trenner() {
+    let run = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache("run", "l", None, Some(false), Sprache::DEUTSCH),
+        Sprache::DEUTSCH,
+    );
+    let befehl = Argumente::rest_nach_trenner("--");
+    let argumente: Argumente<'_, _, Void> = kombiniere!(|run, befehl| (run, befehl), run, befehl);
+    let args = ["--run", "--", "ls", "-la"];
+    match argumente.parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert((run, befehl)), nicht_verwendet) => {
+            assert!(run);
+            assert_eq!(befehl, vec![OsString::from("ls"), OsString::from("-la")]);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match argumente.parse(["--run"].iter().map(OsString::from)) {
+        (Ergebnis::Wert((run, befehl)), nicht_verwendet) => {
+            assert!(run);
+            assert!(befehl.is_empty(), "Befehl: {:?}", befehl);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
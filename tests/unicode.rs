@@ -0,0 +1,85 @@
+//! Tests für Unicode-berücksichtigende Vergleiche.
+
+use std::ffi::OsString;
+
+use void::Void;
+
+use kommandozeilen_argumente::{
+    Argumente, Beschreibung, Case, Ergebnis, NonEmpty, Normalisiert, Sprache, Vergleich,
+};
+
+#[test]
+fn diakritika_ignorieren() {
+    let paare = [("café", "cafe"), ("naïve", "naive"), ("über", "uber"), ("Ångström", "Angstrom")];
+    for (mit_akzent, ohne_akzent) in paare {
+        let beachten = Vergleich::from(mit_akzent);
+        assert!(beachten.eq(mit_akzent));
+        assert!(!beachten.eq(ohne_akzent), "{mit_akzent} == {ohne_akzent}");
+        let ignorieren = beachten.ohne_diakritika();
+        assert!(ignorieren.eq(mit_akzent), "{mit_akzent} != {mit_akzent}");
+        assert!(ignorieren.eq(ohne_akzent), "{mit_akzent} != {ohne_akzent}");
+        let umgekehrt = Vergleich::from(ohne_akzent).ohne_diakritika();
+        assert!(umgekehrt.eq(mit_akzent), "{ohne_akzent} != {mit_akzent}");
+    }
+    let case_insensitive = Vergleich::from(("Café", Case::Insensitive)).ohne_diakritika();
+    assert!(case_insensitive.eq("CAFE"));
+    let neu = Vergleich::neu(Normalisiert::neu("Café"), Case::Insensitive);
+    assert_eq!(neu, Vergleich::from(("Café", Case::Insensitive)));
+    assert!(!neu.eq("CAFE"));
+}
+
+#[test]
+fn flag_ohne_diakritika() {
+    let beschreibung = Beschreibung {
+        lang: NonEmpty::singleton(Vergleich::from("café").ohne_diakritika()),
+        ..Beschreibung::neu_mit_sprache("café", None::<&str>, None, Some(false), Sprache::DEUTSCH)
+    };
+    let flag: Argumente<'_, bool, Void> =
+        Argumente::flag_bool_mit_sprache(beschreibung, Sprache::DEUTSCH);
+    for arg in ["--cafe", "--café"] {
+        match flag.parse([OsString::from(arg)].into_iter()) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert!(wert, "{arg}");
+                assert!(nicht_verwendet.is_empty(), "{arg}: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {arg}: {:?}", res),
+        }
+    }
+}
+
+#[test]
+fn ascii_name_mit_kombinierendem_zeichen() {
+    let erzeuge_flag = |vergleich: Vergleich<'static>| {
+        let beschreibung = Beschreibung {
+            lang: NonEmpty::singleton(vergleich),
+            ..Beschreibung::neu_mit_sprache(
+                "cafe",
+                None::<&str>,
+                None,
+                Some(false),
+                Sprache::DEUTSCH,
+            )
+        };
+        Argumente::<'_, bool, Void>::flag_bool_mit_sprache(beschreibung, Sprache::DEUTSCH)
+    };
+    let beachten = erzeuge_flag(Vergleich::from("cafe"));
+    let ignorieren = erzeuge_flag(Vergleich::from("cafe").ohne_diakritika());
+    let case_insensitive = erzeuge_flag(Vergleich::from(("cafe", Case::Insensitive)));
+    // "e" mit kombinierendem Akut wird nach Normalisierung zu "é".
+    let fälle = [
+        (&beachten, "--cafe", true),
+        (&beachten, "--cafe\u{301}", false),
+        (&ignorieren, "--cafe\u{301}", true),
+        (&case_insensitive, "--CAFE", true),
+        (&case_insensitive, "--CAFE\u{301}", false),
+    ];
+    for (flag, arg, erwartet) in fälle {
+        match flag.parse([OsString::from(arg)].into_iter()) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "{arg}");
+                assert_eq!(nicht_verwendet.is_empty(), erwartet, "{arg}: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {arg}: {:?}", res),
+        }
+    }
+}
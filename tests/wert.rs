@@ -0,0 +1,1409 @@
+//! Tests für Wert-Argumente.
+
+use std::{
+    borrow::Cow,
+    collections::BTreeSet,
+    env,
+    ffi::{OsStr, OsString},
+    path::PathBuf,
+    time::Duration,
+};
+
+use kommandozeilen_argumente::{
+    erweitere_umgebungsvariablen_mit_sprache, Argumente, Beschreibung, Duplikate, Either,
+    EnumArgument, Ergebnis, Fehler, LeereElemente, Mehrfach, ParseFehler, Quelle, Sprache,
+    Zahlenformat,
+};
+
+fn parse_string(args: &[&str]) -> (Ergebnis<'static, String, String>, Vec<OsString>) {
+    let arg: Argumente<'_, String, String> = Argumente::wert_from_str_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("name", None::<&str>, None, None, Sprache::DEUTSCH),
+        None,
+        Sprache::DEUTSCH,
+    );
+    arg.parse(args.iter().map(OsString::from))
+}
+
+#[test]
+fn doppelstrich_als_wert() {
+    for args in [&["--name=--"][..], &["--name", "--"]] {
+        match parse_string(args) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, "--", "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
+
+#[test]
+fn stdin_sentinel() {
+    let parse = |args: &[&str]| {
+        let arg: Argumente<'_, Quelle<String>, String> = Argumente::wert_oder_stdin_mit_sprache(
+            Beschreibung::neu_mit_sprache("input", Some("i"), None, None, Sprache::DEUTSCH),
+            "-",
+            None,
+            |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+            String::clone,
+            Sprache::DEUTSCH,
+        );
+        arg.parse(args.iter().map(OsString::from))
+    };
+    let fälle: [(&[&str], Quelle<String>); 5] = [
+        (&["--input", "-"], Quelle::Stdin),
+        (&["--input=-"], Quelle::Stdin),
+        (&["-i", "-"], Quelle::Stdin),
+        (&["--input", "datei.txt"], Quelle::Wert("datei.txt".to_owned())),
+        (&["--input", "--"], Quelle::Wert("--".to_owned())),
+    ];
+    for (args, erwartet) in fälle {
+        match parse(args) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
+
+#[test]
+fn paare_reihenfolge() {
+    let parse = |args: &[&str]| {
+        let arg: Argumente<'_, Vec<(String, String)>, String> = Argumente::wert_paare_mit_sprache(
+            Beschreibung::neu_mit_sprache("header", Some("H"), None, None, Sprache::DEUTSCH),
+            ": ",
+            Sprache::DEUTSCH,
+        );
+        arg.parse(args.iter().map(OsString::from))
+    };
+    let paar = |schlüssel: &str, wert: &str| (schlüssel.to_owned(), wert.to_owned());
+    match parse(&["--header", "A: 1", "-H", "B: 2", "--header=A: 3", "-HC: x: y"]) {
+        (Ergebnis::Wert(paare), nicht_verwendet) => {
+            assert_eq!(
+                paare,
+                vec![paar("A", "1"), paar("B", "2"), paar("A", "3"), paar("C", "x: y")]
+            );
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match parse(&[]) {
+        (Ergebnis::Wert(paare), nicht_verwendet) => {
+            assert!(paare.is_empty(), "{:?}", paare);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match parse(&["--header", "A: 1", "--header", "B=2"]) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+            assert_eq!(fehler.len(), 1, "{:?}", fehler);
+            let fehlermeldung = fehler.head.fehlermeldung();
+            assert!(
+                fehlermeldung.ends_with("Fehlendes Trennzeichen \": \": B=2"),
+                "{fehlermeldung}"
+            );
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn typisierte_paare() {
+    let arg: Argumente<'_, Vec<(String, u32)>, String> =
+        Argumente::wert_typisierte_paare_mit_sprache(
+            Beschreibung::neu_mit_sprache("define", Some("D"), None, None, Sprache::DEUTSCH),
+            "=",
+            Sprache::DEUTSCH,
+        );
+    match arg.parse_aus_strings(["-D", "a=1", "--define=b=2"]) {
+        (Ergebnis::Wert(paare), nicht_verwendet) => {
+            assert_eq!(paare, vec![("a".to_owned(), 1), ("b".to_owned(), 2)]);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match arg.parse_aus_strings(["-D", "a=1", "-D", "b=keine_zahl"]) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+            assert_eq!(fehler.len(), 1, "{:?}", fehler);
+            let fehlermeldung = fehler.head.fehlermeldung();
+            assert!(fehlermeldung.contains("Ungültiger Wert \"keine_zahl\""), "{fehlermeldung}");
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let arg: Argumente<'_, Vec<(u8, String)>, String> =
+        Argumente::wert_typisierte_paare_mit_sprache(
+            Beschreibung::neu_mit_sprache("index", None::<&str>, None, None, Sprache::DEUTSCH),
+            ":",
+            Sprache::DEUTSCH,
+        );
+    match arg.parse_aus_strings(["--index", "x:wert"]) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+            let fehlermeldung = fehler.head.fehlermeldung();
+            assert!(fehlermeldung.contains("Ungültiger Schlüssel \"x\""), "{fehlermeldung}");
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn ausschluss() {
+    let parse = |args: &[&str]| {
+        let arg: Argumente<'_, String, String> = Argumente::wert_mit_ausschluss_und_sprache(
+            Beschreibung::neu_mit_sprache("name", None::<&str>, None, None, Sprache::DEUTSCH),
+            None,
+            vec!["root".to_owned(), "admin".to_owned()],
+            |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+            Sprache::DEUTSCH,
+        );
+        arg.parse(args.iter().map(OsString::from))
+    };
+    match parse(&["--name", "root"]) {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            assert_eq!(fehler.len(), 1, "{:?}", fehler);
+            let fehlermeldung = fehler.head.fehlermeldung();
+            assert!(
+                fehlermeldung.ends_with("Wert nicht erlaubt: \"root\" ∈ {root, admin}"),
+                "{fehlermeldung}"
+            );
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match parse(&["--name", "benutzer"]) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, "benutzer");
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn menge() {
+    let parse = |args: &[&str], duplikate| {
+        let arg: Argumente<'_, BTreeSet<String>, String> = Argumente::wert_menge_mit_sprache(
+            Beschreibung::neu_mit_sprache("features", None::<&str>, None, None, Sprache::DEUTSCH),
+            ',',
+            duplikate,
+            |element| Ok(element.to_owned()),
+            Sprache::DEUTSCH,
+        );
+        arg.parse(args.iter().map(OsString::from))
+    };
+    match parse(&["--features", "a,b,a"], Duplikate::Ignorieren) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, BTreeSet::from(["a".to_owned(), "b".to_owned()]));
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match parse(&["--features", "a,b,a"], Duplikate::Fehler) {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            assert_eq!(fehler.len(), 1, "{:?}", fehler);
+            let fehlermeldung = fehler.head.fehlermeldung();
+            assert!(fehlermeldung.ends_with("Doppeltes Element: a"), "{fehlermeldung}");
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn getrennte_liste() {
+    let parse = |args: &[&str], leere_elemente| {
+        let arg: Argumente<'_, Vec<String>, String> = Argumente::wert_getrennte_liste_mit_sprache(
+            Beschreibung::neu_mit_sprache("tags", None::<&str>, None, None, Sprache::DEUTSCH),
+            ',',
+            leere_elemente,
+            |element| Ok(element.to_owned()),
+            Sprache::DEUTSCH,
+        );
+        arg.parse(args.iter().map(OsString::from))
+    };
+    for leere_elemente in [LeereElemente::Überspringen, LeereElemente::Fehler] {
+        match parse(&["--tags", "a,b,c"], leere_elemente) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, vec!["a", "b", "c"]);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis: {:?}", res),
+        }
+    }
+    match parse(&["--tags", "a,,b,"], LeereElemente::Überspringen) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, vec!["a", "b"]);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match parse(&["--tags", "a,,b"], LeereElemente::Fehler) {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            assert_eq!(fehler.len(), 1, "{:?}", fehler);
+            let fehlermeldung = fehler.head.fehlermeldung();
+            assert!(fehlermeldung.ends_with("Leeres Element: a,,b"), "{fehlermeldung}");
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn getrennte_liste_mehrfach() {
+    let parse = |args: &[&str], leere_elemente| {
+        let arg: Argumente<'_, Vec<String>, String> =
+            Argumente::wert_getrennte_liste_mehrfach_mit_sprache(
+                Beschreibung::neu_mit_sprache("tags", None::<&str>, None, None, Sprache::DEUTSCH),
+                ',',
+                leere_elemente,
+                |element| Ok(element.to_owned()),
+                Sprache::DEUTSCH,
+            );
+        arg.parse(args.iter().map(OsString::from))
+    };
+    match parse(&["--tags", "a,b", "--tags", "c", "--tags=,d,"], LeereElemente::Überspringen) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, vec!["a", "b", "c", "d"]);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match parse(&["--tags", "a,b", "--tags", "c,"], LeereElemente::Fehler) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+            let fehlermeldung = fehler.head.fehlermeldung();
+            assert!(fehlermeldung.ends_with("Leeres Element: c,"), "{fehlermeldung}");
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn normalisierung() {
+    let arg: Argumente<'_, String, String> = Argumente::wert_mit_normalisierung_und_sprache(
+        Beschreibung::neu_mit_sprache("sprache", None::<&str>, None, None, Sprache::DEUTSCH),
+        None,
+        |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+        |wert| match wert.to_lowercase().as_str() {
+            "js" | "javascript" | "ecmascript" => "Javascript".to_owned(),
+            _ => wert,
+        },
+        String::clone,
+        Sprache::DEUTSCH,
+    );
+    for (wert, erwartet) in [
+        ("js", "Javascript"),
+        ("JavaScript", "Javascript"),
+        ("ecmascript", "Javascript"),
+        ("rust", "rust"),
+    ] {
+        match arg.parse(["--sprache", wert].iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", wert, res),
+        }
+    }
+}
+
+#[test]
+fn negierbar() {
+    let parse = |args: &[&str]| {
+        let arg: Argumente<'_, String, String> = Argumente::wert_negierbar_mit_sprache(
+            Beschreibung::neu_mit_sprache(
+                "output",
+                Some("o"),
+                None,
+                Some("standard".to_owned()),
+                Sprache::ENGLISH,
+            ),
+            |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+            String::clone,
+            Sprache::ENGLISH,
+        );
+        arg.parse(args.iter().map(OsString::from))
+    };
+    let fälle: [(&[&str], &str); 4] = [
+        (&["--output", "x"], "x"),
+        (&["--output", "x", "--no-output"], "standard"),
+        (&["-o", "x", "--no-output", "--output=y"], "y"),
+        (&["--no-output"], "standard"),
+    ];
+    for (args, erwartet) in fälle {
+        match parse(args) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
+
+#[test]
+fn mehrfach() {
+    let parse = |args: &[&str], mehrfach| {
+        let arg: Argumente<'_, Vec<String>, String> = Argumente::wert_mehrfach_mit_sprache(
+            Beschreibung::neu_mit_sprache("name", Some("n"), None, None, Sprache::DEUTSCH),
+            mehrfach,
+            |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+            String::clone,
+            Sprache::DEUTSCH,
+        );
+        arg.parse(args.iter().map(OsString::from))
+    };
+    let args = ["--name", "a", "-n", "b", "--name=c"];
+    let fälle: [(Mehrfach, &[&str]); 2] =
+        [(Mehrfach::Ersetze, &["c"]), (Mehrfach::Anhängen, &["a", "b", "c"])];
+    for (mehrfach, erwartet) in fälle {
+        match parse(&args, mehrfach) {
+            (Ergebnis::Wert(werte), nicht_verwendet) => {
+                assert_eq!(werte, erwartet, "{:?}", mehrfach);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", mehrfach, res),
+        }
+    }
+    match parse(&["--name", "a"], Mehrfach::Fehler) {
+        (Ergebnis::Wert(werte), nicht_verwendet) => {
+            assert_eq!(werte, vec!["a"]);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match parse(&args, Mehrfach::Fehler) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+            assert_eq!(fehler.len(), 2, "{:?}", fehler);
+            assert!(
+                fehler.iter().all(|fehler| matches!(fehler, Fehler::MehrfachGenannt { .. })),
+                "{:?}",
+                fehler
+            );
+            let fehlermeldung = fehler.head.fehlermeldung();
+            assert_eq!(fehlermeldung, "Mehrfach genannt: --name( |=)WERT | -n[ |=]WERT");
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn wert_mit_mehrfach() {
+    let parse = |args: &[&str], mehrfach| {
+        let arg: Argumente<'_, String, String> = Argumente::wert_mit_mehrfach_und_sprache(
+            Beschreibung::neu_mit_sprache("name", Some("n"), None, None, Sprache::DEUTSCH),
+            None,
+            mehrfach,
+            |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+            String::clone,
+            Sprache::DEUTSCH,
+        );
+        arg.parse(args.iter().map(OsString::from))
+    };
+    let args = ["--name", "a", "-n", "b"];
+    for mehrfach in [Mehrfach::Ersetze, Mehrfach::Anhängen] {
+        match parse(&args, mehrfach) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, "b", "{:?}", mehrfach);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", mehrfach, res),
+        }
+    }
+    match parse(&["-n", "a"], Mehrfach::Fehler) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, "a");
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match parse(&args, Mehrfach::Fehler) {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            let fehler: Vec<_> = fehler.into_iter().collect();
+            match fehler.as_slice() {
+                [Fehler::MehrfachGenannt { mehrfach_genannt, .. }] => {
+                    assert_eq!(*mehrfach_genannt, Sprache::DEUTSCH.mehrfach_genannt)
+                },
+                _ => panic!("Unerwartete Fehler: {:?}", fehler),
+            }
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn zahl_mit_locale() {
+    let parse = |args: &[&str], zahlenformat| {
+        let arg: Argumente<'_, f64, String> = Argumente::wert_zahl_mit_locale_und_sprache(
+            Beschreibung::neu_mit_sprache("zahl", Some("z"), None, None, Sprache::DEUTSCH),
+            zahlenformat,
+            Sprache::DEUTSCH,
+        );
+        arg.parse(args.iter().map(OsString::from))
+    };
+    let fälle: [(&[&str], Zahlenformat, f64); 4] = [
+        (&["--zahl", "1,5"], Zahlenformat::DEUTSCH, 1.5),
+        (&["--zahl", "1.000,5"], Zahlenformat::DEUTSCH, 1000.5),
+        (&["-z", "-2,25"], Zahlenformat::DEUTSCH, -2.25),
+        (&["--zahl=1.5"], Zahlenformat::default(), 1.5),
+    ];
+    for (args, zahlenformat, erwartet) in fälle {
+        match parse(args, zahlenformat) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+    match parse(&["--zahl", "1,5"], Zahlenformat::STANDARD) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => assert_eq!(fehler.len(), 1),
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    // Gruppierungs-Trennzeichen nur zwischen Gruppen aus drei Ziffern im ganzzahligen Teil.
+    for zahl in ["1.5", "1.2.3", "1.000,0.5", ".000"] {
+        match parse(&["--zahl", zahl], Zahlenformat::DEUTSCH) {
+            (Ergebnis::Fehler(fehler), _nicht_verwendet) => assert!(
+                matches!(
+                    &fehler.head,
+                    Fehler::Fehler { fehler: ParseFehler::ParseFehler(nachricht), .. }
+                        if nachricht.contains("Gruppierung")
+                ),
+                "{:?}",
+                fehler
+            ),
+            res => panic!("Unerwartetes Ergebnis für {}: {:?}", zahl, res),
+        }
+    }
+    match parse(&["--zahl", "-12.345.678,5"], Zahlenformat::DEUTSCH) {
+        (Ergebnis::Wert(wert), _nicht_verwendet) => assert_eq!(wert, -12345678.5),
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn os_str_cow() {
+    let arg: Argumente<'_, String, String> = Argumente::wert_os_str_mit_sprache(
+        Beschreibung::neu_mit_sprache("pfad", Some("p"), None, None, Sprache::DEUTSCH),
+        None,
+        |os_str: Cow<'_, OsStr>| {
+            let art = if matches!(os_str, Cow::Borrowed(_)) { "geliehen" } else { "besessen" };
+            Ok(format!("{art}:{}", os_str.to_string_lossy()))
+        },
+        String::clone,
+        Sprache::DEUTSCH,
+    );
+    let fälle: [(&[&str], &str); 3] = [
+        (&["--pfad", "/tmp/a"], "besessen:/tmp/a"),
+        (&["--pfad=/tmp/b"], "geliehen:/tmp/b"),
+        (&["-p/tmp/c"], "geliehen:/tmp/c"),
+    ];
+    for (args, erwartet) in fälle {
+        match arg.parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
+
+#[test]
+fn umgebungsvariablen() {
+    std::env::set_var("KOMMANDOZEILEN_ARGUMENTE_BASIS", "https://example.org");
+    std::env::remove_var("KOMMANDOZEILEN_ARGUMENTE_UNBEKANNT");
+    let arg: Argumente<'_, String, String> = Argumente::wert_mit_sprache(
+        Beschreibung::neu_mit_sprache("url", None::<&str>, None, None, Sprache::DEUTSCH),
+        None,
+        erweitere_umgebungsvariablen_mit_sprache(
+            |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+            Sprache::DEUTSCH,
+        ),
+        String::clone,
+        Sprache::DEUTSCH,
+    );
+    let fälle: [(&[&str], &str); 3] = [
+        (&["--url", "@env:KOMMANDOZEILEN_ARGUMENTE_BASIS/pfad"], "https://example.org/pfad"),
+        (&["--url=ohne/referenz"], "ohne/referenz"),
+        (&["--url", "name@env:"], "name@env:"),
+    ];
+    for (args, erwartet) in fälle {
+        match arg.parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+    match arg.parse(
+        ["--url", "@env:KOMMANDOZEILEN_ARGUMENTE_UNBEKANNT/pfad"].map(OsString::from).into_iter(),
+    ) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+            let fehlermeldung = fehler.head.fehlermeldung();
+            assert!(
+                fehlermeldung
+                    .ends_with("Unbekannte Umgebungsvariable: KOMMANDOZEILEN_ARGUMENTE_UNBEKANNT"),
+                "{fehlermeldung}"
+            );
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn fehlerbericht() {
+    let anzahl: Argumente<'_, u8, String> = Argumente::wert_from_str_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("anzahl", None::<&str>, None, None, Sprache::DEUTSCH),
+        None,
+        Sprache::DEUTSCH,
+    );
+    let name: Argumente<'_, String, String> = Argumente::wert_from_str_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("name", None::<&str>, None, None, Sprache::DEUTSCH),
+        None,
+        Sprache::DEUTSCH,
+    );
+    let arg = Argumente::kombiniere2(|anzahl, name| (anzahl, name), anzahl, name);
+    let bericht = arg.parse_mit_fehlerbericht(["--anzahl", "x"].map(OsString::from).into_iter());
+    assert!(
+        matches!(
+            bericht.as_slice(),
+            [(anzahl, Fehler::Fehler { .. }), (name, Fehler::FehlenderWert { .. })]
+                if anzahl == "anzahl" && name == "name"
+        ),
+        "{:?}",
+        bericht
+    );
+    let bericht = arg
+        .parse_mit_fehlerbericht(["--anzahl", "3", "--name", "a"].map(OsString::from).into_iter());
+    assert!(bericht.is_empty(), "{:?}", bericht);
+}
+
+#[test]
+fn begrenzt() {
+    let arg: Argumente<'_, Vec<u8>, String> = Argumente::wert_begrenzt_mit_sprache(
+        Beschreibung::neu_mit_sprache("point", None::<&str>, None, None, Sprache::ENGLISH),
+        3,
+        |os_string| {
+            let string = os_string.into_string().map_err(ParseFehler::InvaliderString)?;
+            string.parse().map_err(|fehler: std::num::ParseIntError| {
+                ParseFehler::ParseFehler(fehler.to_string())
+            })
+        },
+        u8::to_string,
+        Sprache::ENGLISH,
+    );
+    let args = ["--point", "1", "--point", "2", "--point", "3"];
+    match arg.parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert(werte), nicht_verwendet) => {
+            assert_eq!(werte, vec![1, 2, 3]);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let args = ["--point", "1", "--point", "2", "--point", "3", "--point", "4"];
+    let bericht = arg.parse_mit_fehlerbericht(args.iter().map(OsString::from));
+    match bericht.as_slice() {
+        [(name, fehler @ Fehler::ZuVieleWerte { maximum: 3, .. })] if name == "point" => {
+            assert_eq!(fehler.error_message(), "Too many values (> 3): --point( |=)VALUE");
+        },
+        _ => panic!("Unerwarteter Fehlerbericht: {:?}", bericht),
+    }
+}
+
+#[test]
+fn verbunden() {
+    let message: Argumente<'_, String, String> = Argumente::wert_verbunden_mit_sprache(
+        Beschreibung::neu_mit_sprache("message", Some("m"), None, None, Sprache::ENGLISH),
+        |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+        String::clone,
+        Sprache::ENGLISH,
+    );
+    let verbose: Argumente<'_, bool, String> = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache("verbose", None::<&str>, None, Some(false), Sprache::ENGLISH),
+        Sprache::ENGLISH,
+    );
+    let arg = Argumente::kombiniere2(|message, verbose| (message, verbose), message, verbose);
+    let fälle: [(&[&str], (&str, bool)); 4] = [
+        (&["--message", "hello", "world", "--verbose"], ("hello world", true)),
+        (&["--verbose", "-m", "hello", "world"], ("hello world", true)),
+        (&["--message=hello", "big", "world"], ("hello big world", false)),
+        (&["--message", "hello world"], ("hello world", false)),
+    ];
+    for (args, (erwartet_message, erwartet_verbose)) in fälle {
+        match arg.parse_aus_strings(args) {
+            (Ergebnis::Wert((message, verbose)), nicht_verwendet) => {
+                assert_eq!(message, erwartet_message, "Argumente: {:?}", args);
+                assert_eq!(verbose, erwartet_verbose, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+    // Bereits von anderen Argumenten verwendete Argumente werden übersprungen.
+    let arg_umgekehrt: Argumente<'_, _, String> = Argumente::kombiniere2(
+        |verbose, message| (message, verbose),
+        Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache(
+                "verbose",
+                None::<&str>,
+                None,
+                Some(false),
+                Sprache::ENGLISH,
+            ),
+            Sprache::ENGLISH,
+        ),
+        Argumente::wert_verbunden_mit_sprache(
+            Beschreibung::neu_mit_sprache("message", Some("m"), None, None, Sprache::ENGLISH),
+            |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+            String::clone,
+            Sprache::ENGLISH,
+        ),
+    );
+    match arg_umgekehrt.parse_aus_strings(["--verbose", "-m", "hello", "world"]) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, ("hello world".to_owned(), true));
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let bericht =
+        arg.parse_mit_fehlerbericht(["--message", "--verbose"].map(OsString::from).into_iter());
+    assert!(
+        matches!(bericht.as_slice(), [(name, Fehler::FehlenderWert { .. })] if name == "message"),
+        "{:?}",
+        bericht
+    );
+}
+
+#[test]
+fn wahrheitswert() {
+    let parse = |args: &[&str], sprache: Sprache| {
+        let arg: Argumente<'_, bool, String> = Argumente::wert_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache("farbe", None::<&str>, None, None, sprache),
+            sprache,
+        );
+        arg.parse(args.iter().map(OsString::from))
+    };
+    let fälle: [(&str, Sprache, bool); 10] = [
+        ("Wahr", Sprache::DEUTSCH, true),
+        ("JA", Sprache::DEUTSCH, true),
+        ("aN", Sprache::DEUTSCH, true),
+        ("Nein", Sprache::DEUTSCH, false),
+        ("AUS", Sprache::DEUTSCH, false),
+        ("True", Sprache::ENGLISH, true),
+        ("Yes", Sprache::ENGLISH, true),
+        ("1", Sprache::ENGLISH, true),
+        ("FALSE", Sprache::ENGLISH, false),
+        ("oFf", Sprache::ENGLISH, false),
+    ];
+    for (wert_arg, sprache, erwartet) in fälle {
+        let args = ["--farbe", wert_arg];
+        match parse(&args, sprache) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+    match parse(&["--farbe=yes"], Sprache::DEUTSCH) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => assert_eq!(fehler.len(), 1),
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn standard_fn() {
+    const UMGEBUNGSVARIABLE: &str = "KOMMANDOZEILEN_ARGUMENTE_STANDARD_DATEI";
+    let datei = std::env::temp_dir().join("kommandozeilen_argumente_standard_fn.txt");
+    std::fs::write(&datei, "aus datei\n").expect("Schreiben der Datei fehlgeschlagen");
+    let parse = |args: &[&str]| {
+        let arg: Argumente<'_, String, String> = Argumente::wert_mit_standard_fn_und_sprache(
+            Beschreibung::neu_mit_sprache("name", None::<&str>, None, None, Sprache::DEUTSCH),
+            None,
+            || {
+                let pfad = std::env::var_os(UMGEBUNGSVARIABLE).ok_or("Kein Pfad")?;
+                let inhalt = std::fs::read_to_string(pfad).map_err(|fehler| fehler.to_string())?;
+                Ok(Some(inhalt.trim().to_owned()))
+            },
+            |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+            String::clone,
+            Sprache::DEUTSCH,
+        );
+        arg.parse(args.iter().map(OsString::from))
+    };
+    std::env::set_var(UMGEBUNGSVARIABLE, &datei);
+    match parse(&["--name", "explizit"]) {
+        (Ergebnis::Wert(wert), _nicht_verwendet) => assert_eq!(wert, "explizit"),
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match parse(&[]) {
+        (Ergebnis::Wert(wert), _nicht_verwendet) => assert_eq!(wert, "aus datei"),
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    std::env::set_var(UMGEBUNGSVARIABLE, datei.with_extension("fehlt"));
+    match parse(&[]) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => match fehler.head {
+            Fehler::Fehler { fehler: ParseFehler::ParseFehler(_), .. } => {},
+            fehler => panic!("Unerwarteter Fehler: {:?}", fehler),
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let _ = std::fs::remove_file(datei);
+}
+
+#[test]
+fn dauer() {
+    let arg: Argumente<'_, Duration, String> = Argumente::wert_dauer_mit_sprache(
+        Beschreibung::neu_mit_sprache(
+            "timeout",
+            None::<&str>,
+            None,
+            Some(Duration::from_secs(90)),
+            Sprache::DEUTSCH,
+        ),
+        Sprache::DEUTSCH,
+    );
+    for (wert, erwartet) in [
+        ("2h30m", Duration::from_secs(9000)),
+        ("500ms", Duration::from_millis(500)),
+        ("1d", Duration::from_secs(86_400)),
+        ("1m 30s", Duration::from_secs(90)),
+        ("10us", Duration::from_micros(10)),
+        ("10µs", Duration::from_micros(10)),
+        ("3ns", Duration::from_nanos(3)),
+    ] {
+        match arg.parse(["--timeout", wert].iter().map(OsString::from)) {
+            (Ergebnis::Wert(dauer), nicht_verwendet) => {
+                assert_eq!(dauer, erwartet, "{}", wert);
+                assert!(nicht_verwendet.is_empty(), "{:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis: {:?}", res),
+        }
+    }
+    for ungültig in ["", "5", "h", "2x", "1.5h"] {
+        match arg.parse(["--timeout", ungültig].iter().map(OsString::from)) {
+            (Ergebnis::Fehler(fehler), _nicht_verwendet) => match fehler.head {
+                Fehler::Fehler { fehler: ParseFehler::ParseFehler(_), .. } => {},
+                fehler => panic!("Unerwarteter Fehler: {:?}", fehler),
+            },
+            res => panic!("Unerwartetes Ergebnis: {:?}", res),
+        }
+    }
+    let hilfe_text = arg.hilfe_text("programm", None, None);
+    assert!(hilfe_text.contains("--timeout(=| )DAUER"), "Unerwarteter Hilfe-Text: {}", hilfe_text);
+    assert!(hilfe_text.contains("[Standard: 1m30s]"), "Unerwarteter Hilfe-Text: {}", hilfe_text);
+}
+
+#[test]
+fn fehlender_und_leerer_wert() {
+    let zahl = || -> Argumente<'static, i32, String> {
+        Argumente::wert_from_str_display_mit_sprache(
+            Beschreibung::neu_mit_sprache("port", "p", None, Some(80), Sprache::DEUTSCH),
+            None,
+            Sprache::DEUTSCH,
+        )
+    };
+    for args in [&["--port"][..], &["-p"]] {
+        match zahl().parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+                assert!(
+                    matches!(fehler.head, Fehler::FehlenderWert { .. }),
+                    "Argumente: {:?}, Fehler: {:?}",
+                    args,
+                    fehler
+                );
+                assert!(fehler.tail.is_empty(), "{:?}", fehler);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+    for args in [&["--port="][..], &["-p="]] {
+        match zahl().parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+                assert!(
+                    matches!(fehler.head, Fehler::Fehler { .. }),
+                    "Argumente: {:?}, Fehler: {:?}",
+                    args,
+                    fehler
+                );
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+    for args in [&["--name="][..], &["--name", ""]] {
+        match parse_string(args) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, "", "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
+
+#[test]
+fn wert_und_negierbar_identisch() {
+    let parse = |os_string: OsString| -> Result<i32, ParseFehler<String>> {
+        os_string.into_string().map_err(ParseFehler::InvaliderString).and_then(|string| {
+            string.parse().map_err(|fehler| ParseFehler::ParseFehler(format!("{fehler}")))
+        })
+    };
+    let beschreibung = || Beschreibung::neu_mit_sprache("n", "n", None, Some(3), Sprache::DEUTSCH);
+    let wert = || {
+        Argumente::wert_mit_sprache(beschreibung(), None, parse, i32::to_string, Sprache::DEUTSCH)
+    };
+    let negierbar = || {
+        Argumente::wert_negierbar_mit_sprache(
+            beschreibung(),
+            parse,
+            i32::to_string,
+            Sprache::DEUTSCH,
+        )
+    };
+    let fälle: [&[&str]; 14] = [
+        &[],
+        &["--n=5"],
+        &["--n", "5"],
+        &["-n5"],
+        &["-n=5"],
+        &["-n", "5"],
+        &["--n", "-5"],
+        &["--n=-5"],
+        &["-n-5"],
+        &["--n"],
+        &["-n"],
+        &["--n="],
+        &["--n", "x"],
+        &["--n", "5", "--andere", "-n"],
+    ];
+    for args in fälle {
+        let ergebnis_wert = format!("{:?}", wert().parse(args.iter().map(OsString::from)));
+        let ergebnis_negierbar =
+            format!("{:?}", negierbar().parse(args.iter().map(OsString::from)));
+        assert_eq!(ergebnis_wert, ergebnis_negierbar, "Argumente: {:?}", args);
+    }
+}
+
+#[test]
+fn arität() {
+    let arg: Argumente<'_, Vec<u32>, String> = Argumente::wert_mit_arität_und_sprache(
+        Beschreibung::neu_mit_sprache("size", None::<&str>, None, None, Sprache::ENGLISH),
+        2,
+        |os_string| {
+            let string = os_string.into_string().map_err(ParseFehler::InvaliderString)?;
+            string.parse().map_err(|fehler: std::num::ParseIntError| {
+                ParseFehler::ParseFehler(fehler.to_string())
+            })
+        },
+        u32::to_string,
+        Sprache::ENGLISH,
+    );
+    let fälle: [(&[&str], Vec<u32>, &[&str]); 3] = [
+        (&["--size", "800", "600"], vec![800, 600], &[]),
+        (&["--size=800", "600", "400"], vec![800, 600], &["400"]),
+        (&["--size", "1", "2", "--size", "3", "4"], vec![3, 4], &[]),
+    ];
+    for (args, erwartet, erwartet_nicht_verwendet) in fälle {
+        match arg.parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(werte), nicht_verwendet) => {
+                assert_eq!(werte, erwartet, "Argumente: {:?}", args);
+                assert_eq!(nicht_verwendet, erwartet_nicht_verwendet, "Argumente: {:?}", args);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+    match arg.parse(["--size", "800"].iter().map(OsString::from)) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => assert_eq!(
+            fehler.head.error_message(),
+            "Parse Error: --size( |=)VALUE\nExpected number of values: 2"
+        ),
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    // Folgende Argumente werden unabhängig von einem Präfix als Wert verwendet.
+    match arg.parse(["--size", "800", "--verbose"].iter().map(OsString::from)) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+            assert!(matches!(fehler.head, Fehler::Fehler { .. }), "{:?}", fehler)
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    // Bereits von anderen Argumenten verwendete Argumente sind kein Name.
+    let verbose: Argumente<'_, bool, String> = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache("verbose", None::<&str>, None, Some(false), Sprache::ENGLISH),
+        Sprache::ENGLISH,
+    );
+    let kombiniert = Argumente::kombiniere2(|verbose, size| (verbose, size), verbose, arg);
+    match kombiniert.parse(["--verbose", "1", "2", "--size", "3", "4"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, (true, vec![3, 4]));
+            assert_eq!(nicht_verwendet, vec!["1", "2"]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum EigenerFehler {
+    Nachricht(String),
+}
+
+#[test]
+fn konvertiere_fehler() {
+    let arg: Argumente<'_, u8, String> = Argumente::wert_from_str_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("zahl", None::<&str>, None, None, Sprache::DEUTSCH),
+        None,
+        Sprache::DEUTSCH,
+    );
+    let (ergebnis, _nicht_verwendet) = arg.parse(["--zahl", "x"].iter().map(OsString::from));
+    match ergebnis.konvertiere_fehler(EigenerFehler::Nachricht) {
+        Ergebnis::Fehler(fehler) => match fehler.head {
+            Fehler::Fehler {
+                fehler: ParseFehler::ParseFehler(EigenerFehler::Nachricht(_)),
+                ..
+            } => {},
+            fehler => panic!("Unerwarteter Fehler: {:?}", fehler),
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let (ergebnis, _nicht_verwendet) = arg.parse(["--zahl", "5"].iter().map(OsString::from));
+    match ergebnis.konvertiere_fehler(EigenerFehler::Nachricht) {
+        Ergebnis::Wert(5) => {},
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stufe {
+    Niedrig,
+    Hoch,
+}
+
+impl std::fmt::Display for Stufe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Stufe::Niedrig => "Eine niedrige Stufe",
+            Stufe::Hoch => "Eine hohe Stufe",
+        })
+    }
+}
+
+impl EnumArgument for Stufe {
+    fn varianten() -> Vec<Self> {
+        vec![Stufe::Niedrig, Stufe::Hoch]
+    }
+
+    fn parse_enum(arg: OsString) -> Result<Self, ParseFehler<String>> {
+        Err(ParseFehler::InvaliderString(arg))
+    }
+}
+
+#[test]
+fn enum_mit_namen() {
+    let arg: Argumente<'_, Stufe, String> = Argumente::wert_enum_mit_namen_und_sprache(
+        Beschreibung::neu_mit_sprache("stufe", None::<&str>, None, None, Sprache::DEUTSCH),
+        |stufe| match stufe {
+            Stufe::Niedrig => "lo".to_owned(),
+            Stufe::Hoch => "hi".to_owned(),
+        },
+        Sprache::DEUTSCH,
+    );
+    match arg.parse(["--stufe", "hi"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, Stufe::Hoch);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match arg.parse(["--stufe", "Eine hohe Stufe"].iter().map(OsString::from)) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+            let fehlermeldung = fehler.head.fehlermeldung();
+            assert!(
+                fehlermeldung.ends_with("Wert nicht erlaubt: \"Eine hohe Stufe\" ∉ {lo, hi}"),
+                "{fehlermeldung}"
+            );
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let hilfe_text = arg.hilfe_text("programm", None, None);
+    assert!(hilfe_text.contains("lo, hi"), "{hilfe_text}");
+    assert!(!hilfe_text.contains("Stufe"), "{hilfe_text}");
+}
+
+#[test]
+fn optional_mit_standard() {
+    let arg: Argumente<'_, u32, String> = Argumente::wert_optional_mit_standard_und_sprache(
+        Beschreibung::neu_mit_sprache("jobs", "j", None, Some(1), Sprache::DEUTSCH),
+        8,
+        |os_string| {
+            let string = os_string.into_string().map_err(ParseFehler::InvaliderString)?;
+            string.parse().map_err(|fehler: std::num::ParseIntError| {
+                ParseFehler::ParseFehler(fehler.to_string())
+            })
+        },
+        u32::to_string,
+        Sprache::DEUTSCH,
+    );
+    let fälle: [(&[&str], u32, &[&str]); 6] = [
+        (&[], 1, &[]),
+        (&["-j"], 8, &[]),
+        (&["-j4"], 4, &[]),
+        (&["--jobs=4"], 4, &[]),
+        (&["--jobs", "4"], 8, &["4"]),
+        (&["-j4", "-j"], 8, &[]),
+    ];
+    for (args, erwartet, erwartet_nicht_verwendet) in fälle {
+        match arg.parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Argumente: {:?}", args);
+                assert_eq!(nicht_verwendet, erwartet_nicht_verwendet, "Argumente: {:?}", args);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
+
+#[test]
+fn ergebnis() {
+    let arg: Argumente<'_, Result<u32, String>, String> = Argumente::wert_ergebnis_mit_sprache(
+        Beschreibung::neu_mit_sprache("anzahl", None::<&str>, None, Some(1), Sprache::DEUTSCH),
+        |os_string| {
+            let string = os_string.to_string_lossy();
+            string.parse().map_err(|_| format!("Keine Zahl: {string}"))
+        },
+        u32::to_string,
+        Sprache::DEUTSCH,
+    );
+    match arg.parse(["--anzahl", "3"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(Ok(3)), nicht_verwendet) if nicht_verwendet.is_empty() => {},
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match arg.parse(["--anzahl=drei"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(Err(fehler)), nicht_verwendet) if nicht_verwendet.is_empty() => {
+            assert_eq!(fehler, "Keine Zahl: drei")
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match arg.parse(std::iter::empty()) {
+        (Ergebnis::Wert(Ok(1)), nicht_verwendet) if nicht_verwendet.is_empty() => {},
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn ohne_verklebte_kurzwerte() {
+    let flag = |name, kurz| {
+        Argumente::flag_bool_mit_sprache(
+            Beschreibung::neu_mit_sprache(name, kurz, None, Some(false), Sprache::DEUTSCH),
+            Sprache::DEUTSCH,
+        )
+    };
+    let ausgabe: Argumente<'_, String, String> = Argumente::wert_from_str_display_mit_sprache(
+        Beschreibung::neu_mit_sprache(
+            "ausgabe",
+            "o",
+            None,
+            Some("standard".to_owned()),
+            Sprache::DEUTSCH,
+        ),
+        None,
+        Sprache::DEUTSCH,
+    );
+    let arg =
+        Argumente::kombiniere3(|o, v, a| (o, v, a), ausgabe, flag("vv", "v"), flag("aa", "a"))
+            .ohne_verklebte_kurzwerte();
+    for args in [&["-o", "va"][..], &["-o=va"]] {
+        match arg.parse_aus_strings(args) {
+            (Ergebnis::Wert((ausgabe, false, false)), nicht_verwendet) => {
+                assert_eq!(ausgabe, "va", "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+    match arg.parse_aus_strings(["-ova", "-va"]) {
+        (Ergebnis::Wert((ausgabe, true, true)), nicht_verwendet) => {
+            assert_eq!(ausgabe, "standard");
+            assert_eq!(nicht_verwendet, vec!["-ova"]);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn leerer_wert_optional() {
+    let name: Argumente<'_, String, String> = Argumente::wert_optional_mit_standard_und_sprache(
+        Beschreibung::neu_mit_sprache(
+            "name",
+            "n",
+            None,
+            Some("standard".to_owned()),
+            Sprache::DEUTSCH,
+        ),
+        "ohne".to_owned(),
+        |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+        String::clone,
+        Sprache::DEUTSCH,
+    );
+    for args in [&["--name="][..], &["-n="]] {
+        match name.parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, "", "Argumente: {:?}", args);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+    let jobs: Argumente<'_, u32, String> = Argumente::wert_optional_mit_standard_und_sprache(
+        Beschreibung::neu_mit_sprache("jobs", "j", None, Some(1), Sprache::DEUTSCH),
+        8,
+        |os_string| {
+            let string = os_string.into_string().map_err(ParseFehler::InvaliderString)?;
+            string.parse().map_err(|fehler: std::num::ParseIntError| {
+                ParseFehler::ParseFehler(fehler.to_string())
+            })
+        },
+        u32::to_string,
+        Sprache::DEUTSCH,
+    );
+    for args in [&["--jobs="][..], &["-j="]] {
+        match jobs.parse(args.iter().map(OsString::from)) {
+            (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+                assert!(
+                    matches!(
+                        fehler.head,
+                        Fehler::Fehler { fehler: ParseFehler::ParseFehler(_), .. }
+                    ),
+                    "Argumente: {:?}, Fehler: {:?}",
+                    args,
+                    fehler
+                );
+                assert!(fehler.tail.is_empty(), "{:?}", fehler);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", args, res),
+        }
+    }
+}
+
+#[test]
+fn mit_aktion() {
+    let arg: Argumente<'_, String, String> = Argumente::wert_mit_aktion_und_sprache(
+        Beschreibung::neu_mit_sprache("plugin", None::<&str>, None, None, Sprache::DEUTSCH),
+        |os_string| {
+            let string = os_string.into_string().map_err(ParseFehler::InvaliderString)?;
+            if string == "liste" {
+                Ok(Either::Left("eins, zwei".to_owned()))
+            } else {
+                Ok(Either::Right(string))
+            }
+        },
+        String::clone,
+        Sprache::DEUTSCH,
+    );
+    match arg.parse_aus_strings(["--plugin", "eins"]) {
+        (Ergebnis::Wert(wert), nicht_verwendet) if nicht_verwendet.is_empty() => {
+            assert_eq!(wert, "eins")
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match arg.parse_aus_strings(["--plugin=liste"]) {
+        (Ergebnis::FrühesBeenden(nachrichten), nicht_verwendet) if nicht_verwendet.is_empty() => {
+            assert_eq!(nachrichten.head, "eins, zwei");
+            assert!(nachrichten.tail.is_empty(), "{:?}", nachrichten);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match arg.parse_aus_strings(["--plugin"]) {
+        (Ergebnis::Fehler(fehler), _nicht_verwendet) => {
+            assert!(matches!(fehler.head, Fehler::FehlenderWert { .. }), "{:?}", fehler)
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn geliehene_werte() {
+    const FARBEN: &[&str] = &["rot", "grün", "blau"];
+    let argumente = || -> Argumente<'static, &'static str, String> {
+        Argumente::wert_mit_geliehenen_werten_und_sprache(
+            Beschreibung::neu_mit_sprache("farbe", None::<&str>, None, None, Sprache::DEUTSCH),
+            FARBEN,
+            |os_string| {
+                FARBEN
+                    .iter()
+                    .find(|farbe| os_string == **farbe)
+                    .copied()
+                    .ok_or(ParseFehler::ParseFehler(format!("{os_string:?}")))
+            },
+            |farbe| farbe.to_string(),
+            Sprache::DEUTSCH,
+        )
+    };
+    match argumente().parse(["--farbe", "grün"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, "grün");
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let nachrichten = argumente()
+        .hilfe_mit_sprache("programm", None, None, Sprache::DEUTSCH)
+        .frühes_beenden_nachrichten(["--hilfe"].iter().map(OsString::from))
+        .expect("Frühes Beenden erwartet.");
+    assert!(nachrichten.head.contains("Erlaubte Werte: rot, grün, blau"), "{}", nachrichten.head);
+}
+
+#[test]
+fn fehlermeldung_fehlend() {
+    let konfig: Argumente<'_, String, String> = Argumente::wert_from_str_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("config", None::<&str>, None, None, Sprache::DEUTSCH),
+        None,
+        Sprache::DEUTSCH,
+    )
+    .fehlermeldung_fehlend("--config muss angegeben werden, siehe --hilfe");
+    let name: Argumente<'_, String, String> = Argumente::wert_from_str_display_mit_sprache(
+        Beschreibung::neu_mit_sprache("name", None::<&str>, None, None, Sprache::DEUTSCH),
+        None,
+        Sprache::DEUTSCH,
+    );
+    let argumente = Argumente::kombiniere2(|konfig, name| (konfig, name), konfig, name);
+    match argumente.parse(std::iter::empty()) {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            let fehlermeldungen: Vec<_> = fehler.iter().map(Fehler::fehlermeldung).collect();
+            assert_eq!(
+                fehlermeldungen,
+                vec![
+                    "--config muss angegeben werden, siehe --hilfe",
+                    "Fehlender Wert: --name( |=)WERT"
+                ]
+            );
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    match argumente.parse(["--config=datei", "--name", "x"].iter().map(OsString::from)) {
+        (Ergebnis::Wert(wert), nicht_verwendet) => {
+            assert_eq!(wert, ("datei".to_owned(), "x".to_owned()));
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    // Ein genannter Name ohne Wert erzeugt weiterhin die generische Fehlermeldung.
+    match argumente.parse(["--name", "x", "--config"].iter().map(OsString::from)) {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            assert!(
+                matches!(
+                    fehler.head,
+                    Fehler::FehlenderWert { genannt: true, fehlermeldung: None, .. }
+                ),
+                "{:?}",
+                fehler
+            );
+            assert_eq!(fehler.head.fehlermeldung(), "Fehlender Wert: --config( |=)WERT");
+            assert!(fehler.tail.is_empty(), "{:?}", fehler);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn negierbar_exakter_name() {
+    let ausgabe: Argumente<'_, String, String> = Argumente::wert_negierbar_mit_sprache(
+        Beschreibung::neu_mit_sprache(
+            "ausgabe",
+            None::<&str>,
+            None,
+            Some("standard".to_owned()),
+            Sprache::DEUTSCH,
+        ),
+        |os_string| os_string.into_string().map_err(ParseFehler::InvaliderString),
+        String::clone,
+        Sprache::DEUTSCH,
+    );
+    let kein_ausgabe: Argumente<'_, bool, String> = Argumente::flag_bool_mit_sprache(
+        Beschreibung::neu_mit_sprache(
+            "kein-ausgabe",
+            None::<&str>,
+            None,
+            Some(false),
+            Sprache::DEUTSCH,
+        ),
+        Sprache::DEUTSCH,
+    );
+    let argumente = Argumente::kombiniere2(
+        |ausgabe, kein_ausgabe| (ausgabe, kein_ausgabe),
+        ausgabe,
+        kein_ausgabe,
+    );
+    let args = ["--ausgabe", "x", "--kein-ausgabe"];
+    match argumente.parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Wert((ausgabe, kein_ausgabe)), nicht_verwendet) => {
+            assert_eq!(ausgabe, "x");
+            assert!(kein_ausgabe);
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+}
+
+#[test]
+fn vec_validiert() {
+    let port: Argumente<'_, Vec<u32>, String> = Argumente::wert_vec_validiert_mit_sprache(
+        Beschreibung::neu_mit_sprache("port", None::<&str>, None, None, Sprache::DEUTSCH),
+        |port| {
+            if (1..=65535).contains(port) {
+                Ok(())
+            } else {
+                Err(format!("Ungültiger Port {port}"))
+            }
+        },
+        |os_string| {
+            os_string.into_string().map_err(ParseFehler::InvaliderString).and_then(|string| {
+                string.parse().map_err(|fehler| ParseFehler::ParseFehler(format!("{fehler}")))
+            })
+        },
+        u32::to_string,
+        Sprache::DEUTSCH,
+    );
+    let args = ["--port", "80", "--port", "99999", "--port", "x"];
+    match port.parse(args.iter().map(OsString::from)) {
+        (Ergebnis::Fehler(fehler), nicht_verwendet) => {
+            let fehlermeldungen: Vec<_> = fehler.iter().map(Fehler::fehlermeldung).collect();
+            assert_eq!(fehlermeldungen.len(), 2, "{fehlermeldungen:?}");
+            assert!(fehlermeldungen[0].ends_with("Ungültiger Port 99999"), "{fehlermeldungen:?}");
+            assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+        },
+        res => panic!("Unerwartetes Ergebnis: {:?}", res),
+    }
+    let (werte, fehler, nicht_verwendet) = port.parse_nachsichtig(args.iter().map(OsString::from));
+    assert_eq!(werte, Some(vec![80]));
+    assert_eq!(fehler.len(), 2, "{fehler:?}");
+    assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+}
+
+#[test]
+fn pfad_relativ_zu() {
+    let basis = env::temp_dir().join("konfiguration");
+    let arg: Argumente<'_, PathBuf, String> = Argumente::wert_pfad_relativ_zu_mit_sprache(
+        Beschreibung::neu_mit_sprache("pfad", None::<&str>, None, None, Sprache::DEUTSCH),
+        basis.clone(),
+        Sprache::DEUTSCH,
+    );
+    let absolut = env::temp_dir().join("absolut.txt");
+    let fälle = [
+        (OsString::from("daten/eingabe.txt"), basis.join("daten").join("eingabe.txt")),
+        (absolut.clone().into_os_string(), absolut),
+    ];
+    for (pfad, erwartet) in fälle {
+        match arg.parse([OsString::from("--pfad"), pfad.clone()].into_iter()) {
+            (Ergebnis::Wert(wert), nicht_verwendet) => {
+                assert_eq!(wert, erwartet, "Pfad: {:?}", pfad);
+                assert!(nicht_verwendet.is_empty(), "Nicht verwendet: {:?}", nicht_verwendet);
+            },
+            res => panic!("Unerwartetes Ergebnis für {:?}: {:?}", pfad, res),
+        }
+    }
+}